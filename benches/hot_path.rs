@@ -0,0 +1,60 @@
+// Hot paths of ingestion services. Compare with `--no-default-features` to see the effect of
+// the `scratch-buffers` feature.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use phonelib::{normalize_phone_number, Parser};
+
+const PHONE_NUMBERS: [&str; 4] = [
+    "+1 (202) 555-0173",
+    "+44 7012 345678",
+    "00 961 79 123 123",
+    "+33 6 12 34 56 78",
+];
+
+fn normalize(c: &mut Criterion) {
+    c.bench_function("normalize_phone_number", |b| {
+        b.iter(|| {
+            for phone_number in PHONE_NUMBERS {
+                black_box(normalize_phone_number(black_box(phone_number)));
+            }
+        })
+    });
+
+    let parser = Parser::new();
+    c.bench_function("Parser::normalize", |b| {
+        b.iter(|| {
+            for phone_number in PHONE_NUMBERS {
+                black_box(parser.normalize(black_box(phone_number)));
+            }
+        })
+    });
+}
+
+fn validate(c: &mut Criterion) {
+    let parser = Parser::new();
+    c.bench_function("Parser::is_valid", |b| {
+        b.iter(|| {
+            for phone_number in PHONE_NUMBERS {
+                black_box(parser.is_valid(black_box(phone_number)));
+            }
+        })
+    });
+    c.bench_function("Parser::extract_country", |b| {
+        b.iter(|| {
+            for phone_number in PHONE_NUMBERS {
+                black_box(parser.extract_country(black_box(phone_number)));
+            }
+        })
+    });
+    #[cfg(feature = "unstable")]
+    c.bench_function("is_do_not_originate", |b| {
+        b.iter(|| {
+            for phone_number in PHONE_NUMBERS {
+                black_box(phonelib::is_do_not_originate(black_box(phone_number)));
+            }
+        })
+    });
+}
+
+criterion_group!(benches, normalize, validate);
+criterion_main!(benches);
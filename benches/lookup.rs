@@ -0,0 +1,94 @@
+// Metadata lookups of the public functions, per input class, to measure optimizations of
+// the country and pattern lookups. Save a baseline before a change and compare after it:
+//
+//     cargo bench --bench lookup -- --save-baseline before
+//     cargo bench --bench lookup -- --baseline before
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use phonelib::{
+    extract_country, format_phone_number, is_valid_phone_number, normalize_phone_number,
+    PhoneFormat,
+};
+
+// (class, inputs): the short numbers of one-digit calling codes, the long numbers of
+// three-digit calling codes written with separators and extensions, and invalid inputs,
+// which are rejected after scanning every candidate country
+const INPUTS: &[(&str, &[&str])] = &[
+    (
+        "short",
+        &[
+            "+12025550173",
+            "+447012345678",
+            "+33612345678",
+            "+74951234567",
+        ],
+    ),
+    (
+        "long",
+        &[
+            "00 961 (79) 123-123 ext. 45",
+            "+971 50 123 4567 x 12",
+            "+880 1712-345678",
+            "+1 (242) 357-1234 ext. 1234",
+        ],
+    ),
+    (
+        "invalid",
+        &[
+            "+999 123 456",
+            "+33 6 12",
+            "invalid_phone_number",
+            "+961 79 123 123 123 123",
+        ],
+    ),
+];
+
+fn bench_per_class<O>(c: &mut Criterion, name: &str, function: impl Fn(&str) -> O) {
+    let mut group = c.benchmark_group(name);
+    for (class, inputs) in INPUTS {
+        group.bench_with_input(BenchmarkId::from_parameter(class), inputs, |b, inputs| {
+            b.iter(|| {
+                for phone_number in *inputs {
+                    black_box(function(black_box(phone_number)));
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+fn validation(c: &mut Criterion) {
+    bench_per_class(c, "is_valid_phone_number", |phone_number| {
+        is_valid_phone_number(phone_number)
+    });
+}
+
+fn normalization(c: &mut Criterion) {
+    bench_per_class(c, "normalize_phone_number", |phone_number| {
+        normalize_phone_number(phone_number)
+    });
+}
+
+fn country_extraction(c: &mut Criterion) {
+    bench_per_class(c, "extract_country", |phone_number| {
+        extract_country(phone_number)
+    });
+}
+
+fn formatting(c: &mut Criterion) {
+    bench_per_class(c, "format_phone_number/international", |phone_number| {
+        format_phone_number(phone_number, PhoneFormat::International)
+    });
+    bench_per_class(c, "format_phone_number/national", |phone_number| {
+        format_phone_number(phone_number, PhoneFormat::National)
+    });
+}
+
+criterion_group!(
+    benches,
+    validation,
+    normalization,
+    country_extraction,
+    formatting
+);
+criterion_main!(benches);
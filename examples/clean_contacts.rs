@@ -0,0 +1,114 @@
+//! Cleans an address book export: every contact's number is parsed, read in the contact's
+//! region when it is written in national form, deduplicated and written back as CSV with
+//! its E.164 and international forms.
+//!
+//! ```text
+//! cargo run --example clean_contacts -- contacts.csv > cleaned.csv
+//! ```
+//!
+//! The input has a `name,phone,region` header, the region (an ISO 3166 code) being
+//! optional. Without a file a small sample is cleaned. Rejected lines are reported on
+//! stderr.
+
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::process::ExitCode;
+
+use phonelib::{PhoneError, PhoneFormat, PhoneNumber, E164};
+
+const SAMPLE: &str = "\
+name,phone,region
+Ada Lovelace,020 7946 0018,GB
+\"Lovelace, Ada\",+44 20 7946 0018,
+Grace Hopper,(212) 555-0123 ext. 42,US
+Alan Turing,+44 7911 123456,
+Marie Curie,06 12 34 56 78,FR
+Nikola Tesla,not a number,
+";
+
+fn main() -> ExitCode {
+    let input = match env::args().nth(1) {
+        Some(path) => match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("can't read {}: {}", path, error);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => SAMPLE.to_string(),
+    };
+
+    println!("name,e164,international,region,calling_code,type,extension");
+    // the first line of every number, to report the duplicates
+    let mut seen: HashMap<E164, usize> = HashMap::new();
+    for (index, line) in input.lines().enumerate().skip(1) {
+        let line_number = index + 1;
+        let fields = split_csv_line(line);
+        let field = |index: usize| fields.get(index).map_or("", |field| field.trim());
+        let (name, phone, region) = (field(0), field(1), field(2));
+
+        let number = match parse(phone, region) {
+            Ok(number) => number,
+            Err(error) => {
+                eprintln!("line {}: {:?}: {}", line_number, phone, error);
+                continue;
+            }
+        };
+        if let Some(first) = seen.get(&number.e164()) {
+            eprintln!("line {}: duplicate of line {}", line_number, first);
+            continue;
+        }
+        seen.insert(number.e164(), line_number);
+
+        let country = number.country();
+        let record = [
+            name,
+            &number.e164(),
+            &number.format(PhoneFormat::International),
+            country.code,
+            &country.calling_code().to_string(),
+            number.number_type().as_str(),
+            number.extension().unwrap_or_default(),
+        ];
+        let record: Vec<String> = record.iter().map(|field| quote_csv_field(field)).collect();
+        println!("{}", record.join(","));
+    }
+    ExitCode::SUCCESS
+}
+
+// reads the number in `region` if one is given, telling why it was rejected
+fn parse(phone: &str, region: &str) -> Result<PhoneNumber, String> {
+    if region.is_empty() {
+        return PhoneNumber::try_parse(phone).map_err(|error: PhoneError| error.to_string());
+    }
+    PhoneNumber::parse_with_country(phone, region)
+        .ok_or_else(|| format!("not a valid number of region {}", region))
+}
+
+// fields separated by commas, in double quotes when they contain one, `""` standing for a
+// quote inside quotes
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(String::new()),
+            c => fields.last_mut().unwrap().push(c),
+        }
+    }
+    fields
+}
+
+fn quote_csv_field(field: &str) -> String {
+    match field.contains([',', '"', '\n']) {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field.to_string(),
+    }
+}
@@ -0,0 +1,126 @@
+#ifndef PHONELIB_H
+#define PHONELIB_H
+
+/* Generated with cbindgen from src/ffi.rs, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Mirror of [`PhoneFormat`], with fixed discriminants.
+typedef enum PhonelibFormat {
+  PHONELIB_FORMAT_E164 = 0,
+  PHONELIB_FORMAT_INTERNATIONAL = 1,
+  PHONELIB_FORMAT_NATIONAL = 2,
+  PHONELIB_FORMAT_RFC3966 = 3,
+} PhonelibFormat;
+
+// Mirror of [`PhoneError`], `Ok` when the number was parsed.
+typedef enum PhonelibErrorCode {
+  PHONELIB_ERROR_CODE_OK = 0,
+  PHONELIB_ERROR_CODE_TOO_SHORT = 1,
+  PHONELIB_ERROR_CODE_TOO_LONG = 2,
+  // See [`Analysis::error_position`].
+  PHONELIB_ERROR_CODE_INVALID_CHARACTER = 3,
+  PHONELIB_ERROR_CODE_UNKNOWN_COUNTRY_CODE = 4,
+  PHONELIB_ERROR_CODE_INVALID_LENGTH_FOR_COUNTRY = 5,
+} PhonelibErrorCode;
+
+// Mirror of [`PhoneNumberType`], with fixed discriminants.
+typedef enum PhonelibNumberType {
+  PHONELIB_NUMBER_TYPE_MOBILE = 0,
+  PHONELIB_NUMBER_TYPE_FIXED_LINE = 1,
+  PHONELIB_NUMBER_TYPE_FIXED_LINE_OR_MOBILE = 2,
+  PHONELIB_NUMBER_TYPE_TOLL_FREE = 3,
+  PHONELIB_NUMBER_TYPE_PREMIUM_RATE = 4,
+  PHONELIB_NUMBER_TYPE_SHARED_COST = 5,
+  PHONELIB_NUMBER_TYPE_VOIP = 6,
+  PHONELIB_NUMBER_TYPE_PERSONAL_NUMBER = 7,
+  PHONELIB_NUMBER_TYPE_PAGER = 8,
+  PHONELIB_NUMBER_TYPE_UAN = 9,
+  PHONELIB_NUMBER_TYPE_TEST_NUMBER = 10,
+  PHONELIB_NUMBER_TYPE_UNKNOWN = 11,
+  PHONELIB_NUMBER_TYPE_EMERGENCY = 12,
+  PHONELIB_NUMBER_TYPE_SHORT_CODE = 13,
+} PhonelibNumberType;
+
+// Result of [`analyze`]: the parsed number, or why it was rejected.
+//
+// When `error` isn't [`ErrorCode::Ok`] only `error` and `error_position` are set, the other
+// fields are zeroed.
+typedef struct PhonelibAnalysis {
+  enum PhonelibErrorCode error;
+  // Position of the invalid character, counted in characters from 0, for
+  // [`ErrorCode::InvalidCharacter`].
+  uint32_t error_position;
+  // The number in E.164 form, NUL-terminated.
+  uint8_t e164[17];
+  // ISO 3166 code of the country, NUL-terminated.
+  uint8_t region[3];
+  // See [`crate::Country::id`].
+  uint16_t country_id;
+  uint32_t calling_code;
+  enum PhonelibNumberType number_type;
+  // Digits of the extension, NUL-terminated and cut to their first 16 digits.
+  uint8_t extension[17];
+} PhonelibAnalysis;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Returns true if the NUL-terminated `phone_number` is valid, see
+// [`crate::is_valid_phone_number`].
+//
+// # Safety
+//
+// `phone_number` must be null or point to a NUL-terminated string.
+bool phonelib_is_valid(const char *phone_number);
+
+// Returns the E.164 form of `phone_number`, see [`crate::normalize_phone_number`], or null
+// if it is not valid. The string is freed with [`phonelib_free_string`].
+//
+// # Safety
+//
+// `phone_number` must be null or point to a NUL-terminated string.
+char *phonelib_normalize(const char *phone_number);
+
+// Returns `phone_number` formatted in `format`, see [`crate::format_phone_number`], or null
+// if it is not valid. The string is freed with [`phonelib_free_string`].
+//
+// # Safety
+//
+// `phone_number` must be null or point to a NUL-terminated string.
+char *phonelib_format(const char *phone_number, enum PhonelibFormat format);
+
+// Returns the ISO 3166 code of the country of `phone_number`, see
+// [`crate::extract_country`], or null if it is not valid. The string is freed with
+// [`phonelib_free_string`].
+//
+// # Safety
+//
+// `phone_number` must be null or point to a NUL-terminated string.
+char *phonelib_extract_country(const char *phone_number);
+
+// Same as [`analyze`], an input that is null or not UTF-8 being rejected with
+// [`ErrorCode::InvalidCharacter`] at position 0.
+//
+// # Safety
+//
+// `phone_number` must be null or point to a NUL-terminated string.
+struct PhonelibAnalysis phonelib_analyze(const char *phone_number);
+
+// Frees a string returned by the library. Does nothing for null.
+//
+// # Safety
+//
+// `string` must be null or a string returned by a `phonelib_*` function, not freed yet.
+void phonelib_free_string(char *string);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* PHONELIB_H */
@@ -0,0 +1,55 @@
+// Kernels over Arrow string arrays, so dataframe engines clean whole columns without
+// converting them to Rust strings first. Null inputs give null outputs.
+
+use arrow_array::builder::StringDictionaryBuilder;
+use arrow_array::types::UInt16Type;
+use arrow_array::{Array, BooleanArray, DictionaryArray, GenericStringArray, OffsetSizeTrait};
+
+use crate::scratch::with_scratch;
+use crate::{
+    extract_country_data, is_valid_phone_number_str, normalize_phone_number_in_place,
+    remove_unwanted_character,
+};
+
+/// [`crate::normalize_phone_number`] over an array, invalid numbers are null.
+pub fn normalize_array<O: OffsetSizeTrait>(
+    phone_numbers: &GenericStringArray<O>,
+) -> GenericStringArray<O> {
+    phone_numbers
+        .iter()
+        .map(|phone_number| {
+            with_scratch(|buffer| {
+                buffer.push_str(phone_number?);
+                normalize_phone_number_in_place(buffer)
+            })
+        })
+        .collect()
+}
+
+/// [`crate::is_valid_phone_number`] over an array.
+pub fn is_valid_array<O: OffsetSizeTrait>(phone_numbers: &GenericStringArray<O>) -> BooleanArray {
+    phone_numbers
+        .iter()
+        .map(|phone_number| phone_number.map(is_valid_phone_number_str))
+        .collect()
+}
+
+/// [`crate::extract_country`] over an array, as a dictionary of ISO 3166 codes. Numbers
+/// without a country are null.
+pub fn country_array<O: OffsetSizeTrait>(
+    phone_numbers: &GenericStringArray<O>,
+) -> DictionaryArray<UInt16Type> {
+    let mut builder =
+        StringDictionaryBuilder::<UInt16Type>::with_capacity(phone_numbers.len(), 64, 256);
+    for phone_number in phone_numbers.iter() {
+        let country = phone_number.and_then(|phone_number| {
+            with_scratch(|buffer| {
+                buffer.push_str(phone_number);
+                remove_unwanted_character(buffer);
+                extract_country_data(buffer)
+            })
+        });
+        builder.append_option(country.map(|country| country.code));
+    }
+    builder.finish()
+}
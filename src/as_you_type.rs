@@ -0,0 +1,163 @@
+use crate::constants::COUNTRIES;
+use crate::definitions::Country;
+use crate::formatting::{patterns, FormatPattern};
+use crate::{calling_code, find_country_by_code};
+
+/// Formats a phone number while it is typed, for input fields: each call to
+/// [`AsYouTypeFormatter::input_digit`] returns the whole input formatted so far.
+///
+/// Numbers starting with `+` are formatted internationally once their calling code is
+/// complete, the others as national numbers of the region given to
+/// [`AsYouTypeFormatter::new`]. Inputs that no formatting pattern fits (too many digits,
+/// countries without formatting metadata) are returned as typed.
+///
+/// ```
+/// let mut formatter = phonelib::AsYouTypeFormatter::new("FR");
+/// let typed: Vec<String> = "0612".chars().map(|c| formatter.input_digit(c)).collect();
+///
+/// assert_eq!(typed, ["0", "06", "06 1", "06 12"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct AsYouTypeFormatter {
+    region: Option<&'static Country>,
+    international: bool,
+    digits: String,
+}
+
+impl AsYouTypeFormatter {
+    /// Creates a formatter for the national numbers of `region` (an ISO 3166 code). With an
+    /// unknown region only international numbers are formatted.
+    pub fn new(region: &str) -> AsYouTypeFormatter {
+        AsYouTypeFormatter {
+            region: find_country_by_code(region),
+            international: false,
+            digits: String::new(),
+        }
+    }
+
+    /// Adds a typed character and returns the formatted input. `+` is only accepted as the
+    /// first character, other characters than ASCII digits are ignored.
+    pub fn input_digit(&mut self, c: char) -> String {
+        match c {
+            '+' if self.digits.is_empty() => self.international = true,
+            '0'..='9' => self.digits.push(c),
+            _ => {}
+        }
+        self.formatted()
+    }
+
+    /// Forgets the input, to format another number.
+    pub fn clear(&mut self) {
+        self.international = false;
+        self.digits.clear();
+    }
+
+    fn formatted(&self) -> String {
+        if self.international {
+            return self.formatted_international();
+        }
+        let Some(country) = self.region else {
+            return self.digits.clone();
+        };
+
+        // a typed trunk prefix is kept as the national templates write it
+        let trunk_prefix = country.trunk_prefix().unwrap_or_default();
+        let national_number = match self.digits.strip_prefix(trunk_prefix) {
+            Some(national_number) if !trunk_prefix.is_empty() => national_number,
+            _ => return self.formatted_national_number(country, &self.digits, false),
+        };
+        if national_number.is_empty() {
+            return self.digits.clone();
+        }
+        self.formatted_national_number(country, national_number, true)
+    }
+
+    fn formatted_international(&self) -> String {
+        // calling codes are prefix free, the first one starting the digits is the right one
+        let code = (1..=self.digits.len().min(3))
+            .map(|len| &self.digits[..len])
+            .find(|code| {
+                COUNTRIES
+                    .iter()
+                    .any(|country| calling_code(country).to_string() == *code)
+            });
+        let Some(code) = code else {
+            return format!("+{}", self.digits);
+        };
+
+        let national_number = &self.digits[code.len()..];
+        if national_number.is_empty() {
+            return format!("+{}", code);
+        }
+        let formatted = as_you_type_pattern(code.parse().unwrap_or_default(), national_number)
+            .map_or_else(
+                || national_number.to_string(),
+                |pattern| apply_partially(pattern.international, national_number),
+            );
+        format!("+{} {}", code, formatted)
+    }
+
+    fn formatted_national_number(
+        &self,
+        country: &Country,
+        national_number: &str,
+        with_trunk_prefix: bool,
+    ) -> String {
+        let trunk_prefix = country.trunk_prefix().unwrap_or_default();
+        let Some(pattern) = as_you_type_pattern(calling_code(country), national_number) else {
+            return self.digits.clone();
+        };
+        match (
+            with_trunk_prefix,
+            pattern.national.strip_prefix(trunk_prefix),
+        ) {
+            // the national template writes the trunk prefix
+            (true, Some(_)) => apply_partially(pattern.national, national_number),
+            (true, None) => format!(
+                "{} {}",
+                trunk_prefix,
+                apply_partially(pattern.national, national_number)
+            ),
+            (false, Some(template)) => apply_partially(template.trim_start(), national_number),
+            (false, None) => apply_partially(pattern.national, national_number),
+        }
+    }
+}
+
+// the first pattern that can still apply to the national number typed so far, one for as
+// many digits as typed first
+fn as_you_type_pattern(calling_code: u32, national_number: &str) -> Option<&'static FormatPattern> {
+    let fits = |pattern: &&FormatPattern| {
+        national_number.starts_with(pattern.leading_digits)
+            || pattern.leading_digits.starts_with(national_number)
+    };
+    let digits = |pattern: &FormatPattern| pattern.national.matches('#').count();
+    let patterns = patterns(calling_code);
+
+    patterns
+        .iter()
+        .filter(fits)
+        .find(|pattern| digits(pattern) == national_number.len())
+        .or_else(|| {
+            patterns
+                .iter()
+                .filter(fits)
+                .find(|pattern| digits(pattern) > national_number.len())
+        })
+}
+
+// the template up to the last typed digit
+fn apply_partially(template: &str, national_number: &str) -> String {
+    let mut digits = national_number.chars().peekable();
+    let mut formatted = String::new();
+    for c in template.chars() {
+        if digits.peek().is_none() {
+            break;
+        }
+        match c {
+            '#' => formatted.extend(digits.next()),
+            c => formatted.push(c),
+        }
+    }
+    formatted
+}
@@ -0,0 +1,21 @@
+use crate::e164::without_tel_scheme;
+use crate::{strip_extension, E164};
+
+/// Returns the canonical form of a telephone number used in STIR/SHAKEN PASSporT `orig`
+/// and `dest` claims (ATIS-1000074, which defers to RFC 8224 section 8.3): the full E.164
+/// number as digits only, without the leading `+`, visual separators or extension.
+///
+/// `"+1 (202) 555-0173 ext. 12"` becomes `"12025550173"`. Inputs containing anything other
+/// than digits, `+` and visual separators (space, `-`, `.`, `/`, parentheses) are rejected.
+pub fn canonical_for_attestation(phone_number: &str) -> Option<String> {
+    let phone_number = strip_extension(phone_number.trim());
+    let phone_number = without_tel_scheme(phone_number);
+
+    let is_allowed = |c: char| c.is_ascii_digit() || "+-. /()".contains(c);
+    if !phone_number.chars().all(is_allowed) {
+        return None;
+    }
+
+    let number = E164::parse(phone_number)?;
+    Some(number[1..].to_string())
+}
@@ -0,0 +1,90 @@
+use crate::is_valid_phone_number_str;
+
+/// Validity of the numbers of a slab, one bit per number, see [`validate_packed`].
+///
+/// Bits are packed least significant bit first in 64 bit words, the layout of Arrow
+/// validity bitmaps on little-endian platforms.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ValidityBitmap {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl ValidityBitmap {
+    fn with_len(len: usize) -> ValidityBitmap {
+        ValidityBitmap {
+            words: vec![0; len.div_ceil(64)],
+            len,
+        }
+    }
+
+    /// Number of numbers, valid or not.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns whether the `index`th number is valid, `false` when out of bounds.
+    pub fn get(&self, index: usize) -> bool {
+        index < self.len && self.words[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// Number of valid numbers.
+    pub fn count_valid(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// The packed bits, unused bits of the last word are zero.
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    fn set(&mut self, index: usize) {
+        self.words[index / 64] |= 1 << (index % 64);
+    }
+}
+
+/// Validates the numbers of a slab without materializing strings, for columnar engines.
+///
+/// Number `i` is `buffer[offsets[i]..offsets[i + 1]]` (Arrow string offsets), so `n + 1`
+/// offsets describe `n` numbers. A trailing `"\n"` or `"\r\n"` is ignored, see
+/// [`newline_offsets`] for newline separated slabs. Numbers are validated like
+/// [`crate::is_valid_phone_number`]; numbers that are not UTF-8 or have out of bounds or
+/// decreasing offsets are invalid.
+pub fn validate_packed(buffer: &[u8], offsets: &[usize]) -> ValidityBitmap {
+    let mut validity = ValidityBitmap::with_len(offsets.len().saturating_sub(1));
+    for (index, range) in offsets.windows(2).enumerate() {
+        let number = buffer
+            .get(range[0]..range[1])
+            .and_then(|number| std::str::from_utf8(number).ok())
+            .map(|number| number.trim_end_matches('\n').trim_end_matches('\r'));
+
+        if number.is_some_and(is_valid_phone_number_str) {
+            validity.set(index);
+        }
+    }
+    validity
+}
+
+/// Returns the offsets of the lines of a newline separated slab for [`validate_packed`]. A
+/// trailing newline doesn't start an empty last line.
+pub fn newline_offsets(buffer: &[u8]) -> Vec<usize> {
+    let mut offsets = vec![0];
+    offsets.extend(
+        buffer
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| **b == b'\n')
+            .map(|(position, _)| position + 1),
+    );
+    if buffer.last().is_some_and(|b| *b != b'\n') {
+        offsets.push(buffer.len());
+    }
+    offsets
+}
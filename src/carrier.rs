@@ -0,0 +1,102 @@
+// Carrier names of mobile ranges, behind the `carrier` feature so applications that don't
+// display them don't ship the table.
+
+use crate::number_type::number_type_of;
+use crate::{calling_code, stats, PhoneNumberType, E164};
+
+// (calling code, national number prefix, carrier) of the mobile ranges as allocated by the
+// regulators, sorted by calling code, the longest matching prefix wins
+#[rustfmt::skip]
+const CARRIERS: &[(u32, &str, &str)] = &[
+    (20, "10", "Vodafone"),
+    (20, "11", "Etisalat"),
+    (20, "12", "Orange"),
+    (20, "15", "WE"),
+    (49, "151", "Telekom"),
+    (49, "160", "Telekom"),
+    (49, "170", "Telekom"),
+    (49, "171", "Telekom"),
+    (49, "175", "Telekom"),
+    (49, "152", "Vodafone"),
+    (49, "162", "Vodafone"),
+    (49, "172", "Vodafone"),
+    (49, "173", "Vodafone"),
+    (49, "174", "Vodafone"),
+    (49, "157", "O2"),
+    (49, "159", "O2"),
+    (49, "163", "O2"),
+    (49, "176", "O2"),
+    (49, "177", "O2"),
+    (49, "178", "O2"),
+    (49, "179", "O2"),
+    (92, "30", "Jazz"),
+    (92, "31", "Zong"),
+    (92, "32", "Jazz"),
+    (92, "33", "Ufone"),
+    (92, "34", "Telenor"),
+    (234, "701", "Airtel"),
+    (234, "708", "Airtel"),
+    (234, "802", "Airtel"),
+    (234, "808", "Airtel"),
+    (234, "812", "Airtel"),
+    (234, "902", "Airtel"),
+    (234, "907", "Airtel"),
+    (234, "705", "Glo"),
+    (234, "805", "Glo"),
+    (234, "807", "Glo"),
+    (234, "811", "Glo"),
+    (234, "815", "Glo"),
+    (234, "905", "Glo"),
+    (234, "703", "MTN"),
+    (234, "706", "MTN"),
+    (234, "803", "MTN"),
+    (234, "806", "MTN"),
+    (234, "810", "MTN"),
+    (234, "813", "MTN"),
+    (234, "814", "MTN"),
+    (234, "816", "MTN"),
+    (234, "903", "MTN"),
+    (234, "906", "MTN"),
+    (234, "809", "9mobile"),
+    (234, "817", "9mobile"),
+    (234, "818", "9mobile"),
+    (234, "908", "9mobile"),
+    (234, "909", "9mobile"),
+    (966, "50", "stc"),
+    (966, "53", "stc"),
+    (966, "55", "stc"),
+    (966, "54", "Mobily"),
+    (966, "56", "Mobily"),
+    (966, "58", "Zain"),
+    (966, "59", "Zain"),
+    (971, "50", "etisalat"),
+    (971, "54", "etisalat"),
+    (971, "56", "etisalat"),
+    (971, "52", "du"),
+    (971, "55", "du"),
+    (971, "58", "du"),
+];
+
+/// Returns the name of the carrier a mobile number was allocated to, e.g. `"Vodafone"` for
+/// `"+49 172 1234567"`, or `None` if the number is not valid, not a mobile number or its
+/// range is unknown.
+///
+/// Numbers ported to another carrier keep the carrier of their range, so the name is a
+/// display hint, not a routing decision.
+pub fn carrier_for_number(phone_number: &str) -> Option<&'static str> {
+    let number = E164::parse(phone_number)?;
+    if number_type_of(&number) != PhoneNumberType::Mobile {
+        return None;
+    }
+    let calling_code = calling_code(number.country());
+    let national_number = number.national_number();
+
+    stats::record_table_lookup();
+    let start = CARRIERS.partition_point(|(code, _, _)| *code < calling_code);
+    CARRIERS[start..]
+        .iter()
+        .take_while(|(code, _, _)| *code == calling_code)
+        .filter(|(_, prefix, _)| national_number.starts_with(prefix))
+        .max_by_key(|(_, prefix, _)| prefix.len())
+        .map(|(_, _, carrier)| *carrier)
+}
@@ -0,0 +1,383 @@
+use crate::PhoneNumberType;
+
+/// Version of the bundled country metadata, bumped whenever [`changelog`] gets a new entry.
+pub const METADATA_VERSION: u32 = 5;
+
+/// A single change to the country metadata. Countries are referred to by their stable
+/// [`crate::Country::id`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetadataChange {
+    /// A country was added to the table.
+    CountryAdded { id: u16 },
+    /// A country was removed from the table; its id is retired and never reused.
+    CountryRemoved {
+        id: u16,
+        code: &'static str,
+        prefix: u32,
+        phone_lengths: &'static [u8],
+    },
+    /// The calling code of a country changed.
+    PrefixChanged { id: u16, before: u32, after: u32 },
+    /// The accepted national number lengths of a country changed.
+    LengthsChanged {
+        id: u16,
+        before: &'static [u8],
+        after: &'static [u8],
+    },
+    /// The shortest and longest national numbers of a type of a country changed, `None`
+    /// when the type has no lengths of its own, see [`crate::Country::length_range_for_type`].
+    TypeLengthsChanged {
+        id: u16,
+        number_type: PhoneNumberType,
+        before: Option<(u8, u8)>,
+        after: Option<(u8, u8)>,
+    },
+    /// The area codes telling a country apart from the others sharing its calling code
+    /// changed, empty when the country had none.
+    AreaCodesChanged {
+        id: u16,
+        before: &'static [&'static str],
+        after: &'static [&'static str],
+    },
+}
+
+impl MetadataChange {
+    /// Returns the id of the country affected by this change.
+    pub fn country_id(&self) -> u16 {
+        match *self {
+            MetadataChange::CountryAdded { id }
+            | MetadataChange::CountryRemoved { id, .. }
+            | MetadataChange::PrefixChanged { id, .. }
+            | MetadataChange::LengthsChanged { id, .. }
+            | MetadataChange::TypeLengthsChanged { id, .. }
+            | MetadataChange::AreaCodesChanged { id, .. } => id,
+        }
+    }
+}
+
+/// All metadata changes shipped in one metadata version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChangelogEntry {
+    pub metadata_version: u32,
+    /// First crate version shipping this metadata.
+    pub crate_version: &'static str,
+    /// ISO 8601 week (year, week) in which the metadata was published.
+    pub iso_week: (u16, u8),
+    pub changes: &'static [MetadataChange],
+}
+
+const CHANGELOG: &[ChangelogEntry] = &[
+    ChangelogEntry {
+        // first tracked snapshot of the table, every later change is recorded against it
+        metadata_version: 1,
+        crate_version: "0.1.5",
+        iso_week: (2026, 42),
+        changes: &[],
+    },
+    ChangelogEntry {
+        metadata_version: 2,
+        crate_version: "0.1.6",
+        iso_week: (2026, 42),
+        // +800 universal international freephone numbers
+        changes: &[MetadataChange::CountryAdded { id: 247 }],
+    },
+    ChangelogEntry {
+        metadata_version: 3,
+        crate_version: "0.1.6",
+        iso_week: (2026, 42),
+        changes: &[
+            // NANP members count the digits after their area code, which is part of their
+            // calling code in the table
+            MetadataChange::LengthsChanged {
+                id: 5,
+                before: &[10],
+                after: &[7],
+            },
+            MetadataChange::LengthsChanged {
+                id: 6,
+                before: &[10],
+                after: &[7],
+            },
+            MetadataChange::LengthsChanged {
+                id: 12,
+                before: &[10],
+                after: &[7],
+            },
+            MetadataChange::LengthsChanged {
+                id: 19,
+                before: &[10],
+                after: &[7],
+            },
+            MetadataChange::LengthsChanged {
+                id: 30,
+                before: &[10],
+                after: &[7],
+            },
+            MetadataChange::LengthsChanged {
+                id: 34,
+                before: &[10],
+                after: &[7],
+            },
+            MetadataChange::LengthsChanged {
+                id: 61,
+                before: &[10],
+                after: &[7],
+            },
+            MetadataChange::LengthsChanged {
+                id: 62,
+                before: &[10],
+                after: &[7],
+            },
+            MetadataChange::LengthsChanged {
+                id: 79,
+                before: &[10],
+                after: &[7],
+            },
+            MetadataChange::LengthsChanged {
+                id: 93,
+                before: &[10],
+                after: &[7],
+            },
+            MetadataChange::LengthsChanged {
+                id: 112,
+                before: &[10],
+                after: &[7],
+            },
+            MetadataChange::LengthsChanged {
+                id: 120,
+                before: &[10],
+                after: &[7],
+            },
+            MetadataChange::LengthsChanged {
+                id: 152,
+                before: &[10],
+                after: &[7],
+            },
+            MetadataChange::LengthsChanged {
+                id: 209,
+                before: &[10],
+                after: &[7],
+            },
+            MetadataChange::LengthsChanged {
+                id: 212,
+                before: &[10],
+                after: &[7],
+            },
+            MetadataChange::LengthsChanged {
+                id: 224,
+                before: &[10],
+                after: &[7],
+            },
+            MetadataChange::LengthsChanged {
+                id: 236,
+                before: &[10],
+                after: &[7],
+            },
+            MetadataChange::LengthsChanged {
+                id: 237,
+                before: &[10],
+                after: &[7],
+            },
+            // Reunion numbers have 9 digits like the Mayotte ones, told apart by area code
+            MetadataChange::LengthsChanged {
+                id: 186,
+                before: &[10],
+                after: &[9],
+            },
+            // Kazakhstan, Reunion and Mayotte are told apart from the other countries of
+            // their calling code by area code
+            MetadataChange::AreaCodesChanged {
+                id: 125,
+                before: &[],
+                after: &["6", "7"],
+            },
+            MetadataChange::AreaCodesChanged {
+                id: 186,
+                before: &[],
+                after: &["262", "263", "692", "693"],
+            },
+            MetadataChange::AreaCodesChanged {
+                id: 243,
+                before: &[],
+                after: &["269", "639"],
+            },
+            MetadataChange::CountryAdded { id: 248 },
+            MetadataChange::CountryAdded { id: 249 },
+        ],
+    },
+    ChangelogEntry {
+        metadata_version: 4,
+        crate_version: "0.1.6",
+        iso_week: (2026, 42),
+        changes: &[
+            // German mobiles have 10 or 11 digits, shorter numbers are landlines
+            MetadataChange::TypeLengthsChanged {
+                id: 58,
+                number_type: PhoneNumberType::Mobile,
+                before: None,
+                after: Some((10, 11)),
+            },
+            MetadataChange::TypeLengthsChanged {
+                id: 58,
+                number_type: PhoneNumberType::FixedLine,
+                before: None,
+                after: Some((6, 11)),
+            },
+            // 9 digit landlines and 10 digit mobiles, the 11 digits were never assigned
+            MetadataChange::LengthsChanged {
+                id: 114,
+                before: &[10, 11],
+                after: &[9, 10],
+            },
+            MetadataChange::TypeLengthsChanged {
+                id: 114,
+                number_type: PhoneNumberType::Mobile,
+                before: None,
+                after: Some((10, 10)),
+            },
+            MetadataChange::TypeLengthsChanged {
+                id: 114,
+                number_type: PhoneNumberType::FixedLine,
+                before: None,
+                after: Some((9, 9)),
+            },
+            // landlines of the cities with 2 digit area codes have 10 digits
+            MetadataChange::LengthsChanged {
+                id: 49,
+                before: &[11],
+                after: &[10, 11],
+            },
+            // 11 digit mobiles, and landlines of 10 or 11 digits depending on the area code
+            MetadataChange::TypeLengthsChanged {
+                id: 49,
+                number_type: PhoneNumberType::Mobile,
+                before: None,
+                after: Some((11, 11)),
+            },
+            MetadataChange::TypeLengthsChanged {
+                id: 49,
+                number_type: PhoneNumberType::FixedLine,
+                before: None,
+                after: Some((10, 11)),
+            },
+        ],
+    },
+    ChangelogEntry {
+        metadata_version: 5,
+        crate_version: "0.1.6",
+        iso_week: (2026, 42),
+        changes: &[
+            // the Dominican Republic has 3 area codes, told apart from the United States
+            // like Canada and Puerto Rico
+            MetadataChange::PrefixChanged {
+                id: 62,
+                before: 1809,
+                after: 1,
+            },
+            MetadataChange::LengthsChanged {
+                id: 62,
+                before: &[7],
+                after: &[10],
+            },
+            // 08 9162 xxxx on the Cocos Islands
+            MetadataChange::LengthsChanged {
+                id: 40,
+                before: &[10],
+                after: &[9],
+            },
+            // Guernsey and Jersey numbers have 10 digits like the other +44 ones, told apart
+            // by area code
+            MetadataChange::LengthsChanged {
+                id: 82,
+                before: &[6],
+                after: &[10],
+            },
+            MetadataChange::LengthsChanged {
+                id: 111,
+                before: &[6],
+                after: &[10],
+            },
+            // every other country sharing its calling code is told apart by area code
+            MetadataChange::AreaCodesChanged {
+                id: 10,
+                before: &[],
+                after: &["1"],
+            },
+            MetadataChange::AreaCodesChanged {
+                id: 16,
+                before: &[],
+                after: &["18"],
+            },
+            MetadataChange::AreaCodesChanged {
+                id: 29,
+                before: &[],
+                after: &["59027", "59029"],
+            },
+            MetadataChange::AreaCodesChanged {
+                id: 40,
+                before: &[],
+                after: &["89162"],
+            },
+            MetadataChange::AreaCodesChanged {
+                id: 55,
+                before: &[],
+                after: &["89164"],
+            },
+            MetadataChange::AreaCodesChanged {
+                id: 62,
+                before: &[],
+                after: &["809", "829", "849"],
+            },
+            MetadataChange::AreaCodesChanged {
+                id: 67,
+                before: &[],
+                after: &["5288", "5289"],
+            },
+            MetadataChange::AreaCodesChanged {
+                id: 82,
+                before: &[],
+                after: &["1481", "7781", "7839", "7911"],
+            },
+            MetadataChange::AreaCodesChanged {
+                id: 104,
+                before: &[],
+                after: &["1624", "7524", "7624", "7924"],
+            },
+            MetadataChange::AreaCodesChanged {
+                id: 111,
+                before: &[],
+                after: &["1534", "7509", "7797", "7829", "7937"],
+            },
+            MetadataChange::AreaCodesChanged {
+                id: 141,
+                before: &[],
+                after: &["59051", "59052", "59077", "59087"],
+            },
+            MetadataChange::AreaCodesChanged {
+                id: 163,
+                before: &[],
+                after: &["3"],
+            },
+            MetadataChange::AreaCodesChanged {
+                id: 199,
+                before: &[],
+                after: &["79"],
+            },
+        ],
+    },
+];
+
+/// Lists the metadata changes between crate versions, oldest first.
+pub fn changelog() -> &'static [ChangelogEntry] {
+    CHANGELOG
+}
+
+/// Returns the changes made after `metadata_version`, oldest first.
+pub fn changes_since(
+    metadata_version: u32,
+) -> impl DoubleEndedIterator<Item = &'static MetadataChange> {
+    CHANGELOG
+        .iter()
+        .filter(move |entry| entry.metadata_version > metadata_version)
+        .flat_map(|entry| entry.changes.iter())
+}
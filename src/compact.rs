@@ -0,0 +1,193 @@
+// Metadata tables packed into byte blobs at compile time, behind the `compact-metadata`
+// feature. The tables of number types, formats and places are only read by the const fns
+// packing them, so the binary holds the blobs instead: every string is stored once with its
+// length in one byte, without the pointers and padding of the tables. The entries of a
+// calling code are decoded on first use and kept for the life of the process, so a
+// service that only parses a few countries only decodes those.
+
+use std::sync::OnceLock;
+
+use crate::PhoneNumberType;
+
+// bytes and calling codes reserved while packing a table, far above the largest table
+pub(crate) const MAX_LEN: usize = 1 << 16;
+pub(crate) const MAX_CODES: usize = 256;
+
+// the types in declaration order, packed as their index
+const NUMBER_TYPES: [PhoneNumberType; 14] = [
+    PhoneNumberType::Mobile,
+    PhoneNumberType::FixedLine,
+    PhoneNumberType::FixedLineOrMobile,
+    PhoneNumberType::TollFree,
+    PhoneNumberType::PremiumRate,
+    PhoneNumberType::SharedCost,
+    PhoneNumberType::Voip,
+    PhoneNumberType::PersonalNumber,
+    PhoneNumberType::Pager,
+    PhoneNumberType::Uan,
+    PhoneNumberType::TestNumber,
+    PhoneNumberType::Emergency,
+    PhoneNumberType::ShortCode,
+    PhoneNumberType::Unknown,
+];
+
+const _: () = {
+    let mut i = 0;
+    while i < NUMBER_TYPES.len() {
+        assert!(
+            NUMBER_TYPES[i] as usize == i,
+            "NUMBER_TYPES out of declaration order"
+        );
+        i += 1;
+    }
+};
+
+// A table being packed: the entries of each calling code follow each other, in ascending
+// order of calling code, and start at the offset recorded with their calling code.
+pub(crate) struct Packer<const LEN: usize, const CODES: usize> {
+    bytes: [u8; LEN],
+    len: usize,
+    codes: [u32; CODES],
+    starts: [usize; CODES],
+    sections: usize,
+}
+
+impl<const LEN: usize, const CODES: usize> Packer<LEN, CODES> {
+    pub(crate) const fn new() -> Packer<LEN, CODES> {
+        Packer {
+            bytes: [0; LEN],
+            len: 0,
+            codes: [0; CODES],
+            starts: [0; CODES],
+            sections: 0,
+        }
+    }
+
+    // starts an entry of `calling_code`
+    pub(crate) const fn entry(&mut self, calling_code: u32) {
+        if self.sections > 0 && self.codes[self.sections - 1] == calling_code {
+            return;
+        }
+        assert!(
+            self.sections == 0 || self.codes[self.sections - 1] < calling_code,
+            "packed tables must be sorted by calling code"
+        );
+        self.codes[self.sections] = calling_code;
+        self.starts[self.sections] = self.len;
+        self.sections += 1;
+    }
+
+    pub(crate) const fn byte(&mut self, byte: u8) {
+        self.bytes[self.len] = byte;
+        self.len += 1;
+    }
+
+    pub(crate) const fn number_type(&mut self, number_type: PhoneNumberType) {
+        self.byte(number_type as u8);
+    }
+
+    pub(crate) const fn str(&mut self, s: &str) {
+        let bytes = s.as_bytes();
+        assert!(
+            bytes.len() <= u8::MAX as usize,
+            "packed strings are at most 255 bytes"
+        );
+        self.byte(bytes.len() as u8);
+        let mut i = 0;
+        while i < bytes.len() {
+            self.byte(bytes[i]);
+            i += 1;
+        }
+    }
+
+    // the bytes and calling codes used, to size the packed table
+    pub(crate) const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub(crate) const fn sections(&self) -> usize {
+        self.sections
+    }
+
+    // the table packed with `MAX_LEN` and `MAX_CODES`, copied to its actual size
+    pub(crate) const fn shrink<T, const L: usize, const C: usize>(&self) -> Packed<T, L, C> {
+        assert!(self.len == L && self.sections == C);
+        let (mut bytes, mut codes, mut starts) = ([0; L], [0; C], [0; C]);
+        let mut i = 0;
+        while i < L {
+            bytes[i] = self.bytes[i];
+            i += 1;
+        }
+        let mut i = 0;
+        while i < C {
+            codes[i] = self.codes[i];
+            starts[i] = self.starts[i];
+            i += 1;
+        }
+        Packed {
+            bytes,
+            codes,
+            starts,
+            decoded: [const { OnceLock::new() }; C],
+        }
+    }
+}
+
+// A packed table and its entries decoded so far, by calling code.
+pub(crate) struct Packed<T: 'static, const LEN: usize, const CODES: usize> {
+    bytes: [u8; LEN],
+    codes: [u32; CODES],
+    starts: [usize; CODES],
+    decoded: [OnceLock<Box<[T]>>; CODES],
+}
+
+impl<T, const LEN: usize, const CODES: usize> Packed<T, LEN, CODES> {
+    // the entries of `calling_code`, decoded with `decode` on first use, empty for the
+    // calling codes without entries
+    pub(crate) fn entries(
+        &'static self,
+        calling_code: u32,
+        decode: fn(&mut Reader) -> T,
+    ) -> &'static [T] {
+        let Ok(section) = self.codes.binary_search(&calling_code) else {
+            return &[];
+        };
+        self.decoded[section].get_or_init(|| {
+            let end = self.starts.get(section + 1).map_or(LEN, |start| *start);
+            let mut reader = Reader {
+                bytes: &self.bytes[..end],
+                position: self.starts[section],
+            };
+            let mut entries = Vec::new();
+            while reader.position < end {
+                entries.push(decode(&mut reader));
+            }
+            entries.into_boxed_slice()
+        })
+    }
+}
+
+// Reads the entries of a calling code, in the order they were packed.
+pub(crate) struct Reader {
+    bytes: &'static [u8],
+    position: usize,
+}
+
+impl Reader {
+    fn byte(&mut self) -> u8 {
+        self.position += 1;
+        self.bytes[self.position - 1]
+    }
+
+    pub(crate) fn number_type(&mut self) -> PhoneNumberType {
+        NUMBER_TYPES[usize::from(self.byte())]
+    }
+
+    pub(crate) fn str(&mut self) -> &'static str {
+        let len = usize::from(self.byte());
+        let start = self.position;
+        self.position += len;
+        std::str::from_utf8(&self.bytes[start..self.position])
+            .expect("packed strings are copied from str")
+    }
+}
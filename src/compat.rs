@@ -0,0 +1,30 @@
+//! The `String` taking signatures of the first releases, kept for one major version so
+//! downstream crates can move to the `&str` and `Result` based functions one call site at a
+//! time. Only the functions of 0.1.5 whose signature changed are here, each deprecated with
+//! its replacement.
+
+use crate::Country;
+
+#[deprecated(
+    since = "0.1.6",
+    note = "use `phonelib::is_valid_phone_number`, which takes `&str` as well"
+)]
+pub fn is_valid_phone_number(phone_number: String) -> bool {
+    crate::is_valid_phone_number(phone_number)
+}
+
+#[deprecated(
+    since = "0.1.6",
+    note = "use `phonelib::try_extract_country`, which takes `&str` and tells why a number is rejected"
+)]
+pub fn extract_country(phone_number: String) -> Option<&'static Country> {
+    crate::extract_country(phone_number)
+}
+
+#[deprecated(
+    since = "0.1.6",
+    note = "use `phonelib::try_normalize_phone_number`, which takes `&str` and tells why a number is rejected"
+)]
+pub fn normalize_phone_number(phone_number: String) -> Option<String> {
+    crate::normalize_phone_number(phone_number)
+}
@@ -2,1481 +2,2070 @@ use crate::definitions;
 
 use definitions::Country;
 
-pub const COUNTRIES: [Country; 246] = [
+pub const COUNTRIES: [Country; 249] = [
     Country {
+        id: 1,
         name: "Andorra",
         code: "AD",
         phone_lengths: &[6],
         prefix: 376,
     },
     Country {
+        id: 2,
         name: "Ascension Island",
         code: "AC",
         phone_lengths: &[4, 5, 6],
         prefix: 247,
     },
     Country {
+        id: 3,
         name: "United Arab Emirates",
         code: "AE",
         phone_lengths: &[9],
         prefix: 971,
     },
     Country {
+        id: 4,
         name: "Afghanistan",
         code: "AF",
         phone_lengths: &[9],
         prefix: 93,
     },
     Country {
+        id: 5,
         name: "Antigua and Barbuda",
         code: "AG",
-        phone_lengths: &[10],
+        phone_lengths: &[7],
         prefix: 1268,
     },
     Country {
+        id: 6,
         name: "Anguilla",
         code: "AI",
-        phone_lengths: &[10],
+        phone_lengths: &[7],
         prefix: 1264,
     },
     Country {
+        id: 7,
         name: "Albania",
         code: "AL",
         phone_lengths: &[9],
         prefix: 355,
     },
     Country {
+        id: 8,
         name: "Armenia",
         code: "AM",
         phone_lengths: &[6, 7, 8],
         prefix: 374,
     },
     Country {
+        id: 9,
         name: "Angola",
         code: "AO",
         phone_lengths: &[9],
         prefix: 244,
     },
     Country {
+        id: 10,
         name: "Antarctica",
         code: "AQ",
         phone_lengths: &[4, 5, 6],
         prefix: 672,
     },
     Country {
+        id: 11,
         name: "Argentina",
         code: "AR",
         phone_lengths: &[6, 7, 8, 10],
         prefix: 54,
     },
     Country {
+        id: 12,
         name: "American Samoa",
         code: "AS",
-        phone_lengths: &[10],
+        phone_lengths: &[7],
         prefix: 1684,
     },
     Country {
+        id: 13,
         name: "Austria",
         code: "AT",
         phone_lengths: &[10, 11],
         prefix: 43,
     },
     Country {
+        id: 14,
         name: "Australia",
         code: "AU",
         phone_lengths: &[9],
         prefix: 61,
     },
     Country {
+        id: 15,
         name: "Aruba",
         code: "AW",
         phone_lengths: &[7],
         prefix: 297,
     },
     Country {
+        id: 16,
         name: "Alland Islands",
         code: "AX",
         phone_lengths: &[5, 6, 7, 8, 9, 10],
         prefix: 358,
     },
     Country {
+        id: 17,
         name: "Azerbaijan",
         code: "AZ",
         phone_lengths: &[9],
         prefix: 994,
     },
     Country {
+        id: 18,
         name: "Bosnia and Herzegovina",
         code: "BA",
         phone_lengths: &[8],
         prefix: 387,
     },
     Country {
+        id: 19,
         name: "Barbados",
         code: "BB",
-        phone_lengths: &[10],
+        phone_lengths: &[7],
         prefix: 1246,
     },
     Country {
+        id: 20,
         name: "Bangladesh",
         code: "BD",
         phone_lengths: &[6, 7, 8, 9, 10, 11],
         prefix: 880,
     },
     Country {
+        id: 21,
         name: "Belgium",
         code: "BE",
         phone_lengths: &[9],
         prefix: 32,
     },
     Country {
+        id: 22,
         name: "Burkina Faso",
         code: "BF",
         phone_lengths: &[8],
         prefix: 226,
     },
     Country {
+        id: 23,
         name: "Bulgaria",
         code: "BG",
         phone_lengths: &[7, 8, 9],
         prefix: 359,
     },
     Country {
+        id: 24,
         name: "Bahrain",
         code: "BH",
         phone_lengths: &[8],
         prefix: 973,
     },
     Country {
+        id: 25,
         name: "Palestine",
         code: "PS",
         phone_lengths: &[9],
         prefix: 970,
     },
     Country {
+        id: 26,
         name: "Israel",
         code: "IL",
         phone_lengths: &[9],
         prefix: 972,
     },
     Country {
+        id: 27,
         name: "Burundi",
         code: "BI",
         phone_lengths: &[8],
         prefix: 257,
     },
     Country {
+        id: 28,
         name: "Benin",
         code: "BJ",
         phone_lengths: &[8],
         prefix: 229,
     },
     Country {
+        id: 29,
         name: "Saint Barthelemy",
         code: "BL",
         phone_lengths: &[9],
         prefix: 590,
     },
     Country {
+        id: 30,
         name: "Bermuda",
         code: "BM",
-        phone_lengths: &[10],
+        phone_lengths: &[7],
         prefix: 1441,
     },
     Country {
+        id: 31,
         name: "Brunei Darussalam",
         code: "BN",
         phone_lengths: &[7],
         prefix: 673,
     },
     Country {
+        id: 32,
         name: "Bolivia",
         code: "BO",
         phone_lengths: &[8, 9],
         prefix: 591,
     },
     Country {
+        id: 33,
         name: "Brazil",
         code: "BR",
         phone_lengths: &[11],
         prefix: 55,
     },
     Country {
+        id: 34,
         name: "Bahamas",
         code: "BS",
-        phone_lengths: &[10],
+        phone_lengths: &[7],
         prefix: 1242,
     },
     Country {
+        id: 35,
         name: "Bhutan",
         code: "BT",
         phone_lengths: &[7, 8],
         prefix: 975,
     },
     Country {
+        id: 36,
         name: "Bouvet Island",
         code: "BV",
         phone_lengths: &[10],
         prefix: 47,
     },
     Country {
+        id: 37,
         name: "Botswana",
         code: "BW",
         phone_lengths: &[7, 8],
         prefix: 267,
     },
     Country {
+        id: 38,
         name: "Belarus",
         code: "BY",
         phone_lengths: &[9],
         prefix: 375,
     },
     Country {
+        id: 39,
         name: "Belize",
         code: "BZ",
         phone_lengths: &[7],
         prefix: 501,
     },
     Country {
+        id: 40,
         name: "Cocos (Keeling) Islands",
         code: "CC",
-        phone_lengths: &[10],
+        phone_lengths: &[9],
         prefix: 61,
     },
     Country {
+        id: 41,
         name: "Congo, Democratic Republic of the",
         code: "CD",
         phone_lengths: &[9],
         prefix: 243,
     },
     Country {
+        id: 42,
         name: "Central African Republic",
         code: "CF",
         phone_lengths: &[8],
         prefix: 236,
     },
     Country {
+        id: 43,
         name: "Congo, Republic of the",
         code: "CG",
         phone_lengths: &[9],
         prefix: 242,
     },
     Country {
+        id: 44,
         name: "Switzerland",
         code: "CH",
         phone_lengths: &[9],
         prefix: 41,
     },
     Country {
+        id: 45,
         name: "Cote d'Ivoire",
         code: "CI",
         phone_lengths: &[8, 9],
         prefix: 225,
     },
     Country {
+        id: 46,
         name: "Cook Islands",
         code: "CK",
         phone_lengths: &[5, 7],
         prefix: 682,
     },
     Country {
+        id: 47,
         name: "Chile",
         code: "CL",
         phone_lengths: &[9],
         prefix: 56,
     },
     Country {
+        id: 48,
         name: "Cameroon",
         code: "CM",
         phone_lengths: &[9],
         prefix: 237,
     },
     Country {
+        id: 49,
         name: "China",
         code: "CN",
-        phone_lengths: &[11],
+        phone_lengths: &[10, 11],
         prefix: 86,
     },
     Country {
+        id: 50,
         name: "Colombia",
         code: "CO",
         phone_lengths: &[10],
         prefix: 57,
     },
     Country {
+        id: 51,
         name: "Costa Rica",
         code: "CR",
         phone_lengths: &[8],
         prefix: 506,
     },
     Country {
+        id: 52,
         name: "Cuba",
         code: "CU",
         phone_lengths: &[8],
         prefix: 53,
     },
     Country {
+        id: 53,
         name: "Cape Verde",
         code: "CV",
         phone_lengths: &[7],
         prefix: 238,
     },
     Country {
+        id: 54,
         name: "Curacao",
         code: "CW",
         phone_lengths: &[7, 8],
         prefix: 599,
     },
     Country {
+        id: 55,
         name: "Christmas Island",
         code: "CX",
         phone_lengths: &[6, 7, 8, 9],
         prefix: 61,
     },
     Country {
+        id: 56,
         name: "Cyprus",
         code: "CY",
         phone_lengths: &[8],
         prefix: 357,
     },
     Country {
+        id: 57,
         name: "Czech Republic",
         code: "CZ",
         phone_lengths: &[9],
         prefix: 420,
     },
     Country {
+        id: 58,
         name: "Germany",
         code: "DE",
         phone_lengths: &[6, 7, 8, 9, 10, 11],
         prefix: 49,
     },
     Country {
+        id: 59,
         name: "Djibouti",
         code: "DJ",
         phone_lengths: &[8],
         prefix: 253,
     },
     Country {
+        id: 60,
         name: "Denmark",
         code: "DK",
         phone_lengths: &[8],
         prefix: 45,
     },
     Country {
+        id: 61,
         name: "Dominica",
         code: "DM",
-        phone_lengths: &[10],
+        phone_lengths: &[7],
         prefix: 1767,
     },
     Country {
+        id: 62,
         name: "Dominican Republic",
         code: "DO",
         phone_lengths: &[10],
-        prefix: 1809,
+        prefix: 1,
     },
     Country {
+        id: 63,
         name: "Algeria",
         code: "DZ",
         phone_lengths: &[9],
         prefix: 213,
     },
     Country {
+        id: 64,
         name: "Ecuador",
         code: "EC",
         phone_lengths: &[9],
         prefix: 593,
     },
     Country {
+        id: 65,
         name: "Estonia",
         code: "EE",
         phone_lengths: &[8],
         prefix: 372,
     },
     Country {
+        id: 66,
         name: "Egypt",
         code: "EG",
         phone_lengths: &[10],
         prefix: 20,
     },
     Country {
+        id: 67,
         name: "Western Sahara",
         code: "EH",
         phone_lengths: &[9],
         prefix: 212,
     },
     Country {
+        id: 68,
         name: "Eritrea",
         code: "ER",
         phone_lengths: &[7],
         prefix: 291,
     },
     Country {
+        id: 69,
         name: "Spain",
         code: "ES",
         phone_lengths: &[9],
         prefix: 34,
     },
     Country {
+        id: 70,
         name: "Ethiopia",
         code: "ET",
         phone_lengths: &[9],
         prefix: 251,
     },
     Country {
+        id: 71,
         name: "Finland",
         code: "FI",
         phone_lengths: &[9, 11],
         prefix: 358,
     },
     Country {
+        id: 72,
         name: "Fiji",
         code: "FJ",
         phone_lengths: &[7],
         prefix: 679,
     },
     Country {
+        id: 73,
         name: "Falkland Islands (Malvinas)",
         code: "FK",
         phone_lengths: &[5],
         prefix: 500,
     },
     Country {
+        id: 74,
         name: "Micronesia, Federated States of",
         code: "FM",
         phone_lengths: &[7],
         prefix: 691,
     },
     Country {
+        id: 75,
         name: "Faroe Islands",
         code: "FO",
         phone_lengths: &[5, 6],
         prefix: 298,
     },
     Country {
+        id: 76,
         name: "France",
         code: "FR",
         phone_lengths: &[9],
         prefix: 33,
     },
     Country {
+        id: 77,
         name: "Gabon",
         code: "GA",
         phone_lengths: &[8, 9],
         prefix: 241,
     },
     Country {
+        id: 78,
         name: "United Kingdom",
         code: "GB",
         phone_lengths: &[10],
         prefix: 44,
     },
     Country {
+        id: 79,
         name: "Grenada",
         code: "GD",
-        phone_lengths: &[10],
+        phone_lengths: &[7],
         prefix: 1473,
     },
     Country {
+        id: 80,
         name: "Georgia",
         code: "GE",
         phone_lengths: &[9],
         prefix: 995,
     },
     Country {
+        id: 81,
         name: "French Guiana",
         code: "GF",
         phone_lengths: &[9],
         prefix: 594,
     },
     Country {
+        id: 82,
         name: "Guernsey",
         code: "GG",
-        phone_lengths: &[6],
+        phone_lengths: &[10],
         prefix: 44,
     },
     Country {
+        id: 83,
         name: "Ghana",
         code: "GH",
         phone_lengths: &[9],
         prefix: 233,
     },
     Country {
+        id: 84,
         name: "Gibraltar",
         code: "GI",
         phone_lengths: &[8],
         prefix: 350,
     },
     Country {
+        id: 85,
         name: "Greenland",
         code: "GL",
         phone_lengths: &[6],
         prefix: 299,
     },
     Country {
+        id: 86,
         name: "Gambia",
         code: "GM",
         phone_lengths: &[7],
         prefix: 220,
     },
     Country {
+        id: 87,
         name: "Guinea",
         code: "GN",
         phone_lengths: &[9],
         prefix: 224,
     },
     Country {
+        id: 88,
         name: "Guadeloupe",
         code: "GP",
         phone_lengths: &[9],
         prefix: 590,
     },
     Country {
+        id: 89,
         name: "Equatorial Guinea",
         code: "GQ",
         phone_lengths: &[9],
         prefix: 240,
     },
     Country {
+        id: 90,
         name: "Greece",
         code: "GR",
         phone_lengths: &[10],
         prefix: 30,
     },
     Country {
+        id: 91,
         name: "South Georgia and the South Sandwich Islands",
         code: "GS",
         phone_lengths: &[5],
         prefix: 500,
     },
     Country {
+        id: 92,
         name: "Guatemala",
         code: "GT",
         phone_lengths: &[8],
         prefix: 502,
     },
     Country {
+        id: 93,
         name: "Guam",
         code: "GU",
-        phone_lengths: &[10],
+        phone_lengths: &[7],
         prefix: 1671,
     },
     Country {
+        id: 94,
         name: "Guinea-Bissau",
         code: "GW",
         phone_lengths: &[9],
         prefix: 245,
     },
     Country {
+        id: 95,
         name: "Guyana",
         code: "GY",
         phone_lengths: &[7],
         prefix: 592,
     },
     Country {
+        id: 96,
         name: "Hong Kong",
         code: "HK",
         phone_lengths: &[8],
         prefix: 852,
     },
     Country {
+        id: 97,
         name: "Heard Island and McDonald Islands",
         code: "HM",
         phone_lengths: &[10],
         prefix: 672,
     },
     Country {
+        id: 98,
         name: "Honduras",
         code: "HN",
         phone_lengths: &[8],
         prefix: 504,
     },
     Country {
+        id: 99,
         name: "Croatia",
         code: "HR",
         phone_lengths: &[9],
         prefix: 385,
     },
     Country {
+        id: 100,
         name: "Haiti",
         code: "HT",
         phone_lengths: &[8],
         prefix: 509,
     },
     Country {
+        id: 101,
         name: "Hungary",
         code: "HU",
         phone_lengths: &[9],
         prefix: 36,
     },
     Country {
+        id: 102,
         name: "Indonesia",
         code: "ID",
         phone_lengths: &[9, 10, 11, 12],
         prefix: 62,
     },
     Country {
+        id: 103,
         name: "Ireland",
         code: "IE",
         phone_lengths: &[9],
         prefix: 353,
     },
     Country {
+        id: 104,
         name: "Isle of Man",
         code: "IM",
         phone_lengths: &[10],
         prefix: 44,
     },
     Country {
+        id: 105,
         name: "India",
         code: "IN",
         phone_lengths: &[10],
         prefix: 91,
     },
     Country {
+        id: 106,
         name: "British Indian Ocean Territory",
         code: "IO",
         phone_lengths: &[7],
         prefix: 246,
     },
     Country {
+        id: 107,
         name: "Iraq",
         code: "IQ",
         phone_lengths: &[10],
         prefix: 964,
     },
     Country {
+        id: 108,
         name: "Iran",
         code: "IR",
-        phone_lengths: &[11, 10],
+        phone_lengths: &[10, 11],
         prefix: 98,
     },
     Country {
+        id: 109,
         name: "Iceland",
         code: "IS",
         phone_lengths: &[7],
         prefix: 354,
     },
     Country {
+        id: 110,
         name: "Italy",
         code: "IT",
         phone_lengths: &[10],
         prefix: 39,
     },
     Country {
+        id: 111,
         name: "Jersey",
         code: "JE",
-        phone_lengths: &[6],
+        phone_lengths: &[10],
         prefix: 44,
     },
     Country {
+        id: 112,
         name: "Jamaica",
         code: "JM",
-        phone_lengths: &[10],
+        phone_lengths: &[7],
         prefix: 1876,
     },
     Country {
+        id: 113,
         name: "Jordan",
         code: "JO",
         phone_lengths: &[8, 9],
         prefix: 962,
     },
     Country {
+        id: 114,
         name: "Japan",
         code: "JP",
-        phone_lengths: &[10, 11],
+        phone_lengths: &[9, 10],
         prefix: 81,
     },
     Country {
+        id: 115,
         name: "Kenya",
         code: "KE",
         phone_lengths: &[9],
         prefix: 254,
     },
     Country {
+        id: 116,
         name: "Kyrgyzstan",
         code: "KG",
         phone_lengths: &[9],
         prefix: 996,
     },
     Country {
+        id: 117,
         name: "Cambodia",
         code: "KH",
         phone_lengths: &[8, 9],
         prefix: 855,
     },
     Country {
+        id: 118,
         name: "Kiribati",
         code: "KI",
         phone_lengths: &[5],
         prefix: 686,
     },
     Country {
+        id: 119,
         name: "Comoros",
         code: "KM",
         phone_lengths: &[7],
         prefix: 269,
     },
     Country {
+        id: 120,
         name: "Saint Kitts and Nevis",
         code: "KN",
-        phone_lengths: &[10],
+        phone_lengths: &[7],
         prefix: 1869,
     },
     Country {
+        id: 121,
         name: "Korea, Democratic People's Republic of",
         code: "KP",
         phone_lengths: &[6, 7, 8, 10, 11],
         prefix: 850,
     },
     Country {
+        id: 122,
         name: "Korea, Republic of",
         code: "KR",
         phone_lengths: &[7, 8, 9, 10, 11],
         prefix: 82,
     },
     Country {
+        id: 123,
         name: "Kuwait",
         code: "KW",
         phone_lengths: &[8],
         prefix: 965,
     },
     Country {
+        id: 124,
         name: "Cayman Islands",
         code: "KY",
         phone_lengths: &[7],
         prefix: 1345,
     },
     Country {
+        id: 125,
         name: "Kazakhstan",
         code: "KZ",
         phone_lengths: &[10],
         prefix: 7,
     },
     Country {
+        id: 126,
         name: "Lao People's Democratic Republic",
         code: "LA",
         phone_lengths: &[8, 9],
         prefix: 856,
     },
     Country {
+        id: 127,
         name: "Lebanon",
         code: "LB",
         phone_lengths: &[7, 8],
         prefix: 961,
     },
     Country {
+        id: 128,
         name: "Saint Lucia",
         code: "LC",
         phone_lengths: &[7],
         prefix: 1758,
     },
     Country {
+        id: 129,
         name: "Liechtenstein",
         code: "LI",
         phone_lengths: &[7],
         prefix: 423,
     },
     Country {
+        id: 130,
         name: "Sri Lanka",
         code: "LK",
         phone_lengths: &[7, 9, 10],
         prefix: 94,
     },
     Country {
+        id: 131,
         name: "Liberia",
         code: "LR",
         phone_lengths: &[8, 9],
         prefix: 231,
     },
     Country {
+        id: 132,
         name: "Lesotho",
         code: "LS",
         phone_lengths: &[8],
         prefix: 266,
     },
     Country {
+        id: 133,
         name: "Lithuania",
         code: "LT",
         phone_lengths: &[8],
         prefix: 370,
     },
     Country {
+        id: 134,
         name: "Luxembourg",
         code: "LU",
         phone_lengths: &[4, 5, 6, 7, 8, 9],
         prefix: 352,
     },
     Country {
+        id: 135,
         name: "Latvia",
         code: "LV",
         phone_lengths: &[8],
         prefix: 371,
     },
     Country {
+        id: 136,
         name: "Libya",
         code: "LY",
         phone_lengths: &[10],
         prefix: 218,
     },
     Country {
+        id: 137,
         name: "Morocco",
         code: "MA",
         phone_lengths: &[9],
         prefix: 212,
     },
     Country {
+        id: 138,
         name: "Monaco",
         code: "MC",
         phone_lengths: &[8],
         prefix: 377,
     },
     Country {
+        id: 139,
         name: "Moldova, Republic of",
         code: "MD",
         phone_lengths: &[8],
         prefix: 373,
     },
     Country {
+        id: 140,
         name: "Montenegro",
         code: "ME",
         phone_lengths: &[8],
         prefix: 382,
     },
     Country {
+        id: 141,
         name: "Saint Martin (French part)",
         code: "MF",
         phone_lengths: &[9],
         prefix: 590,
     },
     Country {
+        id: 142,
         name: "Madagascar",
         code: "MG",
         phone_lengths: &[7, 8, 9],
         prefix: 261,
     },
     Country {
+        id: 143,
         name: "Marshall Islands",
         code: "MH",
         phone_lengths: &[7],
         prefix: 692,
     },
     Country {
+        id: 144,
         name: "Macedonia, the Former Yugoslav Republic of",
         code: "MK",
         phone_lengths: &[8],
         prefix: 389,
     },
     Country {
+        id: 145,
         name: "Mali",
         code: "ML",
         phone_lengths: &[8],
         prefix: 223,
     },
     Country {
+        id: 146,
         name: "Myanmar",
         code: "MM",
         phone_lengths: &[7, 10],
         prefix: 95,
     },
     Country {
+        id: 147,
         name: "Mongolia",
         code: "MN",
         phone_lengths: &[8],
         prefix: 976,
     },
     Country {
+        id: 148,
         name: "Macao",
         code: "MO",
         phone_lengths: &[8],
         prefix: 853,
     },
     Country {
+        id: 149,
         name: "Northern Mariana Islands",
         code: "MP",
         phone_lengths: &[7],
         prefix: 1670,
     },
     Country {
+        id: 150,
         name: "Martinique",
         code: "MQ",
         phone_lengths: &[9],
         prefix: 596,
     },
     Country {
+        id: 151,
         name: "Mauritania",
         code: "MR",
         phone_lengths: &[8],
         prefix: 222,
     },
     Country {
+        id: 152,
         name: "Montserrat",
         code: "MS",
-        phone_lengths: &[10],
+        phone_lengths: &[7],
         prefix: 1664,
     },
     Country {
+        id: 153,
         name: "Malta",
         code: "MT",
         phone_lengths: &[8],
         prefix: 356,
     },
     Country {
+        id: 154,
         name: "Mauritius",
         code: "MU",
         phone_lengths: &[8],
         prefix: 230,
     },
     Country {
+        id: 155,
         name: "Maldives",
         code: "MV",
         phone_lengths: &[7],
         prefix: 960,
     },
     Country {
+        id: 156,
         name: "Malawi",
         code: "MW",
         phone_lengths: &[7, 8, 9],
         prefix: 265,
     },
     Country {
+        id: 157,
         name: "Mexico",
         code: "MX",
         phone_lengths: &[10],
         prefix: 52,
     },
     Country {
+        id: 158,
         name: "Malaysia",
         code: "MY",
         phone_lengths: &[7, 8, 9, 10],
         prefix: 60,
     },
     Country {
+        id: 159,
         name: "Mozambique",
         code: "MZ",
         phone_lengths: &[8, 9],
         prefix: 258,
     },
     Country {
+        id: 160,
         name: "Namibia",
         code: "NA",
         phone_lengths: &[7, 8, 9, 10],
         prefix: 264,
     },
     Country {
+        id: 161,
         name: "New Caledonia",
         code: "NC",
         phone_lengths: &[6],
         prefix: 687,
     },
     Country {
+        id: 162,
         name: "Niger",
         code: "NE",
         phone_lengths: &[8],
         prefix: 227,
     },
     Country {
+        id: 163,
         name: "Norfolk Island",
         code: "NF",
         phone_lengths: &[6],
         prefix: 672,
     },
     Country {
+        id: 164,
         name: "Nigeria",
         code: "NG",
         phone_lengths: &[7, 8, 9, 10],
         prefix: 234,
     },
     Country {
+        id: 165,
         name: "Nicaragua",
         code: "NI",
         phone_lengths: &[8],
         prefix: 505,
     },
     Country {
+        id: 166,
         name: "Netherlands",
         code: "NL",
         phone_lengths: &[9],
         prefix: 31,
     },
     Country {
+        id: 167,
         name: "Norway",
         code: "NO",
         phone_lengths: &[8],
         prefix: 47,
     },
     Country {
+        id: 168,
         name: "Nepal",
         code: "NP",
         phone_lengths: &[10],
         prefix: 977,
     },
     Country {
+        id: 169,
         name: "Nauru",
         code: "NR",
         phone_lengths: &[7],
         prefix: 674,
     },
     Country {
+        id: 170,
         name: "Niue",
         code: "NU",
         phone_lengths: &[4],
         prefix: 683,
     },
     Country {
+        id: 171,
         name: "New Zealand",
         code: "NZ",
         phone_lengths: &[8],
         prefix: 64,
     },
     Country {
+        id: 172,
         name: "Oman",
         code: "OM",
         phone_lengths: &[8],
         prefix: 968,
     },
     Country {
+        id: 173,
         name: "Panama",
         code: "PA",
         phone_lengths: &[8],
         prefix: 507,
     },
     Country {
+        id: 174,
         name: "Peru",
         code: "PE",
         phone_lengths: &[9],
         prefix: 51,
     },
     Country {
+        id: 175,
         name: "French Polynesia",
         code: "PF",
         phone_lengths: &[8],
         prefix: 689,
     },
     Country {
+        id: 176,
         name: "Papua New Guinea",
         code: "PG",
         phone_lengths: &[7, 8],
         prefix: 675,
     },
     Country {
+        id: 177,
         name: "Philippines",
         code: "PH",
         phone_lengths: &[10],
         prefix: 63,
     },
     Country {
+        id: 178,
         name: "Pakistan",
         code: "PK",
         phone_lengths: &[10],
         prefix: 92,
     },
     Country {
+        id: 179,
         name: "Poland",
         code: "PL",
         phone_lengths: &[9],
         prefix: 48,
     },
     Country {
+        id: 180,
         name: "Saint Pierre and Miquelon",
         code: "PM",
         phone_lengths: &[6, 8, 9],
         prefix: 508,
     },
     Country {
+        id: 181,
         name: "Pitcairn",
         code: "PN",
         phone_lengths: &[6],
         prefix: 870,
     },
     Country {
+        id: 182,
         name: "Portugal",
         code: "PT",
         phone_lengths: &[9],
         prefix: 351,
     },
     Country {
+        id: 183,
         name: "Palau",
         code: "PW",
         phone_lengths: &[7],
         prefix: 680,
     },
     Country {
+        id: 184,
         name: "Paraguay",
         code: "PY",
         phone_lengths: &[9],
         prefix: 595,
     },
     Country {
+        id: 185,
         name: "Qatar",
         code: "QA",
         phone_lengths: &[8],
         prefix: 974,
     },
     Country {
+        id: 186,
         name: "Reunion",
         code: "RE",
-        phone_lengths: &[10],
+        phone_lengths: &[9],
         prefix: 262,
     },
     Country {
+        id: 187,
         name: "Romania",
         code: "RO",
         phone_lengths: &[10],
         prefix: 40,
     },
     Country {
+        id: 188,
         name: "Serbia",
         code: "RS",
         phone_lengths: &[9],
         prefix: 381,
     },
     Country {
+        id: 189,
         name: "Russian Federation",
         code: "RU",
         phone_lengths: &[10],
         prefix: 7,
     },
     Country {
+        id: 190,
         name: "Rwanda",
         code: "RW",
         phone_lengths: &[9],
         prefix: 250,
     },
     Country {
+        id: 191,
         name: "Saudi Arabia",
         code: "SA",
         phone_lengths: &[9],
         prefix: 966,
     },
     Country {
+        id: 192,
         name: "Solomon Islands",
         code: "SB",
         phone_lengths: &[5, 6, 7],
         prefix: 677,
     },
     Country {
+        id: 193,
         name: "Seychelles",
         code: "SC",
         phone_lengths: &[7],
         prefix: 248,
     },
     Country {
+        id: 194,
         name: "Sudan",
         code: "SD",
         phone_lengths: &[7, 9, 10],
         prefix: 249,
     },
     Country {
+        id: 195,
         name: "Sweden",
         code: "SE",
         phone_lengths: &[7, 8, 9, 10],
         prefix: 46,
     },
     Country {
+        id: 196,
         name: "Singapore",
         code: "SG",
         phone_lengths: &[8],
         prefix: 65,
     },
     Country {
+        id: 197,
         name: "Saint Helena",
         code: "SH",
         phone_lengths: &[4, 5],
         prefix: 290,
     },
     Country {
+        id: 198,
         name: "Slovenia",
         code: "SI",
         phone_lengths: &[8],
         prefix: 386,
     },
     Country {
+        id: 199,
         name: "Svalbard and Jan Mayen",
         code: "SJ",
         phone_lengths: &[8],
         prefix: 47,
     },
     Country {
+        id: 200,
         name: "Slovakia",
         code: "SK",
         phone_lengths: &[9],
         prefix: 421,
     },
     Country {
+        id: 201,
         name: "Sierra Leone",
         code: "SL",
         phone_lengths: &[8],
         prefix: 232,
     },
     Country {
+        id: 202,
         name: "San Marino",
         code: "SM",
         phone_lengths: &[6, 7, 8, 9, 10],
         prefix: 378,
     },
     Country {
+        id: 203,
         name: "Senegal",
         code: "SN",
         phone_lengths: &[9],
         prefix: 221,
     },
     Country {
+        id: 204,
         name: "Somalia",
         code: "SO",
         phone_lengths: &[8, 9],
         prefix: 252,
     },
     Country {
+        id: 205,
         name: "Suriname",
         code: "SR",
         phone_lengths: &[6, 7],
         prefix: 597,
     },
     Country {
+        id: 206,
         name: "South Sudan",
         code: "SS",
         phone_lengths: &[7, 9],
         prefix: 211,
     },
     Country {
+        id: 207,
         name: "Sao Tome and Principe",
         code: "ST",
         phone_lengths: &[7],
         prefix: 239,
     },
     Country {
+        id: 208,
         name: "El Salvador",
         code: "SV",
         phone_lengths: &[8],
         prefix: 503,
     },
     Country {
+        id: 209,
         name: "Sint Maarten (Dutch part)",
         code: "SX",
-        phone_lengths: &[10],
+        phone_lengths: &[7],
         prefix: 1721,
     },
     Country {
+        id: 210,
         name: "Syrian Arab Republic",
         code: "SY",
         phone_lengths: &[7, 8, 9, 10],
         prefix: 963,
     },
     Country {
+        id: 211,
         name: "Swaziland",
         code: "SZ",
         phone_lengths: &[8],
         prefix: 268,
     },
     Country {
+        id: 212,
         name: "Turks and Caicos Islands",
         code: "TC",
-        phone_lengths: &[10],
+        phone_lengths: &[7],
         prefix: 1649,
     },
     Country {
+        id: 213,
         name: "Chad",
         code: "TD",
         phone_lengths: &[6, 8],
         prefix: 235,
     },
     Country {
+        id: 214,
         name: "French Southern Territories",
         code: "TF",
         phone_lengths: &[10],
         prefix: 262,
     },
     Country {
+        id: 215,
         name: "Togo",
         code: "TG",
         phone_lengths: &[8],
         prefix: 228,
     },
     Country {
+        id: 216,
         name: "Thailand",
         code: "TH",
         phone_lengths: &[9],
         prefix: 66,
     },
     Country {
+        id: 217,
         name: "Tajikistan",
         code: "TJ",
         phone_lengths: &[9],
         prefix: 992,
     },
     Country {
+        id: 218,
         name: "Tokelau",
         code: "TK",
         phone_lengths: &[4, 5],
         prefix: 690,
     },
     Country {
+        id: 219,
         name: "Timor-Leste",
         code: "TL",
         phone_lengths: &[8],
         prefix: 670,
     },
     Country {
+        id: 220,
         name: "Turkmenistan",
         code: "TM",
         phone_lengths: &[8, 9],
         prefix: 993,
     },
     Country {
+        id: 221,
         name: "Tunisia",
         code: "TN",
         phone_lengths: &[8],
         prefix: 216,
     },
     Country {
+        id: 222,
         name: "Tonga",
         code: "TO",
         phone_lengths: &[5, 6, 7, 8],
         prefix: 676,
     },
     Country {
+        id: 223,
         name: "Turkey",
         code: "TR",
         phone_lengths: &[10, 11],
         prefix: 90,
     },
     Country {
+        id: 224,
         name: "Trinidad and Tobago",
         code: "TT",
-        phone_lengths: &[10],
+        phone_lengths: &[7],
         prefix: 1868,
     },
     Country {
+        id: 225,
         name: "Tuvalu",
         code: "TV",
         phone_lengths: &[5, 6, 7],
         prefix: 688,
     },
     Country {
+        id: 226,
         name: "Taiwan",
         code: "TW",
         phone_lengths: &[9],
         prefix: 886,
     },
     Country {
+        id: 227,
         name: "Tanzania, United Republic of",
         code: "TZ",
         phone_lengths: &[9],
         prefix: 255,
     },
     Country {
+        id: 228,
         name: "Ukraine",
         code: "UA",
         phone_lengths: &[9],
         prefix: 380,
     },
     Country {
+        id: 229,
         name: "Uganda",
         code: "UG",
         phone_lengths: &[9],
         prefix: 256,
     },
     Country {
+        id: 230,
         name: "United States",
         code: "US",
         phone_lengths: &[10],
         prefix: 1,
     },
     Country {
+        id: 231,
         name: "Uruguay",
         code: "UY",
         phone_lengths: &[8, 9],
         prefix: 598,
     },
     Country {
+        id: 232,
         name: "Uzbekistan",
         code: "UZ",
         phone_lengths: &[9],
         prefix: 998,
     },
     Country {
+        id: 233,
         name: "Holy See (Vatican City State)",
         code: "VA",
         phone_lengths: &[5, 6, 7, 8, 9, 10],
         prefix: 379,
     },
     Country {
+        id: 234,
         name: "Saint Vincent and the Grenadines",
         code: "VC",
         phone_lengths: &[7],
         prefix: 1784,
     },
     Country {
+        id: 235,
         name: "Venezuela",
         code: "VE",
         phone_lengths: &[10],
         prefix: 58,
     },
     Country {
+        id: 236,
         name: "Virgin Islands, British",
         code: "VG",
-        phone_lengths: &[10],
+        phone_lengths: &[7],
         prefix: 1284,
     },
     Country {
+        id: 237,
         name: "Virgin Islands, U.S.",
         code: "VI",
-        phone_lengths: &[10],
+        phone_lengths: &[7],
         prefix: 1340,
     },
     Country {
+        id: 238,
         name: "Vietnam",
         code: "VN",
         phone_lengths: &[9],
         prefix: 84,
     },
     Country {
+        id: 239,
         name: "Vanuatu",
         code: "VU",
         phone_lengths: &[5, 6, 7],
         prefix: 678,
     },
     Country {
+        id: 240,
         name: "Wallis and Futuna",
         code: "WF",
         phone_lengths: &[6],
         prefix: 681,
     },
     Country {
+        id: 241,
         name: "Samoa",
         code: "WS",
         phone_lengths: &[5, 6, 7],
         prefix: 685,
     },
     Country {
+        id: 242,
         name: "Yemen",
         code: "YE",
         phone_lengths: &[9],
         prefix: 967,
     },
     Country {
+        id: 243,
         name: "Mayotte",
         code: "YT",
         phone_lengths: &[9],
         prefix: 262,
     },
     Country {
+        id: 244,
         name: "South Africa",
         code: "ZA",
         phone_lengths: &[9],
         prefix: 27,
     },
     Country {
+        id: 245,
         name: "Zambia",
         code: "ZM",
         phone_lengths: &[9],
         prefix: 260,
     },
     Country {
+        id: 246,
         name: "Zimbabwe",
         code: "ZW",
         phone_lengths: &[9],
         prefix: 263,
     },
+    Country {
+        id: 247,
+        name: "International Freephone",
+        code: "001",
+        phone_lengths: &[8],
+        prefix: 800,
+    },
+    Country {
+        id: 248,
+        name: "Canada",
+        code: "CA",
+        phone_lengths: &[10],
+        prefix: 1,
+    },
+    Country {
+        id: 249,
+        name: "Puerto Rico",
+        code: "PR",
+        phone_lengths: &[10],
+        prefix: 1,
+    },
+];
+
+// national trunk prefix of the countries where numbers are commonly stored in national form,
+// the trunk prefixes of Hungary and the countries of the Soviet plan are quirks, see `quirks`
+pub const TRUNK_PREFIXES: &[(&str, &str)] = &[
+    ("AE", "0"),
+    ("AF", "0"),
+    ("AR", "0"),
+    ("AT", "0"),
+    ("AU", "0"),
+    ("AX", "0"),
+    ("BA", "0"),
+    ("BD", "0"),
+    ("BE", "0"),
+    ("BG", "0"),
+    ("BR", "0"),
+    ("CA", "1"),
+    ("CC", "0"),
+    ("CH", "0"),
+    ("CN", "0"),
+    ("CX", "0"),
+    ("DE", "0"),
+    ("DZ", "0"),
+    ("EG", "0"),
+    ("EH", "0"),
+    ("ET", "0"),
+    ("FI", "0"),
+    ("FR", "0"),
+    ("GB", "0"),
+    ("GG", "0"),
+    ("GH", "0"),
+    ("HR", "0"),
+    ("ID", "0"),
+    ("IE", "0"),
+    ("IL", "0"),
+    ("IM", "0"),
+    ("IN", "0"),
+    ("IQ", "0"),
+    ("IR", "0"),
+    ("JE", "0"),
+    ("JO", "0"),
+    ("JP", "0"),
+    ("KE", "0"),
+    ("KR", "0"),
+    ("LB", "0"),
+    ("LK", "0"),
+    ("MA", "0"),
+    ("MK", "0"),
+    ("MM", "0"),
+    ("MY", "0"),
+    ("NG", "0"),
+    ("NL", "0"),
+    ("NP", "0"),
+    ("NZ", "0"),
+    ("PE", "0"),
+    ("PH", "0"),
+    ("PK", "0"),
+    ("PR", "1"),
+    ("RO", "0"),
+    ("RS", "0"),
+    ("SA", "0"),
+    ("SE", "0"),
+    ("SI", "0"),
+    ("SK", "0"),
+    ("SY", "0"),
+    ("TH", "0"),
+    ("TR", "0"),
+    ("TW", "0"),
+    ("TZ", "0"),
+    ("UA", "0"),
+    ("UG", "0"),
+    ("US", "1"),
+    ("VN", "0"),
+    ("YE", "0"),
+    ("ZA", "0"),
+    ("ZM", "0"),
+    ("ZW", "0"),
+];
+
+// international dialing prefix of the countries that don't use the recommended "00"
+pub const INTERNATIONAL_PREFIXES: &[(&str, &str)] = &[
+    ("AG", "011"),
+    ("AI", "011"),
+    ("AS", "011"),
+    ("AU", "0011"),
+    ("BB", "011"),
+    ("BM", "011"),
+    ("BS", "011"),
+    ("CA", "011"),
+    ("DM", "011"),
+    ("DO", "011"),
+    ("GD", "011"),
+    ("GU", "011"),
+    ("HK", "001"),
+    ("ID", "001"),
+    ("JM", "011"),
+    ("JP", "010"),
+    ("KE", "000"),
+    ("KH", "001"),
+    ("KN", "011"),
+    ("KR", "001"),
+    ("KY", "011"),
+    ("KZ", "810"),
+    ("LC", "011"),
+    ("MN", "001"),
+    ("MP", "011"),
+    ("MS", "011"),
+    ("NG", "009"),
+    ("PR", "011"),
+    ("RU", "810"),
+    ("SG", "001"),
+    ("SX", "011"),
+    ("TC", "011"),
+    ("TH", "001"),
+    ("TT", "011"),
+    ("TW", "002"),
+    ("TZ", "000"),
+    ("UG", "000"),
+    ("US", "011"),
+    ("VC", "011"),
+    ("VG", "011"),
+    ("VI", "011"),
+];
+
+// markers written between a number and the digits of its extension, matched in any case
+pub const EXTENSION_MARKERS: &[&str] = &[";ext=", "ext.", "ext", "x", "#", ","];
+
+// (alias, ISO 3166 code) of the region codes commonly used instead of the ISO ones
+pub const REGION_ALIASES: &[(&str, &str)] = &[
+    // European Union country codes
+    ("EL", "GR"),
+    ("UK", "GB"),
+];
+
+// pairs of neighboring countries (land borders and close maritime neighbors), either order
+#[rustfmt::skip]
+pub const NEIGHBORS: &[(&str, &str)] = &[
+    // Europe
+    ("FR", "BE"), ("FR", "LU"), ("FR", "DE"), ("FR", "CH"), ("FR", "IT"), ("FR", "MC"),
+    ("FR", "ES"), ("FR", "AD"), ("FR", "GB"), ("BE", "NL"), ("BE", "DE"), ("BE", "LU"),
+    ("NL", "DE"), ("DE", "DK"), ("DE", "PL"), ("DE", "CZ"), ("DE", "AT"), ("DE", "CH"),
+    ("DE", "LU"), ("CH", "IT"), ("CH", "AT"), ("CH", "LI"), ("AT", "LI"), ("AT", "IT"),
+    ("AT", "SI"), ("AT", "HU"), ("AT", "SK"), ("AT", "CZ"), ("IT", "SM"), ("IT", "VA"),
+    ("IT", "SI"), ("IT", "MT"), ("ES", "PT"), ("ES", "AD"), ("ES", "GI"), ("GB", "IE"),
+    ("GB", "GG"), ("GB", "JE"), ("GB", "IM"), ("GG", "JE"), ("PL", "CZ"), ("PL", "SK"),
+    ("PL", "UA"), ("PL", "BY"), ("PL", "LT"), ("PL", "RU"), ("CZ", "SK"), ("SK", "HU"),
+    ("SK", "UA"), ("HU", "UA"), ("HU", "RO"), ("HU", "RS"), ("HU", "HR"), ("HU", "SI"),
+    ("SI", "HR"), ("HR", "BA"), ("HR", "RS"), ("HR", "ME"), ("BA", "RS"), ("BA", "ME"),
+    ("RS", "ME"), ("RS", "MK"), ("RS", "BG"), ("RS", "RO"), ("ME", "AL"), ("AL", "MK"),
+    ("AL", "GR"), ("MK", "GR"), ("MK", "BG"), ("GR", "BG"), ("GR", "TR"), ("GR", "CY"),
+    ("CY", "TR"), ("BG", "TR"), ("BG", "RO"), ("RO", "UA"), ("RO", "MD"), ("MD", "UA"),
+    ("UA", "BY"), ("UA", "RU"), ("BY", "RU"), ("BY", "LT"), ("BY", "LV"), ("LT", "LV"),
+    ("LT", "RU"), ("LV", "EE"), ("LV", "RU"), ("EE", "RU"), ("EE", "FI"), ("FI", "RU"),
+    ("FI", "SE"), ("FI", "NO"), ("FI", "AX"), ("AX", "SE"), ("NO", "SE"), ("NO", "RU"),
+    ("NO", "SJ"), ("DK", "SE"), ("DK", "FO"), ("DK", "GL"), ("IS", "FO"),
+    // Caucasus, Central Asia and the Middle East
+    ("RU", "KZ"), ("RU", "GE"), ("RU", "AZ"), ("RU", "MN"), ("RU", "CN"), ("RU", "KP"),
+    ("KZ", "CN"), ("KZ", "KG"), ("KZ", "UZ"), ("KZ", "TM"), ("UZ", "KG"), ("UZ", "TJ"),
+    ("UZ", "AF"), ("UZ", "TM"), ("KG", "TJ"), ("KG", "CN"), ("TJ", "AF"), ("TJ", "CN"),
+    ("TM", "AF"), ("TM", "IR"), ("GE", "AZ"), ("GE", "AM"), ("GE", "TR"), ("AM", "AZ"),
+    ("AM", "IR"), ("AM", "TR"), ("AZ", "IR"), ("TR", "SY"), ("TR", "IQ"), ("TR", "IR"),
+    ("SY", "LB"), ("SY", "IQ"), ("SY", "JO"), ("SY", "IL"), ("LB", "IL"), ("IL", "JO"),
+    ("IL", "EG"), ("IL", "PS"), ("PS", "JO"), ("PS", "EG"), ("JO", "IQ"), ("JO", "SA"),
+    ("IQ", "IR"), ("IQ", "KW"), ("IQ", "SA"), ("SA", "KW"), ("SA", "QA"), ("SA", "AE"),
+    ("SA", "OM"), ("SA", "YE"), ("SA", "BH"), ("BH", "QA"), ("QA", "AE"), ("AE", "OM"),
+    ("OM", "YE"),
+    // South, East and Southeast Asia and Oceania
+    ("IR", "AF"), ("IR", "PK"), ("AF", "PK"), ("AF", "CN"), ("PK", "IN"), ("PK", "CN"),
+    ("IN", "CN"), ("IN", "NP"), ("IN", "BT"), ("IN", "BD"), ("IN", "MM"), ("IN", "LK"),
+    ("LK", "MV"), ("BD", "MM"), ("CN", "MN"), ("CN", "KP"), ("CN", "VN"), ("CN", "LA"),
+    ("CN", "MM"), ("CN", "NP"), ("CN", "BT"), ("CN", "HK"), ("CN", "MO"), ("CN", "TW"),
+    ("KP", "KR"), ("KR", "JP"), ("JP", "TW"), ("TW", "PH"), ("VN", "LA"), ("VN", "KH"),
+    ("LA", "TH"), ("LA", "KH"), ("LA", "MM"), ("TH", "MM"), ("TH", "KH"), ("TH", "MY"),
+    ("MY", "SG"), ("MY", "BN"), ("MY", "ID"), ("MY", "PH"), ("ID", "TL"), ("ID", "PG"),
+    ("ID", "SG"), ("ID", "PH"), ("AU", "PG"), ("AU", "NZ"), ("AU", "CX"), ("AU", "CC"),
+    ("AU", "NF"), ("AU", "TL"), ("PG", "SB"), ("SB", "VU"), ("VU", "NC"), ("NC", "FJ"),
+    ("FJ", "TO"), ("TO", "WS"), ("WS", "AS"), ("NZ", "CK"), ("CK", "NU"), ("NU", "TK"),
+    ("GU", "MP"), ("GU", "FM"), ("FM", "MH"), ("FM", "PW"),
+    // Americas
+    ("US", "CA"), ("US", "MX"), ("US", "BS"), ("US", "CU"), ("US", "PR"), ("PR", "DO"),
+    ("PR", "VI"), ("CA", "PM"), ("MX", "GT"), ("MX", "BZ"), ("GT", "BZ"),
+    ("GT", "HN"), ("GT", "SV"), ("HN", "SV"), ("HN", "NI"), ("NI", "CR"), ("CR", "PA"),
+    ("PA", "CO"), ("CO", "VE"), ("CO", "EC"), ("CO", "PE"), ("CO", "BR"), ("VE", "BR"),
+    ("VE", "GY"), ("VE", "TT"), ("VE", "AW"), ("VE", "CW"), ("GY", "BR"), ("GY", "SR"),
+    ("SR", "BR"), ("SR", "GF"), ("GF", "BR"), ("EC", "PE"), ("PE", "BR"), ("PE", "BO"),
+    ("PE", "CL"), ("BO", "BR"), ("BO", "PY"), ("BO", "AR"), ("BO", "CL"), ("CL", "AR"),
+    ("AR", "PY"), ("AR", "BR"), ("AR", "UY"), ("AR", "FK"), ("UY", "BR"), ("PY", "BR"),
+    ("FK", "GS"), ("BS", "CU"), ("BS", "TC"), ("CU", "JM"), ("CU", "HT"), ("CU", "KY"),
+    ("KY", "JM"), ("HT", "DO"), ("HT", "TC"), ("DO", "VI"), ("VI", "VG"), ("VG", "AI"),
+    ("AI", "MF"), ("MF", "SX"), ("MF", "BL"), ("SX", "BL"), ("BL", "KN"), ("KN", "AG"),
+    ("AG", "MS"), ("MS", "GP"), ("GP", "DM"), ("DM", "MQ"), ("MQ", "LC"), ("LC", "VC"),
+    ("VC", "GD"), ("GD", "TT"), ("TT", "BB"), ("BB", "LC"), ("AW", "CW"), ("US", "BM"),
+    ("PM", "GL"),
+    // Africa and the Indian Ocean
+    ("EG", "LY"), ("EG", "SD"), ("LY", "TN"), ("LY", "DZ"), ("LY", "NE"), ("LY", "TD"),
+    ("LY", "SD"), ("TN", "DZ"), ("TN", "IT"), ("DZ", "MA"), ("DZ", "EH"), ("DZ", "MR"),
+    ("DZ", "ML"), ("DZ", "NE"), ("MA", "EH"), ("MA", "ES"), ("EH", "MR"), ("MR", "SN"),
+    ("MR", "ML"), ("SN", "GM"), ("SN", "GW"), ("SN", "GN"), ("SN", "ML"), ("SN", "CV"),
+    ("GN", "GW"), ("GN", "SL"), ("GN", "LR"), ("GN", "CI"), ("GN", "ML"), ("SL", "LR"),
+    ("LR", "CI"), ("CI", "ML"), ("CI", "BF"), ("CI", "GH"), ("GH", "BF"), ("GH", "TG"),
+    ("TG", "BF"), ("TG", "BJ"), ("BJ", "BF"), ("BJ", "NE"), ("BJ", "NG"), ("NG", "NE"),
+    ("NG", "TD"), ("NG", "CM"), ("CM", "TD"), ("CM", "CF"), ("CM", "CG"), ("CM", "GA"),
+    ("CM", "GQ"), ("GA", "GQ"), ("GA", "CG"), ("GQ", "ST"), ("CG", "CD"), ("CG", "CF"),
+    ("CD", "CF"), ("CD", "SS"), ("CD", "UG"), ("CD", "RW"), ("CD", "BI"), ("CD", "TZ"),
+    ("CD", "ZM"), ("CD", "AO"), ("AO", "CG"), ("AO", "ZM"), ("AO", "NA"), ("NA", "BW"),
+    ("NA", "ZA"), ("NA", "ZM"), ("ZA", "BW"), ("ZA", "ZW"), ("ZA", "MZ"), ("ZA", "SZ"),
+    ("ZA", "LS"), ("MZ", "SZ"), ("MZ", "ZW"), ("MZ", "ZM"), ("MZ", "MW"), ("MZ", "TZ"),
+    ("TZ", "KE"), ("TZ", "UG"), ("TZ", "RW"), ("TZ", "BI"), ("TZ", "ZM"), ("TZ", "MW"),
+    ("KE", "UG"), ("KE", "SS"), ("KE", "ET"), ("KE", "SO"), ("ET", "SO"), ("ET", "DJ"),
+    ("ET", "ER"), ("ET", "SD"), ("ET", "SS"), ("SD", "SS"), ("SD", "TD"), ("SD", "CF"),
+    ("SD", "ER"), ("CF", "TD"), ("CF", "SS"), ("DJ", "ER"), ("DJ", "SO"), ("UG", "SS"),
+    ("UG", "RW"), ("RW", "BI"), ("ZM", "ZW"), ("ZM", "MW"), ("ZW", "BW"), ("NE", "TD"),
+    ("NE", "ML"), ("NE", "BF"), ("ML", "BF"), ("MG", "RE"), ("MG", "YT"), ("MG", "MU"),
+    ("RE", "MU"), ("YT", "KM"), ("KM", "MG"), ("SC", "MU"), ("SH", "AC"),
+];
+
+// leading digits of the national numbers of the countries sharing their calling code with
+// another country, a number goes to the country matching the longest calling code and area
+// code, then to the first one in table order. The area codes of a country have the same
+// length.
+#[rustfmt::skip]
+pub const AREA_CODES: &[(&str, &[&str])] = &[
+    ("CA", &[
+        "204", "226", "236", "249", "250", "257", "263", "289", "306", "343", "354", "365", "367",
+        "368", "382", "403", "416", "418", "428", "431", "437", "438", "450", "460", "468", "474",
+        "506", "514", "519", "548", "579", "581", "584", "587", "604", "613", "639", "647", "672",
+        "683", "705", "709", "742", "753", "778", "780", "782", "807", "819", "825", "867", "873",
+        "879", "902", "905",
+    ]),
+    ("AQ", &["1"]),
+    ("AX", &["18"]),
+    ("BL", &["59027", "59029"]),
+    ("CC", &["89162"]),
+    ("CX", &["89164"]),
+    ("DO", &["809", "829", "849"]),
+    ("EH", &["5288", "5289"]),
+    ("GG", &["1481", "7781", "7839", "7911"]),
+    ("IM", &["1624", "7524", "7624", "7924"]),
+    ("JE", &["1534", "7509", "7797", "7829", "7937"]),
+    ("KZ", &["6", "7"]),
+    ("MF", &["59051", "59052", "59077", "59087"]),
+    ("NF", &["3"]),
+    ("PR", &["787", "939"]),
+    ("RE", &["262", "263", "692", "693"]),
+    ("SJ", &["79"]),
+    ("YT", &["269", "639"]),
+];
+
+// emergency numbers of the countries where they are known, the general one first
+#[rustfmt::skip]
+pub const EMERGENCY_NUMBERS: &[(&str, &[&str])] = &[
+    ("AE", &["999", "998", "997"]),
+    ("AR", &["911", "100", "101", "107"]),
+    ("AT", &["112", "122", "133", "144"]),
+    ("AU", &["000", "112"]),
+    ("BE", &["112", "100", "101"]),
+    ("BR", &["190", "192", "193"]),
+    ("CA", &["911"]),
+    ("CH", &["112", "117", "118", "144"]),
+    ("CN", &["110", "119", "120"]),
+    ("DE", &["112", "110"]),
+    ("DK", &["112"]),
+    ("EG", &["122", "123", "180"]),
+    ("ES", &["112"]),
+    ("FI", &["112"]),
+    ("FR", &["112", "15", "17", "18"]),
+    ("GB", &["999", "112"]),
+    ("GR", &["112", "100", "166", "199"]),
+    ("HK", &["999"]),
+    ("IE", &["112", "999"]),
+    ("IL", &["100", "101", "102"]),
+    ("IN", &["112", "100", "101", "102"]),
+    ("IT", &["112", "113", "115", "118"]),
+    ("JP", &["110", "119"]),
+    ("KR", &["112", "119"]),
+    ("LB", &["112", "140", "175"]),
+    ("MX", &["911"]),
+    ("NL", &["112"]),
+    ("NO", &["112", "110", "113"]),
+    ("NZ", &["111"]),
+    ("PH", &["911"]),
+    ("PL", &["112", "997", "998", "999"]),
+    ("PR", &["911"]),
+    ("PT", &["112"]),
+    ("RU", &["112", "101", "102", "103"]),
+    ("SA", &["911", "997", "998", "999"]),
+    ("SE", &["112"]),
+    ("SG", &["999", "995"]),
+    ("TH", &["191", "199", "1669"]),
+    ("TR", &["112"]),
+    ("US", &["911"]),
+    ("ZA", &["10111", "112"]),
+];
+
+// carrier and service short codes dialed without area code, other than the emergency numbers
+#[rustfmt::skip]
+pub const CARRIER_SHORT_CODES: &[(&str, &[&str])] = &[
+    ("AU", &["1223", "1225"]),
+    ("CA", &["211", "311", "411", "611", "711", "811"]),
+    ("DE", &["11833", "11880"]),
+    ("FR", &["3179", "3939"]),
+    ("GB", &["100", "101", "111", "150", "155"]),
+    ("IN", &["121", "198", "199"]),
+    ("US", &["211", "311", "411", "611", "711", "811"]),
+];
+
+// (calling code, national number) of the ranges reserved by regulators for films, books and
+// sample data, where each `#` stands for any digit
+#[rustfmt::skip]
+pub const FICTION_RANGES: &[(u32, &str)] = &[
+    // 555-0100 to 555-0199 in every NANP area code
+    (1, "###55501##"),
+    // Ofcom drama numbers
+    (44, "7700900###"),
+    (44, "1134960###"), (44, "1144960###"), (44, "1154960###"), (44, "1164960###"),
+    (44, "1174960###"), (44, "1184960###"), (44, "1214960###"), (44, "1314960###"),
+    (44, "1414960###"), (44, "1514960###"), (44, "1614960###"), (44, "1914980###"),
+    (44, "2079460###"), (44, "2896496###"), (44, "2920180###"), (44, "1632960###"),
+    (44, "3069990###"), (44, "8081570###"), (44, "9098790###"),
+    // ACMA fictitious numbers, (0x) 5550 xxxx and (0x) 7010 xxxx in every area
+    (61, "25550####"), (61, "35550####"), (61, "75550####"), (61, "85550####"),
+    (61, "27010####"), (61, "37010####"), (61, "77010####"), (61, "87010####"),
 ];
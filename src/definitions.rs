@@ -1,7 +1,86 @@
+use std::ops::RangeInclusive;
+
+use crate::constants::{INTERNATIONAL_PREFIXES, TRUNK_PREFIXES};
+use crate::{number_type, quirks, stats, PhoneNumberType};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Country {
+    /// Stable identifier of the entry, safe to persist instead of the ISO code.
+    ///
+    /// Ids are assigned once when a country is added to the metadata and are never reused,
+    /// even if the entry is later removed or the table is reordered.
+    pub id: u16,
     pub name: &'static str,
     pub code: &'static str,
     pub phone_lengths: &'static [u8],
     pub prefix: u32,
-}
\ No newline at end of file
+}
+
+impl Country {
+    /// Returns the stable identifier of this country, see [`crate::country_by_id`].
+    pub fn id(&self) -> u16 {
+        self.id
+    }
+
+    /// Returns the number of digits of [`Country::prefix`], e.g. `3` for `961`.
+    pub const fn prefix_len(&self) -> usize {
+        let mut prefix = self.prefix / 10;
+        let mut len = 1;
+        while prefix > 0 {
+            prefix /= 10;
+            len += 1;
+        }
+        len
+    }
+
+    /// Returns the E.164 calling code of the country, e.g. `1` for every NANP member whose
+    /// [`Country::prefix`] includes its area codes, such as `1242` for the Bahamas.
+    pub fn calling_code(&self) -> u32 {
+        crate::calling_code(self)
+    }
+
+    /// Returns the range of valid lengths of the national numbers of `number_type`, e.g.
+    /// `10..=11` for German mobiles and `6..=11` for German landlines, or `None` when the
+    /// country doesn't tell the types apart by length and [`Country::phone_lengths`] apply.
+    pub fn length_range_for_type(
+        &self,
+        number_type: PhoneNumberType,
+    ) -> Option<RangeInclusive<u8>> {
+        number_type::type_lengths(self.calling_code(), number_type)
+    }
+
+    /// Returns the national trunk prefix dialed before national numbers, e.g. `"0"` for
+    /// France or `"8"` for Russia, or `None` if the country has none or it is unknown.
+    pub fn trunk_prefix(&self) -> Option<&'static str> {
+        stats::record_table_lookup();
+        TRUNK_PREFIXES
+            .iter()
+            .find(|(code, _)| *code == self.code)
+            .map(|(_, prefix)| *prefix)
+            .or_else(|| quirks::trunk_prefix(self))
+    }
+
+    /// Returns the international dialing prefix (IDD) dialed before the calling code to
+    /// call abroad from the country, e.g. `"011"` from the United States or `"0011"` from
+    /// Australia, and the recommended `"00"` for the countries not listed otherwise.
+    pub fn international_prefix(&self) -> &'static str {
+        stats::record_table_lookup();
+        INTERNATIONAL_PREFIXES
+            .iter()
+            .find(|(code, _)| *code == self.code)
+            .map_or("00", |(_, prefix)| *prefix)
+    }
+
+    /// Returns true if the leading zero of national numbers is part of the number and is
+    /// dialed after the calling code, e.g. `+39 06 1234 5678` in Italy, rather than being a
+    /// trunk prefix dropped in international form.
+    pub fn zero_significant(&self) -> bool {
+        quirks::keeps_leading_zero(self)
+    }
+
+    /// Returns [`Country::prefix`] as digits, e.g. `"961"`.
+    pub fn prefix_str(&self) -> String {
+        self.prefix.to_string()
+    }
+}
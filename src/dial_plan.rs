@@ -0,0 +1,127 @@
+use crate::definitions::Country;
+use crate::{
+    calling_code, emergency_numbers_for_region, find_country_by_code, remove_non_digit_character,
+    E164,
+};
+
+/// Dial plan of an office PBX, used to convert between the strings dialed on desk phones and
+/// E.164 numbers in CTI integrations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DialPlan {
+    /// Country the PBX is connected in.
+    pub home_country: &'static Country,
+    /// Digits dialed to get an outside line, e.g. `"9"`.
+    pub outside_line_prefix: String,
+    /// International dialing prefix of the home country, e.g. `"00"` or `"011"`.
+    pub international_prefix: String,
+    /// National trunk prefix of the home country, e.g. `"0"`, or empty where national
+    /// numbers are dialed without one, e.g. in Italy.
+    pub national_prefix: String,
+    /// Number of digits of internal extensions.
+    pub extension_length: usize,
+    /// Numbers routed to the public network without the outside line prefix.
+    pub emergency_numbers: Vec<String>,
+}
+
+/// What a string dialed on a PBX phone reaches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DialTarget {
+    Extension(String),
+    Emergency(String),
+    /// A public number, in E.164 form.
+    External(String),
+}
+
+impl DialPlan {
+    /// Creates a dial plan for a PBX in `home_region` (an ISO 3166 code) with the
+    /// international and trunk prefixes of the region (see
+    /// [`Country::international_prefix`] and [`Country::trunk_prefix`]), and the emergency
+    /// numbers of the region (see [`crate::emergency_numbers_for_region`]) or `112` if they
+    /// aren't known.
+    pub fn new(
+        home_region: &str,
+        outside_line_prefix: &str,
+        extension_length: usize,
+    ) -> Option<DialPlan> {
+        let home_country = find_country_by_code(home_region)?;
+        // the leading zero of Italian numbers is part of the number, not a trunk prefix
+        let national_prefix = match home_country.zero_significant() {
+            true => "",
+            false => home_country.trunk_prefix().unwrap_or(""),
+        };
+        Some(DialPlan {
+            home_country,
+            outside_line_prefix: outside_line_prefix.to_string(),
+            international_prefix: home_country.international_prefix().to_string(),
+            national_prefix: national_prefix.to_string(),
+            extension_length,
+            emergency_numbers: emergency_numbers_for_region(home_region)
+                .unwrap_or(&["112"])
+                .iter()
+                .map(|number| number.to_string())
+                .collect(),
+        })
+    }
+
+    /// Returns the digits to dial on a PBX phone to reach `number`, which is either an
+    /// internal extension, an emergency number or any phone number accepted by
+    /// [`crate::normalize_phone_number`].
+    pub fn to_internal_dial_string(&self, number: &str) -> Option<String> {
+        let mut digits = number.to_string();
+        remove_non_digit_character(&mut digits);
+        if !number.trim_start().starts_with('+') && self.internal_target(&digits).is_some() {
+            return Some(digits);
+        }
+
+        let number = E164::parse(number)?;
+
+        if calling_code(number.country()) == calling_code(self.home_country) {
+            Some(format!(
+                "{}{}{}",
+                self.outside_line_prefix,
+                self.national_prefix,
+                number.national_number()
+            ))
+        } else {
+            Some(format!(
+                "{}{}{}",
+                self.outside_line_prefix,
+                self.international_prefix,
+                &number[1..]
+            ))
+        }
+    }
+
+    /// Resolves a string dialed on a PBX phone, such as `"9-0049301234567"`.
+    pub fn from_internal(&self, dialed: &str) -> Option<DialTarget> {
+        let mut digits = dialed.to_string();
+        remove_non_digit_character(&mut digits);
+        if let Some(target) = self.internal_target(&digits) {
+            return Some(target);
+        }
+
+        let external = digits.strip_prefix(self.outside_line_prefix.as_str())?;
+        let number = if let Some(international) =
+            external.strip_prefix(self.international_prefix.as_str())
+        {
+            E164::parse(&format!("+{}", international))?
+        } else {
+            let national = external
+                .strip_prefix(self.national_prefix.as_str())
+                .unwrap_or(external);
+            E164::parse(&format!("+{}{}", calling_code(self.home_country), national))?
+        };
+
+        Some(DialTarget::External(number.to_string()))
+    }
+
+    fn internal_target(&self, digits: &str) -> Option<DialTarget> {
+        if self.emergency_numbers.iter().any(|number| number == digits) {
+            Some(DialTarget::Emergency(digits.to_string()))
+        } else if digits.len() == self.extension_length {
+            Some(DialTarget::Extension(digits.to_string()))
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use crate::formatting::format_e164;
+use crate::{calling_code, find_country_by_code, PhoneFormat, E164};
+
+/// How [`display_number`] shortens a number for the viewer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DisplayPolicy {
+    /// Shows the whole number.
+    Full,
+    /// Replaces every digit with `*` except the given number of trailing digits. The calling
+    /// code of foreign numbers stays visible.
+    MaskAllButLast(usize),
+    /// Shows at most the given number of characters, ending with `…` when cut.
+    Truncate(usize),
+}
+
+/// Formats a phone number for display to a viewer in `viewer_region` (an ISO 3166 code):
+/// numbers of the viewer's calling code are shown in national format, others in
+/// international format, then `policy` is applied. Returns `None` if the number is not
+/// valid.
+pub fn display_number(
+    phone_number: &str,
+    viewer_region: &str,
+    policy: DisplayPolicy,
+) -> Option<String> {
+    let number = E164::parse(phone_number)?;
+    let is_domestic = find_country_by_code(viewer_region)
+        .is_some_and(|viewer| calling_code(viewer) == calling_code(number.country()));
+
+    let (formatted, visible_prefix) = if is_domestic {
+        (format_e164(&number, PhoneFormat::National), 0)
+    } else {
+        (
+            format_e164(&number, PhoneFormat::International),
+            // "+" and the calling code
+            1 + calling_code(number.country()).to_string().len(),
+        )
+    };
+
+    Some(match policy {
+        DisplayPolicy::Full => formatted,
+        DisplayPolicy::MaskAllButLast(visible) => mask(&formatted, visible_prefix, visible, '*'),
+        DisplayPolicy::Truncate(max_chars) => truncate(&formatted, max_chars),
+    })
+}
+
+// replaces the digits after the first `visible_prefix` bytes with `mask_char`, except the
+// last `visible_suffix` digits
+pub(crate) fn mask(
+    formatted: &str,
+    visible_prefix: usize,
+    visible_suffix: usize,
+    mask_char: char,
+) -> String {
+    let (prefix, rest) = formatted.split_at(visible_prefix);
+    let mut to_mask = rest
+        .chars()
+        .filter(char::is_ascii_digit)
+        .count()
+        .saturating_sub(visible_suffix);
+
+    let masked: String = rest
+        .chars()
+        .map(|c| {
+            if c.is_ascii_digit() && to_mask > 0 {
+                to_mask -= 1;
+                mask_char
+            } else {
+                c
+            }
+        })
+        .collect();
+    format!("{}{}", prefix, masked)
+}
+
+fn truncate(formatted: &str, max_chars: usize) -> String {
+    if formatted.chars().count() <= max_chars {
+        return formatted.to_string();
+    }
+
+    let mut truncated: String = formatted
+        .chars()
+        .take(max_chars.saturating_sub(1))
+        .collect();
+    truncated.truncate(truncated.trim_end().len());
+    if max_chars > 0 {
+        truncated.push('…');
+    }
+    truncated
+}
@@ -0,0 +1,269 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+
+use crate::definitions::Country;
+use crate::neighbors::likely_country_of;
+use crate::stats;
+use crate::unicode::{self, ascii_digit};
+use crate::{
+    calling_code, country_by_id, find_country_by_code, split_national_number, strip_extension,
+};
+
+/// A normalized phone number in E.164 form (e.g. `+96179123123`).
+///
+/// E.164 numbers are at most 15 digits plus the leading `+`, so the value is stored inline
+/// in a fixed `[u8; 16]` buffer and is `Copy`: producing one never touches the heap.
+/// The matched country is kept as its [`Country::id`]. It isn't part of the identity of the
+/// number: the same digits matched to two countries sharing a calling code, e.g. with
+/// another region hint, are equal and hash the same.
+///
+/// Numbers are ordered by calling code, then by national number, both compared numerically:
+/// `+1…` comes before `+33…`, and `+33 999…` before `+33 1000…`. Numbers of one calling
+/// code and length are contiguous, so a `BTreeMap` range scans a block of numbers:
+///
+/// ```
+/// use std::collections::BTreeSet;
+/// use phonelib::E164;
+///
+/// let numbers: BTreeSet<E164> = ["+493012345678", "+494012345678", "+493099999999"]
+///     .iter()
+///     .filter_map(|phone_number| E164::parse(phone_number))
+///     .collect();
+/// let berlin = E164::parse("+493000000000").unwrap()..E164::parse("+493100000000").unwrap();
+///
+/// assert_eq!(numbers.range(berlin).count(), 2);
+/// ```
+#[derive(Clone, Copy)]
+pub struct E164 {
+    len: u8,
+    bytes: [u8; E164::MAX_LEN],
+    country_id: u16,
+}
+
+impl E164 {
+    /// Maximum length of an E.164 number including the leading `+`.
+    pub const MAX_LEN: usize = 16;
+
+    /// Normalizes `phone_number` the same way as [`crate::normalize_phone_number`], without
+    /// allocating. Extensions are left out, see [`crate::PhoneNumber`] to keep them.
+    pub fn parse(phone_number: &str) -> Option<E164> {
+        let phone_number = strip_extension(phone_number);
+        // collect the digits on the stack, skipping the leading zeros before the country code
+        let mut digits = [0u8; 2 * E164::MAX_LEN];
+        let mut len = 0;
+        stats::record_bytes_scanned(phone_number.len());
+        for c in phone_number.chars() {
+            let Some(c) = ascii_digit(c) else {
+                continue;
+            };
+            if len == 0 && c == '0' {
+                continue;
+            }
+            if len == digits.len() {
+                return None;
+            }
+            digits[len] = c as u8;
+            len += 1;
+        }
+
+        E164::from_digits(std::str::from_utf8(&digits[..len]).ok()?)
+    }
+
+    /// Parses a number as dialed from `region` (an ISO 3166 code): numbers starting with the
+    /// international dialing prefix of the region, e.g. `"011 44…"` from `"US"` or
+    /// `"0011 44…"` from `"AU"`, are read as international numbers once the prefix is
+    /// stripped, the others as national numbers of the region with or without its trunk
+    /// prefix. Numbers starting with `+`, numbers that don't fit the region and all numbers
+    /// of unknown regions are parsed like [`E164::parse`].
+    ///
+    /// When several countries share the calling code, `region` and its neighbors are
+    /// preferred, see [`crate::likely_country`].
+    ///
+    /// ```
+    /// use phonelib::E164;
+    ///
+    /// assert_eq!(E164::parse_with_default_region("011 44 20 7946 0958", "US").unwrap(), "+442079460958");
+    /// assert_eq!(E164::parse_with_default_region("0011 44 20 7946 0958", "AU").unwrap(), "+442079460958");
+    /// assert_eq!(E164::parse_with_default_region("020 7946 0958", "GB").unwrap(), "+442079460958");
+    /// ```
+    pub fn parse_with_default_region(phone_number: &str, region: &str) -> Option<E164> {
+        let Some(country) = find_country_by_code(region) else {
+            return E164::parse(phone_number);
+        };
+        let number = E164::parse_dialed_from(phone_number, country)?;
+        Some(number.with_country(likely_country_of(&number, region)))
+    }
+
+    // reads a number written in the national form of `country`, with or without its trunk
+    // prefix
+    pub(crate) fn parse_national(national_number: &str, country: &Country) -> Option<E164> {
+        let digits: String = strip_extension(national_number)
+            .chars()
+            .filter_map(ascii_digit)
+            .collect();
+        let national_number = country
+            .trunk_prefix()
+            .and_then(|trunk_prefix| digits.strip_prefix(trunk_prefix))
+            .unwrap_or(&digits);
+
+        E164::from_digits(&format!("{}{}", calling_code(country), national_number))
+    }
+
+    // reads a number as dialed from `country`: international numbers start with `+` or the
+    // international prefix of the country, the others are national numbers
+    pub(crate) fn parse_dialed_from(number: &str, country: &Country) -> Option<E164> {
+        let number = without_tel_scheme(unicode::trim_start(number));
+        if number.starts_with('+') {
+            return E164::parse(number);
+        }
+
+        let digits: String = strip_extension(number)
+            .chars()
+            .filter_map(ascii_digit)
+            .collect();
+        // the exit code of the country is stripped as a whole, so "011 44…" from the US
+        // isn't read as a number starting with 11
+        digits
+            .strip_prefix(country.international_prefix())
+            .and_then(|international| E164::from_digits(international.trim_start_matches('0')))
+            .or_else(|| E164::parse_national(number, country))
+            .or_else(|| E164::parse(number))
+    }
+
+    // digits must be ASCII digits without leading zeros
+    pub(crate) fn from_digits(digits: &str) -> Option<E164> {
+        let (country, national_number) = split_national_number(digits)?;
+        let prefix = country.prefix_str();
+
+        let mut number = E164 {
+            len: 0,
+            bytes: [0; E164::MAX_LEN],
+            country_id: country.id(),
+        };
+        number.push(b"+")?;
+        number.push(prefix.as_bytes())?;
+        number.push(national_number.as_bytes())?;
+        Some(number)
+    }
+
+    fn push(&mut self, bytes: &[u8]) -> Option<()> {
+        let start = self.len as usize;
+        let end = start + bytes.len();
+        if end > E164::MAX_LEN {
+            return None;
+        }
+        self.bytes[start..end].copy_from_slice(bytes);
+        self.len = end as u8;
+        Some(())
+    }
+
+    /// Returns the number as a string slice, e.g. `"+96179123123"`.
+    pub fn as_str(&self) -> &str {
+        // only ASCII bytes are ever pushed into the buffer
+        std::str::from_utf8(&self.bytes[..self.len as usize]).expect("E164 is always ASCII")
+    }
+
+    // the same number matched to another country sharing its calling code
+    pub(crate) fn with_country(mut self, country: &Country) -> E164 {
+        debug_assert!(self[1..].starts_with(&country.prefix_str()));
+        self.country_id = country.id();
+        self
+    }
+
+    // the digits after the calling code
+    pub(crate) fn national_number(&self) -> &str {
+        &self[1 + calling_code(self.country()).to_string().len()..]
+    }
+
+    /// Returns the country the number was matched to while normalizing.
+    pub fn country(&self) -> &'static Country {
+        country_by_id(self.country_id).expect("E164 always holds a valid country id")
+    }
+}
+
+impl Deref for E164 {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for E164 {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq for E164 {
+    fn eq(&self, other: &E164) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for E164 {}
+
+impl Hash for E164 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialEq<str> for E164 {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl Ord for E164 {
+    fn cmp(&self, other: &E164) -> Ordering {
+        // national numbers have no leading zeros, zero-significant plans aside, so the longer one is
+        // the larger
+        let (a, b) = (self.national_number(), other.national_number());
+        calling_code(self.country())
+            .cmp(&calling_code(other.country()))
+            .then(a.len().cmp(&b.len()))
+            .then(a.cmp(b))
+    }
+}
+
+impl PartialOrd for E164 {
+    fn partial_cmp(&self, other: &E164) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq<&str> for E164 {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl From<E164> for String {
+    fn from(number: E164) -> String {
+        number.as_str().to_string()
+    }
+}
+
+impl fmt::Display for E164 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for E164 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("E164").field(&self.as_str()).finish()
+    }
+}
+
+// the number of a tel URI (RFC 3966) such as "tel:+44-20-7946-0958", the scheme being
+// case-insensitive
+pub(crate) fn without_tel_scheme(number: &str) -> &str {
+    match number.get(..4) {
+        Some(scheme) if scheme.eq_ignore_ascii_case("tel:") => &number[4..],
+        _ => number,
+    }
+}
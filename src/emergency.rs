@@ -0,0 +1,35 @@
+use crate::constants::{COUNTRIES, EMERGENCY_NUMBERS};
+use crate::definitions::Country;
+use crate::find_country_by_code;
+
+/// Returns the emergency numbers of `region` (an ISO 3166 code), the general one first, e.g.
+/// `["110", "119"]` (police, then fire and ambulance) for Japan. Returns `None` for unknown
+/// regions and the regions whose emergency numbers aren't known.
+///
+/// ```
+/// assert_eq!(
+///     phonelib::emergency_numbers_for_region("JP"),
+///     Some(&["110", "119"][..])
+/// );
+/// ```
+pub fn emergency_numbers_for_region(region: &str) -> Option<&'static [&'static str]> {
+    let country = find_country_by_code(region)?;
+    EMERGENCY_NUMBERS
+        .iter()
+        .find(|(code, _)| *code == country.code)
+        .map(|(_, numbers)| *numbers)
+}
+
+/// Returns the countries where `number` (digits, separators are ignored) is an emergency
+/// number, in table order, e.g. the NANP countries with known emergency numbers for `911`.
+pub fn regions_by_emergency_number(number: &str) -> Vec<&'static Country> {
+    let digits: String = number.chars().filter(char::is_ascii_digit).collect();
+    COUNTRIES
+        .iter()
+        .filter(|country| {
+            EMERGENCY_NUMBERS
+                .iter()
+                .any(|(code, numbers)| *code == country.code && numbers.contains(&&*digits))
+        })
+        .collect()
+}
@@ -0,0 +1,102 @@
+use crate::constants::{AREA_CODES, COUNTRIES};
+use crate::E164;
+
+/// Yields up to `count` successive valid numbers starting with `prefix`, international
+/// digits with or without `+` (separators are ignored), to provision test pools and
+/// load-test target lists.
+///
+/// Numbers come shortest first, then in ascending order. They fit the length and area codes
+/// of their country, which doesn't mean they are assigned: prefer ranges reserved for
+/// fiction when the numbers may be dialed.
+///
+/// ```
+/// let numbers: Vec<String> = phonelib::enumerate_numbers("+44 20 7946 0", 2)
+///     .map(String::from)
+///     .collect();
+///
+/// assert_eq!(numbers, ["+442079460000", "+442079460001"]);
+/// ```
+pub fn enumerate_numbers(prefix: &str, count: usize) -> impl Iterator<Item = E164> {
+    let digits: String = prefix.chars().filter(char::is_ascii_digit).collect();
+    let digits = digits.trim_start_matches('0').to_string();
+    let mut lengths: Vec<usize> = COUNTRIES
+        .iter()
+        .flat_map(|country| {
+            let prefix_len = country.prefix_len();
+            country
+                .phone_lengths
+                .iter()
+                .map(move |&length| prefix_len + usize::from(length))
+        })
+        .filter(|&length| length >= digits.len())
+        .collect();
+    lengths.sort_unstable();
+    lengths.dedup();
+
+    lengths
+        .into_iter()
+        .flat_map(move |length| {
+            blocks(&digits, length).into_iter().flat_map(move |block| {
+                let free = length - block.len();
+                (0..10u64.pow(free as u32)).map(move |suffix| match free {
+                    // a prefix of a whole number, formatting 0 would append a digit
+                    0 => block.clone(),
+                    _ => format!("{}{:0width$}", block, suffix, width = free),
+                })
+            })
+        })
+        // the number can belong to another country than the block, with a leading zero
+        // after its longer calling code
+        .filter_map(|candidate| {
+            E164::from_digits(&candidate).filter(|number| number[1..] == candidate)
+        })
+        .take(count)
+}
+
+// leading digits of the numbers of `length` digits starting with `digits`, sorted and
+// without the blocks contained in another one: the calling code and area code of a country,
+// or its calling code and a national number not starting with 0, extended to `digits`
+fn blocks(digits: &str, length: usize) -> Vec<String> {
+    let mut blocks = vec![];
+    for country in COUNTRIES.iter() {
+        let fits = length
+            .checked_sub(country.prefix_len())
+            .is_some_and(|national_len| country.phone_lengths.contains(&(national_len as u8)));
+        if !fits {
+            continue;
+        }
+
+        let leads: Vec<String> = match AREA_CODES.iter().find(|(code, _)| *code == country.code) {
+            Some((_, area_codes)) => area_codes
+                .iter()
+                .map(|area_code| format!("{}{}", country.prefix, area_code))
+                .collect(),
+            None => ('1'..='9')
+                .map(|digit| format!("{}{}", country.prefix, digit))
+                .collect(),
+        };
+        for lead in leads {
+            let block = match (lead.starts_with(digits), digits.starts_with(&lead)) {
+                (true, _) => lead,
+                (_, true) => digits.to_string(),
+                _ => continue,
+            };
+            if block.len() <= length {
+                blocks.push(block);
+            }
+        }
+    }
+
+    blocks.sort_unstable();
+    blocks.dedup();
+    let mut outer: Vec<String> = vec![];
+    for block in blocks {
+        if !outer
+            .last()
+            .is_some_and(|last| block.starts_with(last.as_str()))
+        {
+            outer.push(block);
+        }
+    }
+    outer
+}
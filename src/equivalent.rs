@@ -0,0 +1,51 @@
+use crate::{calling_code, quirks, remove_unwanted_character, E164};
+
+/// Lists the dialable variants of a phone number that legacy databases may have stored, so
+/// matching engines can search for all of them.
+///
+/// The list starts with the E.164 form and contains the national form with and without
+/// the trunk prefix (`"+33612345678"`, `"0612345678"`, `"612345678"`), as well as the
+/// forms with and without the mobile `9` in Argentina and the legacy mobile `1` in Mexico.
+/// Returns an empty list for invalid numbers.
+pub fn equivalent_forms(phone_number: &str) -> Vec<String> {
+    let Some(number) = parse_with_mobile_digit(phone_number) else {
+        return Vec::new();
+    };
+
+    let country = number.country();
+    // the area code of the NANP members is part of their prefix, not of the calling code
+    let calling_code = calling_code(country);
+    let national_number = number.national_number();
+
+    let mut forms = vec![number.to_string()];
+    // the mobile digits are only dialed internationally
+    if let Some(mobile_digit) = quirks::mobile_digit(country) {
+        forms.push(format!(
+            "+{}{}{}",
+            calling_code, mobile_digit, national_number
+        ));
+    }
+
+    if let Some(trunk_prefix) = country.trunk_prefix() {
+        forms.push(format!("{}{}", trunk_prefix, national_number));
+    }
+    forms.push(national_number.to_string());
+
+    forms
+}
+
+// parses the number, dropping the mobile digit of Argentinian and Mexican mobiles
+fn parse_with_mobile_digit(phone_number: &str) -> Option<E164> {
+    E164::parse(phone_number).or_else(|| {
+        let mut digits = phone_number.to_string();
+        remove_unwanted_character(&mut digits);
+
+        quirks::mobile_digits().find_map(|(calling_code, mobile_digit)| {
+            let calling_code = calling_code.to_string();
+            let national_number = digits
+                .strip_prefix(calling_code.as_str())?
+                .strip_prefix(mobile_digit)?;
+            E164::parse(&format!("{}{}", calling_code, national_number))
+        })
+    })
+}
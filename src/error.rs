@@ -0,0 +1,158 @@
+use std::fmt;
+
+use crate::definitions::Country;
+use crate::formatting::{format_e164, with_extension};
+use crate::metadata::BUNDLED;
+use crate::prefix_index::{countries_with_prefix, MAX_PREFIX_LEN};
+use crate::unicode::{self, ascii_digit, is_bidi_control};
+use crate::{
+    area_code_len, significant_national_number, split_extension, PhoneFormat, PhoneNumber,
+    COUNTRIES, E164,
+};
+
+/// Why a phone number was rejected by the `try_` functions, e.g.
+/// [`try_normalize_phone_number`]. The [`fmt::Display`] output can be shown to end users.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhoneError {
+    /// The number has fewer digits than the numbers of any country.
+    TooShort,
+    /// The number has more digits than the numbers of any country.
+    TooLong,
+    /// The character at this position, counted in characters from 0, is neither a digit, a
+    /// separator (space, `-`, `.`, `(`, `)`, `/`) nor a leading `+`.
+    InvalidCharacter { position: usize },
+    /// No country has a calling code starting the number.
+    UnknownCountryCode,
+    /// The national number doesn't have any of the `expected` lengths of the country of its
+    /// calling code.
+    InvalidLengthForCountry { expected: &'static [u8] },
+}
+
+impl fmt::Display for PhoneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PhoneError::TooShort => f.write_str("the phone number is too short"),
+            PhoneError::TooLong => f.write_str("the phone number is too long"),
+            PhoneError::InvalidCharacter { position } => write!(
+                f,
+                "the phone number has an invalid character at position {}",
+                position
+            ),
+            PhoneError::UnknownCountryCode => f.write_str("the country code is unknown"),
+            PhoneError::InvalidLengthForCountry { expected } => {
+                let expected: Vec<String> = expected.iter().map(u8::to_string).collect();
+                write!(
+                    f,
+                    "the phone number should have {} digits after the country code",
+                    expected.join(" or ")
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for PhoneError {}
+
+/// Same as [`crate::normalize_phone_number`], telling why the number was rejected. Unlike
+/// it, characters other than digits, separators and a leading `+` are rejected instead of
+/// being ignored.
+pub fn try_normalize_phone_number(phone_number: &str) -> Result<String, PhoneError> {
+    parse_strict(phone_number).map(String::from)
+}
+
+/// Same as [`crate::extract_country`], telling why no country was found, see
+/// [`try_normalize_phone_number`].
+pub fn try_extract_country(phone_number: &str) -> Result<&'static Country, PhoneError> {
+    parse_strict(phone_number).map(|number| number.country())
+}
+
+/// Same as [`crate::format_phone_number`], telling why the number was rejected, see
+/// [`try_normalize_phone_number`].
+pub fn try_format_phone_number(
+    phone_number: &str,
+    format: PhoneFormat,
+) -> Result<String, PhoneError> {
+    let (number, extension) = split_extension(phone_number);
+    let number = parse_strict(number)?;
+    Ok(with_extension(
+        format_e164(&number, format),
+        format,
+        extension,
+    ))
+}
+
+impl PhoneNumber {
+    /// Same as [`PhoneNumber::parse`], telling why the number was rejected, see
+    /// [`try_normalize_phone_number`].
+    pub fn try_parse(phone_number: &str) -> Result<PhoneNumber, PhoneError> {
+        parse_strict(split_extension(phone_number).0)?;
+        Ok(PhoneNumber::parse(phone_number).expect("the number was parsed"))
+    }
+}
+
+fn parse_strict(phone_number: &str) -> Result<E164, PhoneError> {
+    let start = phone_number.len() - unicode::trim_start(phone_number).len();
+    let invalid = phone_number.char_indices().position(|(index, c)| {
+        !(ascii_digit(c).is_some()
+            || is_bidi_control(c)
+            || " -.()/".contains(c)
+            || (c == '+' && index == start))
+    });
+    if let Some(position) = invalid {
+        return Err(PhoneError::InvalidCharacter { position });
+    }
+
+    E164::parse(phone_number).ok_or_else(|| diagnose(phone_number))
+}
+
+// why a number made of valid characters didn't parse
+fn diagnose(phone_number: &str) -> PhoneError {
+    let digits: String = phone_number
+        .chars()
+        .filter_map(ascii_digit)
+        .skip_while(|c| *c == '0')
+        .collect();
+    let total_lengths = COUNTRIES.iter().flat_map(|country| {
+        country
+            .phone_lengths
+            .iter()
+            .map(|length| country.prefix_len() + *length as usize)
+    });
+    if total_lengths.clone().all(|length| digits.len() < length) {
+        return PhoneError::TooShort;
+    }
+    if total_lengths.clone().all(|length| digits.len() > length) {
+        return PhoneError::TooLong;
+    }
+
+    // the country the number would be matched to if its length was right, whose national
+    // number is read like `split_national_number` does
+    let Some(country) = country_of_calling_code(&digits) else {
+        return PhoneError::UnknownCountryCode;
+    };
+    match significant_national_number(country, &digits[country.prefix_len()..]) {
+        "" => PhoneError::TooShort,
+        _ => PhoneError::InvalidLengthForCountry {
+            expected: country.phone_lengths,
+        },
+    }
+}
+
+// the country of the longest calling code starting the digits, the one whose area code
+// starts the national number if the calling code is shared
+fn country_of_calling_code(digits: &str) -> Option<&'static Country> {
+    (1..=MAX_PREFIX_LEN.min(digits.len()))
+        .rev()
+        .filter_map(|len| digits[..len].parse().ok())
+        .map(countries_with_prefix)
+        .find(|countries| !countries.is_empty())
+        .and_then(|countries| {
+            countries
+                .iter()
+                .find(|country| {
+                    area_code_len(&BUNDLED, country, &digits[country.prefix_len()..]).is_some()
+                })
+                .or(countries.first())
+                .copied()
+        })
+}
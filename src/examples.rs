@@ -0,0 +1,50 @@
+use crate::formatting::format_e164;
+use crate::number_type::is_of_type;
+use crate::{calling_code, find_country_by_code, PhoneFormat, PhoneNumberType, E164};
+
+// (region, type, national significant number), taken from ranges reserved for fiction
+// where the regulator has one
+const EXAMPLE_NUMBERS: &[(&str, PhoneNumberType, &str)] = &[
+    ("AU", PhoneNumberType::Mobile, "491570156"),
+    ("AU", PhoneNumberType::FixedLine, "255500123"),
+    ("DE", PhoneNumberType::Mobile, "15123456789"),
+    ("DE", PhoneNumberType::FixedLine, "3012345678"),
+    ("DE", PhoneNumberType::TollFree, "8001234567"),
+    ("FR", PhoneNumberType::Mobile, "612345678"),
+    ("FR", PhoneNumberType::FixedLine, "123456789"),
+    ("FR", PhoneNumberType::TollFree, "801234567"),
+    ("GB", PhoneNumberType::Mobile, "7700900123"),
+    ("GB", PhoneNumberType::FixedLine, "2079460123"),
+    ("GB", PhoneNumberType::TollFree, "8081570123"),
+    ("IN", PhoneNumberType::Mobile, "8123456789"),
+    ("IN", PhoneNumberType::FixedLine, "1123456789"),
+    ("LB", PhoneNumberType::Mobile, "71123456"),
+    ("LB", PhoneNumberType::FixedLine, "1123456"),
+    ("US", PhoneNumberType::FixedLineOrMobile, "2015550123"),
+    ("US", PhoneNumberType::TollFree, "8002345678"),
+];
+
+/// Returns an example number of the given type in `region` (an ISO 3166 code), in E.164.
+///
+/// Where mobile and fixed-line numbers share the same ranges, both types return the
+/// [`PhoneNumberType::FixedLineOrMobile`] example.
+pub fn example_number(region: &str, number_type: PhoneNumberType) -> Option<String> {
+    example_e164(region, number_type).map(String::from)
+}
+
+/// Returns a valid-looking placeholder for phone inputs, i.e. an example number of the given
+/// type in national format: `placeholder_for_region("GB", PhoneNumberType::Mobile)` is
+/// `"07700 900123"`.
+pub fn placeholder_for_region(region: &str, number_type: PhoneNumberType) -> Option<String> {
+    let number = example_e164(region, number_type)?;
+    Some(format_e164(&number, PhoneFormat::National))
+}
+
+fn example_e164(region: &str, number_type: PhoneNumberType) -> Option<E164> {
+    let country = find_country_by_code(region)?;
+    let (_, _, national_number) = EXAMPLE_NUMBERS.iter().find(|(code, example_type, _)| {
+        *code == country.code && is_of_type(*example_type, number_type)
+    })?;
+
+    E164::parse(&format!("+{}{}", calling_code(country), national_number))
+}
@@ -0,0 +1,172 @@
+use std::fmt;
+
+use crate::definitions::Country;
+use crate::metadata::BUNDLED;
+use crate::{
+    country_fits, country_fits_length, extract_country_data, Parser, ProcessingTrace, TraceStep,
+    COUNTRIES,
+};
+
+/// Step by step account of how a parser handled one input, see [`explain`].
+///
+/// The [`fmt::Display`] implementation renders it as text suitable for support tools and
+/// bug reports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    /// The normalized number, or `None` if the input was rejected.
+    pub normalized: Option<String>,
+    /// The transformations applied to the input.
+    pub trace: ProcessingTrace,
+    /// Every country whose calling code starts the cleaned number, in table order.
+    pub candidates: Vec<Candidate>,
+}
+
+/// A country considered for a number, see [`Explanation::candidates`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Candidate {
+    pub country: &'static Country,
+    pub verdict: Verdict,
+}
+
+/// Why a [`Candidate`] was chosen or rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Chosen,
+    /// The national number has a length the country does not use.
+    WrongLength {
+        national_number_length: usize,
+    },
+    /// None of the area codes of the country starts the national number.
+    WrongAreaCode,
+    /// The number fits the country, but the chosen country fits it too with a longer
+    /// calling code or area code, or comes earlier in the table.
+    ShadowedBy(&'static Country),
+}
+
+/// Explains how `parser` normalizes `phone_number` without side effects: the
+/// transformations applied and, for every candidate country, why it was chosen or rejected.
+///
+/// Use `&Parser::new()` for the behaviour of [`crate::normalize_phone_number`].
+pub fn explain(phone_number: &str, parser: &Parser) -> Explanation {
+    let (normalized, trace) = parser.normalize_traced(phone_number);
+
+    let digits = trace.matched_digits.as_str();
+
+    let chosen = extract_country_data(digits);
+    let candidates = COUNTRIES
+        .iter()
+        .filter(|country| digits.starts_with(&country.prefix_str()))
+        .map(|country| {
+            let verdict = if !country_fits_length(&BUNDLED, country, digits) {
+                Verdict::WrongLength {
+                    national_number_length: digits.len() - country.prefix_len(),
+                }
+            } else if !country_fits(&BUNDLED, country, digits) {
+                Verdict::WrongAreaCode
+            } else {
+                match chosen {
+                    Some(chosen) if chosen.id != country.id => Verdict::ShadowedBy(chosen),
+                    _ => Verdict::Chosen,
+                }
+            };
+            Candidate { country, verdict }
+        })
+        .collect();
+
+    Explanation {
+        normalized,
+        trace,
+        candidates,
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "input: {:?}", self.trace.input)?;
+        for (index, step) in self.trace.steps.iter().enumerate() {
+            write!(f, "{}. ", index + 1)?;
+            match step {
+                TraceStep::InputTooLong { length, max_length } => writeln!(
+                    f,
+                    "input of {} bytes is longer than the limit of {} bytes",
+                    length, max_length
+                ),
+                TraceStep::PreHook { before, after } => {
+                    writeln!(f, "pre-hook rewrote {:?} to {:?}", before, after)
+                }
+                TraceStep::LettersConverted { before, after } => {
+                    writeln!(f, "converted the letters of {:?} to {:?}", before, after)
+                }
+                TraceStep::ExtensionRemoved(extension) => {
+                    writeln!(f, "removed extension {:?}", extension)
+                }
+                TraceStep::CharactersRemoved(removed) => {
+                    writeln!(f, "removed non-digit characters {:?}", removed)
+                }
+                TraceStep::IddStripped(idd) => {
+                    writeln!(f, "stripped international prefix {:?}", idd)
+                }
+                TraceStep::RegionHintUsed(region) => {
+                    writeln!(f, "read as a national number of {}", Label(region))
+                }
+                TraceStep::CountryChosen { country, .. } => {
+                    writeln!(f, "chose {}", Label(country))
+                }
+                TraceStep::NoCountryFound => writeln!(f, "no country fits the number"),
+                TraceStep::TrunkPrefixRemoved(prefix) => {
+                    writeln!(f, "removed trunk prefix {:?}", prefix)
+                }
+                TraceStep::InvalidNationalLength => {
+                    writeln!(f, "the rest of the number is too short for the country")
+                }
+                TraceStep::PostHook { before, after } => {
+                    writeln!(f, "post-hook rewrote {:?} to {:?}", before, after)
+                }
+                TraceStep::InternationalPrefixApplied(prefix) => {
+                    writeln!(f, "replaced '+' with international prefix {:?}", prefix)
+                }
+            }?;
+        }
+
+        writeln!(f, "candidates:")?;
+        if self.candidates.is_empty() {
+            writeln!(f, "  none, no calling code starts the number")?;
+        }
+        for candidate in &self.candidates {
+            write!(f, "  {}: ", Label(candidate.country))?;
+            match candidate.verdict {
+                Verdict::Chosen => writeln!(f, "chosen"),
+                Verdict::WrongLength {
+                    national_number_length,
+                } => writeln!(
+                    f,
+                    "rejected, {} digits after the calling code but expected {:?}",
+                    national_number_length, candidate.country.phone_lengths
+                ),
+                Verdict::WrongAreaCode => {
+                    writeln!(
+                        f,
+                        "rejected, the number doesn't start with one of its area codes"
+                    )
+                }
+                Verdict::ShadowedBy(country) => {
+                    writeln!(f, "rejected, {} comes first", Label(country))
+                }
+            }?;
+        }
+
+        match &self.normalized {
+            Some(normalized) => write!(f, "result: {}", normalized),
+            None => write!(f, "result: invalid"),
+        }
+    }
+}
+
+// "Lebanon (LB, +961)"
+struct Label(&'static Country);
+
+impl fmt::Display for Label {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({}, +{})", self.0.name, self.0.code, self.0.prefix)
+    }
+}
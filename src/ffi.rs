@@ -0,0 +1,312 @@
+//! C interface of the library, declared in `include/phonelib.h`.
+//!
+//! The `phonelib_*` functions are exported by the `cdylib` built from the crate on demand,
+//! see the README, so C, Swift and Kotlin apps can call them without rewriting the country
+//! tables. The
+//! `#[repr(C)]` types mirror the parsing results, so non-Rust callers get rich results
+//! instead of strings. Every type is plain old data: strings are NUL-terminated byte arrays
+//! of fixed size and enums have fixed discriminants, so the layout can be declared as is in
+//! a C header.
+//!
+//! Strings returned by the functions are owned by the caller, who frees them with
+//! [`phonelib_free_string`]. Null pointers and input that is not UTF-8 are treated as
+//! invalid numbers.
+
+use std::ffi::{c_char, CStr, CString};
+
+use crate::{
+    format_phone_number, is_valid_phone_number, normalize_phone_number, PhoneError, PhoneFormat,
+    PhoneNumber, PhoneNumberType, E164,
+};
+
+/// Mirror of [`PhoneNumberType`], with fixed discriminants.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NumberType {
+    Mobile = 0,
+    FixedLine = 1,
+    FixedLineOrMobile = 2,
+    TollFree = 3,
+    PremiumRate = 4,
+    SharedCost = 5,
+    Voip = 6,
+    PersonalNumber = 7,
+    Pager = 8,
+    Uan = 9,
+    TestNumber = 10,
+    Unknown = 11,
+    Emergency = 12,
+    ShortCode = 13,
+}
+
+/// Mirror of [`PhoneError`], `Ok` when the number was parsed.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    Ok = 0,
+    TooShort = 1,
+    TooLong = 2,
+    /// See [`Analysis::error_position`].
+    InvalidCharacter = 3,
+    UnknownCountryCode = 4,
+    InvalidLengthForCountry = 5,
+}
+
+/// Mirror of [`PhoneFormat`], with fixed discriminants.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Format {
+    E164 = 0,
+    International = 1,
+    National = 2,
+    Rfc3966 = 3,
+}
+
+/// Result of [`analyze`]: the parsed number, or why it was rejected.
+///
+/// When `error` isn't [`ErrorCode::Ok`] only `error` and `error_position` are set, the other
+/// fields are zeroed.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Analysis {
+    pub error: ErrorCode,
+    /// Position of the invalid character, counted in characters from 0, for
+    /// [`ErrorCode::InvalidCharacter`].
+    pub error_position: u32,
+    /// The number in E.164 form, NUL-terminated.
+    pub e164: [u8; 17],
+    /// ISO 3166 code of the country, NUL-terminated.
+    pub region: [u8; 3],
+    /// See [`crate::Country::id`].
+    pub country_id: u16,
+    pub calling_code: u32,
+    pub number_type: NumberType,
+    /// Digits of the extension, NUL-terminated and cut to their first 16 digits.
+    pub extension: [u8; 17],
+}
+
+// the C header declares the E.164 buffer with a literal size
+const _: () = assert!(E164::MAX_LEN + 1 == 17);
+
+impl Analysis {
+    /// Returns [`Analysis::e164`] as a string slice, empty for rejected numbers.
+    pub fn e164(&self) -> &str {
+        c_str(&self.e164)
+    }
+
+    /// Returns [`Analysis::region`] as a string slice, empty for rejected numbers.
+    pub fn region(&self) -> &str {
+        c_str(&self.region)
+    }
+
+    /// Returns [`Analysis::extension`] as a string slice, empty without extension.
+    pub fn extension(&self) -> &str {
+        c_str(&self.extension)
+    }
+
+    fn empty(error: ErrorCode) -> Analysis {
+        Analysis {
+            error,
+            error_position: 0,
+            e164: [0; 17],
+            region: [0; 3],
+            country_id: 0,
+            calling_code: 0,
+            number_type: NumberType::Unknown,
+            extension: [0; 17],
+        }
+    }
+}
+
+/// Parses `phone_number` like [`PhoneNumber::try_parse`] into an [`Analysis`].
+pub fn analyze(phone_number: &str) -> Analysis {
+    PhoneNumber::try_parse(phone_number).into()
+}
+
+/// Returns true if the NUL-terminated `phone_number` is valid, see
+/// [`crate::is_valid_phone_number`].
+///
+/// # Safety
+///
+/// `phone_number` must be null or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn phonelib_is_valid(phone_number: *const c_char) -> bool {
+    input(phone_number).is_some_and(is_valid_phone_number)
+}
+
+/// Returns the E.164 form of `phone_number`, see [`crate::normalize_phone_number`], or null
+/// if it is not valid. The string is freed with [`phonelib_free_string`].
+///
+/// # Safety
+///
+/// `phone_number` must be null or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn phonelib_normalize(phone_number: *const c_char) -> *mut c_char {
+    output(input(phone_number).and_then(normalize_phone_number))
+}
+
+/// Returns `phone_number` formatted in `format`, see [`crate::format_phone_number`], or null
+/// if it is not valid. The string is freed with [`phonelib_free_string`].
+///
+/// # Safety
+///
+/// `phone_number` must be null or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn phonelib_format(
+    phone_number: *const c_char,
+    format: Format,
+) -> *mut c_char {
+    output(
+        input(phone_number)
+            .and_then(|phone_number| format_phone_number(phone_number, format.into())),
+    )
+}
+
+/// Returns the ISO 3166 code of the country of `phone_number`, see
+/// [`crate::extract_country`], or null if it is not valid. The string is freed with
+/// [`phonelib_free_string`].
+///
+/// # Safety
+///
+/// `phone_number` must be null or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn phonelib_extract_country(phone_number: *const c_char) -> *mut c_char {
+    output(
+        input(phone_number)
+            .and_then(crate::extract_country)
+            .map(|country| country.code.to_string()),
+    )
+}
+
+/// Same as [`analyze`], an input that is null or not UTF-8 being rejected with
+/// [`ErrorCode::InvalidCharacter`] at position 0.
+///
+/// # Safety
+///
+/// `phone_number` must be null or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn phonelib_analyze(phone_number: *const c_char) -> Analysis {
+    match input(phone_number) {
+        Some(phone_number) => analyze(phone_number),
+        None => PhoneError::InvalidCharacter { position: 0 }.into(),
+    }
+}
+
+/// Frees a string returned by the library. Does nothing for null.
+///
+/// # Safety
+///
+/// `string` must be null or a string returned by a `phonelib_*` function, not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn phonelib_free_string(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+// the string behind a C pointer, None for null or not UTF-8
+unsafe fn input<'a>(phone_number: *const c_char) -> Option<&'a str> {
+    if phone_number.is_null() {
+        return None;
+    }
+    CStr::from_ptr(phone_number).to_str().ok()
+}
+
+// a string handed over to C, null for None
+fn output(string: Option<String>) -> *mut c_char {
+    string
+        .and_then(|string| CString::new(string).ok())
+        .map_or(std::ptr::null_mut(), CString::into_raw)
+}
+
+impl From<Format> for PhoneFormat {
+    fn from(format: Format) -> PhoneFormat {
+        match format {
+            Format::E164 => PhoneFormat::E164,
+            Format::International => PhoneFormat::International,
+            Format::National => PhoneFormat::National,
+            Format::Rfc3966 => PhoneFormat::RFC3966,
+        }
+    }
+}
+
+impl From<PhoneNumberType> for NumberType {
+    fn from(number_type: PhoneNumberType) -> NumberType {
+        match number_type {
+            PhoneNumberType::Mobile => NumberType::Mobile,
+            PhoneNumberType::FixedLine => NumberType::FixedLine,
+            PhoneNumberType::FixedLineOrMobile => NumberType::FixedLineOrMobile,
+            PhoneNumberType::TollFree => NumberType::TollFree,
+            PhoneNumberType::PremiumRate => NumberType::PremiumRate,
+            PhoneNumberType::SharedCost => NumberType::SharedCost,
+            PhoneNumberType::Voip => NumberType::Voip,
+            PhoneNumberType::PersonalNumber => NumberType::PersonalNumber,
+            PhoneNumberType::Pager => NumberType::Pager,
+            PhoneNumberType::Uan => NumberType::Uan,
+            PhoneNumberType::TestNumber => NumberType::TestNumber,
+            PhoneNumberType::Emergency => NumberType::Emergency,
+            PhoneNumberType::ShortCode => NumberType::ShortCode,
+            PhoneNumberType::Unknown => NumberType::Unknown,
+        }
+    }
+}
+
+impl From<&PhoneError> for ErrorCode {
+    fn from(error: &PhoneError) -> ErrorCode {
+        match error {
+            PhoneError::TooShort => ErrorCode::TooShort,
+            PhoneError::TooLong => ErrorCode::TooLong,
+            PhoneError::InvalidCharacter { .. } => ErrorCode::InvalidCharacter,
+            PhoneError::UnknownCountryCode => ErrorCode::UnknownCountryCode,
+            PhoneError::InvalidLengthForCountry { .. } => ErrorCode::InvalidLengthForCountry,
+        }
+    }
+}
+
+impl From<&PhoneNumber> for Analysis {
+    fn from(number: &PhoneNumber) -> Analysis {
+        let country = number.country();
+        let mut analysis = Analysis::empty(ErrorCode::Ok);
+        copy_c_str(&mut analysis.e164, &number.e164());
+        copy_c_str(&mut analysis.region, country.code);
+        analysis.country_id = country.id;
+        analysis.calling_code = crate::calling_code(country);
+        analysis.number_type = number.number_type().into();
+        copy_c_str(
+            &mut analysis.extension,
+            number.extension().unwrap_or_default(),
+        );
+        analysis
+    }
+}
+
+impl From<PhoneError> for Analysis {
+    fn from(error: PhoneError) -> Analysis {
+        let mut analysis = Analysis::empty((&error).into());
+        if let PhoneError::InvalidCharacter { position } = error {
+            analysis.error_position = position as u32;
+        }
+        analysis
+    }
+}
+
+impl From<Result<PhoneNumber, PhoneError>> for Analysis {
+    fn from(result: Result<PhoneNumber, PhoneError>) -> Analysis {
+        match result {
+            Ok(number) => (&number).into(),
+            Err(error) => error.into(),
+        }
+    }
+}
+
+// copies as much of `s` as fits before the terminating NUL
+fn copy_c_str(buffer: &mut [u8], s: &str) {
+    let len = s.len().min(buffer.len() - 1);
+    buffer[..len].copy_from_slice(&s.as_bytes()[..len]);
+}
+
+fn c_str(buffer: &[u8]) -> &str {
+    let len = buffer.iter().position(|b| *b == 0).unwrap_or(buffer.len());
+    // only ASCII is ever copied in
+    std::str::from_utf8(&buffer[..len]).unwrap_or_default()
+}
@@ -0,0 +1,35 @@
+use crate::constants::FICTION_RANGES;
+use crate::{calling_code, E164};
+
+/// Returns true if `phone_number` is in a range reserved for fiction, which no subscriber
+/// can be assigned, e.g. `+1 202 555 0123` or `+44 7700 900123`. Such numbers are safe in
+/// films, documentation and sample data, see [`crate::generate_test_phone_number`]. Only
+/// the reserved ranges of the United States and the NANP, the United Kingdom and Australia
+/// are known.
+///
+/// ```
+/// assert!(phonelib::is_reserved_test_number("+1 (202) 555-0123"));
+/// assert!(!phonelib::is_reserved_test_number("+1 (202) 555-1234"));
+/// ```
+pub fn is_reserved_test_number(phone_number: &str) -> bool {
+    E164::parse(phone_number).is_some_and(|number| {
+        fiction_ranges(calling_code(number.country()))
+            .any(|range| matches_range(range, number.national_number()))
+    })
+}
+
+// the national number templates of the reserved ranges of `calling_code`
+pub(crate) fn fiction_ranges(calling_code: u32) -> impl Iterator<Item = &'static str> {
+    FICTION_RANGES
+        .iter()
+        .filter(move |(code, _)| *code == calling_code)
+        .map(|(_, range)| *range)
+}
+
+fn matches_range(range: &str, national_number: &str) -> bool {
+    range.len() == national_number.len()
+        && range
+            .bytes()
+            .zip(national_number.bytes())
+            .all(|(expected, digit)| expected == b'#' || expected == digit)
+}
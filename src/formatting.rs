@@ -0,0 +1,415 @@
+#[cfg(feature = "compact-metadata")]
+use crate::compact::{Packed, Packer, Reader, MAX_CODES, MAX_LEN};
+use crate::definitions::Country;
+use crate::{calling_code, find_country_by_code, split_extension, PhoneNumberType, E164};
+
+/// Output formats of [`format_phone_number`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PhoneFormat {
+    /// `+33612345678`
+    E164,
+    /// `+33 6 12 34 56 78`
+    International,
+    /// `06 12 34 56 78`
+    National,
+    /// `tel:+33-6-12-34-56-78`, grouped as in national format
+    RFC3966,
+}
+
+// Formatting rule of the national significant numbers starting with `leading_digits`. In
+// the templates each '#' stands for one digit of the national significant number, every
+// other character is copied as is, so a template only applies to numbers with as many
+// digits as it has '#'.
+pub(crate) struct FormatPattern {
+    pub(crate) leading_digits: &'static str,
+    pub(crate) number_type: PhoneNumberType,
+    pub(crate) national: &'static str,
+    pub(crate) international: &'static str,
+}
+
+const fn pattern(
+    leading_digits: &'static str,
+    number_type: PhoneNumberType,
+    national: &'static str,
+    international: &'static str,
+) -> FormatPattern {
+    FormatPattern {
+        leading_digits,
+        number_type,
+        national,
+        international,
+    }
+}
+
+// (calling code, patterns), the first matching pattern wins and the first pattern of a
+// country is its most common one
+#[rustfmt::skip]
+pub(crate) const FORMATS: &[(u32, &[FormatPattern])] = &[
+    (1, &[
+        pattern("", PhoneNumberType::FixedLineOrMobile, "(###) ###-####", "###-###-####"),
+    ]),
+    (7, &[
+        pattern("9", PhoneNumberType::Mobile, "8 (###) ###-##-##", "### ###-##-##"),
+        pattern("800", PhoneNumberType::TollFree, "8 (###) ###-##-##", "### ###-##-##"),
+        pattern("", PhoneNumberType::FixedLine, "8 (###) ###-##-##", "### ###-##-##"),
+    ]),
+    (27, &[
+        pattern("6", PhoneNumberType::Mobile, "0## ### ####", "## ### ####"),
+        pattern("7", PhoneNumberType::Mobile, "0## ### ####", "## ### ####"),
+        pattern("800", PhoneNumberType::TollFree, "0### ### ###", "### ### ###"),
+        pattern("", PhoneNumberType::FixedLine, "0## ### ####", "## ### ####"),
+    ]),
+    (31, &[
+        pattern("6", PhoneNumberType::Mobile, "0# ########", "# ########"),
+        pattern("800", PhoneNumberType::TollFree, "0800 ######", "800 ######"),
+        pattern("", PhoneNumberType::FixedLine, "0## ### ####", "## ### ####"),
+    ]),
+    (32, &[
+        pattern("4", PhoneNumberType::Mobile, "04## ## ## ##", "4## ## ## ##"),
+        pattern("", PhoneNumberType::FixedLine, "0# ### ## ##", "# ### ## ##"),
+    ]),
+    (33, &[
+        pattern("6", PhoneNumberType::Mobile, "0# ## ## ## ##", "# ## ## ## ##"),
+        pattern("7", PhoneNumberType::Mobile, "0# ## ## ## ##", "# ## ## ## ##"),
+        pattern("80", PhoneNumberType::TollFree, "0 ### ## ## ##", "### ## ## ##"),
+        pattern("", PhoneNumberType::FixedLine, "0# ## ## ## ##", "# ## ## ## ##"),
+    ]),
+    (34, &[
+        pattern("6", PhoneNumberType::Mobile, "### ## ## ##", "### ## ## ##"),
+        pattern("7", PhoneNumberType::Mobile, "### ## ## ##", "### ## ## ##"),
+        pattern("800", PhoneNumberType::TollFree, "### ### ###", "### ### ###"),
+        pattern("", PhoneNumberType::FixedLine, "### ## ## ##", "### ## ## ##"),
+    ]),
+    (39, &[
+        pattern("3", PhoneNumberType::Mobile, "### ### ####", "### ### ####"),
+        pattern("02", PhoneNumberType::FixedLine, "## #### ####", "## #### ####"),
+        pattern("06", PhoneNumberType::FixedLine, "## #### ####", "## #### ####"),
+        pattern("010", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("011", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("015", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("019", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("030", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("031", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("035", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("039", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("040", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("041", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("045", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("049", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("050", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("051", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("055", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("059", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("070", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("071", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("075", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("079", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("080", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("081", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("085", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("089", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("090", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("091", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("095", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("099", PhoneNumberType::FixedLine, "### ### ####", "### ### ####"),
+        pattern("0", PhoneNumberType::FixedLine, "#### ######", "#### ######"),
+    ]),
+    (41, &[
+        pattern("7", PhoneNumberType::Mobile, "0## ### ## ##", "## ### ## ##"),
+        pattern("800", PhoneNumberType::TollFree, "0### ### ###", "### ### ###"),
+        pattern("", PhoneNumberType::FixedLine, "0## ### ## ##", "## ### ## ##"),
+    ]),
+    (44, &[
+        pattern("7", PhoneNumberType::Mobile, "0#### ######", "#### ######"),
+        pattern("20", PhoneNumberType::FixedLine, "0## #### ####", "## #### ####"),
+        pattern("1", PhoneNumberType::FixedLine, "0#### ######", "#### ######"),
+        pattern("800", PhoneNumberType::TollFree, "0### ### ####", "### ### ####"),
+        pattern("3", PhoneNumberType::Uan, "0### ### ####", "### ### ####"),
+        pattern("9", PhoneNumberType::PremiumRate, "0### ### ####", "### ### ####"),
+    ]),
+    (45, &[
+        pattern("", PhoneNumberType::FixedLineOrMobile, "## ## ## ##", "## ## ## ##"),
+    ]),
+    (47, &[
+        pattern("4", PhoneNumberType::Mobile, "### ## ###", "### ## ###"),
+        pattern("9", PhoneNumberType::Mobile, "### ## ###", "### ## ###"),
+        pattern("", PhoneNumberType::FixedLine, "## ## ## ##", "## ## ## ##"),
+    ]),
+    (48, &[
+        pattern("", PhoneNumberType::FixedLineOrMobile, "### ### ###", "### ### ###"),
+    ]),
+    (49, &[
+        pattern("1", PhoneNumberType::Mobile, "0### ########", "### ########"),
+        pattern("1", PhoneNumberType::Mobile, "0### #######", "### #######"),
+        pattern("30", PhoneNumberType::FixedLine, "0## ########", "## ########"),
+        pattern("30", PhoneNumberType::FixedLine, "0## #######", "## #######"),
+        pattern("800", PhoneNumberType::TollFree, "0### #######", "### #######"),
+    ]),
+    (52, &[
+        pattern("", PhoneNumberType::FixedLineOrMobile, "## #### ####", "## #### ####"),
+    ]),
+    (55, &[
+        pattern("", PhoneNumberType::FixedLineOrMobile, "(##) #####-####", "## #####-####"),
+    ]),
+    (61, &[
+        pattern("4", PhoneNumberType::Mobile, "0### ### ###", "### ### ###"),
+        pattern("", PhoneNumberType::FixedLine, "0# #### ####", "# #### ####"),
+    ]),
+    (81, &[
+        pattern("70", PhoneNumberType::Mobile, "0##-####-####", "##-####-####"),
+        pattern("80", PhoneNumberType::Mobile, "0##-####-####", "##-####-####"),
+        pattern("90", PhoneNumberType::Mobile, "0##-####-####", "##-####-####"),
+        pattern("", PhoneNumberType::FixedLine, "0##-####-####", "##-####-####"),
+    ]),
+    (86, &[
+        pattern("1", PhoneNumberType::Mobile, "### #### ####", "### #### ####"),
+    ]),
+    (90, &[
+        pattern("5", PhoneNumberType::Mobile, "0### ### ## ##", "### ### ## ##"),
+        pattern("", PhoneNumberType::FixedLine, "0### ### ## ##", "### ### ## ##"),
+    ]),
+    (91, &[
+        pattern("6", PhoneNumberType::Mobile, "0##### #####", "##### #####"),
+        pattern("7", PhoneNumberType::Mobile, "0##### #####", "##### #####"),
+        pattern("8", PhoneNumberType::Mobile, "0##### #####", "##### #####"),
+        pattern("9", PhoneNumberType::Mobile, "0##### #####", "##### #####"),
+        pattern("1800", PhoneNumberType::TollFree, "#### ### ###", "#### ### ###"),
+        pattern("", PhoneNumberType::FixedLine, "0## #### ####", "## #### ####"),
+    ]),
+    (351, &[
+        pattern("9", PhoneNumberType::Mobile, "### ### ###", "### ### ###"),
+        pattern("", PhoneNumberType::FixedLine, "### ### ###", "### ### ###"),
+    ]),
+    (353, &[
+        pattern("8", PhoneNumberType::Mobile, "0## ### ####", "## ### ####"),
+    ]),
+    (966, &[
+        pattern("5", PhoneNumberType::Mobile, "0## ### ####", "## ### ####"),
+    ]),
+    (971, &[
+        pattern("5", PhoneNumberType::Mobile, "0## ### ####", "## ### ####"),
+    ]),
+];
+
+#[cfg(feature = "compact-metadata")]
+const PACKED_FORMATS: Packer<MAX_LEN, MAX_CODES> = {
+    let mut packer = Packer::new();
+    let mut i = 0;
+    while i < FORMATS.len() {
+        let (calling_code, patterns) = FORMATS[i];
+        let mut j = 0;
+        while j < patterns.len() {
+            packer.entry(calling_code);
+            packer.str(patterns[j].leading_digits);
+            packer.number_type(patterns[j].number_type);
+            packer.str(patterns[j].national);
+            packer.str(patterns[j].international);
+            j += 1;
+        }
+        i += 1;
+    }
+    packer
+};
+
+#[cfg(feature = "compact-metadata")]
+static COMPACT_FORMATS: Packed<
+    FormatPattern,
+    { PACKED_FORMATS.len() },
+    { PACKED_FORMATS.sections() },
+> = PACKED_FORMATS.shrink();
+
+/// Formats a phone number, or returns `None` if the number is not valid.
+///
+/// Numbers without formatting metadata are grouped in blocks of three digits. Extensions
+/// (`"ext. 45"`, `"x45"`, `";ext=45"`...) are kept as `;ext=45` in [`PhoneFormat::RFC3966`]
+/// and as ` ext. 45` in national and international formats, E.164 has no extensions.
+///
+/// The E.164, international and RFC3966 outputs always parse back to the same number with
+/// [`crate::normalize_phone_number`]. See [`format_phone_number_checked`] for national
+/// output.
+pub fn format_phone_number(phone_number: impl AsRef<str>, format: PhoneFormat) -> Option<String> {
+    let (phone_number, extension) = split_extension(phone_number.as_ref());
+    let number = E164::parse(phone_number)?;
+    Some(with_extension(
+        format_e164(&number, format),
+        format,
+        extension,
+    ))
+}
+
+/// Same as [`format_phone_number`], but guarantees that the output parses back to the same
+/// E.164 number, national output being read as a national number of the number's region.
+///
+/// Numbers whose national form would be read differently, e.g. a NANP number whose area code
+/// starts with the `1` trunk prefix, are formatted internationally instead.
+pub fn format_phone_number_checked(
+    phone_number: impl AsRef<str>,
+    format: PhoneFormat,
+) -> Option<String> {
+    let (phone_number, extension) = split_extension(phone_number.as_ref());
+    let number = E164::parse(phone_number)?;
+    let formatted = format_e164(&number, format);
+    let reparsed = match format {
+        PhoneFormat::National => E164::parse_national(&formatted, number.country()),
+        _ => E164::parse(&formatted),
+    };
+
+    Some(match reparsed == Some(number) {
+        true => with_extension(formatted, format, extension),
+        false => with_extension(
+            format_e164(&number, PhoneFormat::International),
+            PhoneFormat::International,
+            extension,
+        ),
+    })
+}
+
+pub(crate) fn format_e164(number: &E164, format: PhoneFormat) -> String {
+    let country = number.country();
+    let calling_code = calling_code(country);
+    let national_number = &number[1 + calling_code.to_string().len()..];
+    let pattern = find_pattern(calling_code, national_number);
+
+    match format {
+        PhoneFormat::E164 => number.to_string(),
+        PhoneFormat::National => match pattern {
+            Some(pattern) => apply_template(pattern.national, national_number),
+            None => format!(
+                "{}{}",
+                country.trunk_prefix().unwrap_or_default(),
+                group_digits(national_number, " ")
+            ),
+        },
+        PhoneFormat::International => match pattern {
+            Some(pattern) => format!(
+                "+{} {}",
+                calling_code,
+                apply_template(pattern.international, national_number)
+            ),
+            None => format!("+{} {}", calling_code, group_digits(national_number, " ")),
+        },
+        PhoneFormat::RFC3966 => {
+            let grouped = match pattern {
+                Some(pattern) => apply_template(pattern.international, national_number)
+                    .replace(|c: char| !c.is_ascii_digit(), "-"),
+                None => group_digits(national_number, "-"),
+            };
+            format!("tel:+{}-{}", calling_code, grouped)
+        }
+    }
+}
+
+/// Returns an input mask for the national numbers of `region` (an ISO 3166 code), where
+/// each `#` stands for one digit, e.g. `"## ## ## ## ##"` for `"FR"`.
+pub fn input_mask_for_region(region: &str) -> Option<String> {
+    let country = find_country_by_code(region)?;
+    match patterns(calling_code(country)).first() {
+        Some(pattern) => Some(mask(pattern.national)),
+        None => Some(fallback_mask(country)),
+    }
+}
+
+/// Returns an input mask for the national numbers of the given type in `region`, see
+/// [`input_mask_for_region`]. Returns `None` if the region has no formatting metadata for
+/// this type.
+pub fn input_mask_for_region_and_type(
+    region: &str,
+    number_type: PhoneNumberType,
+) -> Option<String> {
+    let country = find_country_by_code(region)?;
+    patterns(calling_code(country))
+        .iter()
+        .find(|pattern| pattern.number_type == number_type)
+        .map(|pattern| mask(pattern.national))
+}
+
+pub(crate) fn with_extension(
+    formatted: String,
+    format: PhoneFormat,
+    extension: Option<String>,
+) -> String {
+    match (format, extension) {
+        (PhoneFormat::E164, _) | (_, None) => formatted,
+        (PhoneFormat::RFC3966, Some(extension)) => format!("{};ext={}", formatted, extension),
+        (_, Some(extension)) => format!("{} ext. {}", formatted, extension),
+    }
+}
+
+#[cfg(not(feature = "compact-metadata"))]
+pub(crate) fn patterns(calling_code: u32) -> &'static [FormatPattern] {
+    FORMATS
+        .iter()
+        .find(|(code, _)| *code == calling_code)
+        .map_or(&[], |(_, patterns)| patterns)
+}
+
+#[cfg(feature = "compact-metadata")]
+pub(crate) fn patterns(calling_code: u32) -> &'static [FormatPattern] {
+    COMPACT_FORMATS.entries(calling_code, |reader: &mut Reader| FormatPattern {
+        leading_digits: reader.str(),
+        number_type: reader.number_type(),
+        national: reader.str(),
+        international: reader.str(),
+    })
+}
+
+pub(crate) fn find_pattern(
+    calling_code: u32,
+    national_number: &str,
+) -> Option<&'static FormatPattern> {
+    patterns(calling_code).iter().find(|pattern| {
+        national_number.starts_with(pattern.leading_digits)
+            && pattern.national.matches('#').count() == national_number.len()
+    })
+}
+
+pub(crate) fn apply_template(template: &str, national_number: &str) -> String {
+    let mut digits = national_number.chars();
+    template
+        .chars()
+        .map(|c| match c {
+            '#' => digits.next().unwrap_or('#'),
+            c => c,
+        })
+        .collect()
+}
+
+// groups of three digits, a leftover digit goes to the last group and two leftover digits
+// make the first group
+pub(crate) fn group_digits(national_number: &str, separator: &str) -> String {
+    let mut groups = Vec::new();
+    let mut rest = national_number;
+    if rest.len() % 3 == 2 {
+        groups.push(&rest[..2]);
+        rest = &rest[2..];
+    }
+    while rest.len() > 4 {
+        groups.push(&rest[..3]);
+        rest = &rest[3..];
+    }
+    groups.push(rest);
+    groups.join(separator)
+}
+
+fn mask(template: &str) -> String {
+    template
+        .chars()
+        .map(|c| if c.is_ascii_digit() { '#' } else { c })
+        .collect()
+}
+
+fn fallback_mask(country: &Country) -> String {
+    let length = country
+        .phone_lengths
+        .iter()
+        .max()
+        .copied()
+        .unwrap_or_default();
+    let digits = "#".repeat(length as usize);
+    format!(
+        "{}{}",
+        mask(country.trunk_prefix().unwrap_or_default()),
+        group_digits(&digits, " ")
+    )
+}
@@ -0,0 +1,256 @@
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+
+use crate::constants::AREA_CODES;
+use crate::definitions::Country;
+use crate::fiction::fiction_ranges;
+use crate::number_type::{
+    has_type_metadata, is_of_type, number_type_of, type_lengths, type_prefixes,
+};
+use crate::{
+    best_fitting_countries, calling_code, find_country_by_code, is_reserved_test_number, validate,
+    PhoneNumberType, E164,
+};
+
+// candidates tried before giving up on a country, far more than needed for any country of
+// the table: the leading digits come from its metadata, so most candidates are valid
+const MAX_ATTEMPTS: usize = 1000;
+
+/// Generates random valid phone numbers, the same ones for the same seed, so test data and
+/// fuzzing corpora are reproducible.
+///
+/// Numbers start with an area code or a number range of their country and are valid for
+/// it (see [`crate::ValidationResult::ValidForCountry`]), which doesn't mean they are
+/// assigned: prefer [`PhoneNumberGenerator::generate_test_number`] when the numbers may be
+/// dialed.
+///
+/// ```
+/// use phonelib::PhoneNumberGenerator;
+///
+/// let mut generator = PhoneNumberGenerator::new(42);
+/// let first = generator.generate("FR").unwrap();
+/// let second = generator.generate("FR").unwrap();
+///
+/// assert!(phonelib::is_valid_phone_number(&first));
+/// assert_ne!(first, second);
+/// assert_eq!(PhoneNumberGenerator::new(42).generate("FR"), Some(first));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhoneNumberGenerator {
+    state: u64,
+}
+
+impl PhoneNumberGenerator {
+    pub fn new(seed: u64) -> PhoneNumberGenerator {
+        PhoneNumberGenerator { state: seed }
+    }
+
+    /// Returns a random valid number of `region` (an ISO 3166 code), or `None` for unknown
+    /// regions.
+    pub fn generate(&mut self, region: &str) -> Option<E164> {
+        let country = find_country_by_code(region)?;
+        self.generate_in(country, None)
+    }
+
+    /// Returns a random valid number of `number_type` in `region` (an ISO 3166 code), e.g. a
+    /// mobile number starting with one of the mobile ranges of the country. Where mobile and
+    /// fixed-line numbers share the same ranges, both types return
+    /// [`PhoneNumberType::FixedLineOrMobile`] numbers. Returns `None` for unknown regions and
+    /// the types the metadata of the region doesn't know.
+    ///
+    /// ```
+    /// use phonelib::{detect_phone_number_type, PhoneNumberGenerator, PhoneNumberType};
+    ///
+    /// let mobile = PhoneNumberGenerator::new(1)
+    ///     .generate_of_type("GB", PhoneNumberType::Mobile)
+    ///     .unwrap();
+    /// assert_eq!(detect_phone_number_type(mobile), Some(PhoneNumberType::Mobile));
+    /// ```
+    pub fn generate_of_type(&mut self, region: &str, number_type: PhoneNumberType) -> Option<E164> {
+        let country = find_country_by_code(region)?;
+        self.generate_in(country, Some(number_type))
+    }
+
+    fn generate_in(
+        &mut self,
+        country: &'static Country,
+        number_type: Option<PhoneNumberType>,
+    ) -> Option<E164> {
+        let leads = leads(country, number_type);
+        let lengths: Vec<u8> = match number_type
+            .and_then(|number_type| type_lengths(calling_code(country), number_type))
+        {
+            Some(range) => country
+                .phone_lengths
+                .iter()
+                .copied()
+                .filter(|length| range.contains(length))
+                .collect(),
+            None => country.phone_lengths.to_vec(),
+        };
+        if leads.is_empty() || lengths.is_empty() {
+            return None;
+        }
+        (0..MAX_ATTEMPTS).find_map(|_| self.candidate(country, &leads, &lengths, number_type))
+    }
+
+    fn candidate(
+        &mut self,
+        country: &'static Country,
+        leads: &[&str],
+        lengths: &[u8],
+        number_type: Option<PhoneNumberType>,
+    ) -> Option<E164> {
+        let lead = leads[self.below(leads.len())];
+        let length = usize::from(lengths[self.below(lengths.len())]);
+        if lead.len() > length {
+            return None;
+        }
+
+        let mut digits = format!("{}{}", country.prefix, lead);
+        while digits.len() < country.prefix_len() + length {
+            digits.push(char::from(b'0' + self.below(10) as u8));
+        }
+        valid_number(country, &digits).filter(|number| {
+            number_type.is_none_or(|number_type| is_of_type(number_type_of(number), number_type))
+        })
+    }
+
+    /// Returns a random number of `region` (an ISO 3166 code) in a range reserved for
+    /// fiction, see [`crate::is_reserved_test_number`]. Returns `None` for unknown regions and
+    /// the regions without known reserved ranges.
+    ///
+    /// ```
+    /// use phonelib::PhoneNumberGenerator;
+    ///
+    /// let number = PhoneNumberGenerator::new(3).generate_test_number("US").unwrap();
+    /// assert_eq!(&number[5..10], "55501");
+    /// ```
+    pub fn generate_test_number(&mut self, region: &str) -> Option<E164> {
+        let country = find_country_by_code(region)?;
+        let ranges: Vec<&str> = fiction_ranges(calling_code(country)).collect();
+        if ranges.is_empty() {
+            return None;
+        }
+        // the area code of the NANP members is part of their prefix
+        let skipped = country.prefix_len() - calling_code(country).to_string().len();
+        (0..MAX_ATTEMPTS).find_map(|_| {
+            let range = ranges[self.below(ranges.len())];
+            let mut digits = country.prefix.to_string();
+            for c in range[skipped..].chars() {
+                digits.push(match c {
+                    '#' => char::from(b'0' + self.below(10) as u8),
+                    digit => digit,
+                });
+            }
+            valid_number(country, &digits).filter(|number| is_reserved_test_number(number))
+        })
+    }
+
+    // SplitMix64, fast and good enough for test data
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // a random number below `n`, the modulo bias is negligible for the small `n` used here
+    fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+}
+
+/// Returns a random valid number of `region` (an ISO 3166 code), different on each call.
+/// See [`generate_random_phone_number_with_seed`] for reproducible numbers.
+pub fn generate_random_phone_number(region: &str) -> Option<String> {
+    generate_random_phone_number_with_seed(region, RandomState::new().hash_one(()))
+}
+
+/// Returns a random number of `region` (an ISO 3166 code) in a range reserved for fiction,
+/// different on each call, see [`PhoneNumberGenerator::generate_test_number`]. Such numbers
+/// are safe to print or dial: they are never assigned to a subscriber.
+///
+/// ```
+/// let number = phonelib::generate_test_phone_number("GB").unwrap();
+///
+/// assert!(phonelib::is_reserved_test_number(&number));
+/// assert!(phonelib::is_valid_phone_number(&number));
+/// assert_eq!(phonelib::generate_test_phone_number("FR"), None);
+/// ```
+pub fn generate_test_phone_number(region: &str) -> Option<String> {
+    PhoneNumberGenerator::new(RandomState::new().hash_one(()))
+        .generate_test_number(region)
+        .map(String::from)
+}
+
+/// Returns a random valid number of `number_type` in `region` (an ISO 3166 code), different
+/// on each call, see [`PhoneNumberGenerator::generate_of_type`].
+///
+/// ```
+/// use phonelib::{generate_random_phone_number_of_type, PhoneNumberType};
+///
+/// let number = generate_random_phone_number_of_type("GB", PhoneNumberType::Mobile).unwrap();
+/// assert!(number.starts_with("+447"));
+/// ```
+pub fn generate_random_phone_number_of_type(
+    region: &str,
+    number_type: PhoneNumberType,
+) -> Option<String> {
+    PhoneNumberGenerator::new(RandomState::new().hash_one(()))
+        .generate_of_type(region, number_type)
+        .map(String::from)
+}
+
+/// Returns a random valid number of `region` (an ISO 3166 code), the same one for the same
+/// seed, see [`PhoneNumberGenerator`].
+///
+/// ```
+/// use phonelib::generate_random_phone_number_with_seed;
+///
+/// assert_eq!(
+///     generate_random_phone_number_with_seed("GB", 7),
+///     generate_random_phone_number_with_seed("GB", 7)
+/// );
+/// ```
+pub fn generate_random_phone_number_with_seed(region: &str, seed: u64) -> Option<String> {
+    PhoneNumberGenerator::new(seed)
+        .generate(region)
+        .map(String::from)
+}
+
+// the number of the international digits if they are valid for the country and no other
+// country matches them better, e.g. for a US number not on a Canadian area code
+fn valid_number(country: &'static Country, digits: &str) -> Option<E164> {
+    let number = E164::from_digits(digits)?;
+    (number[1..] == *digits
+        && best_fitting_countries(digits).contains(&country)
+        && validate(&number).is_valid())
+    .then(|| number.with_country(country))
+}
+
+// the digits starting the national numbers of the country after its prefix: its area
+// codes, the prefixes of the number ranges of `number_type` (of any type for `None`) of its
+// calling code, or any digit but 0 when the calling code has no ranges
+fn leads(country: &Country, number_type: Option<PhoneNumberType>) -> Vec<&'static str> {
+    if let Some((_, area_codes)) = AREA_CODES.iter().find(|(code, _)| *code == country.code) {
+        return area_codes.to_vec();
+    }
+    let any_digit = vec!["1", "2", "3", "4", "5", "6", "7", "8", "9"];
+    // the area code of the NANP members is part of their prefix
+    if calling_code(country) != country.prefix || !has_type_metadata(country.prefix) {
+        return match number_type {
+            None => any_digit,
+            // their numbers are classified by the rules of the NANP as a whole
+            Some(_) if calling_code(country) != country.prefix => any_digit,
+            Some(_) => vec![],
+        };
+    }
+    type_prefixes(country.prefix)
+        .filter(|(_, rule_type)| {
+            number_type.is_none_or(|number_type| is_of_type(*rule_type, number_type))
+        })
+        .map(|(prefix, _)| prefix)
+        .collect()
+}
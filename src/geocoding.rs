@@ -0,0 +1,210 @@
+// Places served by fixed-line area codes, behind the `geocoding` feature so applications
+// that don't display them don't ship the tables.
+
+#[cfg(feature = "compact-metadata")]
+use crate::compact::{Packed, Packer, Reader, MAX_CODES, MAX_LEN};
+use crate::number_type::number_type_of;
+use crate::{calling_code, stats, PhoneNumberType, E164};
+
+// (calling code, national number prefix, English name) of the area codes of the major
+// cities and regions, sorted by calling code, the longest matching prefix wins
+#[rustfmt::skip]
+pub(crate) const AREAS: &[(u32, &str, &str)] = &[
+    (1, "202", "Washington, D.C."),
+    (1, "206", "Seattle"),
+    (1, "212", "New York City"),
+    (1, "213", "Los Angeles"),
+    (1, "305", "Miami"),
+    (1, "312", "Chicago"),
+    (1, "415", "San Francisco"),
+    (1, "416", "Toronto"),
+    (1, "514", "Montreal"),
+    (1, "604", "Vancouver"),
+    (1, "617", "Boston"),
+    (1, "646", "New York City"),
+    (1, "718", "New York City"),
+    (33, "1", "Île-de-France"),
+    (33, "2", "Northwest France"),
+    (33, "3", "Northeast France"),
+    (33, "4", "Southeast France"),
+    (33, "5", "Southwest France"),
+    (34, "91", "Madrid"),
+    (34, "93", "Barcelona"),
+    (34, "95", "Seville"),
+    (34, "96", "Valencia"),
+    (39, "02", "Milan"),
+    (39, "06", "Rome"),
+    (39, "011", "Turin"),
+    (39, "081", "Naples"),
+    (41, "22", "Geneva"),
+    (41, "31", "Bern"),
+    (41, "44", "Zurich"),
+    (44, "113", "Leeds"),
+    (44, "117", "Bristol"),
+    (44, "121", "Birmingham"),
+    (44, "131", "Edinburgh"),
+    (44, "141", "Glasgow"),
+    (44, "151", "Liverpool"),
+    (44, "161", "Manchester"),
+    (44, "20", "London"),
+    (44, "28", "Belfast"),
+    (44, "29", "Cardiff"),
+    (49, "221", "Cologne"),
+    (49, "30", "Berlin"),
+    (49, "40", "Hamburg"),
+    (49, "69", "Frankfurt am Main"),
+    (49, "89", "Munich"),
+    (61, "2", "New South Wales and Australian Capital Territory"),
+    (61, "3", "Victoria and Tasmania"),
+    (61, "7", "Queensland"),
+    (61, "8", "Western Australia, South Australia and Northern Territory"),
+    (961, "1", "Beirut"),
+];
+
+// (calling code, national number prefix, language, name) of the names that differ from the
+// English one, sorted as `AREAS`
+#[rustfmt::skip]
+pub(crate) const LOCALIZED_AREAS: &[(u32, &str, &str, &str)] = &[
+    (1, "514", "fr", "Montréal"),
+    (33, "2", "fr", "Nord-Ouest"),
+    (33, "3", "fr", "Nord-Est"),
+    (33, "4", "fr", "Sud-Est"),
+    (33, "5", "fr", "Sud-Ouest"),
+    (34, "95", "es", "Sevilla"),
+    (39, "02", "it", "Milano"),
+    (39, "06", "it", "Roma"),
+    (39, "011", "it", "Torino"),
+    (39, "081", "it", "Napoli"),
+    (41, "22", "fr", "Genève"),
+    (41, "22", "de", "Genf"),
+    (41, "44", "de", "Zürich"),
+    (49, "221", "de", "Köln"),
+    (49, "89", "de", "München"),
+    (961, "1", "ar", "بيروت"),
+    (961, "1", "fr", "Beyrouth"),
+];
+
+#[cfg(feature = "compact-metadata")]
+const PACKED_AREAS: Packer<MAX_LEN, MAX_CODES> = {
+    let mut packer = Packer::new();
+    let mut i = 0;
+    while i < AREAS.len() {
+        let (calling_code, prefix, name) = AREAS[i];
+        packer.entry(calling_code);
+        packer.str(prefix);
+        packer.str(name);
+        i += 1;
+    }
+    packer
+};
+
+#[cfg(feature = "compact-metadata")]
+static COMPACT_AREAS: Packed<(&str, &str), { PACKED_AREAS.len() }, { PACKED_AREAS.sections() }> =
+    PACKED_AREAS.shrink();
+
+#[cfg(feature = "compact-metadata")]
+const PACKED_LOCALIZED_AREAS: Packer<MAX_LEN, MAX_CODES> = {
+    let mut packer = Packer::new();
+    let mut i = 0;
+    while i < LOCALIZED_AREAS.len() {
+        let (calling_code, prefix, lang, name) = LOCALIZED_AREAS[i];
+        packer.entry(calling_code);
+        packer.str(prefix);
+        packer.str(lang);
+        packer.str(name);
+        i += 1;
+    }
+    packer
+};
+
+#[cfg(feature = "compact-metadata")]
+static COMPACT_LOCALIZED_AREAS: Packed<
+    (&str, &str, &str),
+    { PACKED_LOCALIZED_AREAS.len() },
+    { PACKED_LOCALIZED_AREAS.sections() },
+> = PACKED_LOCALIZED_AREAS.shrink();
+
+/// Returns the city or region served by the area code of a fixed-line number, e.g.
+/// `"London"` for `"+44 20 7946 0018"`, in the language `lang` (a language code such as
+/// `"de"` or `"de-CH"`) when the name differs from the English one. Returns `None` if the
+/// number is not valid, is not geographic or its area code is unknown.
+///
+/// The dataset covers the major cities of a few countries only, the place is a caller-ID
+/// hint: numbers keep their area code when their owner moves.
+///
+/// ```
+/// use phonelib::describe_number;
+///
+/// assert_eq!(describe_number("+1 212-555-0123", "en"), Some("New York City"));
+/// assert_eq!(describe_number("+49 89 1234567", "en"), Some("Munich"));
+/// assert_eq!(describe_number("+49 89 1234567", "de-DE"), Some("München"));
+/// // mobile numbers are not tied to a place
+/// assert_eq!(describe_number("+44 7911 123456", "en"), None);
+/// ```
+pub fn describe_number(phone_number: &str, lang: &str) -> Option<&'static str> {
+    let number = E164::parse(phone_number)?;
+    if !matches!(
+        number_type_of(&number),
+        PhoneNumberType::FixedLine | PhoneNumberType::FixedLineOrMobile | PhoneNumberType::Unknown
+    ) {
+        return None;
+    }
+    let calling_code = calling_code(number.country());
+    let national_number = number.national_number();
+
+    stats::record_table_lookup();
+    let (prefix, name) = areas(calling_code)
+        .filter(|(prefix, _)| national_number.starts_with(prefix))
+        .max_by_key(|(prefix, _)| prefix.len())?;
+
+    let language = lang.split(['-', '_']).next().unwrap_or_default();
+    let localized = localized_areas(calling_code)
+        .find(|(area, lang, _)| *area == prefix && lang.eq_ignore_ascii_case(language))
+        .map(|(_, _, name)| name);
+    Some(localized.unwrap_or(name))
+}
+
+// the areas of `calling_code`, (national number prefix, English name)
+#[cfg(not(feature = "compact-metadata"))]
+fn areas(calling_code: u32) -> impl Iterator<Item = (&'static str, &'static str)> {
+    let start = AREAS.partition_point(|(code, _, _)| *code < calling_code);
+    AREAS[start..]
+        .iter()
+        .take_while(move |(code, _, _)| *code == calling_code)
+        .map(|(_, prefix, name)| (*prefix, *name))
+}
+
+#[cfg(feature = "compact-metadata")]
+fn areas(calling_code: u32) -> impl Iterator<Item = (&'static str, &'static str)> {
+    COMPACT_AREAS
+        .entries(calling_code, |reader: &mut Reader| {
+            (reader.str(), reader.str())
+        })
+        .iter()
+        .copied()
+}
+
+// the localized names of the areas of `calling_code`, (national number prefix, language,
+// name)
+#[cfg(not(feature = "compact-metadata"))]
+fn localized_areas(
+    calling_code: u32,
+) -> impl Iterator<Item = (&'static str, &'static str, &'static str)> {
+    let start = LOCALIZED_AREAS.partition_point(|(code, _, _, _)| *code < calling_code);
+    LOCALIZED_AREAS[start..]
+        .iter()
+        .take_while(move |(code, _, _, _)| *code == calling_code)
+        .map(|(_, prefix, lang, name)| (*prefix, *lang, *name))
+}
+
+#[cfg(feature = "compact-metadata")]
+fn localized_areas(
+    calling_code: u32,
+) -> impl Iterator<Item = (&'static str, &'static str, &'static str)> {
+    COMPACT_LOCALIZED_AREAS
+        .entries(calling_code, |reader: &mut Reader| {
+            (reader.str(), reader.str(), reader.str())
+        })
+        .iter()
+        .copied()
+}
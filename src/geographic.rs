@@ -0,0 +1,46 @@
+use crate::formatting::find_pattern;
+use crate::number_type::number_type_of;
+use crate::{calling_code, PhoneNumberType, E164};
+
+/// Tells whether two fixed-line numbers have the same area code, e.g. to check that a
+/// contact number plausibly belongs to the region of a service address.
+///
+/// Returns `Some(false)` for numbers of different countries, and `None` if either number
+/// is invalid, isn't a fixed-line number or has no area code in the formatting metadata.
+/// Numbers ported out of their area keep their area code, so a match is a plausibility
+/// check, not a proof of location.
+///
+/// ```
+/// assert_eq!(
+///     phonelib::same_geographic_area("+44 20 7946 0958", "+44 20 7946 0123"),
+///     Some(true)
+/// );
+/// assert_eq!(
+///     phonelib::same_geographic_area("+44 20 7946 0958", "+44 7700 900123"),
+///     None
+/// );
+/// ```
+pub fn same_geographic_area(a: &str, b: &str) -> Option<bool> {
+    let (a, b) = (E164::parse(a)?, E164::parse(b)?);
+    let (area_a, area_b) = (area_code(&a)?, area_code(&b)?);
+    Some(calling_code(a.country()) == calling_code(b.country()) && area_a == area_b)
+}
+
+// leading digits of a fixed-line number up to the first separator of its formatting
+// pattern
+fn area_code(number: &E164) -> Option<&str> {
+    if !matches!(
+        number_type_of(number),
+        PhoneNumberType::FixedLine | PhoneNumberType::FixedLineOrMobile
+    ) {
+        return None;
+    }
+    let calling_code = calling_code(number.country());
+    let national_number = &number[1 + calling_code.to_string().len()..];
+    let pattern = find_pattern(calling_code, national_number)?;
+    let length = pattern
+        .international
+        .find(|c| c != '#')
+        .unwrap_or(pattern.international.len());
+    national_number.get(..length)
+}
@@ -0,0 +1,134 @@
+use crate::definitions::Country;
+use crate::{best_fitting_countries, find_country_by_code, E164};
+
+// thousands of numbers in service in the countries sharing a calling code and number
+// length with another country, rounded
+#[rustfmt::skip]
+const BUNDLED_PRIORS: &[(&str, u64)] = &[
+    ("US", 400_000),
+    ("RU", 250_000), ("KZ", 25_000),
+    ("GB", 85_000), ("IM", 120), ("JE", 130), ("GG", 80),
+    ("NO", 6_000), ("SJ", 3),
+    ("AU", 35_000), ("CX", 2),
+    ("MA", 50_000), ("EH", 600),
+    ("FI", 10_000), ("AX", 40),
+    ("FK", 4),
+    ("GP", 550), ("MF", 40), ("BL", 15),
+    ("NF", 2),
+];
+
+/// Relative weights of the countries, used by [`guess_countries_with_priors`] to rank the
+/// countries fitting a number equally well.
+///
+/// Weights are counts, e.g. numbers seen per country in your traffic, and are add-one
+/// smoothed: countries without a count weigh 1, so they keep a small chance and tie when
+/// none of the candidates has a count.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CountryPriors {
+    counts: Vec<(&'static str, u64)>,
+}
+
+impl CountryPriors {
+    /// Priors without any count, every candidate is as likely.
+    pub fn uniform() -> CountryPriors {
+        CountryPriors::default()
+    }
+
+    /// The bundled priors: the approximate numbers in service in the countries that share a
+    /// calling code, e.g. the United Kingdom far ahead of the Isle of Man for `+44`.
+    pub fn bundled() -> CountryPriors {
+        CountryPriors {
+            counts: BUNDLED_PRIORS.to_vec(),
+        }
+    }
+
+    /// Priors from the numbers seen per region (ISO 3166 codes) in your traffic. Unknown
+    /// regions are ignored and the counts of a region listed twice add up.
+    pub fn from_counts<'a>(counts: impl IntoIterator<Item = (&'a str, u64)>) -> CountryPriors {
+        counts
+            .into_iter()
+            .fold(CountryPriors::uniform(), |priors, (region, count)| {
+                priors.add(region, count)
+            })
+    }
+
+    /// Adds `count` numbers to the count of `region` (an ISO 3166 code), e.g. to adjust the
+    /// bundled priors to your traffic. Unknown regions are ignored.
+    pub fn add(mut self, region: &str, count: u64) -> CountryPriors {
+        let Some(country) = find_country_by_code(region) else {
+            return self;
+        };
+        match self
+            .counts
+            .iter_mut()
+            .find(|(code, _)| *code == country.code)
+        {
+            Some((_, total)) => *total = total.saturating_add(count),
+            None => self.counts.push((country.code, count)),
+        }
+        self
+    }
+
+    fn weight(&self, country: &Country) -> f64 {
+        let count = self
+            .counts
+            .iter()
+            .find(|(code, _)| *code == country.code)
+            .map_or(0, |(_, count)| *count);
+        count as f64 + 1.0
+    }
+}
+
+/// A country a number may belong to, see [`guess_countries`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CountryGuess {
+    pub country: &'static Country,
+    /// Share of the country among the candidates, the probabilities of a guess add up to 1.
+    pub probability: f64,
+}
+
+/// Returns the countries a number in international form may belong to, the most likely
+/// first, weighted with the bundled [`CountryPriors`]. Returns an empty list if the number
+/// is not valid.
+///
+/// Only the countries fitting the number as well as the country
+/// [`crate::extract_country`] picks are candidates: the number structure, calling code and
+/// area code, decides first, and the priors rank the countries it can't tell apart.
+///
+/// ```
+/// let guesses = phonelib::guess_countries("+500 51234");
+///
+/// assert_eq!(guesses[0].country.code, "FK");
+/// assert_eq!(guesses[1].country.code, "GS");
+/// assert!(guesses[0].probability > guesses[1].probability);
+/// ```
+pub fn guess_countries(phone_number: &str) -> Vec<CountryGuess> {
+    guess_countries_with_priors(phone_number, &CountryPriors::bundled())
+}
+
+/// Same as [`guess_countries`], weighted with your own `priors`, e.g. built from your
+/// traffic mix with [`CountryPriors::from_counts`].
+pub fn guess_countries_with_priors(
+    phone_number: &str,
+    priors: &CountryPriors,
+) -> Vec<CountryGuess> {
+    let Some(number) = E164::parse(phone_number) else {
+        return Vec::new();
+    };
+    let candidates = best_fitting_countries(&number[1..]);
+    let total: f64 = candidates
+        .iter()
+        .map(|country| priors.weight(country))
+        .sum();
+
+    let mut guesses: Vec<CountryGuess> = candidates
+        .into_iter()
+        .map(|country| CountryGuess {
+            country,
+            probability: priors.weight(country) / total,
+        })
+        .collect();
+    // stable, so equally likely countries stay in table order
+    guesses.sort_by(|a, b| b.probability.total_cmp(&a.probability));
+    guesses
+}
@@ -0,0 +1,37 @@
+use crate::formatting::patterns;
+use crate::{calling_code, find_country_by_code, input_mask_for_region};
+
+/// Everything a phone input needs to know about the numbers of a market, see
+/// [`numbering_hints_for_region`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberingHints {
+    /// ISO 3166 code of the region.
+    pub region: &'static str,
+    pub calling_code: u32,
+    /// Accepted lengths of the national significant number.
+    pub national_number_lengths: &'static [u8],
+    /// Whether numbers are customarily written with `+` and the calling code, rather than in
+    /// national form with the trunk prefix.
+    pub plus_customary: bool,
+    /// National trunk prefix, e.g. `"0"` for GB or `"8"` for RU.
+    pub trunk_prefix: Option<&'static str>,
+    /// Typical grouping of a national number, where each `#` stands for one digit.
+    pub grouping: String,
+}
+
+/// Returns the numbering hints of `region` (an ISO 3166 code) so mobile apps can pick the
+/// keyboard, input mask and validation messages per market from one call.
+pub fn numbering_hints_for_region(region: &str) -> Option<NumberingHints> {
+    let country = find_country_by_code(region)?;
+    let trunk_prefix = country.trunk_prefix();
+
+    Some(NumberingHints {
+        region: country.code,
+        calling_code: calling_code(country),
+        national_number_lengths: country.phone_lengths,
+        // without a trunk prefix or national format, numbers are written internationally
+        plus_customary: trunk_prefix.is_none() && patterns(calling_code(country)).is_empty(),
+        trunk_prefix,
+        grouping: input_mask_for_region(region)?,
+    })
+}
@@ -0,0 +1,130 @@
+// Build time checks of the country table: a bad metadata edit fails the build instead of
+// silently misrouting numbers.
+
+use crate::constants::{AREA_CODES, COUNTRIES};
+use crate::definitions::Country;
+
+// (calling code, national number length, code of the first country of the table) of the
+// ranges shared by several countries without area codes. Countries with area codes (see
+// `AREA_CODES`) take the numbers starting with them, the matcher picks the first fitting
+// country of the table for the others, so the listed country has to come first.
+const SHARED_RANGES: &[(u32, u8, &str)] = &[
+    // South Georgia has no numbers of its own
+    (500, 5, "FK"),
+];
+
+const _: () = check_countries(&COUNTRIES);
+
+const fn check_countries(countries: &[Country]) {
+    let mut i = 0;
+    while i < countries.len() {
+        let country = &countries[i];
+        assert!(country.id != 0, "country ids start at 1");
+        assert!(!country.code.is_empty(), "country without code");
+        assert!(country.prefix != 0, "country without calling code");
+        assert!(
+            !country.phone_lengths.is_empty(),
+            "country without phone lengths"
+        );
+
+        let mut l = 0;
+        while l < country.phone_lengths.len() {
+            assert!(country.phone_lengths[l] != 0, "zero phone length");
+            assert!(
+                l == 0 || country.phone_lengths[l - 1] < country.phone_lengths[l],
+                "phone lengths must be sorted and unique"
+            );
+            check_shared_ranges(countries, i, country.phone_lengths[l]);
+            l += 1;
+        }
+
+        let mut j = 0;
+        while j < i {
+            assert!(countries[j].id != country.id, "duplicate country id");
+            assert!(
+                !str_eq(countries[j].code, country.code),
+                "duplicate country code"
+            );
+            j += 1;
+        }
+        i += 1;
+    }
+}
+
+// the first country without area codes fitting (prefix, length) must be the one listed in
+// SHARED_RANGES when another one fits too
+const fn check_shared_ranges(countries: &[Country], index: usize, length: u8) {
+    let country = &countries[index];
+    if has_area_codes(country) {
+        return;
+    }
+    let (mut shared, mut first) = (false, index);
+    let mut j = 0;
+    while j < countries.len() {
+        if j != index
+            && countries[j].prefix == country.prefix
+            && !has_area_codes(&countries[j])
+            && contains(countries[j].phone_lengths, length)
+        {
+            shared = true;
+            if j < first {
+                first = j;
+            }
+        }
+        j += 1;
+    }
+    if !shared {
+        return;
+    }
+
+    let mut k = 0;
+    while k < SHARED_RANGES.len() {
+        let (prefix, shared_length, code) = SHARED_RANGES[k];
+        if prefix == country.prefix && shared_length == length {
+            assert!(
+                str_eq(countries[first].code, code),
+                "shared range resolves to another country than listed in SHARED_RANGES"
+            );
+            return;
+        }
+        k += 1;
+    }
+    panic!("range shared by several countries is missing from SHARED_RANGES");
+}
+
+const fn has_area_codes(country: &Country) -> bool {
+    let mut i = 0;
+    while i < AREA_CODES.len() {
+        if str_eq(AREA_CODES[i].0, country.code) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+const fn contains(lengths: &[u8], length: u8) -> bool {
+    let mut i = 0;
+    while i < lengths.len() {
+        if lengths[i] == length {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+pub(crate) const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
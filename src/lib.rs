@@ -1,58 +1,349 @@
-use constants::COUNTRIES;
-use definitions::Country;
+use constants::{COUNTRIES, EXTENSION_MARKERS, REGION_ALIASES};
+pub use definitions::Country;
+use metadata::BUNDLED;
+use number_type::fits_type_lengths;
+use prefix_index::{countries_with_calling_code, countries_with_prefix, MAX_PREFIX_LEN};
+use scratch::with_scratch;
 
+#[cfg(feature = "arrow")]
+pub use arrow::{country_array, is_valid_array, normalize_array};
+pub use as_you_type::AsYouTypeFormatter;
+pub use attestation::canonical_for_attestation;
+pub use bulk::{newline_offsets, validate_packed, ValidityBitmap};
+#[cfg(feature = "carrier")]
+pub use carrier::carrier_for_number;
+pub use changelog::{changelog, changes_since, ChangelogEntry, MetadataChange, METADATA_VERSION};
+pub use dial_plan::{DialPlan, DialTarget};
+pub use display::{display_number, DisplayPolicy};
+pub use e164::E164;
+pub use emergency::{emergency_numbers_for_region, regions_by_emergency_number};
+pub use enumerate::enumerate_numbers;
+pub use equivalent::equivalent_forms;
+pub use error::{
+    try_extract_country, try_format_phone_number, try_normalize_phone_number, PhoneError,
+};
+pub use examples::{example_number, placeholder_for_region};
+pub use explain::{explain, Candidate, Explanation, Verdict};
+pub use fiction::is_reserved_test_number;
+pub use formatting::{
+    format_phone_number, format_phone_number_checked, input_mask_for_region,
+    input_mask_for_region_and_type, PhoneFormat,
+};
+pub use generate::{
+    generate_random_phone_number, generate_random_phone_number_of_type,
+    generate_random_phone_number_with_seed, generate_test_phone_number, PhoneNumberGenerator,
+};
+#[cfg(feature = "geocoding")]
+pub use geocoding::describe_number;
+pub use geographic::same_geographic_area;
+pub use guess::{guess_countries, guess_countries_with_priors, CountryGuess, CountryPriors};
+pub use hints::{numbering_hints_for_region, NumberingHints};
+pub use locale::region_from_accept_language;
+#[cfg(feature = "unstable")]
+pub use matcher::{Leniency, PhoneNumberMatch, PhoneNumberMatcher};
+pub use matching::{
+    are_phone_numbers_equal, match_by_suffix, match_phone_numbers, match_phone_numbers_with,
+    merge_confidence, ExtensionPolicy, MatchType,
+};
+pub use metadata::{diff_metadata, Metadata, MetadataDiff};
+pub use msisdn::{
+    e164_to_msisdn, iccid_matches_msisdn, is_plausible_msisdn, is_valid_iccid, msisdn_to_e164,
+};
+pub use neighbors::{likely_country, neighbors_of};
+pub use number_type::{detect_phone_number_type, PhoneNumberType};
+pub use parser::{Hook, ParseError, Parser};
+pub use phone_number::PhoneNumber;
+pub use private_plans::{PrivateNumber, PrivateNumberingPlan, PrivatePlanRegistry};
+pub use quiet_hours::{is_within_local_hours, LocalDateTime, QuietHoursProvider};
+pub use redaction::{
+    mask_phone_number, redact_phone_numbers_in_text, redact_phone_numbers_in_text_with_region,
+    MaskStyle, Redaction,
+};
+pub use region_hint::RegionHintProvider;
+pub use revalidation::{revalidate_stored, RevalidationOutcome, RevalidationStatus};
+pub use rfc3966::{parse_rfc3966, sanitize_tel_href};
+pub use risk::IRSF_PRONE_REGIONS;
+#[cfg(feature = "unstable")]
+pub use risk_score::{is_do_not_originate, risk_profile, RangeTag, RiskProfile};
+pub use sampler::{CharacterClass, RejectionSampler, RejectionStats};
+pub use scrub::{scrub_campaign_list, ScrubOptions, ScrubRejection, ScrubReport, ScrubbedNumber};
+pub use sender_id::{is_valid_alpha_sender_id, is_valid_alpha_sender_id_in_region};
+pub use short_codes::{is_emergency_number, is_short_code, short_code_type};
+pub use smpp::{from_smpp_address, to_smpp_address, Npi, SmppAddress, Ton};
+#[cfg(feature = "stats")]
+pub use stats::ParseStats;
+pub use sync::{sync_delta, PhoneNumberSet, SyncDelta};
+pub use test_numbers::{TestNumber, TestNumberRegistry};
+pub use toll_free::itfs_reachable_from;
+pub use trace::{ProcessingTrace, TraceStep};
+pub use validation::{validate, ValidationResult};
+pub use vanity::convert_alpha_characters;
+
+#[cfg(feature = "arrow")]
+mod arrow;
+mod as_you_type;
+mod attestation;
+mod bulk;
+#[cfg(feature = "carrier")]
+mod carrier;
+mod changelog;
+#[cfg(feature = "compact-metadata")]
+mod compact;
+pub mod compat;
 mod constants;
 mod definitions;
+mod dial_plan;
+mod display;
+mod e164;
+mod emergency;
+mod enumerate;
+mod equivalent;
+mod error;
+mod examples;
+mod explain;
+pub mod ffi;
+mod fiction;
+mod formatting;
+mod generate;
+#[cfg(feature = "geocoding")]
+mod geocoding;
+mod geographic;
+mod guess;
+mod hints;
+mod integrity;
+mod locale;
+// public with the `unstable` feature only, `redact_phone_numbers_in_text` uses it
+#[cfg_attr(not(feature = "unstable"), allow(dead_code))]
+mod matcher;
+mod matching;
+mod metadata;
+mod msisdn;
+mod neighbors;
+mod number_type;
+mod parser;
+mod phone_number;
+#[cfg(feature = "polars")]
+mod polars_plugin;
+mod prefix_index;
+mod private_plans;
+mod quiet_hours;
+mod quirks;
+mod redaction;
+mod region_hint;
+mod revalidation;
+mod rfc3966;
+mod risk;
+#[cfg(feature = "unstable")]
+mod risk_score;
+mod sampler;
+mod scratch;
+mod scrub;
+mod sender_id;
+#[cfg(feature = "serde")]
+mod serialization;
+mod short_codes;
+mod smpp;
+mod stats;
+mod sync;
+mod test_numbers;
 mod tests;
+mod toll_free;
+mod trace;
+mod unicode;
+mod validation;
+mod vanity;
+#[cfg(feature = "wasm")]
+mod wasm;
 
-pub fn is_valid_phone_number(phone_number: String) -> bool {
-    // check if the phone number contains invalid character
-    if contains_invalid_character(&phone_number) {
-        return false;
-    }
+/// Returns true if the phone number is valid. Like the other functions of the crate it
+/// accepts both `&str` and `String`, and doesn't allocate.
+pub fn is_valid_phone_number(phone_number: impl AsRef<str>) -> bool {
+    is_valid_phone_number_str(phone_number.as_ref())
+}
 
-    // normalize the phone number and check if it is valid or not
-    normalize_phone_number(phone_number).is_some()
+fn is_valid_phone_number_str(phone_number: &str) -> bool {
+    // check if the phone number contains invalid character, then if it normalizes
+    let phone_number = strip_extension(phone_number);
+    !contains_invalid_character(phone_number)
+        && !has_trailing_letters(phone_number)
+        && E164::parse(phone_number).is_some()
 }
 
-pub fn extract_country(phone_number: String) -> Option<&'static Country> {
-    let mut phone_number = phone_number;
-    remove_unwanted_character(&mut phone_number);
-    extract_country_data(&phone_number)
+// true if letters follow the digits of the number, e.g. "+12025550173xyz", which are not an
+// extension and not a label
+fn has_trailing_letters(phone_number: &str) -> bool {
+    phone_number
+        .rfind(|c| unicode::ascii_digit(c).is_some())
+        .is_some_and(|last_digit| {
+            phone_number[last_digit..]
+                .chars()
+                .any(|c| c.is_alphabetic())
+        })
 }
 
-pub fn normalize_phone_number(phone_number: String) -> Option<String> {
-    // make a copy of phone number and normalize it in place and return it
-    normalize_phone_number_in_place(&mut phone_number.clone())
+pub fn extract_country(phone_number: impl AsRef<str>) -> Option<&'static Country> {
+    with_scratch(|phone_number_buffer| {
+        phone_number_buffer.push_str(phone_number.as_ref());
+        remove_unwanted_character(phone_number_buffer);
+        split_national_number(phone_number_buffer).map(|(country, _)| country)
+    })
 }
 
-pub fn normalize_phone_number_in_place(phone_number: &mut String) -> Option<String> {
-    remove_unwanted_character(phone_number);
+/// Returns the country whose [`Country::id`] is `id`, or `None` for unknown or retired ids.
+pub fn country_by_id(id: u16) -> Option<&'static Country> {
+    // ids follow the table order unless entries were removed, so try the direct slot first
+    COUNTRIES
+        .get((id as usize).wrapping_sub(1))
+        .filter(|country| country.id == id)
+        .or_else(|| COUNTRIES.iter().find(|country| country.id == id))
+}
 
-    // extract country data
-    let country = extract_country_data(&phone_number)?;
+/// Returns the country whose ISO 3166 code is `code`, given in any case or as a common
+/// alias like [`normalize_region_code`], e.g. Germany for `"DE"`.
+pub fn country_by_iso_code(code: &str) -> Option<&'static Country> {
+    find_country_by_code(code)
+}
 
-    // Remove country code from phone number
-    phone_number.replace_range(0..country.prefix.to_string().len(), "");
+/// Returns the countries whose calling code is `calling_code`, e.g. Guernsey, the Isle of
+/// Man, Jersey and the United Kingdom for `44`, or every NANP member for `1`. The countries
+/// with area codes come first, then the others in table order. Empty for unassigned calling
+/// codes, including the area codes of NANP members such as `1242`.
+pub fn countries_by_calling_code(calling_code: u32) -> &'static [&'static Country] {
+    countries_with_calling_code(calling_code)
+}
 
-    // Remove all leading zeros if present
-    leading_zero_remover(phone_number);
+/// Returns every country of the bundled metadata, e.g. to fill a country picker. The
+/// table order only changes with [`METADATA_VERSION`].
+pub fn all_countries() -> &'static [Country] {
+    &COUNTRIES
+}
+
+pub fn normalize_phone_number(phone_number: impl AsRef<str>) -> Option<String> {
+    with_scratch(|phone_number_buffer| {
+        phone_number_buffer.push_str(phone_number.as_ref());
+        normalize_phone_number_in_place(phone_number_buffer)
+    })
+}
+
+pub fn normalize_phone_number_in_place(phone_number: &mut String) -> Option<String> {
+    remove_unwanted_character(phone_number);
+
+    // extract country data, without the zeros after the country code unless they are part
+    // of the national number
+    let (country, national_number) = split_national_number(phone_number)?;
 
     // Add country code again to the phone number and return it
-    let normalize_phone_number = format!("+{}{}", country.prefix, phone_number);
+    let normalize_phone_number = format!("+{}{}", country.prefix, national_number);
 
     Some(normalize_phone_number)
 }
 
+/// Normalizes a phone number dialed from `region` (an ISO 3166 code): national numbers are
+/// read with or without the trunk prefix of the region, so `"06 12 34 56 78"` from `"FR"`
+/// gives `"+33612345678"` where [`normalize_phone_number`] reads an Australian number.
+/// Numbers are read like [`normalize_phone_number`] when `region` is unknown.
+pub fn normalize_phone_number_in_region(
+    phone_number: impl AsRef<str>,
+    region: &str,
+) -> Option<String> {
+    E164::parse_with_default_region(phone_number.as_ref(), region).map(String::from)
+}
+
+/// Normalizes a phone number that is already made of ASCII digits only.
+///
+/// This skips the character filtering done by [`normalize_phone_number`] and is meant for
+/// hot loops where the caller guarantees clean input (e.g. `"0096179123123"`). Passing
+/// anything other than digits is a logic error: it is caught by a debug assertion and
+/// gives unspecified (but memory safe) results in release builds.
+pub fn normalize_unchecked_digits(digits: &str) -> Option<String> {
+    debug_assert!(
+        digits.bytes().all(|b| b.is_ascii_digit()),
+        "normalize_unchecked_digits expects ASCII digits only, got {:?}",
+        digits
+    );
+
+    // remove leading zeros before the country code
+    E164::from_digits(digits.trim_start_matches('0')).map(String::from)
+}
+
+/// Extracts the country of a phone number that is already made of ASCII digits only.
+///
+/// Same contract as [`normalize_unchecked_digits`].
+pub fn extract_country_unchecked_digits(digits: &str) -> Option<&'static Country> {
+    debug_assert!(
+        digits.bytes().all(|b| b.is_ascii_digit()),
+        "extract_country_unchecked_digits expects ASCII digits only, got {:?}",
+        digits
+    );
+
+    split_national_number(digits.trim_start_matches('0')).map(|(country, _)| country)
+}
+
+/// Splits a number in E.164 form (e.g. `"+96179123123"`) into its country and national
+/// number (`"79123123"`) without allocating. Returns `None` if the number is not in E.164
+/// form or no country fits it.
+pub fn strip_country_code(e164: &str) -> Option<(&'static Country, &str)> {
+    let digits = e164.strip_prefix('+')?;
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let country = extract_country_data(digits)?;
+    Some((country, &digits[country.prefix_len()..]))
+}
+
+// returns the phone number without its extension (";ext=12", "x12", "ext. 12", "#12"). An
+// extension is made of the digits ending the input after a marker that follows the digits
+// of the number, so labels in front of the number ("Phone: +1…") and trailing words are kept
+fn strip_extension(phone_number: &str) -> &str {
+    let phone_number = phone_number.trim_end();
+    let before_digits = phone_number.trim_end_matches(|c| unicode::ascii_digit(c).is_some());
+    if before_digits.len() == phone_number.len() {
+        return phone_number;
+    }
+    let before_digits = before_digits.trim_end();
+    EXTENSION_MARKERS
+        .iter()
+        .filter_map(|marker| {
+            let start = before_digits.len().checked_sub(marker.len())?;
+            let suffix = before_digits.get(start..)?;
+            suffix
+                .eq_ignore_ascii_case(marker)
+                .then(|| &before_digits[..start])
+        })
+        // "MAX 12" is a word, not an extension
+        .find(|number| {
+            !number.ends_with(|c: char| c.is_ascii_alphabetic())
+                && number.contains(|c| unicode::ascii_digit(c).is_some())
+        })
+        .map_or(phone_number, str::trim_end)
+}
+
+// splits the phone number into the number and the digits of its extension, if any
+fn split_extension(phone_number: &str) -> (&str, Option<String>) {
+    let number = strip_extension(phone_number);
+    let extension: String = phone_number[number.len()..]
+        .chars()
+        .filter(char::is_ascii_digit)
+        .collect();
+
+    (
+        number,
+        Some(extension).filter(|extension| !extension.is_empty()),
+    )
+}
+
 fn remove_unwanted_character(phone_number: &mut String) {
+    // the digits of an extension are not part of the number
+    phone_number.truncate(strip_extension(phone_number).len());
     remove_non_digit_character(phone_number);
     // Remove leading zero before country code
     leading_zero_remover(phone_number);
 }
 
-
-fn contains_invalid_character(phone_number: &String) -> bool {
+fn contains_invalid_character(phone_number: &str) -> bool {
     let mut parentheses_count = 0;
+    stats::record_bytes_scanned(phone_number.len());
     // check if the phone number contains invalid character
 
     for c in phone_number.chars() {
@@ -61,6 +352,7 @@ fn contains_invalid_character(phone_number: &String) -> bool {
             '(' => parentheses_count += 1,
             ')' if parentheses_count == 0 => return false,
             ')' => parentheses_count -= 1,
+            c if unicode::ascii_digit(c).is_some() || unicode::is_bidi_control(c) => {}
             _ => return false,
         }
     }
@@ -68,30 +360,185 @@ fn contains_invalid_character(phone_number: &String) -> bool {
     parentheses_count == 0
 }
 
-
 fn remove_non_digit_character(phone_number: &mut String) {
-    // remove all non digit character
-    phone_number.retain(|c| c.is_numeric());
+    // remove all non digit character, reading the digits of other scripts as ASCII ones
+    stats::record_bytes_scanned(phone_number.len());
+    match phone_number.is_ascii() {
+        true => phone_number.retain(|c| c.is_ascii_digit()),
+        false => {
+            *phone_number = phone_number
+                .chars()
+                .filter_map(unicode::ascii_digit)
+                .collect()
+        }
+    }
 }
 
 fn leading_zero_remover(phone_number: &mut String) {
-    // remove all leading zeros
-    while phone_number.starts_with('0') {
-        phone_number.remove(0);
-    }
+    // remove all leading zeros at once, removing them one by one is quadratic
+    let zeros = phone_number.len() - phone_number.trim_start_matches('0').len();
+    phone_number.drain(..zeros);
 }
 
+// the calling codes starting the phone number are looked up from the longest, so +1 242
+// goes to the Bahamas rather than the United States
 fn extract_country_data(phone_number: &str) -> Option<&'static Country> {
-    // check if the phone number starts with country code or not and return country data if found
-    for country in COUNTRIES.iter() {
-        if phone_number.starts_with(&country.prefix.to_string()) {
-            if country
-                .phone_lengths
-                .contains(&(phone_number.len() as u8 - country.prefix.to_string().len() as u8))
-            {
-                return Some(country);
+    stats::record_table_lookup();
+    (1..=MAX_PREFIX_LEN)
+        .rev()
+        .filter_map(|len| {
+            let prefix = phone_number.get(..len)?;
+            match prefix.bytes().all(|b| b.is_ascii_digit()) {
+                true => prefix.parse().ok(),
+                false => None,
             }
+        })
+        .flat_map(|prefix| countries_with_prefix(prefix).iter().copied())
+        .find(|country| country_fits(&BUNDLED, country, phone_number))
+}
+
+/// Returns the ISO 3166 code of a region code given in any case or as a common alias, e.g.
+/// `"GB"` for `"gb"` or `"UK"`, or `None` for unknown regions.
+pub fn normalize_region_code(code: &str) -> Option<&'static str> {
+    find_country_by_code(code).map(|country| country.code)
+}
+
+// every region hint goes through here, so all of them accept any case and aliases
+fn find_country_by_code(code: &str) -> Option<&'static Country> {
+    let code = code.trim();
+    stats::record_table_lookup();
+    let code = REGION_ALIASES
+        .iter()
+        .find(|(alias, _)| alias.eq_ignore_ascii_case(code))
+        .map_or(code, |(_, iso_code)| *iso_code);
+
+    COUNTRIES
+        .iter()
+        .find(|country| country.code.eq_ignore_ascii_case(code))
+}
+
+// the E.164 country code, which is shared by every NANP member
+const fn calling_code(country: &Country) -> u32 {
+    match country.prefix {
+        1000..=1999 => 1,
+        prefix => prefix,
+    }
+}
+
+fn find_country<'a>(metadata: &'a Metadata, phone_number: &str) -> Option<&'a Country> {
+    // check if the phone number starts with country code or not and return country data if found
+    metadata
+        .countries()
+        .iter()
+        .filter(|country| country_fits(metadata, country, phone_number))
+        .fold(None, |best, country| {
+            most_specific(metadata, best, country, phone_number)
+        })
+}
+
+// the countries fitting the phone number with the longest calling code and area code, in
+// table order, the first one being the country the number is matched to
+fn best_fitting_countries(phone_number: &str) -> Vec<&'static Country> {
+    let fitting: Vec<&'static Country> = COUNTRIES
+        .iter()
+        .filter(|country| country_fits(&BUNDLED, country, phone_number))
+        .collect();
+    let longest = fitting
+        .iter()
+        .map(|country| match_len(&BUNDLED, country, phone_number))
+        .max();
+
+    fitting
+        .into_iter()
+        .filter(|country| Some(match_len(&BUNDLED, country, phone_number)) == longest)
+        .collect()
+}
+
+// keeps the first of the countries matching the most digits of the phone number
+fn most_specific<'a>(
+    metadata: &Metadata,
+    best: Option<&'a Country>,
+    country: &'a Country,
+    phone_number: &str,
+) -> Option<&'a Country> {
+    match best {
+        Some(best)
+            if match_len(metadata, best, phone_number)
+                >= match_len(metadata, country, phone_number) =>
+        {
+            Some(best)
         }
+        _ => Some(country),
+    }
+}
+
+// digits of a fitting phone number matched by the calling code and area code of the
+// country, so +1 242 goes to the Bahamas and +1 416 to Canada rather than the United States
+fn match_len(metadata: &Metadata, country: &Country, phone_number: &str) -> usize {
+    let national_number = &phone_number[country.prefix_len()..];
+    country.prefix_len() + area_code_len(metadata, country, national_number).unwrap_or_default()
+}
+
+// length of the area code of the country starting the national number, `Some(0)` for the
+// countries without area codes and `None` if none of the area codes of the country starts it
+fn area_code_len(metadata: &Metadata, country: &Country, national_number: &str) -> Option<usize> {
+    let area_codes = metadata.area_codes(country);
+    if area_codes.is_empty() {
+        return Some(0);
+    }
+    area_codes
+        .iter()
+        .find(|area_code| national_number.starts_with(*area_code))
+        .map(|area_code| area_code.len())
+}
+
+// true if the phone number starts with the country code, followed by one of its area codes
+// if it has some, and the rest has a valid length
+fn country_fits(metadata: &Metadata, country: &Country, phone_number: &str) -> bool {
+    country_fits_length(metadata, country, phone_number)
+        && area_code_len(metadata, country, &phone_number[country.prefix_len()..]).is_some()
+}
+
+// the country and national number of international digits, without the zeros dialed
+// after the calling code unless they are significant. The candidate countries are found
+// with the zeros, so the country is matched again on the digits left: "+376 000000" isn't
+// "+376" and "+358 00 1…" gets the country of "+358 1…"
+fn split_national_number(digits: &str) -> Option<(&'static Country, &str)> {
+    let country = extract_country_data(digits)?;
+    let national_number = significant_national_number(country, &digits[country.prefix_len()..]);
+    if national_number.len() == digits.len() - country.prefix_len() {
+        return Some((country, national_number));
     }
-    None
+    let country = extract_country_data(&format!("{}{}", country.prefix, national_number))?;
+    Some((country, national_number))
+}
+
+// the national number without the zeros dialed after the calling code, unless they are
+// part of the numbers of the country
+fn significant_national_number<'a>(country: &Country, national_number: &'a str) -> &'a str {
+    match country.zero_significant() {
+        true => national_number,
+        false => national_number.trim_start_matches('0'),
+    }
+}
+
+// true if the phone number starts with the country code and the rest has a valid length,
+// for its type when the plan has lengths per type
+fn country_fits_length(metadata: &Metadata, country: &Country, phone_number: &str) -> bool {
+    // compares the digits as a number so the hot path doesn't allocate the prefix string
+    let prefix_len = country.prefix_len();
+    stats::record_candidate();
+    let fits = phone_number.get(..prefix_len).is_some_and(|prefix| {
+        prefix.bytes().all(|b| b.is_ascii_digit()) && prefix.parse() == Ok(country.prefix)
+    }) && country
+        .phone_lengths
+        .contains(&((phone_number.len() - prefix_len) as u8));
+
+    // the area code of the NANP members is part of their prefix
+    let calling_code = calling_code(country);
+    let calling_code_len = match calling_code == country.prefix {
+        true => prefix_len,
+        false => 1,
+    };
+    fits && fits_type_lengths(metadata, calling_code, &phone_number[calling_code_len..])
 }
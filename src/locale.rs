@@ -0,0 +1,89 @@
+use crate::normalize_region_code;
+
+// (language, region) of the most likely region of a language without region subtag, from
+// the CLDR likely subtags
+const LIKELY_REGIONS: &[(&str, &str)] = &[
+    ("ar", "EG"),
+    ("bn", "BD"),
+    ("cs", "CZ"),
+    ("da", "DK"),
+    ("de", "DE"),
+    ("el", "GR"),
+    ("en", "US"),
+    ("es", "ES"),
+    ("fa", "IR"),
+    ("fi", "FI"),
+    ("fr", "FR"),
+    ("he", "IL"),
+    ("hi", "IN"),
+    ("hu", "HU"),
+    ("id", "ID"),
+    ("it", "IT"),
+    ("ja", "JP"),
+    ("ko", "KR"),
+    ("ms", "MY"),
+    ("nb", "NO"),
+    ("nl", "NL"),
+    ("pl", "PL"),
+    ("pt", "BR"),
+    ("ro", "RO"),
+    ("ru", "RU"),
+    ("sv", "SE"),
+    ("th", "TH"),
+    ("tr", "TR"),
+    ("uk", "UA"),
+    ("ur", "PK"),
+    ("vi", "VN"),
+    ("zh", "CN"),
+];
+
+/// Returns the regions (ISO 3166 codes) of an `Accept-Language` header or a CLDR locale
+/// string, most preferred first, e.g. `["BE", "FR"]` for `"fr-BE,fr;q=0.9"`.
+///
+/// Languages without a region give their most likely region, and languages with a `q=0`
+/// weight or an unknown region are left out. Meant to pick the default region of a request.
+pub fn region_from_accept_language(accept_language: &str) -> Vec<&'static str> {
+    let mut ranges: Vec<(&str, f32)> = accept_language
+        .split(',')
+        .filter_map(|range| {
+            let mut parameters = range.split(';');
+            let tag = parameters.next()?.trim();
+            let weight = parameters
+                .find_map(|parameter| parameter.trim().strip_prefix("q="))
+                .map_or(Some(1.0), |weight| weight.trim().parse().ok())?;
+            (!tag.is_empty() && tag != "*" && weight > 0.0).then_some((tag, weight))
+        })
+        .collect();
+    // stable, so ranges of equal weight keep the header order
+    ranges.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+    let mut regions = Vec::new();
+    for region in ranges
+        .into_iter()
+        .filter_map(|(tag, _)| region_of_locale(tag))
+    {
+        if !regions.contains(&region) {
+            regions.push(region);
+        }
+    }
+    regions
+}
+
+// "fr-CA", "zh_Hant_TW" or "en-GB-u-ca-gregory" give their region, a bare language its most
+// likely region
+fn region_of_locale(locale: &str) -> Option<&'static str> {
+    let mut subtags = locale.split(['-', '_']);
+    let language = subtags.next()?;
+
+    // the region is the first two letter subtag after the language and an optional script
+    let region = subtags
+        .take(2)
+        .find(|subtag| subtag.len() == 2 && subtag.bytes().all(|b| b.is_ascii_alphabetic()));
+    match region {
+        Some(region) => normalize_region_code(region),
+        None => LIKELY_REGIONS
+            .iter()
+            .find(|(likely_language, _)| likely_language.eq_ignore_ascii_case(language))
+            .and_then(|(_, region)| normalize_region_code(region)),
+    }
+}
@@ -0,0 +1,216 @@
+// Experimental matching of phone numbers in free text, behind the `unstable` feature: the
+// leniency levels and the rules skipping candidates may change in minor releases. The module
+// is always built, `redact_phone_numbers_in_text` finds numbers with it.
+
+use crate::definitions::Country;
+use crate::formatting::format_e164;
+use crate::unicode::ascii_digit;
+use crate::{find_country_by_code, validate, PhoneFormat, PhoneNumber, E164};
+
+/// How strict [`PhoneNumberMatcher`] is about the numbers it reports, from the most
+/// lenient to the strictest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Leniency {
+    /// Numbers that are possible for their calling code, see
+    /// [`crate::ValidationResult::is_possible`].
+    Possible,
+    /// Numbers in the known ranges of their country, see
+    /// [`crate::ValidationResult::is_valid`].
+    Valid,
+    /// Valid numbers whose digits are grouped as in their national or international format,
+    /// or not grouped at all, so `"+33 6 12 34 56 78"` and `"+33612345678"` match but
+    /// `"+33 61 234 5678"` doesn't.
+    StrictGrouping,
+}
+
+/// Finds the phone numbers in free text, e.g. to flag personal data in documents.
+///
+/// Candidates are runs of digits and separators. Those written next to letters, currency
+/// signs or `#` (IDs, prices, order numbers), dates and numbers failing the [`Leniency`] are
+/// skipped. Numbers in international form start with `+` or `00`, the others are only
+/// matched with a [`PhoneNumberMatcher::default_region`]. Extensions are not matched.
+///
+/// ```
+/// # #[cfg(feature = "unstable")] {
+/// use phonelib::{Leniency, PhoneNumberMatcher};
+///
+/// let text = "Call +33 6 12 34 56 78 or 01 23 45 67 89 before 15/10/2026, order #4915112345678.";
+/// let matcher = PhoneNumberMatcher::new(Leniency::Valid).default_region("FR");
+/// let numbers: Vec<String> = matcher
+///     .find_iter(text)
+///     .map(|found| found.number.unwrap().e164().to_string())
+///     .collect();
+///
+/// assert_eq!(numbers, ["+33612345678", "+33123456789"]);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhoneNumberMatcher {
+    leniency: Leniency,
+    default_region: Option<&'static Country>,
+}
+
+/// A number found by [`PhoneNumberMatcher::find_iter`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhoneNumberMatch<'t> {
+    /// Byte offset of the number in the text.
+    pub start: usize,
+    /// The number as written in the text.
+    pub raw: &'t str,
+    /// The parsed number, `None` for the [`Leniency::Possible`] numbers that don't parse.
+    pub number: Option<PhoneNumber>,
+}
+
+impl PhoneNumberMatch<'_> {
+    /// Byte offset of the end of the number in the text.
+    pub fn end(&self) -> usize {
+        self.start + self.raw.len()
+    }
+}
+
+// characters allowed between the digits of a candidate, at most two in a row like ") "
+const SEPARATORS: &[char] = &[' ', '-', '.', '/', '(', ')', '\u{00A0}'];
+
+// characters that make the digits next to them something else than a phone number
+const FORBIDDEN_NEIGHBORS: &[char] = &['$', '€', '£', '¥', '#', '@', '%', '_', '+'];
+
+impl PhoneNumberMatcher {
+    pub fn new(leniency: Leniency) -> PhoneNumberMatcher {
+        PhoneNumberMatcher {
+            leniency,
+            default_region: None,
+        }
+    }
+
+    /// Reads the numbers that are not in international form as national numbers of
+    /// `region` (an ISO 3166 code), see [`PhoneNumber::parse_with_country`]. Unknown regions
+    /// are ignored.
+    pub fn default_region(mut self, region: &str) -> PhoneNumberMatcher {
+        self.default_region = find_country_by_code(region);
+        self
+    }
+
+    /// Returns the numbers of `text`, in text order.
+    pub fn find_iter<'a, 't: 'a>(
+        &'a self,
+        text: &'t str,
+    ) -> impl Iterator<Item = PhoneNumberMatch<'t>> + 'a {
+        candidates(text).filter_map(move |(start, raw)| self.check(text, start, raw))
+    }
+
+    fn check<'t>(&self, text: &str, start: usize, raw: &'t str) -> Option<PhoneNumberMatch<'t>> {
+        if !stands_alone(text, start, start + raw.len()) || is_date(raw) {
+            return None;
+        }
+        let raw_digits = raw.trim_start_matches('(');
+        let plus = raw_digits.starts_with('+');
+        // "00" is the most common international prefix, the default region reads its own
+        let international = plus || raw_digits.starts_with("00");
+        let number = match (plus, self.default_region) {
+            (false, Some(region)) => PhoneNumber::parse_with_country(raw, region.code),
+            _ if international => PhoneNumber::parse(raw),
+            _ => return None,
+        };
+
+        let accepted = match (&number, self.leniency) {
+            (None, Leniency::Possible) => international && validate(raw).is_possible(),
+            (None, _) => false,
+            (Some(number), Leniency::Possible) => validate(&number.e164()).is_possible(),
+            (Some(number), Leniency::Valid) => validate(&number.e164()).is_valid(),
+            (Some(number), Leniency::StrictGrouping) => {
+                validate(&number.e164()).is_valid() && is_grouped_as_formatted(raw, &number.e164())
+            }
+        };
+        accepted.then_some(PhoneNumberMatch { start, raw, number })
+    }
+}
+
+// the runs of digits and separators of the text, starting with a digit, `+` or `(` and
+// ending with a digit, with their byte offset
+fn candidates(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut position = 0;
+    std::iter::from_fn(move || loop {
+        let offset =
+            text[position..].find(|c: char| c == '+' || c == '(' || ascii_digit(c).is_some())?;
+        let start = position + offset;
+        let mut end = None;
+        let mut separators = 0;
+        let mut plus = false;
+        for (index, c) in text[start..].char_indices() {
+            // a single `+` before the digits, e.g. "(+1) 202…"
+            let leading_plus = c == '+' && end.is_none() && !plus;
+            plus |= c == '+';
+            if ascii_digit(c).is_some() {
+                separators = 0;
+                end = Some(start + index + c.len_utf8());
+            } else if leading_plus || (SEPARATORS.contains(&c) && separators < 2) {
+                separators += 1;
+            } else {
+                break;
+            }
+        }
+        match end {
+            Some(end) => {
+                position = end;
+                return Some((start, &text[start..end]));
+            }
+            None => position = start + 1,
+        }
+    })
+}
+
+// true if the candidate isn't glued to letters or symbols, e.g. "ID-12345" or "12345€"
+fn stands_alone(text: &str, start: usize, end: usize) -> bool {
+    let forbidden = |c: char| c.is_alphanumeric() || FORBIDDEN_NEIGHBORS.contains(&c);
+    let mut before = text[..start].chars().rev();
+    let glued_before = match before.next() {
+        Some(c) if "-/.".contains(c) => before.next().is_some_and(forbidden),
+        Some(c) => forbidden(c),
+        None => false,
+    };
+    let glued_after = text[end..].chars().next().is_some_and(forbidden);
+    !glued_before && !glued_after
+}
+
+// true for dates such as "2026-10-15", "15/10/2026" or "15.10.26"
+fn is_date(raw: &str) -> bool {
+    let Some(separator) = raw.chars().find(|c| "-/.".contains(*c)) else {
+        return false;
+    };
+    let parts: Vec<&str> = raw.split(separator).collect();
+    let lengths: Vec<usize> = parts.iter().map(|part| part.len()).collect();
+    parts
+        .iter()
+        .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+        && matches!(
+            lengths.as_slice(),
+            [4, 1..=2, 1..=2] | [1..=2, 1..=2, 4] | [1..=2, 1..=2, 2]
+        )
+}
+
+// true if the digit groups of `raw` are the ones of a format of the number, or the number
+// is written in one block, possibly after its calling code
+fn is_grouped_as_formatted(raw: &str, number: &E164) -> bool {
+    let groups = digit_groups(raw);
+    if groups.len() == 1 {
+        return true;
+    }
+    let international = digit_groups(&format_e164(number, PhoneFormat::International));
+    let national = digit_groups(&format_e164(number, PhoneFormat::National));
+    groups == international
+        || groups == national
+        || (groups.len() == 2 && groups[0] == international[0])
+}
+
+fn digit_groups(s: &str) -> Vec<String> {
+    let mut groups = vec![String::new()];
+    for c in s.chars() {
+        match ascii_digit(c) {
+            Some(digit) => groups.last_mut().unwrap().push(digit),
+            None if !groups.last().unwrap().is_empty() => groups.push(String::new()),
+            None => {}
+        }
+    }
+    groups.retain(|group| !group.is_empty());
+    groups
+}
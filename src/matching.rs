@@ -0,0 +1,169 @@
+use crate::e164::without_tel_scheme;
+use crate::{remove_unwanted_character, split_extension, E164};
+
+/// How closely two phone numbers match, from the weakest to the strongest match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MatchType {
+    NoMatch,
+    /// One national significant number is a suffix of the other, e.g. a caller ID that lost
+    /// its area code.
+    ShortNsnMatch,
+    /// The national significant numbers are equal but the country codes differ or are
+    /// missing on one side.
+    NsnMatch,
+    /// Both numbers normalize to the same E.164 number.
+    ExactMatch,
+}
+
+/// How extensions are compared when matching phone numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ExtensionPolicy {
+    /// Extensions are ignored, e.g. to correlate call logs where they are never recorded.
+    Ignore,
+    /// Extensions must be equal when both numbers have one.
+    #[default]
+    MatchIfPresent,
+    /// Extensions must be equal, a number without extension only matches another number
+    /// without extension, e.g. for CRM deduplication.
+    Require,
+}
+
+// shortest suffix accepted as a short NSN match, below that false positives are common
+const MIN_SUFFIX_DIGITS: usize = 7;
+
+const EXACT_MATCH_WEIGHT: f32 = 0.9;
+const NSN_MATCH_WEIGHT: f32 = 0.6;
+const SHORT_NSN_MATCH_WEIGHT: f32 = 0.3;
+
+/// Compares two phone numbers. Numbers written without `+` or `00` are taken as national
+/// numbers and compared by their national significant number (digits without trunk zeros).
+///
+/// Extensions are compared with [`ExtensionPolicy::MatchIfPresent`], see
+/// [`match_phone_numbers_with`].
+pub fn match_phone_numbers(a: &str, b: &str) -> MatchType {
+    match_phone_numbers_with(a, b, ExtensionPolicy::default())
+}
+
+/// Compares two phone numbers like [`match_phone_numbers`], with the given extension policy.
+/// Numbers whose extensions don't satisfy the policy never match.
+pub fn match_phone_numbers_with(a: &str, b: &str, policy: ExtensionPolicy) -> MatchType {
+    let ((a, a_extension), (b, b_extension)) = (split_extension(a), split_extension(b));
+    let extensions_match = match policy {
+        ExtensionPolicy::Ignore => true,
+        ExtensionPolicy::MatchIfPresent => {
+            a_extension.is_none() || b_extension.is_none() || a_extension == b_extension
+        }
+        ExtensionPolicy::Require => a_extension == b_extension,
+    };
+
+    match extensions_match {
+        true => match_base_numbers(a, b),
+        false => MatchType::NoMatch,
+    }
+}
+
+/// Returns true if both numbers normalize to the same E.164 number and their extensions
+/// satisfy `policy`.
+pub fn are_phone_numbers_equal(a: &str, b: &str, policy: ExtensionPolicy) -> bool {
+    match_phone_numbers_with(a, b, policy) == MatchType::ExactMatch
+}
+
+/// Returns true if the last `min_digits` digits of both numbers are equal, for correlating
+/// caller IDs that were truncated or lost their country code.
+///
+/// `min_digits` is raised to 7 when smaller, since shorter suffixes match unrelated numbers
+/// too often, and numbers with fewer digits than that never match. Extensions are ignored.
+pub fn match_by_suffix(a: &str, b: &str, min_digits: usize) -> bool {
+    let min_digits = min_digits.max(MIN_SUFFIX_DIGITS);
+    let digits = |phone_number: &str| -> Vec<char> {
+        split_extension(phone_number)
+            .0
+            .chars()
+            .filter(char::is_ascii_digit)
+            .collect()
+    };
+    let (a, b) = (digits(a), digits(b));
+
+    a.len() >= min_digits
+        && b.len() >= min_digits
+        && a[a.len() - min_digits..] == b[b.len() - min_digits..]
+}
+
+fn match_base_numbers(a: &str, b: &str) -> MatchType {
+    let (a_number, b_number) = (parse_international(a), parse_international(b));
+    if a_number.is_some() && a_number == b_number {
+        return MatchType::ExactMatch;
+    }
+
+    let (a_nsn, b_nsn) = (
+        national_significant_number(a, a_number),
+        national_significant_number(b, b_number),
+    );
+    if a_nsn.is_empty() || b_nsn.is_empty() {
+        return MatchType::NoMatch;
+    }
+
+    let (shorter, longer) = match a_nsn.len() <= b_nsn.len() {
+        true => (&a_nsn, &b_nsn),
+        false => (&b_nsn, &a_nsn),
+    };
+    if shorter == longer {
+        MatchType::NsnMatch
+    } else if shorter.len() >= MIN_SUFFIX_DIGITS && longer.ends_with(shorter.as_str()) {
+        MatchType::ShortNsnMatch
+    } else {
+        MatchType::NoMatch
+    }
+}
+
+/// Scores how likely two contact records belong to the same person from their phone
+/// numbers, between `0.0` (no number in common) and `1.0`.
+///
+/// Each number of `a` contributes its best match in `b` (exact matches weigh more than
+/// national or suffix matches) and the contributions are combined so that every extra
+/// shared number raises the confidence.
+pub fn merge_confidence(a: &[&str], b: &[&str]) -> f32 {
+    let no_match_probability: f32 = a
+        .iter()
+        .map(|a| {
+            let best_match = b
+                .iter()
+                .map(|b| match_phone_numbers(a, b))
+                .max()
+                .unwrap_or(MatchType::NoMatch);
+            1.0 - match_weight(best_match)
+        })
+        .product();
+
+    1.0 - no_match_probability
+}
+
+fn match_weight(match_type: MatchType) -> f32 {
+    match match_type {
+        MatchType::NoMatch => 0.0,
+        MatchType::ShortNsnMatch => SHORT_NSN_MATCH_WEIGHT,
+        MatchType::NsnMatch => NSN_MATCH_WEIGHT,
+        MatchType::ExactMatch => EXACT_MATCH_WEIGHT,
+    }
+}
+
+fn parse_international(phone_number: &str) -> Option<E164> {
+    let phone_number = phone_number.trim_start();
+    let phone_number = without_tel_scheme(phone_number);
+    if phone_number.starts_with('+') || phone_number.starts_with("00") {
+        E164::parse(phone_number)
+    } else {
+        None
+    }
+}
+
+fn national_significant_number(phone_number: &str, number: Option<E164>) -> String {
+    match number {
+        Some(number) => number.national_number().to_string(),
+        None => {
+            let mut digits = phone_number.to_string();
+            remove_unwanted_character(&mut digits);
+            digits
+        }
+    }
+}
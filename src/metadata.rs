@@ -0,0 +1,244 @@
+use std::borrow::Cow;
+use std::ops::RangeInclusive;
+
+use crate::constants::{AREA_CODES, COUNTRIES};
+use crate::definitions::Country;
+use crate::number_type::TYPE_LENGTHS;
+use crate::{calling_code, MetadataChange, PhoneNumberType, METADATA_VERSION};
+
+// the metadata the numbers are matched with
+pub(crate) static BUNDLED: Metadata = Metadata {
+    version: METADATA_VERSION,
+    countries: Cow::Borrowed(&COUNTRIES),
+    area_codes: Cow::Borrowed(AREA_CODES),
+    type_lengths: Cow::Borrowed(TYPE_LENGTHS),
+};
+
+/// A set of country metadata: the table bundled with the crate, or another version of it
+/// to compare with [`diff_metadata`] before switching to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Metadata {
+    version: u32,
+    countries: Cow<'static, [Country]>,
+    // by country code, see `constants::AREA_CODES`
+    area_codes: Cow<'static, [(&'static str, &'static [&'static str])]>,
+    // by calling code, see `number_type::TYPE_LENGTHS`
+    type_lengths: Cow<'static, [(u32, PhoneNumberType, RangeInclusive<u8>)]>,
+}
+
+impl Metadata {
+    /// Returns the metadata bundled with the crate, at [`METADATA_VERSION`].
+    pub fn bundled() -> Metadata {
+        BUNDLED.clone()
+    }
+
+    /// Builds metadata from its version and country table, with the area codes and the
+    /// lengths per number type of the bundled metadata. Countries are matched across
+    /// versions by [`Country::id`].
+    pub fn new(version: u32, countries: Vec<Country>) -> Metadata {
+        Metadata {
+            version,
+            countries: Cow::Owned(countries),
+            ..Metadata::bundled()
+        }
+    }
+
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    pub fn countries(&self) -> &[Country] {
+        &self.countries
+    }
+
+    fn country(&self, id: u16) -> Option<&Country> {
+        self.countries.iter().find(|country| country.id == id)
+    }
+
+    pub(crate) fn countries_mut(&mut self) -> &mut Vec<Country> {
+        self.countries.to_mut()
+    }
+
+    // the leading digits of the national numbers of the country, empty for the countries
+    // without area codes
+    pub(crate) fn area_codes(&self, country: &Country) -> &'static [&'static str] {
+        self.area_codes
+            .iter()
+            .find(|(code, _)| *code == country.code)
+            .map_or(&[], |(_, area_codes)| *area_codes)
+    }
+
+    pub(crate) fn set_area_codes(&mut self, id: u16, area_codes: &'static [&'static str]) {
+        let Some(code) = self.country(id).map(|country| country.code) else {
+            return;
+        };
+        let table = self.area_codes.to_mut();
+        table.retain(|(country, _)| *country != code);
+        if !area_codes.is_empty() {
+            table.push((code, area_codes));
+        }
+    }
+
+    // true if some types of the numbering plan of the calling code have lengths of their own
+    pub(crate) fn has_type_lengths(&self, calling_code: u32) -> bool {
+        self.type_lengths
+            .iter()
+            .any(|(code, _, _)| *code == calling_code)
+    }
+
+    pub(crate) fn type_lengths(
+        &self,
+        calling_code: u32,
+        number_type: PhoneNumberType,
+    ) -> Option<RangeInclusive<u8>> {
+        self.type_lengths
+            .iter()
+            .find(|(code, type_, _)| *code == calling_code && *type_ == number_type)
+            .map(|(_, _, lengths)| lengths.clone())
+    }
+
+    pub(crate) fn set_type_lengths(
+        &mut self,
+        id: u16,
+        number_type: PhoneNumberType,
+        lengths: Option<RangeInclusive<u8>>,
+    ) {
+        let Some(calling_code) = self.country(id).map(calling_code) else {
+            return;
+        };
+        let table = self.type_lengths.to_mut();
+        table.retain(|(code, type_, _)| (*code, *type_) != (calling_code, number_type));
+        if let Some(lengths) = lengths {
+            table.push((calling_code, number_type, lengths));
+        }
+    }
+
+    fn type_lengths_of(&self, country: &Country) -> Vec<(PhoneNumberType, RangeInclusive<u8>)> {
+        self.type_lengths
+            .iter()
+            .filter(|(code, _, _)| *code == calling_code(country))
+            .map(|(_, number_type, lengths)| (*number_type, lengths.clone()))
+            .collect()
+    }
+}
+
+/// Changes between two metadata sets, see [`diff_metadata`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetadataDiff {
+    pub from_version: u32,
+    pub to_version: u32,
+    /// Changes ordered by country id, in the form used by [`crate::changelog`].
+    pub changes: Vec<MetadataChange>,
+}
+
+impl MetadataDiff {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// Lists what switching from metadata `a` to metadata `b` changes: countries added and
+/// removed, and calling codes, number lengths, lengths per number type and area codes
+/// changed.
+///
+/// ```
+/// use phonelib::{diff_metadata, Metadata, MetadataChange};
+///
+/// let bundled = Metadata::bundled();
+/// let mut countries = bundled.countries().to_vec();
+/// countries.retain(|country| country.code != "LB");
+/// let update = Metadata::new(bundled.version() + 1, countries);
+///
+/// let diff = diff_metadata(&bundled, &update);
+/// assert!(matches!(diff.changes[..], [MetadataChange::CountryRemoved { code: "LB", .. }]));
+/// ```
+pub fn diff_metadata(a: &Metadata, b: &Metadata) -> MetadataDiff {
+    let mut ids: Vec<u16> = a
+        .countries()
+        .iter()
+        .chain(b.countries())
+        .map(|country| country.id)
+        .collect();
+    ids.sort_unstable();
+    ids.dedup();
+
+    let mut changes = Vec::new();
+    for id in ids {
+        match (a.country(id), b.country(id)) {
+            (None, Some(_)) => changes.push(MetadataChange::CountryAdded { id }),
+            (Some(before), None) => changes.push(MetadataChange::CountryRemoved {
+                id,
+                code: before.code,
+                prefix: before.prefix,
+                phone_lengths: before.phone_lengths,
+            }),
+            (Some(before), Some(after)) => {
+                if before.prefix != after.prefix {
+                    changes.push(MetadataChange::PrefixChanged {
+                        id,
+                        before: before.prefix,
+                        after: after.prefix,
+                    });
+                }
+                if before.phone_lengths != after.phone_lengths {
+                    changes.push(MetadataChange::LengthsChanged {
+                        id,
+                        before: before.phone_lengths,
+                        after: after.phone_lengths,
+                    });
+                }
+                changes.extend(type_lengths_changes(
+                    id,
+                    &a.type_lengths_of(before),
+                    &b.type_lengths_of(after),
+                ));
+                if a.area_codes(before) != b.area_codes(after) {
+                    changes.push(MetadataChange::AreaCodesChanged {
+                        id,
+                        before: a.area_codes(before),
+                        after: b.area_codes(after),
+                    });
+                }
+            }
+            (None, None) => {}
+        }
+    }
+
+    MetadataDiff {
+        from_version: a.version(),
+        to_version: b.version(),
+        changes,
+    }
+}
+
+// the changes of the lengths of the types of a country, in declaration order of the types
+fn type_lengths_changes(
+    id: u16,
+    before: &[(PhoneNumberType, RangeInclusive<u8>)],
+    after: &[(PhoneNumberType, RangeInclusive<u8>)],
+) -> Vec<MetadataChange> {
+    let lengths = |table: &[(PhoneNumberType, RangeInclusive<u8>)], number_type| {
+        table
+            .iter()
+            .find(|(type_, _)| *type_ == number_type)
+            .map(|(_, lengths)| (*lengths.start(), *lengths.end()))
+    };
+    let mut number_types: Vec<PhoneNumberType> = before
+        .iter()
+        .chain(after)
+        .map(|(number_type, _)| *number_type)
+        .collect();
+    number_types.sort_by_key(|number_type| *number_type as u8);
+    number_types.dedup();
+
+    number_types
+        .into_iter()
+        .filter(|number_type| lengths(before, *number_type) != lengths(after, *number_type))
+        .map(|number_type| MetadataChange::TypeLengthsChanged {
+            id,
+            number_type,
+            before: lengths(before, number_type),
+            after: lengths(after, number_type),
+        })
+        .collect()
+}
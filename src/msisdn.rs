@@ -0,0 +1,73 @@
+use crate::{extract_country_data, E164};
+
+/// Returns true if `msisdn` looks like an MSISDN as used by provisioning systems (HLR/HSS,
+/// SIM management): digits only, no `+` or `00` prefix, at most 15 digits and starting with
+/// a known country code.
+pub fn is_plausible_msisdn(msisdn: &str) -> bool {
+    !msisdn.is_empty()
+        && msisdn.len() <= 15
+        && !msisdn.starts_with('0')
+        && msisdn.bytes().all(|b| b.is_ascii_digit())
+        && extract_country_data(msisdn).is_some()
+}
+
+/// Converts an MSISDN (`"96179123123"`) to E.164 (`"+96179123123"`).
+pub fn msisdn_to_e164(msisdn: &str) -> Option<String> {
+    if !is_plausible_msisdn(msisdn) {
+        return None;
+    }
+
+    E164::parse(msisdn).map(String::from)
+}
+
+/// Converts any phone number accepted by [`crate::normalize_phone_number`] to an MSISDN,
+/// i.e. the E.164 digits without the leading `+`.
+pub fn e164_to_msisdn(phone_number: &str) -> Option<String> {
+    E164::parse(phone_number).map(|number| number[1..].to_string())
+}
+
+/// Returns true if `iccid` is a well formed SIM card number (ITU-T E.118): 19 or 20 digits,
+/// starting with the telecom industry identifier `89` and ending with a valid Luhn check
+/// digit.
+pub fn is_valid_iccid(iccid: &str) -> bool {
+    (19..=20).contains(&iccid.len())
+        && iccid.starts_with("89")
+        && iccid.bytes().all(|b| b.is_ascii_digit())
+        && luhn_checksum(iccid) == 0
+}
+
+/// Checks that the country code encoded in an ICCID matches the country of an MSISDN, which
+/// catches SIM cards provisioned against a number from the wrong market.
+///
+/// Returns `None` if either value is malformed.
+pub fn iccid_matches_msisdn(iccid: &str, msisdn: &str) -> Option<bool> {
+    if !is_valid_iccid(iccid) || !is_plausible_msisdn(msisdn) {
+        return None;
+    }
+
+    let country = extract_country_data(msisdn)?;
+    // ICCIDs carry the E.164 country code, which is "1" for every NANP member
+    let calling_code = match country.prefix_str() {
+        prefix if prefix.starts_with('1') => "1".to_string(),
+        prefix => prefix,
+    };
+
+    Some(iccid[2..].starts_with(&calling_code))
+}
+
+fn luhn_checksum(digits: &str) -> u32 {
+    digits
+        .bytes()
+        .rev()
+        .enumerate()
+        .map(|(index, b)| {
+            let digit = (b - b'0') as u32;
+            match index % 2 {
+                0 => digit,
+                _ if digit * 2 > 9 => digit * 2 - 9,
+                _ => digit * 2,
+            }
+        })
+        .sum::<u32>()
+        % 10
+}
@@ -0,0 +1,53 @@
+use crate::constants::NEIGHBORS;
+use crate::definitions::Country;
+use crate::{best_fitting_countries, find_country_by_code, E164};
+
+/// Returns the ISO 3166 codes of the neighbors of `region` (an ISO 3166 code), the countries
+/// it shares a land border or a close maritime border with, e.g. `["BE", "LU", "DE", ...]`
+/// for `"FR"`, in metadata order.
+pub fn neighbors_of(region: &str) -> Vec<&'static str> {
+    let Some(country) = find_country_by_code(region) else {
+        return Vec::new();
+    };
+    NEIGHBORS
+        .iter()
+        .filter_map(|(a, b)| match country.code {
+            code if code == *a => Some(*b),
+            code if code == *b => Some(*a),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the most likely country of a phone number in international form for a user of
+/// `hint_region`. When several countries share the calling code and area code and fit the
+/// number, the hinted country wins, then its neighbors, then the country
+/// [`crate::extract_country`] picks: `"+500 51234"` is from South Georgia for a user there
+/// but from the Falkland Islands for a user in Argentina. Numbers told apart by area code,
+/// like `"+7 701 234 5678"` from Kazakhstan, don't depend on the hint. Numbers not
+/// in international form are read as dialed from
+/// `hint_region`, see [`crate::normalize_phone_number_in_region`].
+pub fn likely_country(phone_number: &str, hint_region: &str) -> Option<&'static Country> {
+    let number = match find_country_by_code(hint_region) {
+        Some(hint) => E164::parse_dialed_from(phone_number, hint),
+        None => E164::parse(phone_number),
+    }?;
+    Some(likely_country_of(&number, hint_region))
+}
+
+pub(crate) fn likely_country_of(number: &E164, hint_region: &str) -> &'static Country {
+    let Some(hint) = find_country_by_code(hint_region) else {
+        return number.country();
+    };
+    let neighbors = neighbors_of(hint.code);
+    let rank = |country: &Country| match country.code {
+        code if code == hint.code => 0,
+        code if neighbors.contains(&code) => 1,
+        _ => 2,
+    };
+
+    best_fitting_countries(&number[1..])
+        .into_iter()
+        .min_by_key(|country| rank(country))
+        .unwrap_or(number.country())
+}
@@ -0,0 +1,1405 @@
+use std::ops::RangeInclusive;
+
+#[cfg(feature = "compact-metadata")]
+use crate::compact::{Packed, Packer, Reader, MAX_CODES, MAX_LEN};
+use crate::metadata::BUNDLED;
+use crate::{calling_code, stats, Metadata, E164};
+
+/// Kind of service a phone number belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PhoneNumberType {
+    Mobile,
+    FixedLine,
+    /// Mobile and fixed-line numbers share the same ranges (e.g. the NANP).
+    FixedLineOrMobile,
+    TollFree,
+    PremiumRate,
+    /// The cost of the call is shared between the caller and the recipient.
+    SharedCost,
+    Voip,
+    PersonalNumber,
+    Pager,
+    /// Universal access number, a single number routed to several locations.
+    Uan,
+    /// A number registered as a test number, see [`crate::TestNumberRegistry`]. Only
+    /// reported by [`crate::Parser::number_type`].
+    TestNumber,
+    /// An emergency number such as `112`, see [`crate::is_emergency_number`]. Only reported
+    /// for short codes, which have no country code.
+    Emergency,
+    /// A carrier or service short code such as `611`, see [`crate::is_short_code`].
+    ShortCode,
+    Unknown,
+}
+
+impl PhoneNumberType {
+    /// Returns the name of the type in snake case, e.g. `"fixed_line_or_mobile"`, stable
+    /// across releases so it can be written to files and databases.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PhoneNumberType::Mobile => "mobile",
+            PhoneNumberType::FixedLine => "fixed_line",
+            PhoneNumberType::FixedLineOrMobile => "fixed_line_or_mobile",
+            PhoneNumberType::TollFree => "toll_free",
+            PhoneNumberType::PremiumRate => "premium_rate",
+            PhoneNumberType::SharedCost => "shared_cost",
+            PhoneNumberType::Voip => "voip",
+            PhoneNumberType::PersonalNumber => "personal_number",
+            PhoneNumberType::Pager => "pager",
+            PhoneNumberType::Uan => "uan",
+            PhoneNumberType::TestNumber => "test_number",
+            PhoneNumberType::Emergency => "emergency",
+            PhoneNumberType::ShortCode => "short_code",
+            PhoneNumberType::Unknown => "unknown",
+        }
+    }
+}
+
+// (calling code, national number prefix, type), the longest matching prefix wins
+pub(crate) const TYPE_RULES: &[(u32, &str, PhoneNumberType)] = &[
+    (1, "800", PhoneNumberType::TollFree),
+    (1, "833", PhoneNumberType::TollFree),
+    (1, "844", PhoneNumberType::TollFree),
+    (1, "855", PhoneNumberType::TollFree),
+    (1, "866", PhoneNumberType::TollFree),
+    (1, "877", PhoneNumberType::TollFree),
+    (1, "888", PhoneNumberType::TollFree),
+    (1, "900", PhoneNumberType::PremiumRate),
+    (1, "500", PhoneNumberType::PersonalNumber),
+    (1, "533", PhoneNumberType::PersonalNumber),
+    (1, "544", PhoneNumberType::PersonalNumber),
+    (1, "566", PhoneNumberType::PersonalNumber),
+    (1, "577", PhoneNumberType::PersonalNumber),
+    (1, "588", PhoneNumberType::PersonalNumber),
+    (1, "", PhoneNumberType::FixedLineOrMobile),
+    (7, "9", PhoneNumberType::Mobile),
+    (7, "3", PhoneNumberType::FixedLine),
+    (7, "4", PhoneNumberType::FixedLine),
+    (7, "8", PhoneNumberType::FixedLine),
+    (7, "800", PhoneNumberType::TollFree),
+    (7, "70", PhoneNumberType::Mobile),
+    (7, "77", PhoneNumberType::Mobile),
+    (7, "71", PhoneNumberType::FixedLine),
+    (7, "72", PhoneNumberType::FixedLine),
+    (20, "10", PhoneNumberType::Mobile),
+    (20, "11", PhoneNumberType::Mobile),
+    (20, "12", PhoneNumberType::Mobile),
+    (20, "15", PhoneNumberType::Mobile),
+    (20, "2", PhoneNumberType::FixedLine),
+    (20, "3", PhoneNumberType::FixedLine),
+    (20, "800", PhoneNumberType::TollFree),
+    (20, "900", PhoneNumberType::PremiumRate),
+    (27, "6", PhoneNumberType::Mobile),
+    (27, "7", PhoneNumberType::Mobile),
+    (27, "81", PhoneNumberType::Mobile),
+    (27, "82", PhoneNumberType::Mobile),
+    (27, "83", PhoneNumberType::Mobile),
+    (27, "84", PhoneNumberType::Mobile),
+    (27, "1", PhoneNumberType::FixedLine),
+    (27, "2", PhoneNumberType::FixedLine),
+    (27, "3", PhoneNumberType::FixedLine),
+    (27, "4", PhoneNumberType::FixedLine),
+    (27, "5", PhoneNumberType::FixedLine),
+    (27, "800", PhoneNumberType::TollFree),
+    (27, "86", PhoneNumberType::SharedCost),
+    (27, "87", PhoneNumberType::Voip),
+    (30, "69", PhoneNumberType::Mobile),
+    (30, "2", PhoneNumberType::FixedLine),
+    (30, "800", PhoneNumberType::TollFree),
+    (30, "90", PhoneNumberType::PremiumRate),
+    (31, "6", PhoneNumberType::Mobile),
+    (31, "1", PhoneNumberType::FixedLine),
+    (31, "2", PhoneNumberType::FixedLine),
+    (31, "3", PhoneNumberType::FixedLine),
+    (31, "4", PhoneNumberType::FixedLine),
+    (31, "5", PhoneNumberType::FixedLine),
+    (31, "7", PhoneNumberType::FixedLine),
+    (31, "800", PhoneNumberType::TollFree),
+    (31, "90", PhoneNumberType::PremiumRate),
+    (32, "46", PhoneNumberType::Mobile),
+    (32, "47", PhoneNumberType::Mobile),
+    (32, "48", PhoneNumberType::Mobile),
+    (32, "49", PhoneNumberType::Mobile),
+    (32, "1", PhoneNumberType::FixedLine),
+    (32, "2", PhoneNumberType::FixedLine),
+    (32, "3", PhoneNumberType::FixedLine),
+    (32, "4", PhoneNumberType::FixedLine),
+    (32, "5", PhoneNumberType::FixedLine),
+    (32, "6", PhoneNumberType::FixedLine),
+    (32, "8", PhoneNumberType::FixedLine),
+    (32, "9", PhoneNumberType::FixedLine),
+    (32, "800", PhoneNumberType::TollFree),
+    (32, "90", PhoneNumberType::PremiumRate),
+    (33, "1", PhoneNumberType::FixedLine),
+    (33, "2", PhoneNumberType::FixedLine),
+    (33, "3", PhoneNumberType::FixedLine),
+    (33, "4", PhoneNumberType::FixedLine),
+    (33, "5", PhoneNumberType::FixedLine),
+    (33, "6", PhoneNumberType::Mobile),
+    (33, "7", PhoneNumberType::Mobile),
+    (33, "80", PhoneNumberType::TollFree),
+    (33, "81", PhoneNumberType::SharedCost),
+    (33, "82", PhoneNumberType::SharedCost),
+    (33, "89", PhoneNumberType::PremiumRate),
+    (33, "9", PhoneNumberType::Voip),
+    (34, "6", PhoneNumberType::Mobile),
+    (34, "71", PhoneNumberType::Mobile),
+    (34, "72", PhoneNumberType::Mobile),
+    (34, "73", PhoneNumberType::Mobile),
+    (34, "74", PhoneNumberType::Mobile),
+    (34, "8", PhoneNumberType::FixedLine),
+    (34, "9", PhoneNumberType::FixedLine),
+    (34, "800", PhoneNumberType::TollFree),
+    (34, "900", PhoneNumberType::TollFree),
+    (34, "803", PhoneNumberType::PremiumRate),
+    (34, "806", PhoneNumberType::PremiumRate),
+    (34, "807", PhoneNumberType::PremiumRate),
+    (34, "901", PhoneNumberType::SharedCost),
+    (36, "20", PhoneNumberType::Mobile),
+    (36, "30", PhoneNumberType::Mobile),
+    (36, "31", PhoneNumberType::Mobile),
+    (36, "50", PhoneNumberType::Mobile),
+    (36, "70", PhoneNumberType::Mobile),
+    (36, "1", PhoneNumberType::FixedLine),
+    (36, "80", PhoneNumberType::TollFree),
+    (36, "90", PhoneNumberType::PremiumRate),
+    (39, "0", PhoneNumberType::FixedLine),
+    (39, "3", PhoneNumberType::Mobile),
+    (39, "800", PhoneNumberType::TollFree),
+    (39, "803", PhoneNumberType::TollFree),
+    (39, "89", PhoneNumberType::PremiumRate),
+    (40, "7", PhoneNumberType::Mobile),
+    (40, "2", PhoneNumberType::FixedLine),
+    (40, "3", PhoneNumberType::FixedLine),
+    (40, "800", PhoneNumberType::TollFree),
+    (40, "90", PhoneNumberType::PremiumRate),
+    (41, "75", PhoneNumberType::Mobile),
+    (41, "76", PhoneNumberType::Mobile),
+    (41, "77", PhoneNumberType::Mobile),
+    (41, "78", PhoneNumberType::Mobile),
+    (41, "79", PhoneNumberType::Mobile),
+    (41, "2", PhoneNumberType::FixedLine),
+    (41, "3", PhoneNumberType::FixedLine),
+    (41, "4", PhoneNumberType::FixedLine),
+    (41, "5", PhoneNumberType::FixedLine),
+    (41, "6", PhoneNumberType::FixedLine),
+    (41, "71", PhoneNumberType::FixedLine),
+    (41, "81", PhoneNumberType::FixedLine),
+    (41, "91", PhoneNumberType::FixedLine),
+    (41, "800", PhoneNumberType::TollFree),
+    (41, "90", PhoneNumberType::PremiumRate),
+    (43, "6", PhoneNumberType::Mobile),
+    (43, "1", PhoneNumberType::FixedLine),
+    (43, "2", PhoneNumberType::FixedLine),
+    (43, "3", PhoneNumberType::FixedLine),
+    (43, "4", PhoneNumberType::FixedLine),
+    (43, "5", PhoneNumberType::FixedLine),
+    (43, "7", PhoneNumberType::FixedLine),
+    (43, "800", PhoneNumberType::TollFree),
+    (43, "900", PhoneNumberType::PremiumRate),
+    (43, "930", PhoneNumberType::PremiumRate),
+    (44, "1", PhoneNumberType::FixedLine),
+    (44, "2", PhoneNumberType::FixedLine),
+    (44, "3", PhoneNumberType::Uan),
+    (44, "55", PhoneNumberType::Uan),
+    (44, "56", PhoneNumberType::Voip),
+    (44, "70", PhoneNumberType::PersonalNumber),
+    (44, "7", PhoneNumberType::Mobile),
+    (44, "76", PhoneNumberType::Pager),
+    (44, "800", PhoneNumberType::TollFree),
+    (44, "808", PhoneNumberType::TollFree),
+    (44, "84", PhoneNumberType::SharedCost),
+    (44, "87", PhoneNumberType::SharedCost),
+    (44, "9", PhoneNumberType::PremiumRate),
+    (45, "2", PhoneNumberType::Mobile),
+    (45, "30", PhoneNumberType::Mobile),
+    (45, "31", PhoneNumberType::Mobile),
+    (45, "40", PhoneNumberType::Mobile),
+    (45, "41", PhoneNumberType::Mobile),
+    (45, "42", PhoneNumberType::Mobile),
+    (45, "50", PhoneNumberType::Mobile),
+    (45, "51", PhoneNumberType::Mobile),
+    (45, "52", PhoneNumberType::Mobile),
+    (45, "53", PhoneNumberType::Mobile),
+    (45, "60", PhoneNumberType::Mobile),
+    (45, "61", PhoneNumberType::Mobile),
+    (45, "71", PhoneNumberType::Mobile),
+    (45, "81", PhoneNumberType::Mobile),
+    (45, "91", PhoneNumberType::Mobile),
+    (45, "92", PhoneNumberType::Mobile),
+    (45, "93", PhoneNumberType::Mobile),
+    (45, "80", PhoneNumberType::TollFree),
+    (45, "90", PhoneNumberType::PremiumRate),
+    (46, "70", PhoneNumberType::Mobile),
+    (46, "72", PhoneNumberType::Mobile),
+    (46, "73", PhoneNumberType::Mobile),
+    (46, "76", PhoneNumberType::Mobile),
+    (46, "79", PhoneNumberType::Mobile),
+    (46, "1", PhoneNumberType::FixedLine),
+    (46, "3", PhoneNumberType::FixedLine),
+    (46, "4", PhoneNumberType::FixedLine),
+    (46, "5", PhoneNumberType::FixedLine),
+    (46, "6", PhoneNumberType::FixedLine),
+    (46, "8", PhoneNumberType::FixedLine),
+    (46, "9", PhoneNumberType::FixedLine),
+    (46, "20", PhoneNumberType::TollFree),
+    (46, "900", PhoneNumberType::PremiumRate),
+    (47, "4", PhoneNumberType::Mobile),
+    (47, "9", PhoneNumberType::Mobile),
+    (47, "2", PhoneNumberType::FixedLine),
+    (47, "3", PhoneNumberType::FixedLine),
+    (47, "5", PhoneNumberType::FixedLine),
+    (47, "6", PhoneNumberType::FixedLine),
+    (47, "7", PhoneNumberType::FixedLine),
+    (47, "800", PhoneNumberType::TollFree),
+    (47, "82", PhoneNumberType::PremiumRate),
+    (48, "45", PhoneNumberType::Mobile),
+    (48, "50", PhoneNumberType::Mobile),
+    (48, "51", PhoneNumberType::Mobile),
+    (48, "53", PhoneNumberType::Mobile),
+    (48, "57", PhoneNumberType::Mobile),
+    (48, "60", PhoneNumberType::Mobile),
+    (48, "66", PhoneNumberType::Mobile),
+    (48, "69", PhoneNumberType::Mobile),
+    (48, "72", PhoneNumberType::Mobile),
+    (48, "73", PhoneNumberType::Mobile),
+    (48, "78", PhoneNumberType::Mobile),
+    (48, "79", PhoneNumberType::Mobile),
+    (48, "88", PhoneNumberType::Mobile),
+    (48, "800", PhoneNumberType::TollFree),
+    (48, "70", PhoneNumberType::PremiumRate),
+    (49, "15", PhoneNumberType::Mobile),
+    (49, "16", PhoneNumberType::Mobile),
+    (49, "17", PhoneNumberType::Mobile),
+    (49, "137", PhoneNumberType::PremiumRate),
+    (49, "180", PhoneNumberType::SharedCost),
+    (49, "2", PhoneNumberType::FixedLine),
+    (49, "3", PhoneNumberType::FixedLine),
+    (49, "4", PhoneNumberType::FixedLine),
+    (49, "5", PhoneNumberType::FixedLine),
+    (49, "6", PhoneNumberType::FixedLine),
+    (49, "700", PhoneNumberType::PersonalNumber),
+    (49, "7", PhoneNumberType::FixedLine),
+    (49, "800", PhoneNumberType::TollFree),
+    (49, "8", PhoneNumberType::FixedLine),
+    (49, "900", PhoneNumberType::PremiumRate),
+    (49, "9", PhoneNumberType::FixedLine),
+    (51, "9", PhoneNumberType::Mobile),
+    (51, "1", PhoneNumberType::FixedLine),
+    (51, "800", PhoneNumberType::TollFree),
+    (52, "", PhoneNumberType::FixedLineOrMobile),
+    (52, "800", PhoneNumberType::TollFree),
+    (52, "900", PhoneNumberType::PremiumRate),
+    (53, "5", PhoneNumberType::Mobile),
+    (53, "7", PhoneNumberType::FixedLine),
+    (54, "9", PhoneNumberType::Mobile),
+    (54, "11", PhoneNumberType::FixedLine),
+    (54, "2", PhoneNumberType::FixedLine),
+    (54, "3", PhoneNumberType::FixedLine),
+    (54, "800", PhoneNumberType::TollFree),
+    (55, "800", PhoneNumberType::TollFree),
+    (56, "9", PhoneNumberType::Mobile),
+    (56, "2", PhoneNumberType::FixedLine),
+    (56, "800", PhoneNumberType::TollFree),
+    (57, "3", PhoneNumberType::Mobile),
+    (57, "60", PhoneNumberType::FixedLine),
+    (57, "1800", PhoneNumberType::TollFree),
+    (58, "4", PhoneNumberType::Mobile),
+    (58, "2", PhoneNumberType::FixedLine),
+    (58, "800", PhoneNumberType::TollFree),
+    (60, "1", PhoneNumberType::Mobile),
+    (60, "1300", PhoneNumberType::SharedCost),
+    (60, "1800", PhoneNumberType::TollFree),
+    (60, "3", PhoneNumberType::FixedLine),
+    (60, "4", PhoneNumberType::FixedLine),
+    (60, "5", PhoneNumberType::FixedLine),
+    (60, "6", PhoneNumberType::FixedLine),
+    (60, "7", PhoneNumberType::FixedLine),
+    (60, "8", PhoneNumberType::FixedLine),
+    (60, "9", PhoneNumberType::FixedLine),
+    (61, "2", PhoneNumberType::FixedLine),
+    (61, "3", PhoneNumberType::FixedLine),
+    (61, "4", PhoneNumberType::Mobile),
+    (61, "7", PhoneNumberType::FixedLine),
+    (61, "8", PhoneNumberType::FixedLine),
+    (61, "13", PhoneNumberType::SharedCost),
+    (61, "1800", PhoneNumberType::TollFree),
+    (61, "190", PhoneNumberType::PremiumRate),
+    (62, "8", PhoneNumberType::Mobile),
+    (62, "2", PhoneNumberType::FixedLine),
+    (62, "3", PhoneNumberType::FixedLine),
+    (62, "4", PhoneNumberType::FixedLine),
+    (62, "5", PhoneNumberType::FixedLine),
+    (62, "6", PhoneNumberType::FixedLine),
+    (62, "7", PhoneNumberType::FixedLine),
+    (62, "9", PhoneNumberType::FixedLine),
+    (62, "800", PhoneNumberType::TollFree),
+    (63, "9", PhoneNumberType::Mobile),
+    (63, "2", PhoneNumberType::FixedLine),
+    (63, "1800", PhoneNumberType::TollFree),
+    (64, "2", PhoneNumberType::Mobile),
+    (64, "3", PhoneNumberType::FixedLine),
+    (64, "4", PhoneNumberType::FixedLine),
+    (64, "6", PhoneNumberType::FixedLine),
+    (64, "7", PhoneNumberType::FixedLine),
+    (64, "9", PhoneNumberType::FixedLine),
+    (64, "800", PhoneNumberType::TollFree),
+    (64, "508", PhoneNumberType::TollFree),
+    (64, "900", PhoneNumberType::PremiumRate),
+    (65, "8", PhoneNumberType::Mobile),
+    (65, "9", PhoneNumberType::Mobile),
+    (65, "6", PhoneNumberType::FixedLine),
+    (65, "3", PhoneNumberType::Voip),
+    (65, "800", PhoneNumberType::TollFree),
+    (65, "1800", PhoneNumberType::TollFree),
+    (65, "1900", PhoneNumberType::PremiumRate),
+    (66, "6", PhoneNumberType::Mobile),
+    (66, "8", PhoneNumberType::Mobile),
+    (66, "9", PhoneNumberType::Mobile),
+    (66, "2", PhoneNumberType::FixedLine),
+    (66, "1800", PhoneNumberType::TollFree),
+    (81, "70", PhoneNumberType::Mobile),
+    (81, "80", PhoneNumberType::Mobile),
+    (81, "90", PhoneNumberType::Mobile),
+    (81, "1", PhoneNumberType::FixedLine),
+    (81, "2", PhoneNumberType::FixedLine),
+    (81, "3", PhoneNumberType::FixedLine),
+    (81, "4", PhoneNumberType::FixedLine),
+    (81, "5", PhoneNumberType::FixedLine),
+    (81, "6", PhoneNumberType::FixedLine),
+    (81, "7", PhoneNumberType::FixedLine),
+    (81, "8", PhoneNumberType::FixedLine),
+    (81, "9", PhoneNumberType::FixedLine),
+    (81, "50", PhoneNumberType::Voip),
+    (81, "120", PhoneNumberType::TollFree),
+    (81, "800", PhoneNumberType::TollFree),
+    (82, "10", PhoneNumberType::Mobile),
+    (82, "2", PhoneNumberType::FixedLine),
+    (82, "3", PhoneNumberType::FixedLine),
+    (82, "4", PhoneNumberType::FixedLine),
+    (82, "5", PhoneNumberType::FixedLine),
+    (82, "6", PhoneNumberType::FixedLine),
+    (82, "70", PhoneNumberType::Voip),
+    (82, "80", PhoneNumberType::TollFree),
+    (84, "3", PhoneNumberType::Mobile),
+    (84, "5", PhoneNumberType::Mobile),
+    (84, "7", PhoneNumberType::Mobile),
+    (84, "8", PhoneNumberType::Mobile),
+    (84, "9", PhoneNumberType::Mobile),
+    (84, "2", PhoneNumberType::FixedLine),
+    (84, "1800", PhoneNumberType::TollFree),
+    (84, "1900", PhoneNumberType::PremiumRate),
+    (86, "13", PhoneNumberType::Mobile),
+    (86, "14", PhoneNumberType::Mobile),
+    (86, "15", PhoneNumberType::Mobile),
+    (86, "16", PhoneNumberType::Mobile),
+    (86, "17", PhoneNumberType::Mobile),
+    (86, "18", PhoneNumberType::Mobile),
+    (86, "19", PhoneNumberType::Mobile),
+    (86, "10", PhoneNumberType::FixedLine),
+    (86, "2", PhoneNumberType::FixedLine),
+    (86, "3", PhoneNumberType::FixedLine),
+    (86, "4", PhoneNumberType::FixedLine),
+    (86, "5", PhoneNumberType::FixedLine),
+    (86, "6", PhoneNumberType::FixedLine),
+    (86, "7", PhoneNumberType::FixedLine),
+    (86, "8", PhoneNumberType::FixedLine),
+    (86, "9", PhoneNumberType::FixedLine),
+    (86, "800", PhoneNumberType::TollFree),
+    (90, "50", PhoneNumberType::Mobile),
+    (90, "53", PhoneNumberType::Mobile),
+    (90, "54", PhoneNumberType::Mobile),
+    (90, "55", PhoneNumberType::Mobile),
+    (90, "2", PhoneNumberType::FixedLine),
+    (90, "3", PhoneNumberType::FixedLine),
+    (90, "4", PhoneNumberType::FixedLine),
+    (90, "800", PhoneNumberType::TollFree),
+    (90, "900", PhoneNumberType::PremiumRate),
+    (91, "1800", PhoneNumberType::TollFree),
+    (91, "2", PhoneNumberType::FixedLine),
+    (91, "3", PhoneNumberType::FixedLine),
+    (91, "4", PhoneNumberType::FixedLine),
+    (91, "5", PhoneNumberType::FixedLine),
+    (91, "6", PhoneNumberType::Mobile),
+    (91, "7", PhoneNumberType::Mobile),
+    (91, "8", PhoneNumberType::Mobile),
+    (91, "9", PhoneNumberType::Mobile),
+    (92, "3", PhoneNumberType::Mobile),
+    (92, "2", PhoneNumberType::FixedLine),
+    (92, "4", PhoneNumberType::FixedLine),
+    (92, "5", PhoneNumberType::FixedLine),
+    (92, "6", PhoneNumberType::FixedLine),
+    (92, "8", PhoneNumberType::FixedLine),
+    (92, "9", PhoneNumberType::FixedLine),
+    (92, "800", PhoneNumberType::TollFree),
+    (92, "900", PhoneNumberType::PremiumRate),
+    (93, "7", PhoneNumberType::Mobile),
+    (94, "7", PhoneNumberType::Mobile),
+    (94, "1", PhoneNumberType::FixedLine),
+    (94, "2", PhoneNumberType::FixedLine),
+    (94, "3", PhoneNumberType::FixedLine),
+    (94, "4", PhoneNumberType::FixedLine),
+    (94, "5", PhoneNumberType::FixedLine),
+    (94, "6", PhoneNumberType::FixedLine),
+    (94, "8", PhoneNumberType::FixedLine),
+    (94, "9", PhoneNumberType::FixedLine),
+    (95, "9", PhoneNumberType::Mobile),
+    (98, "9", PhoneNumberType::Mobile),
+    (98, "1", PhoneNumberType::FixedLine),
+    (98, "2", PhoneNumberType::FixedLine),
+    (98, "3", PhoneNumberType::FixedLine),
+    (98, "4", PhoneNumberType::FixedLine),
+    (98, "5", PhoneNumberType::FixedLine),
+    (98, "6", PhoneNumberType::FixedLine),
+    (98, "7", PhoneNumberType::FixedLine),
+    (98, "8", PhoneNumberType::FixedLine),
+    (211, "9", PhoneNumberType::Mobile),
+    (211, "18", PhoneNumberType::FixedLine),
+    (212, "6", PhoneNumberType::Mobile),
+    (212, "7", PhoneNumberType::Mobile),
+    (212, "5", PhoneNumberType::FixedLine),
+    (212, "800", PhoneNumberType::TollFree),
+    (213, "5", PhoneNumberType::Mobile),
+    (213, "6", PhoneNumberType::Mobile),
+    (213, "7", PhoneNumberType::Mobile),
+    (213, "2", PhoneNumberType::FixedLine),
+    (213, "3", PhoneNumberType::FixedLine),
+    (213, "4", PhoneNumberType::FixedLine),
+    (213, "800", PhoneNumberType::TollFree),
+    (216, "2", PhoneNumberType::Mobile),
+    (216, "4", PhoneNumberType::Mobile),
+    (216, "5", PhoneNumberType::Mobile),
+    (216, "9", PhoneNumberType::Mobile),
+    (216, "3", PhoneNumberType::FixedLine),
+    (216, "7", PhoneNumberType::FixedLine),
+    (216, "80", PhoneNumberType::TollFree),
+    (218, "9", PhoneNumberType::Mobile),
+    (220, "2", PhoneNumberType::Mobile),
+    (220, "3", PhoneNumberType::Mobile),
+    (220, "6", PhoneNumberType::Mobile),
+    (220, "7", PhoneNumberType::Mobile),
+    (220, "9", PhoneNumberType::Mobile),
+    (220, "4", PhoneNumberType::FixedLine),
+    (220, "5", PhoneNumberType::FixedLine),
+    (221, "7", PhoneNumberType::Mobile),
+    (221, "3", PhoneNumberType::FixedLine),
+    (222, "2", PhoneNumberType::Mobile),
+    (222, "3", PhoneNumberType::Mobile),
+    (222, "4", PhoneNumberType::Mobile),
+    (222, "25", PhoneNumberType::FixedLine),
+    (222, "35", PhoneNumberType::FixedLine),
+    (222, "45", PhoneNumberType::FixedLine),
+    (222, "800", PhoneNumberType::TollFree),
+    (223, "5", PhoneNumberType::Mobile),
+    (223, "6", PhoneNumberType::Mobile),
+    (223, "7", PhoneNumberType::Mobile),
+    (223, "8", PhoneNumberType::Mobile),
+    (223, "9", PhoneNumberType::Mobile),
+    (223, "2", PhoneNumberType::FixedLine),
+    (223, "4", PhoneNumberType::FixedLine),
+    (223, "800", PhoneNumberType::TollFree),
+    (224, "6", PhoneNumberType::Mobile),
+    (224, "3", PhoneNumberType::FixedLine),
+    (225, "4", PhoneNumberType::Mobile),
+    (225, "5", PhoneNumberType::Mobile),
+    (225, "6", PhoneNumberType::Mobile),
+    (225, "7", PhoneNumberType::Mobile),
+    (225, "2", PhoneNumberType::FixedLine),
+    (225, "3", PhoneNumberType::FixedLine),
+    (226, "5", PhoneNumberType::Mobile),
+    (226, "6", PhoneNumberType::Mobile),
+    (226, "7", PhoneNumberType::Mobile),
+    (226, "2", PhoneNumberType::FixedLine),
+    (227, "8", PhoneNumberType::Mobile),
+    (227, "9", PhoneNumberType::Mobile),
+    (227, "2", PhoneNumberType::FixedLine),
+    (228, "7", PhoneNumberType::Mobile),
+    (228, "9", PhoneNumberType::Mobile),
+    (228, "2", PhoneNumberType::FixedLine),
+    (229, "4", PhoneNumberType::Mobile),
+    (229, "5", PhoneNumberType::Mobile),
+    (229, "6", PhoneNumberType::Mobile),
+    (229, "9", PhoneNumberType::Mobile),
+    (229, "2", PhoneNumberType::FixedLine),
+    (230, "5", PhoneNumberType::Mobile),
+    (230, "2", PhoneNumberType::FixedLine),
+    (230, "4", PhoneNumberType::FixedLine),
+    (230, "8", PhoneNumberType::FixedLine),
+    (230, "800", PhoneNumberType::TollFree),
+    (231, "5", PhoneNumberType::Mobile),
+    (231, "7", PhoneNumberType::Mobile),
+    (231, "8", PhoneNumberType::Mobile),
+    (231, "2", PhoneNumberType::FixedLine),
+    (232, "2", PhoneNumberType::Mobile),
+    (232, "3", PhoneNumberType::Mobile),
+    (232, "4", PhoneNumberType::Mobile),
+    (232, "5", PhoneNumberType::Mobile),
+    (232, "7", PhoneNumberType::Mobile),
+    (232, "8", PhoneNumberType::Mobile),
+    (232, "9", PhoneNumberType::Mobile),
+    (232, "22", PhoneNumberType::FixedLine),
+    (233, "2", PhoneNumberType::Mobile),
+    (233, "5", PhoneNumberType::Mobile),
+    (233, "3", PhoneNumberType::FixedLine),
+    (234, "70", PhoneNumberType::Mobile),
+    (234, "80", PhoneNumberType::Mobile),
+    (234, "81", PhoneNumberType::Mobile),
+    (234, "90", PhoneNumberType::Mobile),
+    (234, "91", PhoneNumberType::Mobile),
+    (234, "1", PhoneNumberType::FixedLine),
+    (234, "800", PhoneNumberType::TollFree),
+    (235, "6", PhoneNumberType::Mobile),
+    (235, "7", PhoneNumberType::Mobile),
+    (235, "9", PhoneNumberType::Mobile),
+    (235, "2", PhoneNumberType::FixedLine),
+    (236, "7", PhoneNumberType::Mobile),
+    (236, "2", PhoneNumberType::FixedLine),
+    (237, "6", PhoneNumberType::Mobile),
+    (237, "2", PhoneNumberType::FixedLine),
+    (238, "5", PhoneNumberType::Mobile),
+    (238, "9", PhoneNumberType::Mobile),
+    (238, "2", PhoneNumberType::FixedLine),
+    (238, "800", PhoneNumberType::TollFree),
+    (239, "9", PhoneNumberType::Mobile),
+    (239, "2", PhoneNumberType::FixedLine),
+    (240, "222", PhoneNumberType::Mobile),
+    (240, "55", PhoneNumberType::Mobile),
+    (240, "3", PhoneNumberType::FixedLine),
+    (241, "6", PhoneNumberType::Mobile),
+    (241, "7", PhoneNumberType::Mobile),
+    (241, "1", PhoneNumberType::FixedLine),
+    (242, "2", PhoneNumberType::FixedLine),
+    (243, "8", PhoneNumberType::Mobile),
+    (243, "9", PhoneNumberType::Mobile),
+    (244, "9", PhoneNumberType::Mobile),
+    (244, "2", PhoneNumberType::FixedLine),
+    (245, "9", PhoneNumberType::Mobile),
+    (245, "4", PhoneNumberType::FixedLine),
+    (246, "38", PhoneNumberType::Mobile),
+    (246, "37", PhoneNumberType::FixedLine),
+    (247, "4", PhoneNumberType::Mobile),
+    (247, "6", PhoneNumberType::FixedLine),
+    (248, "2", PhoneNumberType::Mobile),
+    (248, "4", PhoneNumberType::FixedLine),
+    (248, "800", PhoneNumberType::TollFree),
+    (249, "9", PhoneNumberType::Mobile),
+    (250, "7", PhoneNumberType::Mobile),
+    (251, "7", PhoneNumberType::Mobile),
+    (251, "9", PhoneNumberType::Mobile),
+    (251, "1", PhoneNumberType::FixedLine),
+    (252, "6", PhoneNumberType::Mobile),
+    (252, "7", PhoneNumberType::Mobile),
+    (252, "9", PhoneNumberType::Mobile),
+    (252, "1", PhoneNumberType::FixedLine),
+    (252, "4", PhoneNumberType::FixedLine),
+    (252, "5", PhoneNumberType::FixedLine),
+    (253, "7", PhoneNumberType::Mobile),
+    (253, "2", PhoneNumberType::FixedLine),
+    (254, "7", PhoneNumberType::Mobile),
+    (254, "1", PhoneNumberType::Mobile),
+    (254, "20", PhoneNumberType::FixedLine),
+    (254, "800", PhoneNumberType::TollFree),
+    (255, "6", PhoneNumberType::Mobile),
+    (255, "7", PhoneNumberType::Mobile),
+    (255, "2", PhoneNumberType::FixedLine),
+    (256, "7", PhoneNumberType::Mobile),
+    (256, "4", PhoneNumberType::FixedLine),
+    (256, "800", PhoneNumberType::TollFree),
+    (257, "6", PhoneNumberType::Mobile),
+    (257, "7", PhoneNumberType::Mobile),
+    (257, "2", PhoneNumberType::FixedLine),
+    (258, "8", PhoneNumberType::Mobile),
+    (258, "2", PhoneNumberType::FixedLine),
+    (258, "800", PhoneNumberType::TollFree),
+    (260, "7", PhoneNumberType::Mobile),
+    (260, "9", PhoneNumberType::Mobile),
+    (260, "2", PhoneNumberType::FixedLine),
+    (261, "3", PhoneNumberType::Mobile),
+    (261, "2", PhoneNumberType::FixedLine),
+    (262, "692", PhoneNumberType::Mobile),
+    (262, "693", PhoneNumberType::Mobile),
+    (262, "639", PhoneNumberType::Mobile),
+    (262, "262", PhoneNumberType::FixedLine),
+    (262, "263", PhoneNumberType::FixedLine),
+    (262, "269", PhoneNumberType::FixedLine),
+    (263, "7", PhoneNumberType::Mobile),
+    (264, "8", PhoneNumberType::Mobile),
+    (264, "6", PhoneNumberType::FixedLine),
+    (264, "800", PhoneNumberType::TollFree),
+    (265, "8", PhoneNumberType::Mobile),
+    (265, "9", PhoneNumberType::Mobile),
+    (265, "1", PhoneNumberType::FixedLine),
+    (266, "5", PhoneNumberType::Mobile),
+    (266, "6", PhoneNumberType::Mobile),
+    (266, "2", PhoneNumberType::FixedLine),
+    (266, "800", PhoneNumberType::TollFree),
+    (267, "7", PhoneNumberType::Mobile),
+    (267, "2", PhoneNumberType::FixedLine),
+    (267, "3", PhoneNumberType::FixedLine),
+    (267, "4", PhoneNumberType::FixedLine),
+    (267, "5", PhoneNumberType::FixedLine),
+    (267, "6", PhoneNumberType::FixedLine),
+    (268, "7", PhoneNumberType::Mobile),
+    (268, "2", PhoneNumberType::FixedLine),
+    (268, "800", PhoneNumberType::TollFree),
+    (269, "3", PhoneNumberType::Mobile),
+    (269, "4", PhoneNumberType::Mobile),
+    (269, "7", PhoneNumberType::FixedLine),
+    (290, "5", PhoneNumberType::Mobile),
+    (290, "6", PhoneNumberType::Mobile),
+    (290, "2", PhoneNumberType::FixedLine),
+    (291, "7", PhoneNumberType::Mobile),
+    (291, "17", PhoneNumberType::Mobile),
+    (291, "1", PhoneNumberType::FixedLine),
+    (291, "8", PhoneNumberType::FixedLine),
+    (297, "5", PhoneNumberType::Mobile),
+    (297, "6", PhoneNumberType::Mobile),
+    (297, "7", PhoneNumberType::Mobile),
+    (297, "9", PhoneNumberType::Mobile),
+    (297, "52", PhoneNumberType::FixedLine),
+    (297, "58", PhoneNumberType::FixedLine),
+    (297, "800", PhoneNumberType::TollFree),
+    (297, "900", PhoneNumberType::PremiumRate),
+    (298, "2", PhoneNumberType::Mobile),
+    (298, "5", PhoneNumberType::Mobile),
+    (298, "7", PhoneNumberType::Mobile),
+    (298, "20", PhoneNumberType::FixedLine),
+    (298, "3", PhoneNumberType::FixedLine),
+    (298, "4", PhoneNumberType::FixedLine),
+    (298, "8", PhoneNumberType::FixedLine),
+    (298, "80", PhoneNumberType::TollFree),
+    (298, "90", PhoneNumberType::PremiumRate),
+    (299, "2", PhoneNumberType::Mobile),
+    (299, "4", PhoneNumberType::Mobile),
+    (299, "5", PhoneNumberType::Mobile),
+    (299, "3", PhoneNumberType::FixedLine),
+    (299, "6", PhoneNumberType::FixedLine),
+    (299, "8", PhoneNumberType::FixedLine),
+    (299, "9", PhoneNumberType::FixedLine),
+    (299, "80", PhoneNumberType::TollFree),
+    (350, "5", PhoneNumberType::Mobile),
+    (350, "6", PhoneNumberType::Mobile),
+    (350, "2", PhoneNumberType::FixedLine),
+    (350, "80", PhoneNumberType::TollFree),
+    (350, "8", PhoneNumberType::PremiumRate),
+    (351, "91", PhoneNumberType::Mobile),
+    (351, "92", PhoneNumberType::Mobile),
+    (351, "93", PhoneNumberType::Mobile),
+    (351, "96", PhoneNumberType::Mobile),
+    (351, "2", PhoneNumberType::FixedLine),
+    (351, "800", PhoneNumberType::TollFree),
+    (352, "6", PhoneNumberType::Mobile),
+    (352, "2", PhoneNumberType::FixedLine),
+    (352, "3", PhoneNumberType::FixedLine),
+    (352, "4", PhoneNumberType::FixedLine),
+    (352, "5", PhoneNumberType::FixedLine),
+    (352, "7", PhoneNumberType::FixedLine),
+    (352, "8", PhoneNumberType::FixedLine),
+    (352, "9", PhoneNumberType::FixedLine),
+    (352, "800", PhoneNumberType::TollFree),
+    (352, "90", PhoneNumberType::PremiumRate),
+    (353, "83", PhoneNumberType::Mobile),
+    (353, "85", PhoneNumberType::Mobile),
+    (353, "86", PhoneNumberType::Mobile),
+    (353, "87", PhoneNumberType::Mobile),
+    (353, "89", PhoneNumberType::Mobile),
+    (353, "1", PhoneNumberType::FixedLine),
+    (353, "2", PhoneNumberType::FixedLine),
+    (353, "4", PhoneNumberType::FixedLine),
+    (353, "5", PhoneNumberType::FixedLine),
+    (353, "6", PhoneNumberType::FixedLine),
+    (353, "7", PhoneNumberType::FixedLine),
+    (353, "9", PhoneNumberType::FixedLine),
+    (353, "1800", PhoneNumberType::TollFree),
+    (353, "15", PhoneNumberType::PremiumRate),
+    (354, "6", PhoneNumberType::Mobile),
+    (354, "7", PhoneNumberType::Mobile),
+    (354, "8", PhoneNumberType::Mobile),
+    (354, "4", PhoneNumberType::FixedLine),
+    (354, "5", PhoneNumberType::FixedLine),
+    (354, "800", PhoneNumberType::TollFree),
+    (354, "90", PhoneNumberType::PremiumRate),
+    (355, "6", PhoneNumberType::Mobile),
+    (355, "2", PhoneNumberType::FixedLine),
+    (355, "3", PhoneNumberType::FixedLine),
+    (355, "4", PhoneNumberType::FixedLine),
+    (355, "5", PhoneNumberType::FixedLine),
+    (355, "8", PhoneNumberType::FixedLine),
+    (355, "800", PhoneNumberType::TollFree),
+    (355, "900", PhoneNumberType::PremiumRate),
+    (356, "77", PhoneNumberType::Mobile),
+    (356, "79", PhoneNumberType::Mobile),
+    (356, "99", PhoneNumberType::Mobile),
+    (356, "2", PhoneNumberType::FixedLine),
+    (357, "9", PhoneNumberType::Mobile),
+    (357, "2", PhoneNumberType::FixedLine),
+    (357, "800", PhoneNumberType::TollFree),
+    (357, "90", PhoneNumberType::PremiumRate),
+    (358, "4", PhoneNumberType::Mobile),
+    (358, "50", PhoneNumberType::Mobile),
+    (358, "1", PhoneNumberType::FixedLine),
+    (358, "2", PhoneNumberType::FixedLine),
+    (358, "3", PhoneNumberType::FixedLine),
+    (358, "5", PhoneNumberType::FixedLine),
+    (358, "6", PhoneNumberType::FixedLine),
+    (358, "8", PhoneNumberType::FixedLine),
+    (358, "9", PhoneNumberType::FixedLine),
+    (358, "800", PhoneNumberType::TollFree),
+    (359, "87", PhoneNumberType::Mobile),
+    (359, "88", PhoneNumberType::Mobile),
+    (359, "89", PhoneNumberType::Mobile),
+    (359, "98", PhoneNumberType::Mobile),
+    (359, "2", PhoneNumberType::FixedLine),
+    (359, "800", PhoneNumberType::TollFree),
+    (359, "90", PhoneNumberType::PremiumRate),
+    (370, "6", PhoneNumberType::Mobile),
+    (370, "3", PhoneNumberType::FixedLine),
+    (370, "4", PhoneNumberType::FixedLine),
+    (370, "5", PhoneNumberType::FixedLine),
+    (370, "800", PhoneNumberType::TollFree),
+    (370, "90", PhoneNumberType::PremiumRate),
+    (371, "2", PhoneNumberType::Mobile),
+    (371, "6", PhoneNumberType::FixedLine),
+    (371, "80", PhoneNumberType::TollFree),
+    (371, "90", PhoneNumberType::PremiumRate),
+    (372, "5", PhoneNumberType::Mobile),
+    (372, "81", PhoneNumberType::Mobile),
+    (372, "82", PhoneNumberType::Mobile),
+    (372, "83", PhoneNumberType::Mobile),
+    (372, "84", PhoneNumberType::Mobile),
+    (372, "3", PhoneNumberType::FixedLine),
+    (372, "4", PhoneNumberType::FixedLine),
+    (372, "6", PhoneNumberType::FixedLine),
+    (372, "7", PhoneNumberType::FixedLine),
+    (372, "800", PhoneNumberType::TollFree),
+    (372, "900", PhoneNumberType::PremiumRate),
+    (373, "6", PhoneNumberType::Mobile),
+    (373, "7", PhoneNumberType::Mobile),
+    (373, "2", PhoneNumberType::FixedLine),
+    (374, "33", PhoneNumberType::Mobile),
+    (374, "4", PhoneNumberType::Mobile),
+    (374, "5", PhoneNumberType::Mobile),
+    (374, "7", PhoneNumberType::Mobile),
+    (374, "88", PhoneNumberType::Mobile),
+    (374, "9", PhoneNumberType::Mobile),
+    (374, "1", PhoneNumberType::FixedLine),
+    (374, "2", PhoneNumberType::FixedLine),
+    (374, "3", PhoneNumberType::FixedLine),
+    (374, "47", PhoneNumberType::FixedLine),
+    (374, "800", PhoneNumberType::TollFree),
+    (374, "90", PhoneNumberType::PremiumRate),
+    (375, "25", PhoneNumberType::Mobile),
+    (375, "29", PhoneNumberType::Mobile),
+    (375, "33", PhoneNumberType::Mobile),
+    (375, "44", PhoneNumberType::Mobile),
+    (375, "1", PhoneNumberType::FixedLine),
+    (375, "2", PhoneNumberType::FixedLine),
+    (376, "3", PhoneNumberType::Mobile),
+    (376, "4", PhoneNumberType::Mobile),
+    (376, "6", PhoneNumberType::Mobile),
+    (376, "7", PhoneNumberType::FixedLine),
+    (376, "8", PhoneNumberType::FixedLine),
+    (376, "180", PhoneNumberType::TollFree),
+    (377, "3", PhoneNumberType::Mobile),
+    (377, "4", PhoneNumberType::Mobile),
+    (377, "6", PhoneNumberType::Mobile),
+    (377, "8", PhoneNumberType::FixedLine),
+    (377, "9", PhoneNumberType::FixedLine),
+    (377, "80", PhoneNumberType::TollFree),
+    (378, "6", PhoneNumberType::Mobile),
+    (378, "5", PhoneNumberType::FixedLine),
+    (379, "6", PhoneNumberType::FixedLine),
+    (380, "39", PhoneNumberType::Mobile),
+    (380, "50", PhoneNumberType::Mobile),
+    (380, "63", PhoneNumberType::Mobile),
+    (380, "66", PhoneNumberType::Mobile),
+    (380, "67", PhoneNumberType::Mobile),
+    (380, "68", PhoneNumberType::Mobile),
+    (380, "73", PhoneNumberType::Mobile),
+    (380, "91", PhoneNumberType::Mobile),
+    (380, "92", PhoneNumberType::Mobile),
+    (380, "93", PhoneNumberType::Mobile),
+    (380, "94", PhoneNumberType::Mobile),
+    (380, "95", PhoneNumberType::Mobile),
+    (380, "96", PhoneNumberType::Mobile),
+    (380, "97", PhoneNumberType::Mobile),
+    (380, "98", PhoneNumberType::Mobile),
+    (380, "99", PhoneNumberType::Mobile),
+    (380, "800", PhoneNumberType::TollFree),
+    (380, "900", PhoneNumberType::PremiumRate),
+    (381, "6", PhoneNumberType::Mobile),
+    (381, "1", PhoneNumberType::FixedLine),
+    (381, "2", PhoneNumberType::FixedLine),
+    (381, "3", PhoneNumberType::FixedLine),
+    (381, "800", PhoneNumberType::TollFree),
+    (381, "90", PhoneNumberType::PremiumRate),
+    (382, "6", PhoneNumberType::Mobile),
+    (382, "2", PhoneNumberType::FixedLine),
+    (382, "3", PhoneNumberType::FixedLine),
+    (382, "4", PhoneNumberType::FixedLine),
+    (382, "5", PhoneNumberType::FixedLine),
+    (382, "80", PhoneNumberType::TollFree),
+    (382, "9", PhoneNumberType::PremiumRate),
+    (385, "91", PhoneNumberType::Mobile),
+    (385, "92", PhoneNumberType::Mobile),
+    (385, "95", PhoneNumberType::Mobile),
+    (385, "97", PhoneNumberType::Mobile),
+    (385, "98", PhoneNumberType::Mobile),
+    (385, "99", PhoneNumberType::Mobile),
+    (385, "1", PhoneNumberType::FixedLine),
+    (385, "2", PhoneNumberType::FixedLine),
+    (385, "3", PhoneNumberType::FixedLine),
+    (385, "4", PhoneNumberType::FixedLine),
+    (385, "5", PhoneNumberType::FixedLine),
+    (385, "800", PhoneNumberType::TollFree),
+    (386, "30", PhoneNumberType::Mobile),
+    (386, "31", PhoneNumberType::Mobile),
+    (386, "40", PhoneNumberType::Mobile),
+    (386, "41", PhoneNumberType::Mobile),
+    (386, "51", PhoneNumberType::Mobile),
+    (386, "64", PhoneNumberType::Mobile),
+    (386, "68", PhoneNumberType::Mobile),
+    (386, "69", PhoneNumberType::Mobile),
+    (386, "70", PhoneNumberType::Mobile),
+    (386, "71", PhoneNumberType::Mobile),
+    (386, "1", PhoneNumberType::FixedLine),
+    (386, "2", PhoneNumberType::FixedLine),
+    (386, "3", PhoneNumberType::FixedLine),
+    (386, "4", PhoneNumberType::FixedLine),
+    (386, "5", PhoneNumberType::FixedLine),
+    (386, "7", PhoneNumberType::FixedLine),
+    (386, "80", PhoneNumberType::TollFree),
+    (386, "90", PhoneNumberType::PremiumRate),
+    (387, "6", PhoneNumberType::Mobile),
+    (389, "7", PhoneNumberType::Mobile),
+    (420, "6", PhoneNumberType::Mobile),
+    (420, "7", PhoneNumberType::Mobile),
+    (420, "2", PhoneNumberType::FixedLine),
+    (420, "3", PhoneNumberType::FixedLine),
+    (420, "4", PhoneNumberType::FixedLine),
+    (420, "5", PhoneNumberType::FixedLine),
+    (420, "800", PhoneNumberType::TollFree),
+    (420, "90", PhoneNumberType::PremiumRate),
+    (421, "90", PhoneNumberType::Mobile),
+    (421, "91", PhoneNumberType::Mobile),
+    (421, "94", PhoneNumberType::Mobile),
+    (421, "95", PhoneNumberType::Mobile),
+    (421, "2", PhoneNumberType::FixedLine),
+    (421, "3", PhoneNumberType::FixedLine),
+    (421, "4", PhoneNumberType::FixedLine),
+    (421, "5", PhoneNumberType::FixedLine),
+    (421, "800", PhoneNumberType::TollFree),
+    (423, "7", PhoneNumberType::Mobile),
+    (500, "5", PhoneNumberType::Mobile),
+    (500, "6", PhoneNumberType::Mobile),
+    (500, "2", PhoneNumberType::FixedLine),
+    (500, "3", PhoneNumberType::FixedLine),
+    (500, "4", PhoneNumberType::FixedLine),
+    (501, "6", PhoneNumberType::Mobile),
+    (501, "2", PhoneNumberType::FixedLine),
+    (501, "3", PhoneNumberType::FixedLine),
+    (501, "4", PhoneNumberType::FixedLine),
+    (501, "5", PhoneNumberType::FixedLine),
+    (501, "7", PhoneNumberType::FixedLine),
+    (501, "8", PhoneNumberType::FixedLine),
+    (502, "3", PhoneNumberType::Mobile),
+    (502, "4", PhoneNumberType::Mobile),
+    (502, "5", PhoneNumberType::Mobile),
+    (502, "2", PhoneNumberType::FixedLine),
+    (502, "6", PhoneNumberType::FixedLine),
+    (502, "7", PhoneNumberType::FixedLine),
+    (503, "6", PhoneNumberType::Mobile),
+    (503, "7", PhoneNumberType::Mobile),
+    (503, "2", PhoneNumberType::FixedLine),
+    (504, "3", PhoneNumberType::Mobile),
+    (504, "8", PhoneNumberType::Mobile),
+    (504, "9", PhoneNumberType::Mobile),
+    (504, "2", PhoneNumberType::FixedLine),
+    (505, "8", PhoneNumberType::Mobile),
+    (505, "2", PhoneNumberType::FixedLine),
+    (506, "6", PhoneNumberType::Mobile),
+    (506, "7", PhoneNumberType::Mobile),
+    (506, "8", PhoneNumberType::Mobile),
+    (506, "2", PhoneNumberType::FixedLine),
+    (506, "800", PhoneNumberType::TollFree),
+    (507, "6", PhoneNumberType::Mobile),
+    (508, "5", PhoneNumberType::Mobile),
+    (508, "4", PhoneNumberType::FixedLine),
+    (508, "80", PhoneNumberType::TollFree),
+    (509, "3", PhoneNumberType::Mobile),
+    (509, "4", PhoneNumberType::Mobile),
+    (509, "2", PhoneNumberType::FixedLine),
+    (509, "8", PhoneNumberType::TollFree),
+    (590, "690", PhoneNumberType::Mobile),
+    (590, "691", PhoneNumberType::Mobile),
+    (590, "590", PhoneNumberType::FixedLine),
+    (591, "6", PhoneNumberType::Mobile),
+    (591, "7", PhoneNumberType::Mobile),
+    (591, "2", PhoneNumberType::FixedLine),
+    (591, "3", PhoneNumberType::FixedLine),
+    (591, "4", PhoneNumberType::FixedLine),
+    (592, "6", PhoneNumberType::Mobile),
+    (592, "7", PhoneNumberType::Mobile),
+    (592, "2", PhoneNumberType::FixedLine),
+    (592, "3", PhoneNumberType::FixedLine),
+    (592, "4", PhoneNumberType::FixedLine),
+    (592, "77", PhoneNumberType::FixedLine),
+    (593, "9", PhoneNumberType::Mobile),
+    (593, "2", PhoneNumberType::FixedLine),
+    (593, "3", PhoneNumberType::FixedLine),
+    (593, "4", PhoneNumberType::FixedLine),
+    (593, "5", PhoneNumberType::FixedLine),
+    (593, "6", PhoneNumberType::FixedLine),
+    (593, "7", PhoneNumberType::FixedLine),
+    (594, "694", PhoneNumberType::Mobile),
+    (594, "594", PhoneNumberType::FixedLine),
+    (595, "9", PhoneNumberType::Mobile),
+    (596, "696", PhoneNumberType::Mobile),
+    (596, "697", PhoneNumberType::Mobile),
+    (596, "596", PhoneNumberType::FixedLine),
+    (597, "6", PhoneNumberType::Mobile),
+    (597, "7", PhoneNumberType::Mobile),
+    (597, "8", PhoneNumberType::Mobile),
+    (597, "2", PhoneNumberType::FixedLine),
+    (597, "3", PhoneNumberType::FixedLine),
+    (597, "4", PhoneNumberType::FixedLine),
+    (597, "5", PhoneNumberType::FixedLine),
+    (598, "9", PhoneNumberType::Mobile),
+    (598, "2", PhoneNumberType::FixedLine),
+    (598, "4", PhoneNumberType::FixedLine),
+    (598, "800", PhoneNumberType::TollFree),
+    (599, "95", PhoneNumberType::Mobile),
+    (599, "96", PhoneNumberType::Mobile),
+    (599, "9", PhoneNumberType::FixedLine),
+    (599, "950", PhoneNumberType::FixedLine),
+    (670, "7", PhoneNumberType::Mobile),
+    (670, "2", PhoneNumberType::FixedLine),
+    (670, "3", PhoneNumberType::FixedLine),
+    (670, "4", PhoneNumberType::FixedLine),
+    (670, "8", PhoneNumberType::FixedLine),
+    (672, "35", PhoneNumberType::Mobile),
+    (672, "38", PhoneNumberType::Mobile),
+    (672, "1", PhoneNumberType::FixedLine),
+    (672, "3", PhoneNumberType::FixedLine),
+    (673, "7", PhoneNumberType::Mobile),
+    (673, "8", PhoneNumberType::Mobile),
+    (673, "2", PhoneNumberType::FixedLine),
+    (673, "3", PhoneNumberType::FixedLine),
+    (673, "4", PhoneNumberType::FixedLine),
+    (673, "5", PhoneNumberType::FixedLine),
+    (674, "5", PhoneNumberType::Mobile),
+    (674, "4", PhoneNumberType::FixedLine),
+    (675, "7", PhoneNumberType::Mobile),
+    (675, "3", PhoneNumberType::FixedLine),
+    (675, "4", PhoneNumberType::FixedLine),
+    (675, "5", PhoneNumberType::FixedLine),
+    (675, "6", PhoneNumberType::FixedLine),
+    (675, "8", PhoneNumberType::FixedLine),
+    (675, "9", PhoneNumberType::FixedLine),
+    (675, "180", PhoneNumberType::TollFree),
+    (676, "7", PhoneNumberType::Mobile),
+    (676, "8", PhoneNumberType::Mobile),
+    (676, "2", PhoneNumberType::FixedLine),
+    (676, "3", PhoneNumberType::FixedLine),
+    (676, "4", PhoneNumberType::FixedLine),
+    (676, "5", PhoneNumberType::FixedLine),
+    (676, "6", PhoneNumberType::FixedLine),
+    (677, "7", PhoneNumberType::Mobile),
+    (677, "8", PhoneNumberType::Mobile),
+    (677, "9", PhoneNumberType::Mobile),
+    (677, "1", PhoneNumberType::FixedLine),
+    (677, "2", PhoneNumberType::FixedLine),
+    (677, "3", PhoneNumberType::FixedLine),
+    (677, "4", PhoneNumberType::FixedLine),
+    (677, "5", PhoneNumberType::FixedLine),
+    (677, "6", PhoneNumberType::FixedLine),
+    (678, "5", PhoneNumberType::Mobile),
+    (678, "7", PhoneNumberType::Mobile),
+    (678, "2", PhoneNumberType::FixedLine),
+    (678, "3", PhoneNumberType::FixedLine),
+    (678, "4", PhoneNumberType::FixedLine),
+    (678, "8", PhoneNumberType::FixedLine),
+    (679, "2", PhoneNumberType::Mobile),
+    (679, "4", PhoneNumberType::Mobile),
+    (679, "5", PhoneNumberType::Mobile),
+    (679, "7", PhoneNumberType::Mobile),
+    (679, "8", PhoneNumberType::Mobile),
+    (679, "9", PhoneNumberType::Mobile),
+    (679, "3", PhoneNumberType::FixedLine),
+    (679, "6", PhoneNumberType::FixedLine),
+    (679, "85", PhoneNumberType::FixedLine),
+    (679, "88", PhoneNumberType::FixedLine),
+    (680, "6", PhoneNumberType::Mobile),
+    (680, "7", PhoneNumberType::Mobile),
+    (680, "8", PhoneNumberType::Mobile),
+    (680, "2", PhoneNumberType::FixedLine),
+    (680, "3", PhoneNumberType::FixedLine),
+    (680, "4", PhoneNumberType::FixedLine),
+    (680, "5", PhoneNumberType::FixedLine),
+    (681, "8", PhoneNumberType::Mobile),
+    (681, "72", PhoneNumberType::FixedLine),
+    (682, "5", PhoneNumberType::Mobile),
+    (682, "7", PhoneNumberType::Mobile),
+    (682, "8", PhoneNumberType::Mobile),
+    (682, "2", PhoneNumberType::FixedLine),
+    (682, "3", PhoneNumberType::FixedLine),
+    (682, "4", PhoneNumberType::FixedLine),
+    (683, "888", PhoneNumberType::Mobile),
+    (683, "4", PhoneNumberType::FixedLine),
+    (683, "7", PhoneNumberType::FixedLine),
+    (685, "7", PhoneNumberType::Mobile),
+    (685, "2", PhoneNumberType::FixedLine),
+    (685, "3", PhoneNumberType::FixedLine),
+    (685, "4", PhoneNumberType::FixedLine),
+    (685, "5", PhoneNumberType::FixedLine),
+    (685, "6", PhoneNumberType::FixedLine),
+    (685, "800", PhoneNumberType::TollFree),
+    (686, "6", PhoneNumberType::Mobile),
+    (686, "7", PhoneNumberType::Mobile),
+    (686, "2", PhoneNumberType::FixedLine),
+    (686, "3", PhoneNumberType::FixedLine),
+    (686, "8", PhoneNumberType::FixedLine),
+    (687, "5", PhoneNumberType::Mobile),
+    (687, "7", PhoneNumberType::Mobile),
+    (687, "8", PhoneNumberType::Mobile),
+    (687, "9", PhoneNumberType::Mobile),
+    (687, "2", PhoneNumberType::FixedLine),
+    (687, "3", PhoneNumberType::FixedLine),
+    (687, "4", PhoneNumberType::FixedLine),
+    (687, "36", PhoneNumberType::PremiumRate),
+    (688, "7", PhoneNumberType::Mobile),
+    (688, "9", PhoneNumberType::Mobile),
+    (688, "2", PhoneNumberType::FixedLine),
+    (689, "8", PhoneNumberType::Mobile),
+    (689, "4", PhoneNumberType::FixedLine),
+    (690, "7", PhoneNumberType::Mobile),
+    (690, "2", PhoneNumberType::FixedLine),
+    (690, "3", PhoneNumberType::FixedLine),
+    (690, "4", PhoneNumberType::FixedLine),
+    (691, "3", PhoneNumberType::FixedLineOrMobile),
+    (691, "8", PhoneNumberType::FixedLineOrMobile),
+    (691, "9", PhoneNumberType::FixedLineOrMobile),
+    (692, "2", PhoneNumberType::Mobile),
+    (692, "3", PhoneNumberType::Mobile),
+    (692, "4", PhoneNumberType::Mobile),
+    (692, "5", PhoneNumberType::Mobile),
+    (692, "247", PhoneNumberType::FixedLine),
+    (692, "528", PhoneNumberType::FixedLine),
+    (692, "625", PhoneNumberType::FixedLine),
+    (800, "", PhoneNumberType::TollFree),
+    (850, "19", PhoneNumberType::Mobile),
+    (850, "195", PhoneNumberType::FixedLine),
+    (850, "2", PhoneNumberType::FixedLine),
+    (850, "3", PhoneNumberType::FixedLine),
+    (850, "4", PhoneNumberType::FixedLine),
+    (850, "5", PhoneNumberType::FixedLine),
+    (850, "6", PhoneNumberType::FixedLine),
+    (850, "7", PhoneNumberType::FixedLine),
+    (850, "8", PhoneNumberType::FixedLine),
+    (852, "5", PhoneNumberType::Mobile),
+    (852, "6", PhoneNumberType::Mobile),
+    (852, "9", PhoneNumberType::Mobile),
+    (852, "2", PhoneNumberType::FixedLine),
+    (852, "3", PhoneNumberType::FixedLine),
+    (852, "800", PhoneNumberType::TollFree),
+    (853, "6", PhoneNumberType::Mobile),
+    (853, "28", PhoneNumberType::FixedLine),
+    (855, "1", PhoneNumberType::Mobile),
+    (855, "6", PhoneNumberType::Mobile),
+    (855, "7", PhoneNumberType::Mobile),
+    (855, "8", PhoneNumberType::Mobile),
+    (855, "9", PhoneNumberType::Mobile),
+    (855, "2", PhoneNumberType::FixedLine),
+    (855, "3", PhoneNumberType::FixedLine),
+    (855, "4", PhoneNumberType::FixedLine),
+    (855, "5", PhoneNumberType::FixedLine),
+    (855, "1800", PhoneNumberType::TollFree),
+    (855, "1900", PhoneNumberType::PremiumRate),
+    (856, "20", PhoneNumberType::Mobile),
+    (856, "2", PhoneNumberType::FixedLine),
+    (856, "3", PhoneNumberType::FixedLine),
+    (856, "4", PhoneNumberType::FixedLine),
+    (856, "5", PhoneNumberType::FixedLine),
+    (856, "6", PhoneNumberType::FixedLine),
+    (856, "7", PhoneNumberType::FixedLine),
+    (856, "8", PhoneNumberType::FixedLine),
+    (870, "", PhoneNumberType::Mobile),
+    (880, "1", PhoneNumberType::Mobile),
+    (886, "9", PhoneNumberType::Mobile),
+    (886, "2", PhoneNumberType::FixedLine),
+    (886, "3", PhoneNumberType::FixedLine),
+    (886, "4", PhoneNumberType::FixedLine),
+    (886, "5", PhoneNumberType::FixedLine),
+    (886, "6", PhoneNumberType::FixedLine),
+    (886, "7", PhoneNumberType::FixedLine),
+    (886, "8", PhoneNumberType::FixedLine),
+    (886, "80", PhoneNumberType::TollFree),
+    (886, "20", PhoneNumberType::PremiumRate),
+    (960, "7", PhoneNumberType::Mobile),
+    (960, "9", PhoneNumberType::Mobile),
+    (960, "3", PhoneNumberType::FixedLine),
+    (960, "6", PhoneNumberType::FixedLine),
+    (960, "800", PhoneNumberType::TollFree),
+    (960, "900", PhoneNumberType::PremiumRate),
+    (961, "3", PhoneNumberType::Mobile),
+    (961, "70", PhoneNumberType::Mobile),
+    (961, "71", PhoneNumberType::Mobile),
+    (961, "76", PhoneNumberType::Mobile),
+    (961, "78", PhoneNumberType::Mobile),
+    (961, "79", PhoneNumberType::Mobile),
+    (961, "81", PhoneNumberType::Mobile),
+    (961, "1", PhoneNumberType::FixedLine),
+    (961, "4", PhoneNumberType::FixedLine),
+    (961, "5", PhoneNumberType::FixedLine),
+    (961, "6", PhoneNumberType::FixedLine),
+    (961, "7", PhoneNumberType::FixedLine),
+    (961, "8", PhoneNumberType::FixedLine),
+    (961, "9", PhoneNumberType::FixedLine),
+    (962, "77", PhoneNumberType::Mobile),
+    (962, "78", PhoneNumberType::Mobile),
+    (962, "79", PhoneNumberType::Mobile),
+    (962, "2", PhoneNumberType::FixedLine),
+    (962, "3", PhoneNumberType::FixedLine),
+    (962, "5", PhoneNumberType::FixedLine),
+    (962, "6", PhoneNumberType::FixedLine),
+    (962, "800", PhoneNumberType::TollFree),
+    (962, "900", PhoneNumberType::PremiumRate),
+    (963, "9", PhoneNumberType::Mobile),
+    (964, "7", PhoneNumberType::Mobile),
+    (965, "5", PhoneNumberType::Mobile),
+    (965, "6", PhoneNumberType::Mobile),
+    (965, "9", PhoneNumberType::Mobile),
+    (965, "2", PhoneNumberType::FixedLine),
+    (966, "5", PhoneNumberType::Mobile),
+    (966, "1", PhoneNumberType::FixedLine),
+    (966, "800", PhoneNumberType::TollFree),
+    (967, "7", PhoneNumberType::Mobile),
+    (968, "7", PhoneNumberType::Mobile),
+    (968, "9", PhoneNumberType::Mobile),
+    (968, "2", PhoneNumberType::FixedLine),
+    (968, "800", PhoneNumberType::TollFree),
+    (970, "5", PhoneNumberType::Mobile),
+    (971, "5", PhoneNumberType::Mobile),
+    (971, "2", PhoneNumberType::FixedLine),
+    (971, "3", PhoneNumberType::FixedLine),
+    (971, "4", PhoneNumberType::FixedLine),
+    (971, "6", PhoneNumberType::FixedLine),
+    (971, "7", PhoneNumberType::FixedLine),
+    (971, "9", PhoneNumberType::FixedLine),
+    (971, "800", PhoneNumberType::TollFree),
+    (971, "600", PhoneNumberType::Uan),
+    (972, "5", PhoneNumberType::Mobile),
+    (972, "2", PhoneNumberType::FixedLine),
+    (972, "3", PhoneNumberType::FixedLine),
+    (972, "4", PhoneNumberType::FixedLine),
+    (972, "8", PhoneNumberType::FixedLine),
+    (972, "9", PhoneNumberType::FixedLine),
+    (972, "7", PhoneNumberType::Voip),
+    (972, "1800", PhoneNumberType::TollFree),
+    (973, "3", PhoneNumberType::Mobile),
+    (973, "1", PhoneNumberType::FixedLine),
+    (973, "80", PhoneNumberType::TollFree),
+    (973, "90", PhoneNumberType::PremiumRate),
+    (974, "3", PhoneNumberType::Mobile),
+    (974, "5", PhoneNumberType::Mobile),
+    (974, "6", PhoneNumberType::Mobile),
+    (974, "7", PhoneNumberType::Mobile),
+    (974, "4", PhoneNumberType::FixedLine),
+    (974, "800", PhoneNumberType::TollFree),
+    (975, "17", PhoneNumberType::Mobile),
+    (975, "77", PhoneNumberType::Mobile),
+    (975, "2", PhoneNumberType::FixedLine),
+    (975, "3", PhoneNumberType::FixedLine),
+    (975, "4", PhoneNumberType::FixedLine),
+    (975, "5", PhoneNumberType::FixedLine),
+    (975, "6", PhoneNumberType::FixedLine),
+    (975, "7", PhoneNumberType::FixedLine),
+    (975, "8", PhoneNumberType::FixedLine),
+    (976, "8", PhoneNumberType::Mobile),
+    (976, "9", PhoneNumberType::Mobile),
+    (977, "97", PhoneNumberType::Mobile),
+    (977, "98", PhoneNumberType::Mobile),
+    (977, "1", PhoneNumberType::FixedLine),
+    (992, "1", PhoneNumberType::Mobile),
+    (992, "2", PhoneNumberType::Mobile),
+    (992, "5", PhoneNumberType::Mobile),
+    (992, "7", PhoneNumberType::Mobile),
+    (992, "8", PhoneNumberType::Mobile),
+    (992, "9", PhoneNumberType::Mobile),
+    (992, "3", PhoneNumberType::FixedLine),
+    (992, "4", PhoneNumberType::FixedLine),
+    (993, "6", PhoneNumberType::Mobile),
+    (993, "7", PhoneNumberType::Mobile),
+    (993, "1", PhoneNumberType::FixedLine),
+    (993, "2", PhoneNumberType::FixedLine),
+    (993, "3", PhoneNumberType::FixedLine),
+    (993, "4", PhoneNumberType::FixedLine),
+    (993, "5", PhoneNumberType::FixedLine),
+    (994, "10", PhoneNumberType::Mobile),
+    (994, "4", PhoneNumberType::Mobile),
+    (994, "5", PhoneNumberType::Mobile),
+    (994, "6", PhoneNumberType::Mobile),
+    (994, "7", PhoneNumberType::Mobile),
+    (994, "9", PhoneNumberType::Mobile),
+    (994, "1", PhoneNumberType::FixedLine),
+    (994, "2", PhoneNumberType::FixedLine),
+    (994, "3", PhoneNumberType::FixedLine),
+    (994, "46", PhoneNumberType::FixedLine),
+    (994, "88", PhoneNumberType::TollFree),
+    (994, "900", PhoneNumberType::PremiumRate),
+    (995, "5", PhoneNumberType::Mobile),
+    (995, "3", PhoneNumberType::FixedLine),
+    (995, "4", PhoneNumberType::FixedLine),
+    (995, "800", PhoneNumberType::TollFree),
+    (995, "900", PhoneNumberType::PremiumRate),
+    (996, "2", PhoneNumberType::Mobile),
+    (996, "5", PhoneNumberType::Mobile),
+    (996, "6", PhoneNumberType::Mobile),
+    (996, "7", PhoneNumberType::Mobile),
+    (996, "8", PhoneNumberType::Mobile),
+    (996, "9", PhoneNumberType::Mobile),
+    (996, "3", PhoneNumberType::FixedLine),
+    (996, "800", PhoneNumberType::TollFree),
+    (998, "2", PhoneNumberType::Mobile),
+    (998, "33", PhoneNumberType::Mobile),
+    (998, "50", PhoneNumberType::Mobile),
+    (998, "77", PhoneNumberType::Mobile),
+    (998, "8", PhoneNumberType::Mobile),
+    (998, "9", PhoneNumberType::Mobile),
+    (998, "5", PhoneNumberType::FixedLine),
+    (998, "6", PhoneNumberType::FixedLine),
+    (998, "7", PhoneNumberType::FixedLine),
+];
+
+#[cfg(feature = "compact-metadata")]
+const PACKED_TYPE_RULES: Packer<MAX_LEN, MAX_CODES> = {
+    let mut packer = Packer::new();
+    let mut i = 0;
+    while i < TYPE_RULES.len() {
+        let (calling_code, prefix, number_type) = TYPE_RULES[i];
+        packer.entry(calling_code);
+        packer.str(prefix);
+        packer.number_type(number_type);
+        i += 1;
+    }
+    packer
+};
+
+#[cfg(feature = "compact-metadata")]
+static COMPACT_TYPE_RULES: Packed<
+    (&str, PhoneNumberType),
+    { PACKED_TYPE_RULES.len() },
+    { PACKED_TYPE_RULES.sections() },
+> = PACKED_TYPE_RULES.shrink();
+
+// (calling code, type, national number lengths) of the types whose lengths differ from
+// the other numbers of the plan, sorted by calling code. Lengths are ranges, as plans with
+// open numbering give the shortest and longest numbers rather than every length. The
+// lengths of the country (`Country::phone_lengths`) cover the ranges of its types.
+#[rustfmt::skip]
+pub(crate) const TYPE_LENGTHS: &[(u32, PhoneNumberType, RangeInclusive<u8>)] = &[
+    // 30 1234 in Berlin up to 11 digits in small towns, 151 23456789 but 170 1234567
+    (49, PhoneNumberType::Mobile, 10..=11),
+    (49, PhoneNumberType::FixedLine, 6..=11),
+    // 90 1234 5678 and 3 1234 5678 in Tokyo
+    (81, PhoneNumberType::Mobile, 10..=10),
+    (81, PhoneNumberType::FixedLine, 9..=9),
+    // 138 0013 8000 and 10 1234 5678 in Beijing, 3-digit area codes with 7-digit numbers
+    (86, PhoneNumberType::Mobile, 11..=11),
+    (86, PhoneNumberType::FixedLine, 10..=11),
+];
+
+/// Detects the type of a phone number, or returns `None` if the number is not valid.
+///
+/// Every calling code has type metadata, numbers outside the known ranges of their country
+/// are reported as [`PhoneNumberType::Unknown`].
+pub fn detect_phone_number_type(phone_number: impl AsRef<str>) -> Option<PhoneNumberType> {
+    let number = E164::parse(phone_number.as_ref())?;
+    Some(number_type_of(&number))
+}
+
+// true if the numbering plan of the calling code has type rules
+pub(crate) fn has_type_metadata(calling_code: u32) -> bool {
+    type_rules(calling_code).next().is_some()
+}
+
+// true if a number of `actual` type is one of `requested` type, the ranges shared by mobile
+// and fixed-line numbers being both
+pub(crate) fn is_of_type(actual: PhoneNumberType, requested: PhoneNumberType) -> bool {
+    actual == requested
+        || (actual == PhoneNumberType::FixedLineOrMobile
+            && matches!(
+                requested,
+                PhoneNumberType::Mobile | PhoneNumberType::FixedLine
+            ))
+}
+
+// the national number prefixes of the type rules of `calling_code`, with their type
+pub(crate) fn type_prefixes(
+    calling_code: u32,
+) -> impl Iterator<Item = (&'static str, PhoneNumberType)> {
+    type_rules(calling_code)
+}
+
+// the type rules of `calling_code`, (national number prefix, type)
+#[cfg(not(feature = "compact-metadata"))]
+fn type_rules(calling_code: u32) -> impl Iterator<Item = (&'static str, PhoneNumberType)> {
+    // the table is sorted by calling code, so only the rules of the number's are scanned
+    let start = TYPE_RULES.partition_point(|(code, _, _)| *code < calling_code);
+    TYPE_RULES[start..]
+        .iter()
+        .take_while(move |(code, _, _)| *code == calling_code)
+        .map(|(_, prefix, number_type)| (*prefix, *number_type))
+}
+
+#[cfg(feature = "compact-metadata")]
+fn type_rules(calling_code: u32) -> impl Iterator<Item = (&'static str, PhoneNumberType)> {
+    COMPACT_TYPE_RULES
+        .entries(calling_code, |reader: &mut Reader| {
+            (reader.str(), reader.number_type())
+        })
+        .iter()
+        .copied()
+}
+
+// the national number lengths of `number_type` in the plan of `calling_code`, None when they
+// are the lengths of the country
+pub(crate) fn type_lengths(
+    calling_code: u32,
+    number_type: PhoneNumberType,
+) -> Option<RangeInclusive<u8>> {
+    BUNDLED.type_lengths(calling_code, number_type)
+}
+
+// true if the national number has a length of its type, e.g. false for a German mobile
+// number of 6 digits, which is a valid length for a landline
+pub(crate) fn fits_type_lengths(
+    metadata: &Metadata,
+    calling_code: u32,
+    national_number: &str,
+) -> bool {
+    // most plans have none, skip classifying their numbers
+    if !metadata.has_type_lengths(calling_code) {
+        return true;
+    }
+    metadata
+        .type_lengths(calling_code, type_of(calling_code, national_number))
+        .is_none_or(|lengths| {
+            u8::try_from(national_number.len()).is_ok_and(|len| lengths.contains(&len))
+        })
+}
+
+pub(crate) fn number_type_of(number: &E164) -> PhoneNumberType {
+    let calling_code = calling_code(number.country());
+    type_of(calling_code, &number[1 + calling_code.to_string().len()..])
+}
+
+fn type_of(calling_code: u32, national_number: &str) -> PhoneNumberType {
+    stats::record_table_lookup();
+    type_rules(calling_code)
+        .filter(|(prefix, _)| national_number.starts_with(prefix))
+        .map(|(prefix, number_type)| (prefix.len(), number_type))
+        .max_by_key(|(prefix_len, _)| *prefix_len)
+        .map_or(PhoneNumberType::Unknown, |(_, number_type)| number_type)
+}
@@ -0,0 +1,485 @@
+use std::fmt;
+use std::sync::Arc;
+
+use crate::definitions::Country;
+use crate::risk::avoid_irsf_prone;
+use crate::scratch::with_scratch;
+use crate::trace::{normalize_traced, ProcessingTrace, TraceStep};
+use crate::unicode;
+use crate::vanity::convert_vanity_letters;
+use crate::{
+    contains_invalid_character, find_country_by_code, normalize_phone_number_in_place,
+    short_code_type, split_extension, strip_extension, PhoneFormat, PhoneNumber, PhoneNumberType,
+    PrivateNumber, PrivatePlanRegistry, RegionHintProvider, RejectionSampler, TestNumber,
+    TestNumberRegistry, E164,
+};
+
+/// A normalization hook, called with the phone number it may rewrite in place.
+pub type Hook = Box<dyn Fn(&mut String) + Send + Sync>;
+
+/// A configurable phone number parser.
+///
+/// Hooks let applications encode local dial-plan quirks without forking the crate: pre-hooks
+/// rewrite the raw input before it is cleaned (e.g. strip an internal PBX prefix) and
+/// post-hooks rewrite the normalized E.164 output. Hooks run in registration order.
+///
+/// ```
+/// let parser = phonelib::Parser::new().pre_hook(|phone_number| {
+///     if let Some(external) = phone_number.strip_prefix("9-") {
+///         *phone_number = external.to_string();
+///     }
+/// });
+///
+/// assert_eq!(parser.normalize("9-+96179123123"), Some("+96179123123".to_string()));
+/// ```
+pub struct Parser {
+    pre_hooks: Vec<Hook>,
+    post_hooks: Vec<Hook>,
+    trace: bool,
+    international_prefix: Option<&'static str>,
+    region_hints: Option<Box<dyn RegionHintProvider>>,
+    rejection_sampler: Option<Arc<RejectionSampler>>,
+    max_input_length: usize,
+    deprioritize_irsf_prone: bool,
+    test_numbers: Option<Arc<TestNumberRegistry>>,
+    private_plans: Option<Arc<PrivatePlanRegistry>>,
+    vanity_numbers: bool,
+}
+
+/// Why [`Parser::try_normalize`] rejected an input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// The input is longer than [`Parser::max_input_length`], it was not looked at.
+    InputTooLong { length: usize, max_length: usize },
+    /// The input is not a valid phone number.
+    InvalidNumber,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InputTooLong { length, max_length } => write!(
+                f,
+                "input of {} bytes is longer than the limit of {} bytes",
+                length, max_length
+            ),
+            ParseError::InvalidNumber => f.write_str("invalid phone number"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl Default for Parser {
+    fn default() -> Parser {
+        Parser {
+            pre_hooks: Vec::new(),
+            post_hooks: Vec::new(),
+            trace: false,
+            international_prefix: None,
+            region_hints: None,
+            rejection_sampler: None,
+            max_input_length: Parser::DEFAULT_MAX_INPUT_LENGTH,
+            deprioritize_irsf_prone: false,
+            test_numbers: None,
+            private_plans: None,
+            vanity_numbers: false,
+        }
+    }
+}
+
+impl Parser {
+    /// Default of [`Parser::max_input_length`], far above the length of any real number
+    /// written with separators and an extension.
+    pub const DEFAULT_MAX_INPUT_LENGTH: usize = 256;
+
+    pub fn new() -> Parser {
+        Parser::default()
+    }
+
+    /// Registers a hook run on the raw input before normalization.
+    pub fn pre_hook(mut self, hook: impl Fn(&mut String) + Send + Sync + 'static) -> Parser {
+        self.pre_hooks.push(Box::new(hook));
+        self
+    }
+
+    /// Registers a hook run on the normalized E.164 number.
+    pub fn post_hook(mut self, hook: impl Fn(&mut String) + Send + Sync + 'static) -> Parser {
+        self.post_hooks.push(Box::new(hook));
+        self
+    }
+
+    /// Enables recording a [`ProcessingTrace`] in [`Parser::normalize_with_trace`].
+    pub fn trace(mut self, enabled: bool) -> Parser {
+        self.trace = enabled;
+        self
+    }
+
+    /// Makes [`Parser::normalize`] replace the leading `+` with the international dialing
+    /// prefix of `sender_region` (an ISO 3166 code), e.g. `"0049…"` from `"FR"` or
+    /// `"01149…"` from `"US"`, for systems that don't accept `+`. Unknown regions use `"00"`.
+    ///
+    /// The prefix is applied after the post-hooks, which still see the E.164 number.
+    pub fn international_prefix_of(mut self, sender_region: &str) -> Parser {
+        self.international_prefix =
+            Some(find_country_by_code(sender_region).map_or("00", Country::international_prefix));
+        self
+    }
+
+    /// Reads numbers that are not in international form as national numbers of the region
+    /// given by `provider`, see [`RegionHintProvider`].
+    pub fn region_hints(mut self, provider: impl RegionHintProvider + 'static) -> Parser {
+        self.region_hints = Some(Box::new(provider));
+        self
+    }
+
+    /// Records the inputs rejected by [`Parser::normalize`] and
+    /// [`Parser::normalize_with_trace`] in `sampler`, which can be shared between parsers.
+    pub fn rejection_sampler(mut self, sampler: Arc<RejectionSampler>) -> Parser {
+        self.rejection_sampler = Some(sampler);
+        self
+    }
+
+    /// Rejects inputs longer than `max_length` bytes before any processing, so services
+    /// exposed to untrusted input don't spend time cleaning huge strings. Defaults to
+    /// [`Parser::DEFAULT_MAX_INPUT_LENGTH`], the limit applies to the raw input, before
+    /// the pre-hooks.
+    pub fn max_input_length(mut self, max_length: usize) -> Parser {
+        self.max_input_length = max_length;
+        self
+    }
+
+    /// Makes [`Parser::extract_country`] pass over the destinations of
+    /// [`crate::IRSF_PRONE_REGIONS`] when another country shares the calling code and fits
+    /// the number as well, so routing decisions based on the country don't default to
+    /// toll-fraud destinations. Numbers told apart by area code keep their country, e.g.
+    /// Antarctica for `"+672 1…"`, and a region hint naming the destination still picks it.
+    pub fn deprioritize_irsf_prone(mut self, enabled: bool) -> Parser {
+        self.deprioritize_irsf_prone = enabled;
+        self
+    }
+
+    /// Flags the numbers of `registry` as test numbers in [`Parser::number_type`] and
+    /// [`Parser::test_number`]. The registry can be shared between parsers.
+    pub fn test_numbers(mut self, registry: Arc<TestNumberRegistry>) -> Parser {
+        self.test_numbers = Some(registry);
+        self
+    }
+
+    /// Accepts the numbers of the private plans of `registry` in [`Parser::normalize`],
+    /// [`Parser::is_valid`] and [`Parser::format`], see [`PrivatePlanRegistry`]. The registry
+    /// can be shared between parsers.
+    pub fn private_plans(mut self, registry: Arc<PrivatePlanRegistry>) -> Parser {
+        self.private_plans = Some(registry);
+        self
+    }
+
+    /// Accepts vanity numbers such as `"1-800-FLOWERS"`, whose letters are converted to the
+    /// digits of their phone keypad key after the pre-hooks, see
+    /// [`crate::convert_alpha_characters`]. Extensions written after the letters are kept.
+    ///
+    /// ```
+    /// let parser = phonelib::Parser::new().vanity_numbers(true);
+    ///
+    /// assert!(parser.is_valid("+1-800-FLOWERS"));
+    /// assert_eq!(parser.normalize("+1-800-FLOWERS"), Some("+18003569377".to_string()));
+    /// assert!(!phonelib::Parser::new().is_valid("+1-800-FLOWERS"));
+    /// ```
+    pub fn vanity_numbers(mut self, enabled: bool) -> Parser {
+        self.vanity_numbers = enabled;
+        self
+    }
+
+    /// Same as [`crate::normalize_phone_number`], with the hooks applied.
+    pub fn normalize(&self, phone_number: &str) -> Option<String> {
+        self.try_normalize(phone_number).ok()
+    }
+
+    /// Same as [`Parser::normalize`], telling overlong inputs apart from invalid numbers.
+    /// Overlong inputs are not recorded by the rejection sampler.
+    pub fn try_normalize(&self, phone_number: &str) -> Result<String, ParseError> {
+        self.check_length(phone_number)?;
+        let mut normalized = with_scratch(|input| {
+            self.apply_pre_hooks(phone_number, input);
+            let normalized = self.normalize_without_post_hooks(input);
+            if normalized.is_none() {
+                self.record_rejection(input);
+            }
+            normalized
+        })
+        .ok_or(ParseError::InvalidNumber)?;
+        for hook in &self.post_hooks {
+            hook(&mut normalized);
+        }
+        if let Some(prefix) = self.international_prefix {
+            apply_international_prefix(&mut normalized, prefix);
+        }
+        Ok(normalized)
+    }
+
+    /// Same as [`Parser::normalize`], also returning the trace of the transformations applied
+    /// when tracing is enabled. Without tracing this is as cheap as [`Parser::normalize`], so
+    /// pipelines can switch tracing on from their configuration without changing call sites.
+    pub fn normalize_with_trace(
+        &self,
+        phone_number: &str,
+    ) -> (Option<String>, Option<ProcessingTrace>) {
+        if !self.trace {
+            return (self.normalize(phone_number), None);
+        }
+
+        let (normalized, trace) = self.normalize_traced(phone_number);
+        if normalized.is_none() && self.check_length(phone_number).is_ok() {
+            self.record_rejection(trace.pre_hooks_output());
+        }
+        (normalized, Some(trace))
+    }
+
+    // normalizes with the hooks applied and always records the trace
+    pub(crate) fn normalize_traced(&self, phone_number: &str) -> (Option<String>, ProcessingTrace) {
+        let mut trace = ProcessingTrace::new(phone_number);
+        if let Err(ParseError::InputTooLong { length, max_length }) =
+            self.check_length(phone_number)
+        {
+            trace
+                .steps
+                .push(TraceStep::InputTooLong { length, max_length });
+            return (None, trace);
+        }
+        let mut input = phone_number.to_string();
+        for hook in &self.pre_hooks {
+            run_traced(hook, &mut input, &mut trace, |before, after| {
+                TraceStep::PreHook { before, after }
+            });
+        }
+        if self.vanity_numbers {
+            let before = input.clone();
+            convert_vanity_letters(&mut input);
+            if input != before {
+                trace.steps.push(TraceStep::LettersConverted {
+                    before,
+                    after: input.clone(),
+                });
+            }
+        }
+
+        let mut normalized = normalize_traced(&input, self.hinted_region(&input), &mut trace)
+            .or_else(|| Some(self.registered_private_number(&input)?.e164()));
+        if let Some(normalized) = &mut normalized {
+            for hook in &self.post_hooks {
+                run_traced(hook, normalized, &mut trace, |before, after| {
+                    TraceStep::PostHook { before, after }
+                });
+            }
+            if let Some(prefix) = self.international_prefix {
+                if apply_international_prefix(normalized, prefix) {
+                    trace
+                        .steps
+                        .push(TraceStep::InternationalPrefixApplied(prefix.to_string()));
+                }
+            }
+        }
+        (normalized, trace)
+    }
+
+    /// Same as [`PhoneNumber::parse`], with the pre-hooks applied and national numbers read
+    /// in the hinted region. The extension is kept apart, post-hooks don't apply since the
+    /// result is not a string.
+    pub fn parse(&self, phone_number: &str) -> Option<PhoneNumber> {
+        self.check_length(phone_number).ok()?;
+        let mut input = String::new();
+        self.apply_pre_hooks(phone_number, &mut input);
+
+        let (number, extension) = split_extension(&input);
+        let parsed = match self.hinted_region(number) {
+            Some(region) => E164::parse_dialed_from(number, region),
+            None => E164::parse(number),
+        }?;
+        Some(PhoneNumber::from_parts(
+            phone_number,
+            parsed,
+            extension,
+            !contains_invalid_character(number),
+        ))
+    }
+
+    /// Returns the type of the number parsed with [`Parser::parse`],
+    /// [`PhoneNumberType::TestNumber`] for the numbers of the test number registry. Numbers
+    /// that don't parse are checked for short codes of the hinted region, see
+    /// [`crate::short_code_type`].
+    pub fn number_type(&self, phone_number: &str) -> Option<PhoneNumberType> {
+        let Some(number) = self.parse(phone_number) else {
+            let region = self.region_hints.as_ref()?.region_hint()?;
+            return short_code_type(phone_number, &region);
+        };
+        Some(match self.registered_test_number(&number).is_some() {
+            true => PhoneNumberType::TestNumber,
+            false => number.number_type(),
+        })
+    }
+
+    /// Returns the registration of the number parsed with [`Parser::parse`] in the test
+    /// number registry, see [`Parser::test_numbers`].
+    pub fn test_number(&self, phone_number: &str) -> Option<TestNumber> {
+        self.registered_test_number(&self.parse(phone_number)?)
+    }
+
+    /// Returns the number of the private plans parsed from `phone_number` with the pre-hooks
+    /// applied, see [`Parser::private_plans`].
+    pub fn private_number(&self, phone_number: &str) -> Option<PrivateNumber> {
+        self.check_length(phone_number).ok()?;
+        with_scratch(|input| {
+            self.apply_pre_hooks(phone_number, input);
+            self.registered_private_number(input)
+        })
+    }
+
+    /// Same as [`crate::format_phone_number`] for the numbers parsed with [`Parser::parse`],
+    /// the numbers of the private plans formatted as registered.
+    pub fn format(&self, phone_number: &str, format: PhoneFormat) -> Option<String> {
+        if let (Some(registry), Some(number)) =
+            (&self.private_plans, self.private_number(phone_number))
+        {
+            return Some(registry.format(&number, format));
+        }
+        Some(self.parse(phone_number)?.format(format))
+    }
+
+    /// Same as [`crate::is_valid_phone_number`], with the pre-hooks applied.
+    pub fn is_valid(&self, phone_number: &str) -> bool {
+        if self.check_length(phone_number).is_err() {
+            return false;
+        }
+        with_scratch(|phone_number_buffer| {
+            self.apply_pre_hooks(phone_number, phone_number_buffer);
+            if self
+                .registered_private_number(phone_number_buffer)
+                .is_some()
+            {
+                return true;
+            }
+            match self.hinted_region(phone_number_buffer) {
+                Some(region) => E164::parse_dialed_from(phone_number_buffer, region).is_some(),
+                None => {
+                    !contains_invalid_character(strip_extension(phone_number_buffer))
+                        && normalize_phone_number_in_place(phone_number_buffer).is_some()
+                }
+            }
+        })
+    }
+
+    /// Same as [`crate::extract_country`], with the pre-hooks applied.
+    pub fn extract_country(&self, phone_number: &str) -> Option<&'static Country> {
+        self.check_length(phone_number).ok()?;
+        with_scratch(|phone_number_buffer| {
+            self.apply_pre_hooks(phone_number, phone_number_buffer);
+            let region = self.hinted_region(phone_number_buffer);
+            let number = match region {
+                Some(region) => E164::parse_dialed_from(phone_number_buffer, region),
+                None => E164::parse(phone_number_buffer),
+            }?;
+            Some(match self.deprioritize_irsf_prone {
+                true => avoid_irsf_prone(&number, region),
+                false => number.country(),
+            })
+        })
+    }
+
+    fn normalize_without_post_hooks(&self, phone_number: &str) -> Option<String> {
+        match self.hinted_region(phone_number) {
+            Some(region) => E164::parse_dialed_from(phone_number, region).map(String::from),
+            None => with_scratch(|phone_number_buffer| {
+                phone_number_buffer.push_str(phone_number);
+                normalize_phone_number_in_place(phone_number_buffer)
+            }),
+        }
+        .or_else(|| Some(self.registered_private_number(phone_number)?.e164()))
+    }
+
+    fn registered_private_number(&self, phone_number: &str) -> Option<PrivateNumber> {
+        self.private_plans.as_ref()?.get(phone_number)
+    }
+
+    fn registered_test_number(&self, number: &PhoneNumber) -> Option<TestNumber> {
+        self.test_numbers.as_ref()?.get_e164(&number.e164())
+    }
+
+    fn check_length(&self, phone_number: &str) -> Result<(), ParseError> {
+        match phone_number.len() > self.max_input_length {
+            true => Err(ParseError::InputTooLong {
+                length: phone_number.len(),
+                max_length: self.max_input_length,
+            }),
+            false => Ok(()),
+        }
+    }
+
+    // the phone number is the pre-hooks output
+    fn record_rejection(&self, phone_number: &str) {
+        if let Some(sampler) = &self.rejection_sampler {
+            sampler.record(phone_number, self.hinted_region(phone_number));
+        }
+    }
+
+    // the region the phone number is dialed from, unless it starts with `+`. Numbers
+    // starting with the international prefix of the region are read as international ones,
+    // see `E164::parse_dialed_from`
+    fn hinted_region(&self, phone_number: &str) -> Option<&'static Country> {
+        if unicode::trim_start(phone_number).starts_with('+') {
+            return None;
+        }
+        find_country_by_code(&self.region_hints.as_ref()?.region_hint()?)
+    }
+
+    // writes the phone number rewritten by the pre-hooks to the empty `output`
+    fn apply_pre_hooks(&self, phone_number: &str, output: &mut String) {
+        output.push_str(phone_number);
+        for hook in &self.pre_hooks {
+            hook(output);
+        }
+        if self.vanity_numbers {
+            convert_vanity_letters(output);
+        }
+    }
+}
+
+impl fmt::Debug for Parser {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Parser")
+            .field("pre_hooks", &self.pre_hooks.len())
+            .field("post_hooks", &self.post_hooks.len())
+            .field("trace", &self.trace)
+            .field("international_prefix", &self.international_prefix)
+            .field("region_hints", &self.region_hints.is_some())
+            .field("rejection_sampler", &self.rejection_sampler)
+            .field("max_input_length", &self.max_input_length)
+            .field("deprioritize_irsf_prone", &self.deprioritize_irsf_prone)
+            .field("test_numbers", &self.test_numbers)
+            .field("private_plans", &self.private_plans)
+            .field("vanity_numbers", &self.vanity_numbers)
+            .finish()
+    }
+}
+
+// replaces the leading '+' with the prefix, returns false if there is no '+'
+fn apply_international_prefix(phone_number: &mut String, prefix: &str) -> bool {
+    let has_plus = phone_number.starts_with('+');
+    if has_plus {
+        phone_number.replace_range(..1, prefix);
+    }
+    has_plus
+}
+
+// runs the hook and records a step if it changed the phone number
+fn run_traced(
+    hook: &Hook,
+    phone_number: &mut String,
+    trace: &mut ProcessingTrace,
+    step: impl Fn(String, String) -> TraceStep,
+) {
+    let before = phone_number.clone();
+    hook(phone_number);
+    if *phone_number != before {
+        trace.steps.push(step(before, phone_number.clone()));
+    }
+}
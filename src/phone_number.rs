@@ -0,0 +1,185 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+use crate::definitions::Country;
+use crate::formatting::{format_e164, with_extension};
+use crate::number_type::number_type_of;
+use crate::{
+    contains_invalid_character, split_extension, ExtensionPolicy, PhoneFormat, PhoneNumberType,
+    E164,
+};
+
+/// A parsed phone number, to query several attributes of a number without normalizing it
+/// again for each of them.
+///
+/// ```
+/// let number = phonelib::PhoneNumber::parse("+1 (202) 555-0173 ext. 45").unwrap();
+///
+/// assert_eq!(number.e164(), "+12025550173");
+/// assert_eq!(number.country().code, "US");
+/// assert_eq!(number.national_number(), "2025550173");
+/// assert_eq!(number.extension(), Some("45"));
+/// assert_eq!(number.raw(), "+1 (202) 555-0173 ext. 45");
+/// ```
+///
+/// Numbers are equal, and hash the same, when they have the same E.164 number and
+/// extension whatever the formatting of their input, so they can be used as `HashMap` keys
+/// for deduplication. See [`PhoneNumber::key`] to ignore extensions. They are ordered like
+/// their [`E164`] number, then by extension.
+#[derive(Debug, Clone)]
+pub struct PhoneNumber {
+    raw: String,
+    number: E164,
+    extension: Option<String>,
+    phone_context: Option<String>,
+    valid: bool,
+}
+
+impl PhoneNumber {
+    /// Parses a phone number and its extension, if any. Returns `None` if the number
+    /// can't be normalized, see [`crate::normalize_phone_number`].
+    pub fn parse(phone_number: &str) -> Option<PhoneNumber> {
+        let (number, extension) = split_extension(phone_number);
+        Some(PhoneNumber {
+            raw: phone_number.to_string(),
+            number: E164::parse(number)?,
+            extension,
+            phone_context: None,
+            valid: !contains_invalid_character(number),
+        })
+    }
+
+    /// Parses a phone number written in the national form of `region` (an ISO 3166 code),
+    /// with or without its trunk prefix, e.g. `"06 12 34 56 78"` in `"FR"`. Numbers dialed
+    /// with the international prefix of `region` (`"0033…"` in `"DE"`, `"01133…"` in `"US"`)
+    /// are read as international numbers. Numbers that don't fit `region` and numbers
+    /// starting with `+` are parsed like [`PhoneNumber::parse`], as are all numbers when
+    /// `region` is unknown.
+    ///
+    /// When several countries share the calling code, `region` and its neighbors are
+    /// preferred, see [`crate::likely_country`].
+    ///
+    /// ```
+    /// let number = phonelib::PhoneNumber::parse_with_country("06 12 34 56 78", "FR").unwrap();
+    /// assert_eq!(number.e164(), "+33612345678");
+    /// ```
+    pub fn parse_with_country(phone_number: &str, region: &str) -> Option<PhoneNumber> {
+        let (number, extension) = split_extension(phone_number);
+        Some(PhoneNumber {
+            raw: phone_number.to_string(),
+            number: E164::parse_with_default_region(number, region)?,
+            extension,
+            phone_context: None,
+            valid: !contains_invalid_character(number),
+        })
+    }
+
+    pub(crate) fn from_parts(
+        raw: &str,
+        number: E164,
+        extension: Option<String>,
+        valid: bool,
+    ) -> PhoneNumber {
+        PhoneNumber {
+            raw: raw.to_string(),
+            number,
+            extension,
+            phone_context: None,
+            valid,
+        }
+    }
+
+    pub(crate) fn with_phone_context(mut self, phone_context: Option<&str>) -> PhoneNumber {
+        self.phone_context = phone_context.map(str::to_string);
+        self
+    }
+
+    /// Returns the input the number was parsed from.
+    pub fn raw(&self) -> &str {
+        &self.raw
+    }
+
+    /// Returns the number in E.164 form, without its extension.
+    pub fn e164(&self) -> E164 {
+        self.number
+    }
+
+    pub fn country(&self) -> &'static Country {
+        self.number.country()
+    }
+
+    /// Returns the national significant number, the digits after the calling code.
+    pub fn national_number(&self) -> &str {
+        self.number.national_number()
+    }
+
+    /// Returns the digits of the extension, e.g. `"45"` for `"ext. 45"`.
+    pub fn extension(&self) -> Option<&str> {
+        self.extension.as_deref()
+    }
+
+    /// Returns the `phone-context` parameter of a number parsed with
+    /// [`crate::parse_rfc3966`], e.g. `"+1-202"` or `"example.com"`.
+    pub fn phone_context(&self) -> Option<&str> {
+        self.phone_context.as_deref()
+    }
+
+    pub fn number_type(&self) -> PhoneNumberType {
+        number_type_of(&self.number)
+    }
+
+    /// Same as [`crate::is_valid_phone_number`] on the input without its extension.
+    pub fn is_valid(&self) -> bool {
+        self.valid
+    }
+
+    /// Returns a key identifying the number for deduplication with `policy`: extensions are
+    /// left out with [`ExtensionPolicy::Ignore`]. A number without extension can't be equal
+    /// to two numbers with different extensions, so [`ExtensionPolicy::MatchIfPresent`] keys
+    /// like [`ExtensionPolicy::Require`]. The key holds the E.164 digits, not the country
+    /// they were matched to, so it doesn't depend on the region hint of the parse.
+    pub fn key(&self, policy: ExtensionPolicy) -> (E164, Option<&str>) {
+        match policy {
+            ExtensionPolicy::Ignore => (self.number, None),
+            ExtensionPolicy::MatchIfPresent | ExtensionPolicy::Require => {
+                (self.number, self.extension())
+            }
+        }
+    }
+
+    /// Same as [`crate::format_phone_number`].
+    pub fn format(&self, format: PhoneFormat) -> String {
+        with_extension(
+            format_e164(&self.number, format),
+            format,
+            self.extension.clone(),
+        )
+    }
+}
+
+impl PartialEq for PhoneNumber {
+    fn eq(&self, other: &PhoneNumber) -> bool {
+        self.key(ExtensionPolicy::Require) == other.key(ExtensionPolicy::Require)
+    }
+}
+
+impl Eq for PhoneNumber {}
+
+impl Hash for PhoneNumber {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key(ExtensionPolicy::Require).hash(state);
+    }
+}
+
+impl Ord for PhoneNumber {
+    fn cmp(&self, other: &PhoneNumber) -> Ordering {
+        self.key(ExtensionPolicy::Require)
+            .cmp(&other.key(ExtensionPolicy::Require))
+    }
+}
+
+impl PartialOrd for PhoneNumber {
+    fn partial_cmp(&self, other: &PhoneNumber) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
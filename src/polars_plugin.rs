@@ -0,0 +1,74 @@
+// Polars expression plugin. The expressions are exported as `_polars_plugin_<name>` symbols
+// by any cdylib linking the crate with the `polars` feature, the README shows how to
+// register them as the `phone` namespace.
+
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+
+use crate::scratch::with_scratch;
+use crate::{
+    extract_country_data, is_valid_phone_number_str, normalize_phone_number_in_place,
+    remove_unwanted_character,
+};
+
+#[polars_expr(output_type=String)]
+fn normalize(inputs: &[Series]) -> PolarsResult<Series> {
+    normalize_series(&inputs[0])
+}
+
+#[polars_expr(output_type=Boolean)]
+fn is_valid(inputs: &[Series]) -> PolarsResult<Series> {
+    is_valid_series(&inputs[0])
+}
+
+#[polars_expr(output_type=String)]
+fn country(inputs: &[Series]) -> PolarsResult<Series> {
+    country_series(&inputs[0])
+}
+
+// the expressions without the plugin calling convention, null inputs give null outputs
+
+pub(crate) fn normalize_series(phone_numbers: &Series) -> PolarsResult<Series> {
+    let phone_numbers = phone_numbers.str()?;
+    let normalized: StringChunked = phone_numbers
+        .iter()
+        .map(|phone_number| {
+            with_scratch(|buffer| {
+                buffer.push_str(phone_number?);
+                normalize_phone_number_in_place(buffer)
+            })
+        })
+        .collect();
+    Ok(normalized
+        .with_name(phone_numbers.name().clone())
+        .into_series())
+}
+
+pub(crate) fn is_valid_series(phone_numbers: &Series) -> PolarsResult<Series> {
+    let phone_numbers = phone_numbers.str()?;
+    let validity: BooleanChunked = phone_numbers
+        .iter()
+        .map(|phone_number| phone_number.map(is_valid_phone_number_str))
+        .collect();
+    Ok(validity
+        .with_name(phone_numbers.name().clone())
+        .into_series())
+}
+
+// ISO 3166 codes
+pub(crate) fn country_series(phone_numbers: &Series) -> PolarsResult<Series> {
+    let phone_numbers = phone_numbers.str()?;
+    let countries: StringChunked = phone_numbers
+        .iter()
+        .map(|phone_number| {
+            with_scratch(|buffer| {
+                buffer.push_str(phone_number?);
+                remove_unwanted_character(buffer);
+                extract_country_data(buffer).map(|country| country.code)
+            })
+        })
+        .collect();
+    Ok(countries
+        .with_name(phone_numbers.name().clone())
+        .into_series())
+}
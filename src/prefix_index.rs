@@ -0,0 +1,102 @@
+// Index of the country table by calling code, sorted at compile time so looking up the
+// countries of a calling code is a binary search instead of a scan of the table.
+
+use crate::calling_code;
+use crate::constants::{AREA_CODES, COUNTRIES};
+use crate::definitions::Country;
+use crate::integrity::str_eq;
+
+// digits of the longest calling code
+pub(crate) const MAX_PREFIX_LEN: usize = max_prefix_len(&COUNTRIES);
+
+// the countries sorted by calling code, then the countries matching the most digits with
+// their longest area code first, then in table order, so the first fitting country of a
+// calling code is the most specific one
+static BY_PREFIX: [&Country; COUNTRIES.len()] = sort_by_prefix(&COUNTRIES, false);
+
+// the same order by E.164 calling code, so the NANP members whose prefix includes their
+// area code come with the other countries of calling code 1
+static BY_CALLING_CODE: [&Country; COUNTRIES.len()] = sort_by_prefix(&COUNTRIES, true);
+
+// the countries whose calling code is `prefix`, most specific first
+pub(crate) fn countries_with_prefix(prefix: u32) -> &'static [&'static Country] {
+    let start = BY_PREFIX.partition_point(|country| country.prefix < prefix);
+    let end = BY_PREFIX.partition_point(|country| country.prefix <= prefix);
+    &BY_PREFIX[start..end]
+}
+
+// the countries whose E.164 calling code is `code`, most specific first
+pub(crate) fn countries_with_calling_code(code: u32) -> &'static [&'static Country] {
+    let start = BY_CALLING_CODE.partition_point(|country| calling_code(country) < code);
+    let end = BY_CALLING_CODE.partition_point(|country| calling_code(country) <= code);
+    &BY_CALLING_CODE[start..end]
+}
+
+const fn max_prefix_len(countries: &[Country]) -> usize {
+    let (mut max, mut i) = (0, 0);
+    while i < countries.len() {
+        if countries[i].prefix_len() > max {
+            max = countries[i].prefix_len();
+        }
+        i += 1;
+    }
+    max
+}
+
+const fn sort_by_prefix<const N: usize>(
+    countries: &'static [Country; N],
+    by_calling_code: bool,
+) -> [&'static Country; N] {
+    let mut order = [&countries[0]; N];
+    let mut i = 0;
+    while i < N {
+        order[i] = &countries[i];
+        i += 1;
+    }
+
+    // insertion sort, the table is small and const fns can't call sort
+    let mut i = 1;
+    while i < N {
+        let mut j = i;
+        while j > 0 && comes_before(order[j], order[j - 1], by_calling_code) {
+            let swapped = order[j];
+            order[j] = order[j - 1];
+            order[j - 1] = swapped;
+            j -= 1;
+        }
+        i += 1;
+    }
+    order
+}
+
+const fn comes_before(a: &Country, b: &Country, by_calling_code: bool) -> bool {
+    let (key_a, key_b) = match by_calling_code {
+        true => (calling_code(a), calling_code(b)),
+        false => (a.prefix, b.prefix),
+    };
+    if key_a != key_b {
+        return key_a < key_b;
+    }
+    // the sort is stable, countries matching as many digits stay in table order
+    max_match_len(a) > max_match_len(b)
+}
+
+// digits matched by the calling code and the longest area code of the country
+const fn max_match_len(country: &Country) -> usize {
+    let mut longest = 0;
+    let mut i = 0;
+    while i < AREA_CODES.len() {
+        let (code, area_codes) = AREA_CODES[i];
+        if str_eq(code, country.code) {
+            let mut j = 0;
+            while j < area_codes.len() {
+                if area_codes[j].len() > longest {
+                    longest = area_codes[j].len();
+                }
+                j += 1;
+            }
+        }
+        i += 1;
+    }
+    country.prefix_len() + longest
+}
@@ -0,0 +1,212 @@
+use std::ops::RangeInclusive;
+use std::sync::RwLock;
+
+use crate::formatting::{apply_template, group_digits};
+use crate::{all_countries, strip_extension, PhoneFormat};
+
+/// A private numbering plan, e.g. the internal dial plan of a company numbered like E.164
+/// under a calling code no country uses. See [`PrivatePlanRegistry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrivateNumberingPlan {
+    name: String,
+    calling_code: String,
+    lengths: RangeInclusive<usize>,
+    template: Option<String>,
+}
+
+/// A number of a registered [`PrivateNumberingPlan`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PrivateNumber {
+    /// Name of the plan of the number.
+    pub plan: String,
+    pub calling_code: String,
+    pub national_number: String,
+}
+
+/// Private numbering plans registered by the application, so internal numbers are
+/// validated, normalized and formatted by a [`crate::Parser`] like public ones. See
+/// [`crate::Parser::private_plans`].
+///
+/// Private numbers are only recognized in international form, they have no country.
+/// The registry can be shared between parsers and updated at runtime.
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use phonelib::{Parser, PhoneFormat, PrivateNumberingPlan, PrivatePlanRegistry};
+///
+/// let registry = Arc::new(PrivatePlanRegistry::new());
+/// registry.register(PrivateNumberingPlan::new("acme", "999", 6..=6).format("##-####"));
+/// let parser = Parser::new().private_plans(registry.clone());
+///
+/// assert!(parser.is_valid("+999 12-3456"));
+/// assert_eq!(parser.normalize("+999 12-3456"), Some("+999123456".to_string()));
+/// assert_eq!(
+///     parser.format("+999123456", PhoneFormat::International),
+///     Some("+999 12-3456".to_string())
+/// );
+/// assert_eq!(parser.private_number("+999123456").unwrap().plan, "acme");
+///
+/// // public numbers are unaffected
+/// assert_eq!(parser.normalize("+33 6 12 34 56 78"), Some("+33612345678".to_string()));
+/// ```
+#[derive(Debug, Default)]
+pub struct PrivatePlanRegistry {
+    plans: RwLock<Vec<PrivateNumberingPlan>>,
+}
+
+impl PrivateNumberingPlan {
+    /// A plan of numbers made of `calling_code` followed by a national number of one of
+    /// `lengths` digits.
+    pub fn new(
+        name: &str,
+        calling_code: &str,
+        lengths: RangeInclusive<usize>,
+    ) -> PrivateNumberingPlan {
+        PrivateNumberingPlan {
+            name: name.to_string(),
+            calling_code: calling_code.to_string(),
+            lengths,
+            template: None,
+        }
+    }
+
+    /// Sets the template of the national numbers, where each `#` stands for one digit, e.g.
+    /// `"##-####"`. Numbers with another number of digits are grouped in blocks of three.
+    pub fn format(mut self, template: &str) -> PrivateNumberingPlan {
+        self.template = Some(template.to_string());
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn calling_code(&self) -> &str {
+        &self.calling_code
+    }
+
+    // true if the calling code is made of 1 to 3 digits and neither starts nor is started
+    // by a public calling code, so public and private numbers can't be mistaken for each other
+    fn is_reserved_code(&self) -> bool {
+        let code = self.calling_code.as_str();
+        (1..=3).contains(&code.len())
+            && code.bytes().all(|b| b.is_ascii_digit())
+            && !code.starts_with('0')
+            && all_countries().iter().all(|country| {
+                let prefix = country.prefix.to_string();
+                !prefix.starts_with(code) && !code.starts_with(&prefix)
+            })
+    }
+
+    fn number(&self, digits: &str) -> Option<PrivateNumber> {
+        let national_number = digits.strip_prefix(self.calling_code.as_str())?;
+        if !self.lengths.contains(&national_number.len()) {
+            return None;
+        }
+        Some(PrivateNumber {
+            plan: self.name.clone(),
+            calling_code: self.calling_code.clone(),
+            national_number: national_number.to_string(),
+        })
+    }
+
+    fn format_national(&self, national_number: &str) -> String {
+        match &self.template {
+            Some(template) if template.matches('#').count() == national_number.len() => {
+                apply_template(template, national_number)
+            }
+            _ => group_digits(national_number, " "),
+        }
+    }
+}
+
+impl PrivatePlanRegistry {
+    pub fn new() -> PrivatePlanRegistry {
+        PrivatePlanRegistry::default()
+    }
+
+    /// Registers `plan`, replacing the plan of the same calling code if any. Returns false
+    /// if the calling code is not 1 to 3 digits or is used by public numbers.
+    pub fn register(&self, plan: PrivateNumberingPlan) -> bool {
+        if !plan.is_reserved_code() {
+            return false;
+        }
+        let mut plans = self
+            .plans
+            .write()
+            .unwrap_or_else(|error| error.into_inner());
+        plans.retain(|registered| registered.calling_code != plan.calling_code);
+        plans.push(plan);
+        true
+    }
+
+    /// Removes the plan named `name` and returns it.
+    pub fn unregister(&self, name: &str) -> Option<PrivateNumberingPlan> {
+        let mut plans = self
+            .plans
+            .write()
+            .unwrap_or_else(|error| error.into_inner());
+        let position = plans.iter().position(|plan| plan.name == name)?;
+        Some(plans.remove(position))
+    }
+
+    /// Returns `phone_number` as a number of a registered plan, if it is one. The number
+    /// must be in international form, with a `+` or `00`, extensions are ignored.
+    pub fn get(&self, phone_number: &str) -> Option<PrivateNumber> {
+        let digits = international_digits(phone_number)?;
+        self.plans
+            .read()
+            .unwrap_or_else(|error| error.into_inner())
+            .iter()
+            .find_map(|plan| plan.number(&digits))
+    }
+
+    /// Formats a number of a registered plan, the national format leaving the calling code
+    /// out as there is no trunk prefix.
+    pub fn format(&self, number: &PrivateNumber, format: PhoneFormat) -> String {
+        let national = self
+            .plans
+            .read()
+            .unwrap_or_else(|error| error.into_inner())
+            .iter()
+            .find(|plan| plan.calling_code == number.calling_code)
+            .map_or_else(
+                || group_digits(&number.national_number, " "),
+                |plan| plan.format_national(&number.national_number),
+            );
+        match format {
+            PhoneFormat::E164 => number.e164(),
+            PhoneFormat::International => format!("+{} {}", number.calling_code, national),
+            PhoneFormat::National => national,
+            PhoneFormat::RFC3966 => format!(
+                "tel:+{}-{}",
+                number.calling_code,
+                national.replace(|c: char| !c.is_ascii_digit(), "-")
+            ),
+        }
+    }
+}
+
+impl PrivateNumber {
+    /// Returns the number in E.164 form, e.g. `"+999123456"`.
+    pub fn e164(&self) -> String {
+        format!("+{}{}", self.calling_code, self.national_number)
+    }
+}
+
+// the digits of a number in international form, None for other numbers or with letters
+fn international_digits(phone_number: &str) -> Option<String> {
+    let number = strip_extension(phone_number).trim();
+    let number = match number.strip_prefix('+') {
+        Some(number) => number,
+        None => number.strip_prefix("00")?,
+    };
+    if !number
+        .chars()
+        .all(|c| c.is_ascii_digit() || matches!(c, ' ' | '-' | '.' | '(' | ')' | '/'))
+    {
+        return None;
+    }
+    Some(number.chars().filter(char::is_ascii_digit).collect())
+}
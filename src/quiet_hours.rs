@@ -0,0 +1,98 @@
+use crate::definitions::Country;
+use crate::E164;
+
+/// A date and time in the local time of a country.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LocalDateTime {
+    pub year: i32,
+    /// 1 to 12.
+    pub month: u8,
+    /// 1 to 31.
+    pub day: u8,
+    /// 0 to 23.
+    pub hour: u8,
+    /// 0 to 59.
+    pub minute: u8,
+}
+
+impl LocalDateTime {
+    /// Returns the local date and time of `unix_seconds` at a fixed offset from UTC, e.g.
+    /// `120` minutes for UTC+2.
+    pub fn from_unix_seconds(unix_seconds: i64, utc_offset_minutes: i32) -> LocalDateTime {
+        let seconds = unix_seconds + i64::from(utc_offset_minutes) * 60;
+        let (days, seconds_of_day) = (seconds.div_euclid(86_400), seconds.rem_euclid(86_400));
+
+        // days since 1970-01-01 to a proleptic Gregorian date, see Howard Hinnant's
+        // `civil_from_days`
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let month = if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        };
+
+        LocalDateTime {
+            year: (year_of_era + era * 400 + i64::from(month <= 2)) as i32,
+            month: month as u8,
+            day: (day_of_year - (153 * month_index + 2) / 5 + 1) as u8,
+            hour: (seconds_of_day / 3600) as u8,
+            minute: (seconds_of_day % 3600 / 60) as u8,
+        }
+    }
+}
+
+/// Supplies the local time and the calling calendar of countries, see
+/// [`is_within_local_hours`].
+///
+/// Implement it on top of a time zone database and the holiday calendars of a compliance
+/// team, so phonelib doesn't have to bundle that data. By default every day is a working
+/// day and calls are allowed from 08:00 to 21:00 local time.
+pub trait QuietHoursProvider {
+    /// Returns the local date and time in `country` at `unix_seconds`, or `None` if it is
+    /// unknown, e.g. for countries spanning several time zones.
+    fn local_time(&self, country: &'static Country, unix_seconds: i64) -> Option<LocalDateTime>;
+
+    /// Returns true if `country` must not be called at `local`, because of quiet hours or
+    /// a holiday.
+    fn is_quiet(&self, _country: &'static Country, local: &LocalDateTime) -> bool {
+        local.hour < 8 || local.hour >= 21
+    }
+}
+
+/// Tells whether a number may be called at `unix_seconds`, that is outside of the quiet
+/// hours and holidays of its country given by `provider`. Returns `None` if the number is
+/// not valid or the provider doesn't know the local time of its country.
+///
+/// ```
+/// use phonelib::{is_within_local_hours, Country, LocalDateTime, QuietHoursProvider};
+///
+/// struct CentralEuropeanWinter;
+///
+/// impl QuietHoursProvider for CentralEuropeanWinter {
+///     fn local_time(&self, country: &'static Country, unix_seconds: i64) -> Option<LocalDateTime> {
+///         ["FR", "DE"]
+///             .contains(&country.code)
+///             .then(|| LocalDateTime::from_unix_seconds(unix_seconds, 60))
+///     }
+/// }
+///
+/// // 2026-01-15 at 19:30 UTC, 20:30 in Paris
+/// assert_eq!(is_within_local_hours("+33612345678", 1_768_505_400, &CentralEuropeanWinter), Some(true));
+/// assert_eq!(is_within_local_hours("+33612345678", 1_768_505_400 + 3600, &CentralEuropeanWinter), Some(false));
+/// assert_eq!(is_within_local_hours("+96179123123", 1_768_505_400, &CentralEuropeanWinter), None);
+/// ```
+pub fn is_within_local_hours(
+    phone_number: &str,
+    unix_seconds: i64,
+    provider: &dyn QuietHoursProvider,
+) -> Option<bool> {
+    let country = E164::parse(phone_number)?.country();
+    let local = provider.local_time(country, unix_seconds)?;
+    Some(!provider.is_quiet(country, &local))
+}
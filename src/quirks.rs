@@ -0,0 +1,77 @@
+// Country-specific exceptions to the numbering rules applied by the parser. Each quirk is
+// one entry of `QUIRKS` read by a small function below, with its own test file under
+// `tests/quirks`, so a numbering-plan oddity is reviewed in one place.
+
+use crate::definitions::Country;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Quirk {
+    // the leading zero of national numbers is dialed after the calling code and is part of
+    // the number, e.g. +39 06 1234 5678 for Rome
+    SignificantLeadingZero,
+    // digit dialed internationally between the calling code and mobile numbers, which is
+    // left out of the stored number
+    MobileDigit(&'static str),
+    // trunk prefix other than the usual "0", dialed as a whole before national numbers
+    TrunkPrefix(&'static str),
+}
+
+#[rustfmt::skip]
+const QUIRKS: &[(&str, Quirk)] = &[
+    // Italian landlines keep their 0 since the 1998 numbering plan
+    ("IT", Quirk::SignificantLeadingZero),
+    // San Marino landlines start with 0549, as in the Italian plan
+    ("SM", Quirk::SignificantLeadingZero),
+    // +54 9 11 1234 5678 is the mobile form of 011 1234 5678
+    ("AR", Quirk::MobileDigit("9")),
+    // +52 1 55 1234 5678, dropped in 2019 but still found in address books
+    ("MX", Quirk::MobileDigit("1")),
+    // 06 1 234 5678 from Hungary
+    ("HU", Quirk::TrunkPrefix("06")),
+    // 8 912 345-67-89 in Russia and the former Soviet republics keeping the Soviet plan
+    ("RU", Quirk::TrunkPrefix("8")),
+    ("BY", Quirk::TrunkPrefix("8")),
+    ("KZ", Quirk::TrunkPrefix("8")),
+    ("TJ", Quirk::TrunkPrefix("8")),
+    ("TM", Quirk::TrunkPrefix("8")),
+    ("UZ", Quirk::TrunkPrefix("8")),
+];
+
+fn quirks_of(country: &Country) -> impl Iterator<Item = Quirk> + '_ {
+    QUIRKS
+        .iter()
+        .filter(move |(code, _)| *code == country.code)
+        .map(|(_, quirk)| *quirk)
+}
+
+// true if the zeros starting the national number are kept after the calling code
+pub(crate) fn keeps_leading_zero(country: &Country) -> bool {
+    quirks_of(country).any(|quirk| quirk == Quirk::SignificantLeadingZero)
+}
+
+// the digit dialed after the calling code of mobile numbers of the country, if any
+pub(crate) fn mobile_digit(country: &Country) -> Option<&'static str> {
+    quirks_of(country).find_map(|quirk| match quirk {
+        Quirk::MobileDigit(digit) => Some(digit),
+        _ => None,
+    })
+}
+
+// the trunk prefix of the countries not using the usual "0"
+pub(crate) fn trunk_prefix(country: &Country) -> Option<&'static str> {
+    quirks_of(country).find_map(|quirk| match quirk {
+        Quirk::TrunkPrefix(prefix) => Some(prefix),
+        _ => None,
+    })
+}
+
+// the calling code and mobile digit of every country dialing one, e.g. ("54", "9")
+pub(crate) fn mobile_digits() -> impl Iterator<Item = (u32, &'static str)> {
+    QUIRKS.iter().filter_map(|(code, quirk)| match quirk {
+        Quirk::MobileDigit(digit) => {
+            let country = crate::find_country_by_code(code)?;
+            Some((country.prefix, *digit))
+        }
+        _ => None,
+    })
+}
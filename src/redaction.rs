@@ -0,0 +1,135 @@
+use crate::display::mask;
+use crate::formatting::format_e164;
+use crate::matcher::{Leniency, PhoneNumberMatcher};
+use crate::{calling_code, PhoneFormat, E164};
+
+// character replacing the masked digits
+const MASK: char = '•';
+const PLACEHOLDER: &str = "[PHONE]";
+
+/// How [`mask_phone_number`] hides a number. Every style but
+/// [`MaskStyle::Placeholder`] keeps the calling code and the last two digits, enough to
+/// tell numbers apart in logs without exposing them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MaskStyle {
+    /// Six `•` between the calling code and the last two digits whatever the length of the
+    /// number, which is hidden too, e.g. `"+1••••••73"`.
+    Compact,
+    /// The E.164 form with the other digits masked, e.g. `"+1••••••••73"`.
+    E164,
+    /// The international format with the other digits masked, e.g. `"+1 •••-•••-••73"`.
+    International,
+    /// `"[PHONE]"` instead of the number.
+    Placeholder,
+}
+
+/// A number replaced by [`redact_phone_numbers_in_text`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Redaction {
+    /// Byte offset of the number in the input text.
+    pub start: usize,
+    /// Byte offset of the end of the number in the input text.
+    pub end: usize,
+    /// What the number was replaced with.
+    pub replacement: String,
+}
+
+/// Masks a phone number for logs and support tools, see [`MaskStyle`]. Returns `None` if
+/// the number is not valid.
+///
+/// ```
+/// use phonelib::{mask_phone_number, MaskStyle};
+///
+/// assert_eq!(
+///     mask_phone_number("+1 (202) 555-0173", MaskStyle::Compact),
+///     Some("+1••••••73".to_string())
+/// );
+/// ```
+pub fn mask_phone_number(phone_number: &str, style: MaskStyle) -> Option<String> {
+    Some(mask_e164(&E164::parse(phone_number)?, style))
+}
+
+/// Replaces the phone numbers of `text` with their [`MaskStyle::Compact`] mask, so logs can
+/// be sanitized before they are stored. Every possible number in international form, with
+/// `+` or `00`, is masked. Numbers in national form need a region, see
+/// [`redact_phone_numbers_in_text_with_region`], and `PhoneNumberMatcher::redact` (with the
+/// `unstable` feature) picks the leniency and style.
+///
+/// ```
+/// let (redacted, redactions) =
+///     phonelib::redact_phone_numbers_in_text("callback to +33 6 12 34 56 78 failed");
+///
+/// assert_eq!(redacted, "callback to +33••••••78 failed");
+/// assert_eq!((redactions[0].start, redactions[0].end), (12, 29));
+/// ```
+pub fn redact_phone_numbers_in_text(text: &str) -> (String, Vec<Redaction>) {
+    PhoneNumberMatcher::new(Leniency::Possible).redact(text, MaskStyle::Compact)
+}
+
+/// Like [`redact_phone_numbers_in_text`], also masking the numbers written in the national
+/// form of `region` (an ISO 3166 code) or dialed with its international prefix. Unknown
+/// regions are ignored.
+///
+/// ```
+/// let (redacted, _) =
+///     phonelib::redact_phone_numbers_in_text_with_region("call (202) 555-0173", "US");
+///
+/// assert_eq!(redacted, "call +1••••••73");
+/// ```
+pub fn redact_phone_numbers_in_text_with_region(
+    text: &str,
+    region: &str,
+) -> (String, Vec<Redaction>) {
+    PhoneNumberMatcher::new(Leniency::Possible)
+        .default_region(region)
+        .redact(text, MaskStyle::Compact)
+}
+
+impl PhoneNumberMatcher {
+    /// Replaces the numbers found by [`PhoneNumberMatcher::find_iter`] with their mask in
+    /// `style`, see [`redact_phone_numbers_in_text`]. Possible numbers that don't parse keep
+    /// their last two digits only.
+    pub fn redact(&self, text: &str, style: MaskStyle) -> (String, Vec<Redaction>) {
+        let mut redacted = String::with_capacity(text.len());
+        let mut redactions = Vec::new();
+        let mut copied = 0;
+        for found in self.find_iter(text) {
+            let replacement = match (&found.number, style) {
+                (Some(number), _) => mask_e164(&number.e164(), style),
+                (None, MaskStyle::Placeholder) => PLACEHOLDER.to_string(),
+                (None, _) => mask(found.raw, 0, 2, MASK),
+            };
+            redacted.push_str(&text[copied..found.start]);
+            redacted.push_str(&replacement);
+            copied = found.end();
+            redactions.push(Redaction {
+                start: found.start,
+                end: found.end(),
+                replacement,
+            });
+        }
+        redacted.push_str(&text[copied..]);
+        (redacted, redactions)
+    }
+}
+
+fn mask_e164(number: &E164, style: MaskStyle) -> String {
+    // "+" and the calling code
+    let visible_prefix = 1 + calling_code(number.country()).to_string().len();
+    match style {
+        MaskStyle::Compact => format!(
+            "{}{}{}",
+            &number[..visible_prefix],
+            MASK.to_string().repeat(6),
+            &number[number.len() - 2..]
+        ),
+        MaskStyle::E164 => mask(number, visible_prefix, 2, MASK),
+        MaskStyle::International => mask(
+            &format_e164(number, PhoneFormat::International),
+            visible_prefix,
+            2,
+            MASK,
+        ),
+        MaskStyle::Placeholder => PLACEHOLDER.to_string(),
+    }
+}
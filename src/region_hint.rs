@@ -0,0 +1,28 @@
+/// Supplies the region national numbers are read in, see [`crate::Parser::region_hints`].
+///
+/// Implement it on top of a GeoIP database, a user profile or any per-request context, so
+/// phonelib doesn't have to bundle that data. The provider is only consulted for inputs that
+/// are not in international form (starting with `+` or `00`), so costly lookups are skipped
+/// for the others.
+///
+/// Closures returning an ISO 3166 code implement it:
+///
+/// ```
+/// let parser = phonelib::Parser::new().region_hints(|| Some("GB".to_string()));
+///
+/// assert_eq!(parser.normalize("020 7946 0958"), Some("+442079460958".to_string()));
+/// ```
+pub trait RegionHintProvider: Send + Sync {
+    /// Returns the region (an ISO 3166 code, any case or alias) to read the number being
+    /// parsed in, or `None` to read it as an international number.
+    fn region_hint(&self) -> Option<String>;
+}
+
+impl<F> RegionHintProvider for F
+where
+    F: Fn() -> Option<String> + Send + Sync,
+{
+    fn region_hint(&self) -> Option<String> {
+        self()
+    }
+}
@@ -0,0 +1,152 @@
+use crate::constants::COUNTRIES;
+use crate::definitions::Country;
+use crate::metadata::BUNDLED;
+use crate::{
+    changes_since, contains_invalid_character, find_country, remove_unwanted_character, Metadata,
+    MetadataChange,
+};
+
+/// How the validity or country of a stored number changed after a metadata upgrade.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RevalidationStatus {
+    Unchanged,
+    /// The number was rejected by the previous metadata and is valid now.
+    BecameValid,
+    /// The number was valid under the previous metadata and is rejected now.
+    BecameInvalid,
+    /// The number is still valid but now belongs to another country.
+    CountryChanged,
+}
+
+/// Result of re-validating one stored number, see [`revalidate_stored`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevalidationOutcome {
+    pub number: String,
+    pub status: RevalidationStatus,
+    /// Id of the country assigned by the previous metadata, if the number was valid.
+    pub previous_country_id: Option<u16>,
+    /// Country assigned by the current metadata, if the number is valid.
+    pub country: Option<&'static Country>,
+}
+
+impl RevalidationOutcome {
+    /// Returns true when the stored number needs attention.
+    pub fn is_changed(&self) -> bool {
+        self.status != RevalidationStatus::Unchanged
+    }
+}
+
+/// Re-validates stored numbers against the current metadata and flags the ones whose
+/// validity or country assignment changed since `previous_metadata_version` (see
+/// [`crate::METADATA_VERSION`] and [`crate::changelog`]).
+///
+/// Returns one outcome per input number, in input order.
+pub fn revalidate_stored(
+    numbers: &[String],
+    previous_metadata_version: u32,
+) -> Vec<RevalidationOutcome> {
+    let previous = previous_metadata(
+        previous_metadata_version,
+        changes_since(previous_metadata_version),
+    );
+    revalidate_against(numbers, &previous)
+}
+
+pub(crate) fn revalidate_against(
+    numbers: &[String],
+    previous: &Metadata,
+) -> Vec<RevalidationOutcome> {
+    numbers
+        .iter()
+        .map(|number| {
+            let (previous_country_id, country) = if contains_invalid_character(number) {
+                (None, None)
+            } else {
+                let mut digits = number.clone();
+                remove_unwanted_character(&mut digits);
+                (
+                    find_country(previous, &digits).map(|country| country.id),
+                    find_country(&BUNDLED, &digits),
+                )
+            };
+
+            let status = match (previous_country_id, country) {
+                (None, None) => RevalidationStatus::Unchanged,
+                (None, Some(_)) => RevalidationStatus::BecameValid,
+                (Some(_), None) => RevalidationStatus::BecameInvalid,
+                (Some(previous), Some(current)) if previous != current.id => {
+                    RevalidationStatus::CountryChanged
+                }
+                (Some(_), Some(_)) => RevalidationStatus::Unchanged,
+            };
+
+            RevalidationOutcome {
+                number: number.clone(),
+                status,
+                previous_country_id,
+                country,
+            }
+        })
+        .collect()
+}
+
+// rebuilds the metadata at `version` by undoing the given changes, newest first
+pub(crate) fn previous_metadata<'a>(
+    version: u32,
+    changes: impl DoubleEndedIterator<Item = &'a MetadataChange>,
+) -> Metadata {
+    let mut metadata = Metadata::new(version, COUNTRIES.to_vec());
+
+    for change in changes.rev() {
+        let countries = metadata.countries_mut();
+        match *change {
+            MetadataChange::CountryAdded { id } => countries.retain(|country| country.id != id),
+            MetadataChange::CountryRemoved {
+                id,
+                code,
+                prefix,
+                phone_lengths,
+            } => {
+                // keep the table in id order, which is the order countries were matched in
+                let position = countries
+                    .iter()
+                    .position(|country| country.id > id)
+                    .unwrap_or(countries.len());
+                countries.insert(
+                    position,
+                    Country {
+                        id,
+                        name: code,
+                        code,
+                        phone_lengths,
+                        prefix,
+                    },
+                );
+            }
+            MetadataChange::PrefixChanged { id, before, .. } => {
+                for country in countries.iter_mut().filter(|country| country.id == id) {
+                    country.prefix = before;
+                }
+            }
+            MetadataChange::LengthsChanged { id, before, .. } => {
+                for country in countries.iter_mut().filter(|country| country.id == id) {
+                    country.phone_lengths = before;
+                }
+            }
+            MetadataChange::TypeLengthsChanged {
+                id,
+                number_type,
+                before,
+                ..
+            } => {
+                let before = before.map(|(shortest, longest)| shortest..=longest);
+                metadata.set_type_lengths(id, number_type, before);
+            }
+            MetadataChange::AreaCodesChanged { id, before, .. } => {
+                metadata.set_area_codes(id, before);
+            }
+        }
+    }
+
+    metadata
+}
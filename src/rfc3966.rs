@@ -0,0 +1,103 @@
+use crate::{strip_extension, PhoneNumber, E164};
+
+/// Turns a user supplied phone string into a safe `tel:` URI for an `href` attribute, or
+/// rejects it.
+///
+/// The input may be a plain number or a `tel:` URI with visual separators and an optional
+/// extension. Anything that could smuggle markup or another URI scheme (quotes, angle
+/// brackets, percent-encoding, control characters, letters outside of an extension marker)
+/// is rejected, and accepted numbers are normalized to E.164:
+/// `" tel:+1 (202) 555-0173 ext. 12"` becomes `"tel:+12025550173;ext=12"`.
+pub fn sanitize_tel_href(user_supplied: &str) -> Option<String> {
+    let input = user_supplied.trim();
+    let input = match input.get(..4) {
+        Some(scheme) if scheme.eq_ignore_ascii_case("tel:") => &input[4..],
+        _ => input,
+    };
+
+    let base = strip_extension(input);
+    let extension = &input[base.len()..];
+    let is_separator = |c: char| " -.()/".contains(c);
+
+    if base.is_empty()
+        || !base
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == '+' || is_separator(c))
+    {
+        return None;
+    }
+
+    let extension_digits: String = extension.chars().filter(char::is_ascii_digit).collect();
+    let extension_marker: String = extension
+        .chars()
+        .filter(|c| !c.is_ascii_digit() && !is_separator(*c))
+        .collect::<String>()
+        .to_ascii_lowercase();
+    if !matches!(
+        extension_marker.as_str(),
+        "" | ";ext=" | "x" | "ext" | "ext." | "#" | ","
+    ) || (extension_marker.is_empty() != extension_digits.is_empty())
+    {
+        return None;
+    }
+
+    let number = E164::parse(base)?;
+    match extension_digits.is_empty() {
+        true => Some(format!("tel:{}", number)),
+        false => Some(format!("tel:{};ext={}", number, extension_digits)),
+    }
+}
+
+/// Parses a `tel:` URI (RFC 3966) such as `"tel:+1-202-555-0173;ext=12"`, keeping its
+/// extension and `phone-context` parameter.
+///
+/// Local numbers are read in their `phone-context` when it is a global number prefix, e.g.
+/// `"tel:555-0173;phone-context=+1-202"`. Local numbers in a domain context can't be
+/// normalized and give `None`, as do inputs that aren't `tel:` URIs. Visual separators
+/// (`-`, `.`, `(`, `)`) are ignored, as are the other parameters such as `isub`.
+///
+/// ```
+/// let number = phonelib::parse_rfc3966("tel:+1-202-555-0173;ext=12").unwrap();
+///
+/// assert_eq!(number.e164(), "+12025550173");
+/// assert_eq!(number.extension(), Some("12"));
+/// ```
+pub fn parse_rfc3966(uri: &str) -> Option<PhoneNumber> {
+    let input = uri.trim();
+    let input = match input.get(..4) {
+        Some(scheme) if scheme.eq_ignore_ascii_case("tel:") => &input[4..],
+        _ => return None,
+    };
+
+    let mut parts = input.split(';');
+    let number = parts.next()?;
+    let mut extension = None;
+    let mut phone_context = None;
+    for parameter in parts {
+        let (name, value) = parameter.split_once('=').unwrap_or((parameter, ""));
+        if name.eq_ignore_ascii_case("ext") {
+            extension = Some(visual_digits(value).filter(|digits| !digits.is_empty())?);
+        } else if name.eq_ignore_ascii_case("phone-context") {
+            phone_context = Some(value);
+        }
+    }
+
+    let digits = match number.strip_prefix('+') {
+        Some(global_number) => visual_digits(global_number)?,
+        None => {
+            // a domain context doesn't tell the calling code
+            let context = phone_context?.strip_prefix('+')?;
+            visual_digits(context)? + &visual_digits(number)?
+        }
+    };
+    let parsed = E164::parse(&digits)?;
+
+    Some(PhoneNumber::from_parts(uri, parsed, extension, true).with_phone_context(phone_context))
+}
+
+// the digits of a part of a tel URI made of digits and visual separators only
+fn visual_digits(part: &str) -> Option<String> {
+    part.chars()
+        .all(|c| c.is_ascii_digit() || "-.()".contains(c))
+        .then(|| part.chars().filter(char::is_ascii_digit).collect())
+}
@@ -0,0 +1,26 @@
+use crate::definitions::Country;
+use crate::{best_fitting_countries, E164};
+
+/// ISO 3166 codes of the destinations commonly abused for International Revenue Share Fraud
+/// (IRSF): remote islands and territories with high termination rates, where fraudsters
+/// pump traffic to numbers they lease. See [`crate::Parser::deprioritize_irsf_prone`].
+pub const IRSF_PRONE_REGIONS: &[&str] = &[
+    "AC", "AG", "AI", "AQ", "CK", "CU", "DM", "GD", "IO", "JM", "KI", "KM", "KN", "LC", "MS", "NR",
+    "NU", "PG", "SB", "SH", "SO", "ST", "TC", "TK", "TV", "VC", "VG", "VU", "WF",
+];
+
+// the country of the number, passing over IRSF-prone destinations in favor of another
+// country sharing the calling code and fitting the number, unless `explicit` is the
+// IRSF-prone one
+pub(crate) fn avoid_irsf_prone(number: &E164, explicit: Option<&Country>) -> &'static Country {
+    let country = number.country();
+    let is_irsf_prone = |country: &Country| IRSF_PRONE_REGIONS.contains(&country.code);
+    if !is_irsf_prone(country) || explicit.is_some_and(|explicit| explicit.id == country.id) {
+        return country;
+    }
+
+    best_fitting_countries(&number[1..])
+        .into_iter()
+        .find(|candidate| !is_irsf_prone(candidate))
+        .unwrap_or(country)
+}
@@ -0,0 +1,118 @@
+// Experimental risk scoring, behind the `unstable` feature: range tags and profiles may change
+// in minor releases.
+
+use crate::definitions::Country;
+use crate::scratch::with_scratch;
+use crate::{extract_country_data, remove_unwanted_character, split_national_number};
+
+/// Known properties of a number range that matter when deciding how long a number can be
+/// trusted to reach the same subscriber.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RangeTag {
+    /// Premium-rate block, numbers are typically leased for a campaign and reassigned.
+    Premium,
+    /// Personal, follow-me or other non-geographic block often used for temporary numbers.
+    Temporary,
+    /// Range that never originates calls (unassigned, fictional or test blocks), so a call
+    /// claiming it as caller ID is spoofed.
+    DoNotOriginate,
+}
+
+/// Risk related information about a phone number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RiskProfile {
+    pub country: &'static Country,
+    /// Tags of the known ranges the number belongs to, empty when nothing is known.
+    pub tags: Vec<RangeTag>,
+}
+
+impl RiskProfile {
+    pub fn has_tag(&self, tag: RangeTag) -> bool {
+        self.tags.contains(&tag)
+    }
+}
+
+// (calling code, national number prefix, tag)
+const TAGGED_RANGES: &[(u32, &str, RangeTag)] = &[
+    (1, "500", RangeTag::Temporary),
+    (1, "533", RangeTag::Temporary),
+    (1, "544", RangeTag::Temporary),
+    (1, "566", RangeTag::Temporary),
+    (1, "577", RangeTag::Temporary),
+    (1, "588", RangeTag::Temporary),
+    (1, "900", RangeTag::Premium),
+    (33, "89", RangeTag::Premium),
+    (34, "803", RangeTag::Premium),
+    (34, "806", RangeTag::Premium),
+    (34, "807", RangeTag::Premium),
+    (39, "89", RangeTag::Premium),
+    (44, "70", RangeTag::Temporary),
+    (44, "90", RangeTag::Premium),
+    (44, "91", RangeTag::Premium),
+    (44, "98", RangeTag::Premium),
+    (49, "137", RangeTag::Premium),
+    (49, "700", RangeTag::Temporary),
+    (49, "900", RangeTag::Premium),
+    (61, "190", RangeTag::Premium),
+];
+
+/// Returns the risk profile of a phone number, or `None` if the number is not valid.
+pub fn risk_profile(phone_number: impl AsRef<str>) -> Option<RiskProfile> {
+    let mut phone_number = phone_number.as_ref().to_string();
+    remove_unwanted_character(&mut phone_number);
+    let (country, national_number) = split_national_number(&phone_number)?;
+    let mut tags: Vec<RangeTag> = TAGGED_RANGES
+        .iter()
+        .filter(|(prefix, range, _)| {
+            *prefix == country.prefix && national_number.starts_with(range)
+        })
+        .map(|(_, _, tag)| *tag)
+        .collect();
+
+    if is_do_not_originate_digits(&phone_number) {
+        tags.push(RangeTag::DoNotOriginate);
+    }
+
+    Some(RiskProfile { country, tags })
+}
+
+/// Returns true if the number belongs to a do-not-originate range, i.e. a range from which
+/// no legitimate call can come (unassigned NANP area codes and exchanges, the 555 block,
+/// 958/959 test exchanges). Numbers that cannot be parsed return `false`.
+pub fn is_do_not_originate(phone_number: &str) -> bool {
+    with_scratch(|digits| {
+        digits.push_str(phone_number);
+        remove_unwanted_character(digits);
+        extract_country_data(digits).is_some() && is_do_not_originate_digits(digits)
+    })
+}
+
+fn is_do_not_originate_digits(digits: &str) -> bool {
+    // only the NANP has well known structural do-not-originate ranges
+    match digits.strip_prefix('1') {
+        Some(national_number) if national_number.len() == 10 => {
+            is_nanp_do_not_originate(national_number.as_bytes())
+        }
+        _ => false,
+    }
+}
+
+// national_number is NPA-NXX-XXXX
+fn is_nanp_do_not_originate(national_number: &[u8]) -> bool {
+    let (npa, nxx) = (&national_number[0..3], &national_number[3..6]);
+    let is_n11 = |code: &[u8]| code[1] == b'1' && code[2] == b'1';
+
+    // area codes and exchanges can't start with 0 or 1, N11 codes are service codes
+    npa[0] <= b'1'
+        || nxx[0] <= b'1'
+        || is_n11(npa)
+        || is_n11(nxx)
+        // 37X and 96X are reserved, N9X is kept for expansion
+        || npa.starts_with(b"37")
+        || npa.starts_with(b"96")
+        || npa[1] == b'9'
+        // 555 is fictional/directory and 958/959 are used for plant testing
+        || nxx == b"555"
+        || nxx == b"958"
+        || nxx == b"959"
+}
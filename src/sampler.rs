@@ -0,0 +1,120 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use crate::definitions::Country;
+use crate::COUNTRIES;
+
+/// Collects anonymized statistics about the inputs a [`crate::Parser`] rejects, see
+/// [`crate::Parser::rejection_sampler`].
+///
+/// Only the shape of the sampled inputs is kept (digit count, character classes, likely
+/// country), never the inputs themselves, so the statistics can be exported from ingestion
+/// services without logging phone numbers.
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// let sampler = Arc::new(phonelib::RejectionSampler::new(1));
+/// let parser = phonelib::Parser::new().rejection_sampler(sampler.clone());
+///
+/// assert_eq!(parser.normalize("+961 1234"), None);
+/// assert_eq!(sampler.stats().rejected, 1);
+/// ```
+#[derive(Debug)]
+pub struct RejectionSampler {
+    one_in: u64,
+    rejected: AtomicU64,
+    stats: Mutex<RejectionStats>,
+}
+
+/// Statistics of a [`RejectionSampler`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RejectionStats {
+    /// Number of rejected inputs, sampled or not.
+    pub rejected: u64,
+    /// Number of rejected inputs the other statistics are computed from.
+    pub sampled: u64,
+    /// Number of sampled inputs per count of digits.
+    pub digit_counts: BTreeMap<usize, u64>,
+    /// Number of sampled inputs containing each class of characters.
+    pub character_classes: BTreeMap<CharacterClass, u64>,
+    /// Number of sampled inputs per candidate country, i.e. the hinted region or the first
+    /// country whose calling code starts the digits, `None` when there is none.
+    pub candidate_countries: BTreeMap<Option<&'static str>, u64>,
+}
+
+/// Class of the characters of a rejected input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CharacterClass {
+    Digit,
+    Plus,
+    /// Spaces, dashes, dots, slashes and parentheses.
+    Separator,
+    Letter,
+    /// Any other character, including non-ASCII digits.
+    Other,
+}
+
+impl RejectionSampler {
+    /// Creates a sampler computing statistics from one rejected input in `one_in`, all of
+    /// them for `1`. `0` is treated as `1`.
+    pub fn new(one_in: u32) -> RejectionSampler {
+        RejectionSampler {
+            one_in: u64::from(one_in.max(1)),
+            rejected: AtomicU64::new(0),
+            stats: Mutex::new(RejectionStats::default()),
+        }
+    }
+
+    /// Returns a snapshot of the statistics collected so far.
+    pub fn stats(&self) -> RejectionStats {
+        let mut stats = self
+            .stats
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+            .clone();
+        stats.rejected = self.rejected.load(Ordering::Relaxed);
+        stats
+    }
+
+    // records an input rejected by the parser, `region` being the hinted region if any
+    pub(crate) fn record(&self, phone_number: &str, region: Option<&'static Country>) {
+        let rejected = self.rejected.fetch_add(1, Ordering::Relaxed);
+        if !rejected.is_multiple_of(self.one_in) {
+            return;
+        }
+
+        let digits: String = phone_number.chars().filter(char::is_ascii_digit).collect();
+        let candidate = region.or_else(|| {
+            let digits = digits.trim_start_matches('0');
+            COUNTRIES
+                .iter()
+                .find(|country| digits.starts_with(&country.prefix_str()))
+        });
+        let mut classes: Vec<CharacterClass> = phone_number.chars().map(character_class).collect();
+        classes.sort();
+        classes.dedup();
+
+        let mut stats = self.stats.lock().unwrap_or_else(|error| error.into_inner());
+        stats.sampled += 1;
+        *stats.digit_counts.entry(digits.len()).or_default() += 1;
+        for class in classes {
+            *stats.character_classes.entry(class).or_default() += 1;
+        }
+        *stats
+            .candidate_countries
+            .entry(candidate.map(|country| country.code))
+            .or_default() += 1;
+    }
+}
+
+fn character_class(c: char) -> CharacterClass {
+    match c {
+        '0'..='9' => CharacterClass::Digit,
+        '+' => CharacterClass::Plus,
+        ' ' | '-' | '.' | '/' | '(' | ')' => CharacterClass::Separator,
+        c if c.is_alphabetic() => CharacterClass::Letter,
+        _ => CharacterClass::Other,
+    }
+}
@@ -0,0 +1,58 @@
+// Thread-local pool of string buffers for the intermediate values of the cleaning and
+// normalization hot paths, so server workloads don't allocate them on every call. Without
+// the `scratch-buffers` feature every buffer is a fresh allocation.
+
+#[cfg(feature = "scratch-buffers")]
+use std::cell::RefCell;
+
+// buffers kept per thread, enough for the nesting of the parser
+#[cfg(feature = "scratch-buffers")]
+const MAX_BUFFERS: usize = 4;
+// larger buffers are dropped so one huge input doesn't stay allocated
+#[cfg(feature = "scratch-buffers")]
+const MAX_CAPACITY: usize = 256;
+
+#[cfg(feature = "scratch-buffers")]
+thread_local! {
+    static BUFFERS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+// runs `f` with an empty buffer, calls can be nested
+pub(crate) fn with_scratch<R>(f: impl FnOnce(&mut String) -> R) -> R {
+    let mut buffer = take();
+    let result = f(&mut buffer);
+    give_back(buffer);
+    result
+}
+
+#[cfg(feature = "scratch-buffers")]
+fn take() -> String {
+    // the pool is gone while the thread is being destroyed
+    BUFFERS
+        .try_with(|buffers| buffers.borrow_mut().pop())
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
+
+#[cfg(feature = "scratch-buffers")]
+fn give_back(mut buffer: String) {
+    if buffer.capacity() > MAX_CAPACITY {
+        return;
+    }
+    buffer.clear();
+    let _ = BUFFERS.try_with(|buffers| {
+        let mut buffers = buffers.borrow_mut();
+        if buffers.len() < MAX_BUFFERS {
+            buffers.push(buffer);
+        }
+    });
+}
+
+#[cfg(not(feature = "scratch-buffers"))]
+fn take() -> String {
+    String::new()
+}
+
+#[cfg(not(feature = "scratch-buffers"))]
+fn give_back(_buffer: String) {}
@@ -0,0 +1,244 @@
+use std::collections::HashSet;
+
+use crate::definitions::Country;
+use crate::neighbors::likely_country_of;
+use crate::number_type::number_type_of;
+use crate::{find_country_by_code, PhoneNumberType, QuietHoursProvider, E164};
+
+/// Settings of [`scrub_campaign_list`]. Every filter is off by default, so the default
+/// options only normalize and deduplicate.
+#[derive(Default)]
+pub struct ScrubOptions {
+    default_region: Option<&'static Country>,
+    allowed_regions: Option<Vec<&'static str>>,
+    number_types: Option<Vec<PhoneNumberType>>,
+    blocklist: HashSet<E164>,
+    #[cfg(feature = "unstable")]
+    exclude_do_not_originate: bool,
+    quiet_hours: Option<(Box<dyn QuietHoursProvider>, i64)>,
+}
+
+/// Why [`scrub_campaign_list`] dropped a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScrubRejection {
+    /// The input is not a valid phone number.
+    Invalid,
+    /// The number was already listed by an earlier input.
+    Duplicate,
+    /// The number belongs to a region that isn't allowed.
+    RegionNotAllowed,
+    /// The number is not of an allowed type.
+    TypeNotAllowed,
+    /// The number is on the blocklist.
+    Blocklisted,
+    /// The number belongs to a do-not-originate range, see [`crate::is_do_not_originate`].
+    #[cfg(feature = "unstable")]
+    DoNotOriginate,
+}
+
+/// A number kept by [`scrub_campaign_list`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScrubbedNumber {
+    /// Position of the number in the input list.
+    pub index: usize,
+    pub number: E164,
+    pub country: &'static Country,
+    pub number_type: PhoneNumberType,
+    /// Whether the number may be called now, see [`ScrubOptions::quiet_hours`]. `None`
+    /// without quiet hours or when the local time of the country is unknown.
+    pub within_local_hours: Option<bool>,
+}
+
+/// Result of [`scrub_campaign_list`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScrubReport {
+    /// The numbers to call, in input order.
+    pub kept: Vec<ScrubbedNumber>,
+    /// The position in the input list and the reason of every dropped number, in input
+    /// order.
+    pub rejected: Vec<(usize, ScrubRejection)>,
+}
+
+impl ScrubReport {
+    /// Returns the number of inputs dropped for `reason`.
+    pub fn rejected_count(&self, reason: ScrubRejection) -> usize {
+        self.rejected
+            .iter()
+            .filter(|(_, rejection)| *rejection == reason)
+            .count()
+    }
+}
+
+impl ScrubOptions {
+    pub fn new() -> ScrubOptions {
+        ScrubOptions::default()
+    }
+
+    /// Reads numbers that are not in international form as national numbers of `region`
+    /// (an ISO 3166 code), like [`crate::normalize_phone_number_in_region`].
+    pub fn default_region(mut self, region: &str) -> ScrubOptions {
+        self.default_region = find_country_by_code(region);
+        self
+    }
+
+    /// Keeps the numbers of the given regions (ISO 3166 codes) only. Unknown regions are
+    /// ignored.
+    pub fn allowed_regions(mut self, regions: &[&str]) -> ScrubOptions {
+        self.allowed_regions = Some(
+            regions
+                .iter()
+                .filter_map(|region| find_country_by_code(region))
+                .map(|country| country.code)
+                .collect(),
+        );
+        self
+    }
+
+    /// Keeps the numbers of the given types only.
+    pub fn number_types(mut self, number_types: &[PhoneNumberType]) -> ScrubOptions {
+        self.number_types = Some(number_types.to_vec());
+        self
+    }
+
+    /// Keeps the numbers that can be mobiles only, including the
+    /// [`PhoneNumberType::FixedLineOrMobile`] numbers of the plans that don't tell them
+    /// apart, e.g. the NANP.
+    pub fn mobile_only(self) -> ScrubOptions {
+        self.number_types(&[PhoneNumberType::Mobile, PhoneNumberType::FixedLineOrMobile])
+    }
+
+    /// Drops the given numbers, e.g. a do-not-call list. Entries that are not valid numbers
+    /// are ignored.
+    pub fn blocklist<I>(mut self, numbers: I) -> ScrubOptions
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.blocklist.extend(
+            numbers
+                .into_iter()
+                .filter_map(|number| E164::parse(number.as_ref())),
+        );
+        self
+    }
+
+    /// Drops the numbers of do-not-originate ranges, which no subscriber answers.
+    #[cfg(feature = "unstable")]
+    pub fn exclude_do_not_originate(mut self, enabled: bool) -> ScrubOptions {
+        self.exclude_do_not_originate = enabled;
+        self
+    }
+
+    /// Tags the kept numbers with whether they may be called at `unix_seconds`, see
+    /// [`crate::is_within_local_hours`]. Numbers in quiet hours are tagged, not dropped, so
+    /// they can be scheduled later.
+    pub fn quiet_hours(
+        mut self,
+        provider: impl QuietHoursProvider + 'static,
+        unix_seconds: i64,
+    ) -> ScrubOptions {
+        self.quiet_hours = Some((Box::new(provider), unix_seconds));
+        self
+    }
+
+    fn parse(&self, phone_number: &str) -> Option<E164> {
+        match self.default_region {
+            Some(region) => {
+                let number = E164::parse_dialed_from(phone_number, region)?;
+                Some(number.with_country(likely_country_of(&number, region.code)))
+            }
+            None => E164::parse(phone_number),
+        }
+    }
+
+    fn check(&self, number: &E164, number_type: PhoneNumberType) -> Result<(), ScrubRejection> {
+        if let Some(allowed_regions) = &self.allowed_regions {
+            if !allowed_regions.contains(&number.country().code) {
+                return Err(ScrubRejection::RegionNotAllowed);
+            }
+        }
+        if let Some(number_types) = &self.number_types {
+            if !number_types.contains(&number_type) {
+                return Err(ScrubRejection::TypeNotAllowed);
+            }
+        }
+        if self.blocklist.contains(number) {
+            return Err(ScrubRejection::Blocklisted);
+        }
+        #[cfg(feature = "unstable")]
+        if self.exclude_do_not_originate && crate::is_do_not_originate(number) {
+            return Err(ScrubRejection::DoNotOriginate);
+        }
+        Ok(())
+    }
+}
+
+/// Prepares a list of numbers for an outbound campaign in one pass: numbers are normalized,
+/// deduplicated (the first occurrence is kept), filtered by region, type, blocklist and
+/// do-not-originate ranges as set in `options`, then tagged with quiet hours.
+///
+/// ```
+/// use phonelib::{scrub_campaign_list, ScrubOptions, ScrubRejection};
+///
+/// let options = ScrubOptions::new()
+///     .default_region("FR")
+///     .allowed_regions(&["FR", "BE"])
+///     .mobile_only()
+///     .blocklist(["+33 6 99 99 99 99"]);
+/// let report = scrub_campaign_list(
+///     ["06 12 34 56 78", "+33612345678", "01 23 45 67 89", "+4915112345678", "0699999999", "hello"],
+///     &options,
+/// );
+///
+/// assert_eq!(report.kept.len(), 1);
+/// assert_eq!(report.kept[0].number, "+33612345678");
+/// assert_eq!(report.rejected_count(ScrubRejection::Duplicate), 1);
+/// assert_eq!(report.rejected_count(ScrubRejection::TypeNotAllowed), 1);
+/// assert_eq!(report.rejected_count(ScrubRejection::RegionNotAllowed), 1);
+/// assert_eq!(report.rejected_count(ScrubRejection::Blocklisted), 1);
+/// assert_eq!(report.rejected_count(ScrubRejection::Invalid), 1);
+/// ```
+pub fn scrub_campaign_list<I>(numbers: I, options: &ScrubOptions) -> ScrubReport
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut report = ScrubReport::default();
+    let mut seen = HashSet::new();
+
+    for (index, phone_number) in numbers.into_iter().enumerate() {
+        let Some(number) = options.parse(phone_number.as_ref()) else {
+            report.rejected.push((index, ScrubRejection::Invalid));
+            continue;
+        };
+        if !seen.insert(number) {
+            report.rejected.push((index, ScrubRejection::Duplicate));
+            continue;
+        }
+
+        let number_type = number_type_of(&number);
+        if let Err(rejection) = options.check(&number, number_type) {
+            report.rejected.push((index, rejection));
+            continue;
+        }
+
+        let country = number.country();
+        let within_local_hours =
+            options
+                .quiet_hours
+                .as_ref()
+                .and_then(|(provider, unix_seconds)| {
+                    let local = provider.local_time(country, *unix_seconds)?;
+                    Some(!provider.is_quiet(country, &local))
+                });
+        report.kept.push(ScrubbedNumber {
+            index,
+            number,
+            country,
+            number_type,
+            within_local_hours,
+        });
+    }
+
+    report
+}
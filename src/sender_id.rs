@@ -0,0 +1,47 @@
+use crate::find_country_by_code;
+
+// regions whose carriers reject alphanumeric sender ids, messages have to come from a long
+// number or a short code there
+const PROHIBITED_REGIONS: &[&str] = &[
+    "AR", "BR", "CA", "CL", "CN", "CO", "CR", "EC", "GT", "KR", "MX", "PR", "SV", "US", "VE",
+];
+
+// (region, minimum length, maximum length) of the sender ids of the regions whose regulator
+// restricts the usual 1 to 11 characters, e.g. the 6 character headers of India
+const LENGTH_RULES: &[(&str, usize, usize)] = &[("IN", 6, 6)];
+
+/// Tells whether `sender_id` can be sent as an alphanumeric SMS originator
+/// ([`crate::Ton::Alphanumeric`]): 1 to 11 characters among ASCII letters, digits, spaces
+/// and `+ - _ & .`, with at least one letter so it isn't taken for a number.
+///
+/// ```
+/// assert!(phonelib::is_valid_alpha_sender_id("ACME Bank"));
+/// assert!(!phonelib::is_valid_alpha_sender_id("ACME Bank Ltd"));
+/// assert!(!phonelib::is_valid_alpha_sender_id("12345"));
+/// ```
+pub fn is_valid_alpha_sender_id(sender_id: &str) -> bool {
+    has_valid_charset(sender_id) && (1..=11).contains(&sender_id.len())
+}
+
+/// Same as [`is_valid_alpha_sender_id`] with the rules of `region` (an ISO 3166 code):
+/// `Some(false)` in the regions prohibiting alphanumeric senders (e.g. the US and Canada)
+/// and for ids of a length the regulator doesn't allow. Returns `None` for unknown regions.
+pub fn is_valid_alpha_sender_id_in_region(sender_id: &str, region: &str) -> Option<bool> {
+    let country = find_country_by_code(region)?;
+    if PROHIBITED_REGIONS.contains(&country.code) {
+        return Some(false);
+    }
+
+    let (min, max) = LENGTH_RULES
+        .iter()
+        .find(|(code, _, _)| *code == country.code)
+        .map_or((1, 11), |&(_, min, max)| (min, max));
+    Some(has_valid_charset(sender_id) && (min..=max).contains(&sender_id.len()))
+}
+
+fn has_valid_charset(sender_id: &str) -> bool {
+    sender_id
+        .bytes()
+        .all(|b| b.is_ascii_alphanumeric() || matches!(b, b' ' | b'+' | b'-' | b'_' | b'&' | b'.'))
+        && sender_id.bytes().any(|b| b.is_ascii_alphabetic())
+}
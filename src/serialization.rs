@@ -0,0 +1,81 @@
+// Serde support, behind the `serde` feature. Numbers are written in E.164 so stored values
+// don't depend on the formatting of the input, countries are read back as the entry of the
+// bundled metadata with the same id.
+
+use serde::de::{self, Deserializer};
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+
+use crate::definitions::Country;
+use crate::{country_by_id, find_country_by_code, PhoneNumber, E164};
+
+#[derive(Deserialize)]
+struct CountryKey {
+    id: Option<u16>,
+    code: Option<String>,
+}
+
+/// Reads the entry of the bundled metadata with the serialized `id`, or `code` when there is
+/// no id, the other fields are ignored.
+impl<'de> Deserialize<'de> for Country {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Country, D::Error> {
+        let key = CountryKey::deserialize(deserializer)?;
+        let country = match (key.id, key.code) {
+            (Some(id), _) => country_by_id(id),
+            (None, Some(code)) => find_country_by_code(&code),
+            (None, None) => return Err(de::Error::missing_field("id")),
+        };
+        country
+            .copied()
+            .ok_or_else(|| de::Error::custom("unknown country"))
+    }
+}
+
+/// Written as its string, e.g. `"+12025550173"`.
+impl Serialize for E164 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for E164 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<E164, D::Error> {
+        let phone_number = String::deserialize(deserializer)?;
+        E164::parse(&phone_number).ok_or_else(|| de::Error::custom("invalid phone number"))
+    }
+}
+
+/// Written as `{"e164": "+12025550173", "country": "US", "extension": "45"}`, the country
+/// telling apart the countries sharing a calling code. The raw input isn't kept: a read
+/// number returns its E.164 form as [`PhoneNumber::raw`].
+impl Serialize for PhoneNumber {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("PhoneNumber", 3)?;
+        state.serialize_field("e164", &self.e164())?;
+        state.serialize_field("country", self.country().code)?;
+        state.serialize_field("extension", &self.extension())?;
+        state.end()
+    }
+}
+
+#[derive(Deserialize)]
+struct PhoneNumberFields {
+    e164: String,
+    country: String,
+    #[serde(default)]
+    extension: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for PhoneNumber {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<PhoneNumber, D::Error> {
+        let fields = PhoneNumberFields::deserialize(deserializer)?;
+        let number = PhoneNumber::parse_with_country(&fields.e164, &fields.country)
+            .ok_or_else(|| de::Error::custom("invalid phone number"))?;
+        Ok(PhoneNumber::from_parts(
+            &fields.e164,
+            number.e164(),
+            fields.extension,
+            number.is_valid(),
+        ))
+    }
+}
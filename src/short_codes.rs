@@ -0,0 +1,68 @@
+use crate::constants::{CARRIER_SHORT_CODES, EMERGENCY_NUMBERS};
+use crate::{find_country_by_code, PhoneNumberType};
+
+/// Returns true if `number` is an emergency number of `region` (an ISO 3166 code), e.g.
+/// `"112"` in Germany or `"911"` in the United States. Spaces and dashes are ignored, other
+/// characters make the number invalid. `112` is accepted in the regions whose emergency
+/// numbers aren't known, as mobile networks route it everywhere.
+///
+/// ```
+/// use phonelib::is_emergency_number;
+///
+/// assert!(is_emergency_number("112", "DE"));
+/// assert!(is_emergency_number("999", "GB"));
+/// assert!(!is_emergency_number("911", "DE"));
+/// ```
+pub fn is_emergency_number(number: &str, region: &str) -> bool {
+    short_code_type(number, region) == Some(PhoneNumberType::Emergency)
+}
+
+/// Returns true if `number` is a short code of `region` (an ISO 3166 code): an emergency
+/// number (see [`is_emergency_number`]) or a carrier or service short code, e.g. `"611"`
+/// for the customer care of US carriers. Short codes are not valid phone numbers, they are
+/// only dialed within their region.
+pub fn is_short_code(number: &str, region: &str) -> bool {
+    short_code_type(number, region).is_some()
+}
+
+/// Returns [`PhoneNumberType::Emergency`] or [`PhoneNumberType::ShortCode`] if `number` is a
+/// short code of `region` (an ISO 3166 code), or `None` otherwise.
+///
+/// ```
+/// use phonelib::{short_code_type, PhoneNumberType};
+///
+/// assert_eq!(short_code_type("911", "US"), Some(PhoneNumberType::Emergency));
+/// assert_eq!(short_code_type("611", "US"), Some(PhoneNumberType::ShortCode));
+/// assert_eq!(short_code_type("+1 611", "US"), None);
+/// ```
+pub fn short_code_type(number: &str, region: &str) -> Option<PhoneNumberType> {
+    let country = find_country_by_code(region)?;
+    let digits = short_code_digits(number)?;
+    let listed = |table: &[(&str, &'static [&'static str])]| {
+        table
+            .iter()
+            .find(|(code, _)| *code == country.code)
+            .map(|(_, numbers)| numbers.contains(&digits.as_str()))
+    };
+
+    if listed(EMERGENCY_NUMBERS).unwrap_or(digits == "112") {
+        Some(PhoneNumberType::Emergency)
+    } else if listed(CARRIER_SHORT_CODES) == Some(true) {
+        Some(PhoneNumberType::ShortCode)
+    } else {
+        None
+    }
+}
+
+// the digits of a short code, None if it has other characters than spaces and dashes
+fn short_code_digits(number: &str) -> Option<String> {
+    let number = number.trim();
+    if number.is_empty()
+        || !number
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, ' ' | '-'))
+    {
+        return None;
+    }
+    Some(number.chars().filter(char::is_ascii_digit).collect())
+}
@@ -0,0 +1,114 @@
+use crate::E164;
+
+/// Type of number (TON) of an SMPP address, see SMPP v3.4 section 5.2.5.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Ton {
+    Unknown = 0,
+    International = 1,
+    National = 2,
+    NetworkSpecific = 3,
+    SubscriberNumber = 4,
+    Alphanumeric = 5,
+    Abbreviated = 6,
+}
+
+/// Numbering plan indicator (NPI) of an SMPP address, see SMPP v3.4 section 5.2.6.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Npi {
+    Unknown = 0,
+    /// ISDN telephony numbering plan (E.163/E.164).
+    Isdn = 1,
+    Data = 3,
+    Telex = 4,
+    LandMobile = 6,
+    National = 8,
+    Private = 9,
+    Ermes = 10,
+    Internet = 14,
+    WapClientId = 18,
+}
+
+impl TryFrom<u8> for Ton {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Ton, u8> {
+        Ok(match value {
+            0 => Ton::Unknown,
+            1 => Ton::International,
+            2 => Ton::National,
+            3 => Ton::NetworkSpecific,
+            4 => Ton::SubscriberNumber,
+            5 => Ton::Alphanumeric,
+            6 => Ton::Abbreviated,
+            _ => return Err(value),
+        })
+    }
+}
+
+impl TryFrom<u8> for Npi {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Npi, u8> {
+        Ok(match value {
+            0 => Npi::Unknown,
+            1 => Npi::Isdn,
+            3 => Npi::Data,
+            4 => Npi::Telex,
+            6 => Npi::LandMobile,
+            8 => Npi::National,
+            9 => Npi::Private,
+            10 => Npi::Ermes,
+            14 => Npi::Internet,
+            18 => Npi::WapClientId,
+            _ => return Err(value),
+        })
+    }
+}
+
+/// The `*_addr_ton`, `*_addr_npi` and `*_addr` fields of an SMPP PDU.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SmppAddress {
+    pub ton: Ton,
+    pub npi: Npi,
+    pub address: String,
+}
+
+impl SmppAddress {
+    /// Returns the GSM 03.40 type-of-address octet (e.g. `0x91` for international ISDN).
+    pub fn type_of_address(&self) -> u8 {
+        0x80 | ((self.ton as u8 & 0x07) << 4) | (self.npi as u8 & 0x0f)
+    }
+}
+
+/// Converts a phone number to an international ISDN SMPP address: TON 1, NPI 1 and the
+/// E.164 digits without the leading `+`.
+pub fn to_smpp_address(phone_number: &str) -> Option<SmppAddress> {
+    let number = E164::parse(phone_number)?;
+
+    Some(SmppAddress {
+        ton: Ton::International,
+        npi: Npi::Isdn,
+        address: number[1..].to_string(),
+    })
+}
+
+/// Converts an SMPP address back to an E.164 number.
+///
+/// Only ISDN (or unknown plan) addresses of international or unknown type can be converted,
+/// other types (national, alphanumeric, short codes) don't carry a country code.
+pub fn from_smpp_address(address: &SmppAddress) -> Option<String> {
+    if !matches!(address.npi, Npi::Isdn | Npi::Unknown) {
+        return None;
+    }
+
+    match address.ton {
+        Ton::International if address.address.bytes().all(|b| b.is_ascii_digit()) => {
+            E164::parse(&address.address).map(String::from)
+        }
+        // gateways often send unknown TON with a '+' or "00" prefixed international number
+        Ton::Unknown if address.address.starts_with('+') || address.address.starts_with("00") => {
+            E164::parse(&address.address).map(String::from)
+        }
+        _ => None,
+    }
+}
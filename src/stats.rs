@@ -0,0 +1,97 @@
+// Counters of the work done by the parsing functions, collected per thread with
+// `ParseStats::collect`. Without the `stats` feature the counters compile to nothing.
+
+#[cfg(feature = "stats")]
+use std::cell::Cell;
+
+/// Work done by the parsing functions called within [`ParseStats::collect`], to find out
+/// why some inputs are slower than others.
+///
+/// ```
+/// let (normalized, stats) =
+///     phonelib::ParseStats::collect(|| phonelib::normalize_phone_number("+961 79 123 123"));
+///
+/// assert_eq!(normalized, Some("+96179123123".to_string()));
+/// assert!(stats.bytes_scanned >= "+961 79 123 123".len());
+/// assert!(stats.candidates_considered > 0);
+/// ```
+#[cfg(feature = "stats")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParseStats {
+    /// Bytes of input read while cleaning and validating numbers.
+    pub bytes_scanned: usize,
+    /// Countries tried against a number before one fit, or all of them if none did.
+    pub candidates_considered: usize,
+    /// Lookups in the metadata tables: country, region code and side table lookups.
+    pub table_lookups: usize,
+}
+
+#[cfg(feature = "stats")]
+thread_local! {
+    static CURRENT: Cell<Option<ParseStats>> = const { Cell::new(None) };
+}
+
+#[cfg(feature = "stats")]
+impl ParseStats {
+    /// Runs `f` and returns its result with the work done by the parsing functions it
+    /// called on the current thread. Calls can be nested, the outer call counts the work
+    /// of the inner ones too.
+    pub fn collect<R>(f: impl FnOnce() -> R) -> (R, ParseStats) {
+        let outer = CURRENT.with(|current| current.replace(Some(ParseStats::default())));
+        let result = f();
+        let stats = CURRENT
+            .with(|current| current.replace(outer))
+            .unwrap_or_default();
+
+        if let Some(outer) = outer {
+            CURRENT.with(|current| current.set(Some(outer.add(stats))));
+        }
+        (result, stats)
+    }
+
+    fn add(self, other: ParseStats) -> ParseStats {
+        ParseStats {
+            bytes_scanned: self.bytes_scanned + other.bytes_scanned,
+            candidates_considered: self.candidates_considered + other.candidates_considered,
+            table_lookups: self.table_lookups + other.table_lookups,
+        }
+    }
+}
+
+#[cfg(feature = "stats")]
+fn record(update: impl FnOnce(&mut ParseStats)) {
+    // nothing is recorded outside of `ParseStats::collect` or while the thread is destroyed
+    let _ = CURRENT.try_with(|current| {
+        if let Some(mut stats) = current.get() {
+            update(&mut stats);
+            current.set(Some(stats));
+        }
+    });
+}
+
+#[cfg(feature = "stats")]
+pub(crate) fn record_bytes_scanned(bytes: usize) {
+    record(|stats| stats.bytes_scanned += bytes);
+}
+
+#[cfg(feature = "stats")]
+pub(crate) fn record_candidate() {
+    record(|stats| stats.candidates_considered += 1);
+}
+
+#[cfg(feature = "stats")]
+pub(crate) fn record_table_lookup() {
+    record(|stats| stats.table_lookups += 1);
+}
+
+#[cfg(not(feature = "stats"))]
+#[inline(always)]
+pub(crate) fn record_bytes_scanned(_bytes: usize) {}
+
+#[cfg(not(feature = "stats"))]
+#[inline(always)]
+pub(crate) fn record_candidate() {}
+
+#[cfg(not(feature = "stats"))]
+#[inline(always)]
+pub(crate) fn record_table_lookup() {}
@@ -0,0 +1,135 @@
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+
+use crate::E164;
+
+/// A set of phone numbers keyed by their E.164 form, remembering how each number was
+/// written when it was inserted, e.g. the numbers last pushed to a messaging provider.
+///
+/// ```
+/// let mut numbers = phonelib::PhoneNumberSet::new();
+///
+/// assert!(numbers.insert("+33 6 12 34 56 78"));
+/// assert!(!numbers.insert("+33612345678"));
+/// assert!(numbers.contains("0033 6 12 34 56 78"));
+/// assert_eq!(numbers.raw("+33612345678"), Some("+33 6 12 34 56 78"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PhoneNumberSet {
+    numbers: BTreeMap<E164, String>,
+}
+
+impl PhoneNumberSet {
+    pub fn new() -> PhoneNumberSet {
+        PhoneNumberSet::default()
+    }
+
+    /// Adds a number, returns false if it is not valid or already in the set, in which case
+    /// the form inserted first is kept.
+    pub fn insert(&mut self, phone_number: &str) -> bool {
+        let Some(number) = E164::parse(phone_number) else {
+            return false;
+        };
+        match self.numbers.entry(number) {
+            Entry::Vacant(entry) => {
+                entry.insert(phone_number.to_string());
+                true
+            }
+            Entry::Occupied(_) => false,
+        }
+    }
+
+    /// Tells whether the set holds the number, whatever its formatting.
+    pub fn contains(&self, phone_number: &str) -> bool {
+        E164::parse(phone_number).is_some_and(|number| self.numbers.contains_key(&number))
+    }
+
+    /// Returns the number as it was written when inserted.
+    pub fn raw(&self, phone_number: &str) -> Option<&str> {
+        let number = E164::parse(phone_number)?;
+        self.numbers.get(&number).map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.numbers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.numbers.is_empty()
+    }
+
+    /// Iterates over the numbers and the way they were written, ordered like [`E164`].
+    pub fn iter(&self) -> impl Iterator<Item = (E164, &str)> {
+        self.numbers
+            .iter()
+            .map(|(number, raw)| (*number, raw.as_str()))
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<S> for PhoneNumberSet {
+    fn from_iter<I: IntoIterator<Item = S>>(numbers: I) -> PhoneNumberSet {
+        let mut set = PhoneNumberSet::new();
+        for number in numbers {
+            set.insert(number.as_ref());
+        }
+        set
+    }
+}
+
+/// Changes between two versions of a list of numbers, see [`sync_delta`]. Each list is
+/// ordered like [`E164`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SyncDelta {
+    /// Numbers of the current list missing from the previous one.
+    pub added: Vec<E164>,
+    /// Numbers of the previous list missing from the current one.
+    pub removed: Vec<E164>,
+    /// Numbers of both lists that are written differently, e.g. `"0612345678"` that became
+    /// `"+33 6 12 34 56 78"`.
+    pub changed_format: Vec<E164>,
+}
+
+impl SyncDelta {
+    /// True if the lists hold the same numbers written the same way.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed_format.is_empty()
+    }
+}
+
+/// Computes what changed between the numbers already synced and the current list, by
+/// E.164 number, so connectors push only real changes downstream: reformatting a number
+/// doesn't remove and add it again, it is listed in [`SyncDelta::changed_format`].
+///
+/// Invalid numbers of `current` are left out, and a number listed several times is compared
+/// in its first form, like [`PhoneNumberSet::insert`].
+///
+/// ```
+/// use phonelib::{sync_delta, PhoneNumberSet};
+///
+/// let previous: PhoneNumberSet = ["+33612345678", "+4915112345678"].into_iter().collect();
+/// let current = ["+33 6 12 34 56 78".to_string(), "+12025550173".to_string()];
+/// let delta = sync_delta(&previous, &current);
+///
+/// assert_eq!(delta.added, ["+12025550173"]);
+/// assert_eq!(delta.removed, ["+4915112345678"]);
+/// assert_eq!(delta.changed_format, ["+33612345678"]);
+/// ```
+pub fn sync_delta(previous: &PhoneNumberSet, current: &[String]) -> SyncDelta {
+    let current: PhoneNumberSet = current.iter().collect();
+    let mut delta = SyncDelta::default();
+
+    for (number, raw) in current.iter() {
+        match previous.numbers.get(&number) {
+            None => delta.added.push(number),
+            Some(previous_raw) if previous_raw != raw => delta.changed_format.push(number),
+            Some(_) => {}
+        }
+    }
+    delta.removed = previous
+        .iter()
+        .filter(|(number, _)| !current.numbers.contains_key(number))
+        .map(|(number, _)| number)
+        .collect();
+
+    delta
+}
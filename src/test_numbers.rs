@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use crate::E164;
+
+/// Test numbers registered by the application, e.g. the numbers given to app store
+/// reviewers or the Firebase test numbers, so OTP backends can skip real SMS delivery for
+/// them. See [`crate::Parser::test_numbers`].
+///
+/// Numbers are matched in E.164 form whatever their formatting, extensions are ignored.
+/// The registry can be shared between parsers and updated at runtime.
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use phonelib::{Parser, PhoneNumberType, TestNumber, TestNumberRegistry};
+///
+/// let registry = Arc::new(TestNumberRegistry::new());
+/// registry.register("+1 650-555-3434", TestNumber::new("app review").verification_code("654321"));
+/// let parser = Parser::new().test_numbers(registry.clone());
+///
+/// assert_eq!(parser.number_type("+16505553434"), Some(PhoneNumberType::TestNumber));
+/// let test_number = parser.test_number("+1 (650) 555-3434").unwrap();
+/// assert_eq!(test_number.verification_code.as_deref(), Some("654321"));
+///
+/// registry.unregister("+16505553434");
+/// assert_eq!(parser.test_number("+16505553434"), None);
+/// ```
+#[derive(Debug, Default)]
+pub struct TestNumberRegistry {
+    numbers: RwLock<HashMap<E164, TestNumber>>,
+}
+
+/// A registered test number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestNumber {
+    /// What the number is for, e.g. `"app review"`.
+    pub label: String,
+    /// The code to accept instead of sending one, if the number has a fixed one.
+    pub verification_code: Option<String>,
+}
+
+impl TestNumber {
+    pub fn new(label: &str) -> TestNumber {
+        TestNumber {
+            label: label.to_string(),
+            verification_code: None,
+        }
+    }
+
+    /// Sets the fixed verification code of the number.
+    pub fn verification_code(mut self, code: &str) -> TestNumber {
+        self.verification_code = Some(code.to_string());
+        self
+    }
+}
+
+impl TestNumberRegistry {
+    pub fn new() -> TestNumberRegistry {
+        TestNumberRegistry::default()
+    }
+
+    /// Registers `phone_number`, replacing its previous registration if any. Returns false
+    /// if the number can't be normalized.
+    pub fn register(&self, phone_number: &str, test_number: TestNumber) -> bool {
+        let Some(number) = E164::parse(phone_number) else {
+            return false;
+        };
+        self.write().insert(number, test_number);
+        true
+    }
+
+    /// Removes `phone_number` from the registry and returns its registration.
+    pub fn unregister(&self, phone_number: &str) -> Option<TestNumber> {
+        self.write().remove(&E164::parse(phone_number)?)
+    }
+
+    /// Returns the registration of `phone_number`, if it is a test number.
+    pub fn get(&self, phone_number: &str) -> Option<TestNumber> {
+        self.get_e164(&E164::parse(phone_number)?)
+    }
+
+    pub fn is_test_number(&self, phone_number: &str) -> bool {
+        self.get(phone_number).is_some()
+    }
+
+    pub(crate) fn get_e164(&self, number: &E164) -> Option<TestNumber> {
+        self.numbers
+            .read()
+            .unwrap_or_else(|error| error.into_inner())
+            .get(number)
+            .cloned()
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, HashMap<E164, TestNumber>> {
+        self.numbers
+            .write()
+            .unwrap_or_else(|error| error.into_inner())
+    }
+}
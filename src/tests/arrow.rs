@@ -0,0 +1,88 @@
+use arrow_array::{Array, BooleanArray, LargeStringArray, StringArray};
+
+use crate::{
+    country_array, extract_country, is_valid_array, is_valid_phone_number, normalize_array,
+    normalize_phone_number,
+};
+
+const PHONE_NUMBERS: [Option<&str>; 5] = [
+    Some("+96179123123"),
+    None,
+    Some("+1 (234) 567-8990"),
+    Some("invalid_phone_number"),
+    Some("+33612345678"),
+];
+
+#[test]
+fn test_normalize_array() {
+    let normalized = normalize_array(&StringArray::from(PHONE_NUMBERS.to_vec()));
+
+    assert_eq!(
+        normalized,
+        StringArray::from(vec![
+            Some("+96179123123"),
+            None,
+            Some("+12345678990"),
+            None,
+            Some("+33612345678"),
+        ])
+    );
+    let large = normalize_array(&LargeStringArray::from(PHONE_NUMBERS.to_vec()));
+    assert_eq!(large.value(2), "+12345678990");
+}
+
+#[test]
+fn test_is_valid_array() {
+    let validity = is_valid_array(&StringArray::from(PHONE_NUMBERS.to_vec()));
+
+    assert_eq!(
+        validity,
+        BooleanArray::from(vec![Some(true), None, Some(true), Some(false), Some(true)])
+    );
+}
+
+#[test]
+fn test_country_array() {
+    let countries = country_array(&StringArray::from(PHONE_NUMBERS.to_vec()));
+    let codes = countries.downcast_dict::<StringArray>().unwrap();
+
+    assert_eq!(
+        codes.into_iter().collect::<Vec<_>>(),
+        [Some("LB"), None, Some("US"), None, Some("FR")]
+    );
+    assert_eq!(countries.values().len(), 3);
+}
+
+#[test]
+fn test_kernels_match_scalar_functions() {
+    let fixtures: Vec<String> = super::tests::phone_numbers()
+        .map(|fixture| fixture.phone_number)
+        .collect();
+    let array = StringArray::from(fixtures.clone());
+    let normalized = normalize_array(&array);
+    let validity = is_valid_array(&array);
+    let countries = country_array(&array);
+    let codes: Vec<_> = countries
+        .downcast_dict::<StringArray>()
+        .unwrap()
+        .into_iter()
+        .collect();
+
+    for (index, fixture) in fixtures.iter().enumerate() {
+        let fixture = fixture.to_string();
+        assert_eq!(
+            normalized
+                .is_valid(index)
+                .then(|| normalized.value(index).to_string()),
+            normalize_phone_number(fixture.clone())
+        );
+        assert_eq!(
+            validity.value(index),
+            is_valid_phone_number(fixture.clone())
+        );
+        assert_eq!(
+            codes[index],
+            extract_country(fixture).map(|country| country.code)
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use crate::AsYouTypeFormatter;
+
+fn typed(region: &str, input: &str) -> Vec<String> {
+    let mut formatter = AsYouTypeFormatter::new(region);
+    input.chars().map(|c| formatter.input_digit(c)).collect()
+}
+
+#[test]
+fn test_as_you_type_national() {
+    assert_eq!(typed("FR", "0612345678").last().unwrap(), "06 12 34 56 78");
+    assert_eq!(typed("FR", "612345678").last().unwrap(), "6 12 34 56 78");
+    assert_eq!(
+        typed("US", "2025550173"),
+        [
+            "(2",
+            "(20",
+            "(202",
+            "(202) 5",
+            "(202) 55",
+            "(202) 555",
+            "(202) 555-0",
+            "(202) 555-01",
+            "(202) 555-017",
+            "(202) 555-0173",
+        ]
+    );
+    assert_eq!(typed("GB", "02079460958").last().unwrap(), "020 7946 0958");
+    assert_eq!(typed("GB", "07700900123").last().unwrap(), "07700 900123");
+    // separators typed by the user are ignored
+    assert_eq!(typed("FR", "06 12").last().unwrap(), "06 12");
+}
+
+#[test]
+fn test_as_you_type_international() {
+    assert_eq!(
+        typed("FR", "+33612"),
+        ["+", "+3", "+33", "+33 6", "+33 6 1", "+33 6 12"]
+    );
+    assert_eq!(
+        typed("ZZ", "+12025550173").last().unwrap(),
+        "+1 202-555-0173"
+    );
+    // without formatting metadata the national number is kept as typed
+    assert_eq!(typed("FR", "+96179123123").last().unwrap(), "+961 79123123");
+    // + only starts the input
+    assert_eq!(typed("FR", "06+1").last().unwrap(), "06 1");
+}
+
+#[test]
+fn test_as_you_type_fallbacks() {
+    // too many digits for the patterns of the region
+    assert_eq!(typed("FR", "06123456789").last().unwrap(), "06123456789");
+    assert_eq!(typed("ZZ", "0612").last().unwrap(), "0612");
+    assert_eq!(typed("LB", "79123123").last().unwrap(), "79123123");
+
+    let mut formatter = AsYouTypeFormatter::new("FR");
+    formatter.input_digit('+');
+    formatter.input_digit('4');
+    formatter.clear();
+    assert_eq!(formatter.input_digit('0'), "0");
+}
@@ -0,0 +1,34 @@
+use crate::canonical_for_attestation;
+
+#[test]
+fn test_canonical_for_attestation() {
+    let canonical = Some("12025550173".to_string());
+
+    assert_eq!(canonical_for_attestation("+12025550173"), canonical);
+    assert_eq!(canonical_for_attestation("+1 (202) 555-0173"), canonical);
+    assert_eq!(
+        canonical_for_attestation("+1.202.555.0173 ext. 12"),
+        canonical
+    );
+    assert_eq!(
+        canonical_for_attestation("+1 202 555 0173;ext=12"),
+        canonical
+    );
+    assert_eq!(canonical_for_attestation("tel:+1-202-555-0173"), canonical);
+    assert_eq!(canonical_for_attestation("TEL:+1-202-555-0173"), canonical);
+    assert_eq!(
+        canonical_for_attestation("+96109123123"),
+        Some("9619123123".to_string())
+    );
+}
+
+#[test]
+fn test_canonical_for_attestation_rejects_garbage() {
+    assert_eq!(canonical_for_attestation("+1 202 CALL NOW"), None);
+    assert_eq!(
+        canonical_for_attestation("sip:+12025550173@example.com"),
+        None
+    );
+    assert_eq!(canonical_for_attestation("+987654321"), None);
+    assert_eq!(canonical_for_attestation(""), None);
+}
@@ -0,0 +1,56 @@
+use crate::{is_valid_phone_number, newline_offsets, validate_packed};
+
+#[test]
+fn test_validate_packed_newline_slab() {
+    let slab = b"+96179123123\n+1 (234) 567-8990\r\ninvalid_phone_number\n+1 (234) 567-890\n";
+    let offsets = newline_offsets(slab);
+    let validity = validate_packed(slab, &offsets);
+
+    assert_eq!(offsets, [0, 13, 32, 53, 70]);
+    assert_eq!(validity.len(), 4);
+    assert_eq!(
+        (0..5).map(|index| validity.get(index)).collect::<Vec<_>>(),
+        [true, true, false, false, false]
+    );
+    assert_eq!(validity.count_valid(), 2);
+    assert_eq!(validity.words(), [0b11]);
+}
+
+#[test]
+fn test_validate_packed_matches_is_valid_phone_number() {
+    let numbers: Vec<String> = (0..150)
+        .map(|i| match i % 3 {
+            0 => format!("+9617912{:04}", i),
+            1 => format!("+1 (202) 555-{:04}", i),
+            _ => format!("{}", i),
+        })
+        .collect();
+    let mut slab = Vec::new();
+    let mut offsets = vec![0];
+    for number in &numbers {
+        slab.extend_from_slice(number.as_bytes());
+        offsets.push(slab.len());
+    }
+
+    let validity = validate_packed(&slab, &offsets);
+    assert_eq!(validity.words().len(), 3);
+    for (index, number) in numbers.iter().enumerate() {
+        assert_eq!(
+            validity.get(index),
+            is_valid_phone_number(number.clone()),
+            "{}",
+            number
+        );
+    }
+}
+
+#[test]
+fn test_validate_packed_bad_input() {
+    let validity = validate_packed(b"+96179123123\xff+96179123123", &[0, 13, 26, 30, 13]);
+
+    assert_eq!(validity.len(), 4);
+    assert_eq!(validity.count_valid(), 0);
+    assert!(validate_packed(b"", &[]).is_empty());
+    assert_eq!(newline_offsets(b""), [0]);
+    assert_eq!(newline_offsets(b"1\n2"), [0, 2, 3]);
+}
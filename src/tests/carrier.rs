@@ -0,0 +1,20 @@
+use crate::carrier_for_number;
+
+#[test]
+fn test_carrier_for_number() {
+    assert_eq!(carrier_for_number("+49 172 1234567"), Some("Vodafone"));
+    assert_eq!(carrier_for_number("+49 151 12345678"), Some("Telekom"));
+    assert_eq!(carrier_for_number("+49 176 12345678"), Some("O2"));
+    assert_eq!(carrier_for_number("+234 803 123 4567"), Some("MTN"));
+    assert_eq!(carrier_for_number("+971 55 123 4567"), Some("du"));
+    assert_eq!(carrier_for_number("+20 10 1234 5678"), Some("Vodafone"));
+}
+
+#[test]
+fn test_carrier_for_number_unknown() {
+    // fixed line
+    assert_eq!(carrier_for_number("+49 30 12345678"), None);
+    // mobile range without carrier data
+    assert_eq!(carrier_for_number("+33 6 12 34 56 78"), None);
+    assert_eq!(carrier_for_number("invalid_phone_number"), None);
+}
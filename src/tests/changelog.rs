@@ -0,0 +1,29 @@
+use crate::{changelog, changes_since, country_by_id, MetadataChange, METADATA_VERSION};
+
+#[test]
+fn test_changelog_is_ordered_and_current() {
+    let entries = changelog();
+
+    assert!(entries
+        .windows(2)
+        .all(|pair| pair[0].metadata_version < pair[1].metadata_version
+            && pair[0].iso_week <= pair[1].iso_week));
+    assert_eq!(entries.last().unwrap().metadata_version, METADATA_VERSION);
+    assert_eq!(changes_since(METADATA_VERSION).count(), 0);
+}
+
+#[test]
+fn test_changelog_references_known_countries() {
+    let removed = |id: u16| {
+        changes_since(0)
+            .any(|change| matches!(*change, MetadataChange::CountryRemoved { id: removed, .. } if removed == id))
+    };
+
+    for change in changes_since(0) {
+        let id = change.country_id();
+        match *change {
+            MetadataChange::CountryRemoved { .. } => assert_eq!(country_by_id(id), None),
+            _ => assert!(country_by_id(id).is_some() || removed(id)),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::formatting::{patterns, FORMATS};
+use crate::number_type::{type_prefixes, TYPE_RULES};
+
+#[test]
+fn test_packed_type_rules_match_the_table() {
+    for (calling_code, _, _) in TYPE_RULES {
+        let rules: Vec<_> = TYPE_RULES
+            .iter()
+            .filter(|(code, _, _)| code == calling_code)
+            .map(|(_, prefix, number_type)| (*prefix, *number_type))
+            .collect();
+        assert_eq!(type_prefixes(*calling_code).collect::<Vec<_>>(), rules);
+    }
+    assert_eq!(type_prefixes(999).count(), 0);
+}
+
+#[test]
+fn test_packed_formats_match_the_table() {
+    for (calling_code, table) in FORMATS {
+        let packed = patterns(*calling_code);
+        assert_eq!(packed.len(), table.len());
+        for (packed, pattern) in packed.iter().zip(table.iter()) {
+            assert_eq!(packed.leading_digits, pattern.leading_digits);
+            assert_eq!(packed.number_type, pattern.number_type);
+            assert_eq!(packed.national, pattern.national);
+            assert_eq!(packed.international, pattern.international);
+        }
+    }
+    assert!(patterns(999).is_empty());
+}
+
+#[test]
+fn test_calling_codes_are_decoded_once() {
+    assert!(std::ptr::eq(patterns(33), patterns(33)));
+}
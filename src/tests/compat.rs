@@ -0,0 +1,29 @@
+#![allow(deprecated)]
+
+use crate::compat;
+
+#[test]
+fn test_compat_matches_current_api() {
+    for phone_number in [
+        "+96179123123",
+        "+1 202 555 0173",
+        "0096179123123",
+        "abc",
+        "",
+    ] {
+        let owned = phone_number.to_string();
+
+        assert_eq!(
+            compat::is_valid_phone_number(owned.clone()),
+            crate::is_valid_phone_number(phone_number)
+        );
+        assert_eq!(
+            compat::extract_country(owned.clone()),
+            crate::extract_country(phone_number)
+        );
+        assert_eq!(
+            compat::normalize_phone_number(owned),
+            crate::normalize_phone_number(phone_number)
+        );
+    }
+}
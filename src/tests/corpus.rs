@@ -0,0 +1,148 @@
+// Golden-file corpus: every `*.json` file of `src/tests/corpus` holds an array of cases
+//
+//     {"input": "+33 6 12 34 56 78", "normalized": "+33612345678", "country": "FR", "type": "Mobile"}
+//
+// where `normalized` and `country` are `null` for invalid numbers and `type` is left out for
+// unknown types. A case with a `"pending": "<reason>"` documents a known bug: it must still
+// fail, so fixing the bug reminds to remove the flag. New cases only need a line of JSON.
+
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::{
+    detect_phone_number_type, extract_country, likely_country, normalize_phone_number, Country,
+    PhoneNumberType,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Case {
+    input: String,
+    normalized: Option<String>,
+    country: Option<String>,
+    #[serde(rename = "type")]
+    number_type: Option<String>,
+    pending: Option<String>,
+}
+
+impl Case {
+    // returns a description of every expectation the case does not meet
+    fn mismatches(&self) -> Vec<String> {
+        let normalized = normalize_phone_number(self.input.clone());
+        let country = extract_country(self.input.clone()).map(|country| country.code.to_string());
+        let number_type = detect_phone_number_type(self.input.clone())
+            .filter(|number_type| *number_type != PhoneNumberType::Unknown)
+            .map(|number_type| format!("{:?}", number_type));
+
+        [
+            ("normalized", &self.normalized, normalized),
+            ("country", &self.country, country),
+            ("type", &self.number_type, number_type),
+        ]
+        .into_iter()
+        .filter(|(_, expected, actual)| *expected != actual)
+        .map(|(field, expected, actual)| {
+            format!("{}: expected {:?}, got {:?}", field, expected, actual)
+        })
+        .collect()
+    }
+}
+
+fn load_corpus() -> Vec<(String, Vec<Case>)> {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/tests/corpus");
+    let mut paths: Vec<_> = fs::read_dir(&directory)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "json")
+        })
+        .collect();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let name = path.file_name().unwrap().to_string_lossy().into_owned();
+            let cases = serde_json::from_str(&fs::read_to_string(&path).unwrap())
+                .unwrap_or_else(|error| panic!("{}: {}", name, error));
+            (name, cases)
+        })
+        .collect()
+}
+
+#[test]
+fn test_corpus() {
+    let mut failures = Vec::new();
+    for (name, cases) in load_corpus() {
+        for case in cases {
+            let mismatches = case.mismatches();
+            match &case.pending {
+                None if !mismatches.is_empty() => failures.push(format!(
+                    "{} {:?}: {}",
+                    name,
+                    case.input,
+                    mismatches.join(", ")
+                )),
+                Some(reason) if mismatches.is_empty() => failures.push(format!(
+                    "{} {:?}: passes, remove `pending: {:?}`",
+                    name, case.input, reason
+                )),
+                _ => {}
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn test_corpus_covers_every_country() {
+    let corpus = load_corpus();
+    let covered: Vec<&str> = corpus
+        .iter()
+        .flat_map(|(_, cases)| cases)
+        .filter_map(|case| case.country.as_deref())
+        .collect();
+
+    for country in crate::COUNTRIES.iter() {
+        assert!(
+            covered.contains(&country.code),
+            "no corpus case for {}",
+            country.code
+        );
+    }
+}
+
+#[test]
+fn test_corpus_with_region_hints() {
+    // hinting the expected country resolves some of the numbers of shared calling codes the
+    // table order gets wrong, and never breaks a number resolved without hint
+    let (mut resolved, mut resolved_without_hint) = (0, 0);
+    for (name, cases) in load_corpus() {
+        for case in cases {
+            let Some(expected) = &case.country else {
+                continue;
+            };
+            let fits =
+                |country: Option<&Country>| country.is_some_and(|country| country.code == expected);
+
+            if fits(likely_country(&case.input, expected)) {
+                resolved += 1;
+            }
+            if fits(extract_country(&case.input)) {
+                resolved_without_hint += 1;
+                assert!(
+                    fits(likely_country(&case.input, expected)),
+                    "{} {:?}",
+                    name,
+                    case.input
+                );
+            }
+        }
+    }
+
+    assert!(resolved > resolved_without_hint);
+}
@@ -0,0 +1,236 @@
+use std::collections::HashSet;
+
+use crate::constants::COUNTRIES;
+use crate::metadata::BUNDLED;
+use crate::{
+    all_countries, countries_by_calling_code, country_by_id, country_by_iso_code, extract_country,
+    extract_country_data, find_country, find_country_by_code, normalize_region_code,
+    numbering_hints_for_region, DialPlan,
+};
+
+#[test]
+fn test_country_ids_are_unique_and_resolvable() {
+    let mut ids = HashSet::new();
+    for country in COUNTRIES.iter() {
+        assert!(country.id != 0, "{} has no id", country.code);
+        assert!(ids.insert(country.id), "duplicate id {}", country.id);
+        assert_eq!(country_by_id(country.id), Some(country));
+    }
+
+    assert_eq!(country_by_id(0), None);
+}
+
+#[test]
+fn test_country_lookups() {
+    assert_eq!(all_countries(), &COUNTRIES[..]);
+    assert_eq!(country_by_iso_code("DE").unwrap().prefix, 49);
+    assert_eq!(country_by_iso_code("uk").unwrap().code, "GB");
+    assert_eq!(country_by_iso_code("XX"), None);
+
+    let codes = |calling_code| {
+        countries_by_calling_code(calling_code)
+            .iter()
+            .map(|country| country.code)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(codes(49), ["DE"]);
+    assert_eq!(codes(44), ["GG", "IM", "JE", "GB"]);
+    // the countries with area codes first
+    assert_eq!(codes(7), ["KZ", "RU"]);
+    assert!(codes(999).is_empty());
+    // NANP members are listed under calling code 1, not under their area code
+    assert!(codes(1242).is_empty());
+
+    for country in all_countries() {
+        assert!(countries_by_calling_code(country.calling_code()).contains(&country));
+    }
+}
+
+#[test]
+fn test_countries_by_calling_code_lists_every_nanp_member() {
+    let nanp: Vec<&str> = all_countries()
+        .iter()
+        .filter(|country| country.calling_code() == 1)
+        .map(|country| country.code)
+        .collect();
+    let listed: Vec<&str> = countries_by_calling_code(1)
+        .iter()
+        .map(|country| country.code)
+        .collect();
+
+    assert_eq!(listed.len(), nanp.len());
+    for code in ["US", "CA", "PR", "DO", "BS", "JM", "TT", "GU"] {
+        assert!(nanp.contains(&code) && listed.contains(&code), "{}", code);
+    }
+    assert!(nanp.iter().all(|code| listed.contains(code)));
+}
+
+#[test]
+fn test_country_ids_are_stable() {
+    // these values are persisted by users and must never change
+    assert_eq!(country_by_id(1).unwrap().code, "AD");
+    assert_eq!(country_by_id(110).unwrap().code, "IT");
+    assert_eq!(country_by_id(127).unwrap().code, "LB");
+    assert_eq!(country_by_id(230).unwrap().code, "US");
+}
+
+#[test]
+fn test_prefix_len_and_str() {
+    for (id, prefix_len, prefix_str) in [
+        (230, 1, "1"),
+        (110, 2, "39"),
+        (127, 3, "961"),
+        (5, 4, "1268"),
+    ] {
+        let country = country_by_id(id).unwrap();
+        assert_eq!(country.prefix_len(), prefix_len);
+        assert_eq!(country.prefix_str(), prefix_str);
+    }
+    for country in COUNTRIES.iter() {
+        assert_eq!(country.prefix_len(), country.prefix_str().len());
+    }
+}
+
+#[test]
+fn test_region_codes_are_case_insensitive_and_aliased() {
+    assert_eq!(normalize_region_code("GB"), Some("GB"));
+    assert_eq!(normalize_region_code("gb"), Some("GB"));
+    assert_eq!(normalize_region_code(" Gb "), Some("GB"));
+    assert_eq!(normalize_region_code("UK"), Some("GB"));
+    assert_eq!(normalize_region_code("uk"), Some("GB"));
+    assert_eq!(normalize_region_code("el"), Some("GR"));
+    assert_eq!(normalize_region_code("xx"), None);
+    assert_eq!(normalize_region_code(""), None);
+
+    assert_eq!(numbering_hints_for_region("uk").unwrap().region, "GB");
+    assert_eq!(DialPlan::new("fr", "0", 4).unwrap().home_country.code, "FR");
+}
+
+#[test]
+fn test_prefix_index_matches_table_scan() {
+    for country in COUNTRIES.iter() {
+        for &length in country.phone_lengths {
+            let digits = format!("{}{}", country.prefix, "2".repeat(length as usize));
+            assert_eq!(
+                extract_country_data(&digits),
+                find_country(&BUNDLED, &digits),
+                "{}",
+                digits
+            );
+        }
+    }
+
+    assert_eq!(extract_country_data(""), None);
+    assert_eq!(extract_country_data("+96179123123"), None);
+    assert_eq!(extract_country_data("0096179123123"), None);
+}
+
+#[test]
+fn test_trunk_prefix() {
+    let trunk_prefix = |code| find_country_by_code(code).unwrap().trunk_prefix();
+
+    assert_eq!(trunk_prefix("FR"), Some("0"));
+    assert_eq!(trunk_prefix("RU"), Some("8"));
+    assert_eq!(trunk_prefix("US"), Some("1"));
+    assert_eq!(trunk_prefix("IT"), None);
+}
+
+#[test]
+fn test_calling_code() {
+    let calling_code = |code| find_country_by_code(code).unwrap().calling_code();
+
+    assert_eq!(calling_code("LB"), 961);
+    assert_eq!(calling_code("US"), 1);
+    // NANP members whose prefix includes the area code
+    assert_eq!(calling_code("BS"), 1);
+    assert_eq!(calling_code("KZ"), 7);
+}
+
+#[test]
+fn test_shared_calling_codes_are_told_apart_by_area_code() {
+    let code = |phone_number| extract_country_data(phone_number).map(|country| country.code);
+
+    assert_eq!(code("12025550173"), Some("US"));
+    assert_eq!(code("14165550123"), Some("CA"));
+    assert_eq!(code("17875550123"), Some("PR"));
+    assert_eq!(code("12423456789"), Some("BS"));
+    assert_eq!(code("77012345678"), Some("KZ"));
+    assert_eq!(code("79123456789"), Some("RU"));
+    assert_eq!(code("262262123456"), Some("RE"));
+    assert_eq!(code("262639123456"), Some("YT"));
+    assert_eq!(code("262123456789"), None);
+}
+
+#[test]
+fn test_shared_calling_codes_resolve_every_member() {
+    // the territories with no numbers of their own
+    let without_numbers = ["BV", "GS", "HM", "TF"];
+    let numbers = [
+        ("+1 202 555 0173", "US"),
+        ("+1 416 555 0123", "CA"),
+        ("+1 787 235 1234", "PR"),
+        ("+1 809 235 1234", "DO"),
+        ("+1 829 235 1234", "DO"),
+        ("+1 849 235 1234", "DO"),
+        ("+7 912 345 67 89", "RU"),
+        ("+7 701 234 5678", "KZ"),
+        ("+44 20 7946 0958", "GB"),
+        ("+44 1481 256789", "GG"),
+        ("+44 1534 456789", "JE"),
+        ("+44 1624 456789", "IM"),
+        ("+47 22 12 34 56", "NO"),
+        ("+47 79 02 12 34", "SJ"),
+        ("+61 412 345 678", "AU"),
+        ("+61 8 9162 1234", "CC"),
+        ("+61 8 9164 1234", "CX"),
+        ("+212 522 123456", "MA"),
+        ("+212 5288 12345", "EH"),
+        ("+262 262 12 34 56", "RE"),
+        ("+262 269 61 23 45", "YT"),
+        ("+358 20 1234567", "FI"),
+        ("+358 18 123456", "AX"),
+        ("+500 51234", "FK"),
+        ("+590 590 12 34 56", "GP"),
+        ("+590 590 27 12 34", "BL"),
+        ("+590 590 87 12 34", "MF"),
+        ("+672 3 12345", "NF"),
+        ("+672 10 1234", "AQ"),
+    ];
+    for (phone_number, region) in numbers {
+        assert_eq!(
+            extract_country(phone_number).map(|country| country.code),
+            Some(region),
+            "{}",
+            phone_number
+        );
+    }
+
+    for country in COUNTRIES.iter() {
+        let shared = COUNTRIES
+            .iter()
+            .any(|other| other.id != country.id && other.calling_code() == country.calling_code());
+        // the NANP members with an area code of their own as prefix, e.g. BS
+        let own_prefix = country.calling_code() != country.prefix;
+        if shared && !own_prefix && !without_numbers.contains(&country.code) {
+            assert!(
+                numbers.iter().any(|(_, region)| *region == country.code),
+                "no number of {}",
+                country.code
+            );
+        }
+    }
+}
+
+#[test]
+fn test_area_codes_have_the_same_length() {
+    for (code, area_codes) in crate::constants::AREA_CODES {
+        assert!(find_country_by_code(code).is_some(), "{}", code);
+        assert!(
+            area_codes
+                .iter()
+                .all(|area_code| area_code.len() == area_codes[0].len()),
+            "{}",
+            code
+        );
+    }
+}
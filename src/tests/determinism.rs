@@ -0,0 +1,103 @@
+// The documented orders and results that must not depend on the platform, the thread, the
+// call history or the time, only on the metadata version.
+
+use std::thread;
+
+use crate::{
+    countries_by_calling_code, equivalent_forms, guess_countries, neighbors_of,
+    normalize_phone_number, region_from_accept_language, PhoneFormat, PhoneNumber,
+};
+
+const INPUTS: &[&str] = &[
+    "+44 7624 123456",
+    "+1 (242) 345-6789",
+    "0096179123123",
+    "+39 06 1234 5678",
+    "+54 9 11 1234 5678",
+    "+7 701 234 5678",
+    "invalid_phone_number",
+];
+
+// everything an input is turned into, as a string so runs compare in one assertion
+fn digest(phone_number: &str) -> String {
+    let number = PhoneNumber::parse(phone_number);
+    let guesses: Vec<String> = guess_countries(phone_number)
+        .iter()
+        .map(|guess| format!("{}={:e}", guess.country.code, guess.probability))
+        .collect();
+    format!(
+        "{:?} {:?} {:?} {:?} {:?}",
+        normalize_phone_number(phone_number),
+        number.as_ref().map(|number| number.country().code),
+        number.map(|number| number.format(PhoneFormat::International)),
+        guesses,
+        equivalent_forms(phone_number),
+    )
+}
+
+#[test]
+fn test_documented_orders() {
+    let codes = |countries: &[&crate::Country]| -> Vec<&str> {
+        countries.iter().map(|country| country.code).collect()
+    };
+
+    // area codes first, then table order
+    assert_eq!(
+        codes(countries_by_calling_code(44)),
+        ["GG", "IM", "JE", "GB"]
+    );
+    assert_eq!(
+        codes(countries_by_calling_code(1)),
+        [
+            "AG", "AI", "AS", "BB", "BM", "BS", "DM", "DO", "GD", "GU", "JM", "KN", "KY", "LC",
+            "MP", "MS", "SX", "TC", "TT", "VC", "VG", "VI", "CA", "PR", "US"
+        ]
+    );
+    // most likely first
+    let guesses: Vec<&str> = guess_countries("+500 51234")
+        .iter()
+        .map(|guess| guess.country.code)
+        .collect();
+    assert_eq!(guesses, ["FK", "GS"]);
+    // metadata order
+    assert_eq!(
+        neighbors_of("FR"),
+        ["BE", "LU", "DE", "CH", "IT", "MC", "ES", "AD", "GB"]
+    );
+    // E.164 first
+    assert_eq!(
+        equivalent_forms("+54 9 11 1234 5678"),
+        [
+            "+541112345678",
+            "+5491112345678",
+            "01112345678",
+            "1112345678"
+        ]
+    );
+    // equal weights keep the header order
+    assert_eq!(
+        region_from_accept_language("fr-CH, fr;q=0.9, de;q=0.9, en;q=0.8"),
+        ["CH", "FR", "DE", "US"]
+    );
+}
+
+#[test]
+fn test_results_dont_depend_on_call_history() {
+    let fresh: Vec<String> = INPUTS.iter().map(|input| digest(input)).collect();
+
+    // the scratch buffers are reused, grown by a long input
+    normalize_phone_number("+".repeat(10_000));
+    let reversed: Vec<String> = INPUTS.iter().rev().map(|input| digest(input)).collect();
+    assert_eq!(fresh, reversed.into_iter().rev().collect::<Vec<_>>());
+}
+
+#[test]
+fn test_results_dont_depend_on_thread() {
+    let expected: Vec<String> = INPUTS.iter().map(|input| digest(input)).collect();
+    let handles: Vec<_> = (0..4)
+        .map(|_| thread::spawn(|| INPUTS.iter().map(|input| digest(input)).collect::<Vec<_>>()))
+        .collect();
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), expected);
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{DialPlan, DialTarget};
+
+fn berlin_office() -> DialPlan {
+    DialPlan::new("DE", "9", 4).unwrap()
+}
+
+#[test]
+fn test_from_internal() {
+    let plan = berlin_office();
+
+    assert_eq!(
+        plan.from_internal("9-0049301234567"),
+        Some(DialTarget::External("+49301234567".to_string()))
+    );
+    assert_eq!(
+        plan.from_internal("9 030 1234567"),
+        Some(DialTarget::External("+49301234567".to_string()))
+    );
+    assert_eq!(
+        plan.from_internal("9 00961 79 123 123"),
+        Some(DialTarget::External("+96179123123".to_string()))
+    );
+    assert_eq!(
+        plan.from_internal("1234"),
+        Some(DialTarget::Extension("1234".to_string()))
+    );
+    assert_eq!(
+        plan.from_internal("112"),
+        Some(DialTarget::Emergency("112".to_string()))
+    );
+    // external numbers need the outside line prefix
+    assert_eq!(plan.from_internal("0049301234567"), None);
+}
+
+#[test]
+fn test_to_internal_dial_string() {
+    let plan = berlin_office();
+
+    assert_eq!(
+        plan.to_internal_dial_string("+49 30 1234567"),
+        Some("90301234567".to_string())
+    );
+    assert_eq!(
+        plan.to_internal_dial_string("+961 79 123 123"),
+        Some("90096179123123".to_string())
+    );
+    assert_eq!(
+        plan.to_internal_dial_string("1234"),
+        Some("1234".to_string())
+    );
+    assert_eq!(plan.to_internal_dial_string("112"), Some("112".to_string()));
+    assert_eq!(plan.to_internal_dial_string("invalid_phone_number"), None);
+    assert_eq!(DialPlan::new("XX", "9", 4), None);
+}
+
+#[test]
+fn test_dial_plan_uses_the_prefixes_of_the_region() {
+    let new_york = DialPlan::new("US", "9", 4).unwrap();
+
+    assert_eq!(new_york.international_prefix, "011");
+    assert_eq!(new_york.national_prefix, "1");
+    assert_eq!(
+        new_york.to_internal_dial_string("+12025550173"),
+        Some("912025550173".to_string())
+    );
+    assert_eq!(
+        new_york.to_internal_dial_string("+49 30 1234567"),
+        Some("901149301234567".to_string())
+    );
+    assert_eq!(
+        new_york.from_internal("9 1 202 555 0173"),
+        Some(DialTarget::External("+12025550173".to_string()))
+    );
+    assert_eq!(
+        new_york.from_internal("9 011 49 30 1234567"),
+        Some(DialTarget::External("+49301234567".to_string()))
+    );
+
+    // the leading zero of Italian numbers is dialed, there is no trunk prefix
+    let rome = DialPlan::new("IT", "0", 4).unwrap();
+
+    assert_eq!(rome.national_prefix, "");
+    assert_eq!(
+        rome.to_internal_dial_string("+39 06 1234 5678"),
+        Some("00612345678".to_string())
+    );
+    assert_eq!(
+        rome.from_internal("0 06 1234 5678"),
+        Some(DialTarget::External("+390612345678".to_string()))
+    );
+    assert_eq!(
+        rome.to_internal_dial_string("+49 30 1234567"),
+        Some("00049301234567".to_string())
+    );
+    assert_eq!(
+        rome.from_internal("0 0049 30 1234567"),
+        Some(DialTarget::External("+49301234567".to_string()))
+    );
+}
@@ -0,0 +1,47 @@
+use crate::{display_number, DisplayPolicy};
+
+#[test]
+fn test_display_number_by_viewer_region() {
+    assert_eq!(
+        display_number("+33612345678", "FR", DisplayPolicy::Full),
+        Some("06 12 34 56 78".to_string())
+    );
+    assert_eq!(
+        display_number("+33612345678", "GB", DisplayPolicy::Full),
+        Some("+33 6 12 34 56 78".to_string())
+    );
+    // NANP members share the calling code, so numbers stay national between them
+    assert_eq!(
+        display_number("+12025550173", "AG", DisplayPolicy::Full),
+        Some("(202) 555-0173".to_string())
+    );
+    assert_eq!(
+        display_number("+33612345678", "ZZ", DisplayPolicy::Full),
+        Some("+33 6 12 34 56 78".to_string())
+    );
+    assert_eq!(display_number("+3361234", "FR", DisplayPolicy::Full), None);
+}
+
+#[test]
+fn test_display_number_policies() {
+    assert_eq!(
+        display_number("+33612345678", "FR", DisplayPolicy::MaskAllButLast(2)),
+        Some("** ** ** ** 78".to_string())
+    );
+    assert_eq!(
+        display_number("+33612345678", "US", DisplayPolicy::MaskAllButLast(4)),
+        Some("+33 * ** ** 56 78".to_string())
+    );
+    assert_eq!(
+        display_number("+33612345678", "US", DisplayPolicy::MaskAllButLast(20)),
+        Some("+33 6 12 34 56 78".to_string())
+    );
+    assert_eq!(
+        display_number("+33612345678", "US", DisplayPolicy::Truncate(10)),
+        Some("+33 6 12…".to_string())
+    );
+    assert_eq!(
+        display_number("+33612345678", "FR", DisplayPolicy::Truncate(14)),
+        Some("06 12 34 56 78".to_string())
+    );
+}
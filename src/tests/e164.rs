@@ -0,0 +1,137 @@
+use super::tests::phone_numbers;
+use crate::{country_by_id, extract_country, find_country_by_code, normalize_phone_number, E164};
+
+#[test]
+fn test_e164_parse_matches_normalize_phone_number() {
+    for phone_number in phone_numbers() {
+        assert_eq!(
+            E164::parse(&phone_number.phone_number).map(String::from),
+            normalize_phone_number(&phone_number.phone_number)
+        );
+    }
+
+    assert_eq!(E164::parse("+1 (234) 567-8910").unwrap(), "+12345678910");
+    assert_eq!(E164::parse("+0096109123123").unwrap(), "+9619123123");
+    assert_eq!(E164::parse("invalid_phone_number"), None);
+    assert_eq!(E164::parse(&"1".repeat(64)), None);
+    // the national number left without the zeros must still have a valid length
+    assert_eq!(E164::parse("+376 000000"), None);
+    assert_eq!(normalize_phone_number("+376 000000"), None);
+    assert_eq!(E164::parse("+376 012345"), None);
+    // and the country is the one of the digits left
+    assert_eq!(
+        E164::parse("+358 00 100000000").unwrap().country(),
+        E164::parse("+358 100000000").unwrap().country()
+    );
+    assert_eq!(
+        extract_country("+358 00 100000000"),
+        extract_country("+358100000000")
+    );
+}
+
+#[test]
+fn test_e164_is_inline() {
+    let number = E164::parse("+96179123123").unwrap();
+    let copy = number;
+
+    assert_eq!(copy, number);
+    assert_eq!(number.len(), 12);
+    assert_eq!(number.to_string(), "+96179123123");
+    assert_eq!(format!("{:?}", number), "E164(\"+96179123123\")");
+    assert!(std::mem::size_of::<E164>() <= 20);
+}
+
+#[test]
+fn test_country_ids_round_trip() {
+    for phone_number in phone_numbers() {
+        let number = E164::parse(&phone_number.phone_number).unwrap();
+        let country = extract_country(&phone_number.phone_number).unwrap();
+
+        assert_eq!(number.country(), country);
+        assert_eq!(country_by_id(country.id()), Some(country));
+    }
+
+    assert_eq!(country_by_id(u16::MAX), None);
+}
+
+#[test]
+fn test_e164_ordering() {
+    let parse = |phone_number| E164::parse(phone_number).unwrap();
+    let mut numbers = [
+        parse("+96179123123"),
+        parse("+33612345678"),
+        parse("+4930123456"),
+        parse("+493012345678"),
+        parse("+12025550173"),
+        parse("+12425550173"),
+        parse("+4940123456"),
+    ];
+    numbers.sort();
+
+    // calling codes compare numerically, NANP members share +1, and shorter national
+    // numbers come first
+    assert_eq!(
+        numbers.iter().map(E164::as_str).collect::<Vec<_>>(),
+        [
+            "+12025550173",
+            "+12425550173",
+            "+33612345678",
+            "+4930123456",
+            "+4940123456",
+            "+493012345678",
+            "+96179123123",
+        ]
+    );
+    assert_eq!(
+        parse("+1 (202) 555-0173").cmp(&parse("+12025550173")),
+        std::cmp::Ordering::Equal
+    );
+}
+
+#[test]
+fn test_e164_identity_ignores_the_country() {
+    let unhinted = E164::parse("+500 51234").unwrap();
+    let hinted = E164::parse_with_default_region("+500 51234", "GS").unwrap();
+    assert_ne!(unhinted.country(), hinted.country());
+
+    // equality, ordering and hashing agree whatever the region hint
+    assert_eq!(unhinted, hinted);
+    assert_eq!(unhinted.cmp(&hinted), std::cmp::Ordering::Equal);
+    let set: std::collections::HashSet<E164> = [unhinted, hinted].into_iter().collect();
+    assert_eq!(set.len(), 1);
+    let tree: std::collections::BTreeSet<E164> = [unhinted, hinted].into_iter().collect();
+    assert_eq!(tree.len(), 1);
+}
+
+#[test]
+fn test_e164_parse_with_default_region() {
+    let parse = |phone_number, region| {
+        E164::parse_with_default_region(phone_number, region).map(String::from)
+    };
+    let london = Some("+442079460958".to_string());
+
+    // the exit code of the region is stripped as a whole
+    assert_eq!(parse("011 44 20 7946 0958", "US"), london);
+    assert_eq!(parse("0011 44 20 7946 0958", "AU"), london);
+    assert_eq!(parse("010 44 20 7946 0958", "JP"), london);
+    assert_eq!(parse("810 44 20 7946 0958", "RU"), london);
+    assert_eq!(parse("00 44 20 7946 0958", "FR"), london);
+    // other exit codes and national numbers
+    assert_eq!(parse("0044 20 7946 0958", "US"), london);
+    assert_eq!(parse("020 7946 0958", "GB"), london);
+    assert_eq!(parse("+44 20 7946 0958", "AU"), london);
+    assert_eq!(parse("0044 20 7946 0958", "ZZ"), london);
+    // tel URIs, whatever the case of their scheme
+    assert_eq!(parse("tel:+44-20-7946-0958", "US"), london);
+    assert_eq!(parse(" TEL:+44-20-7946-0958", "FR"), london);
+    assert_eq!(parse("tel:020-7946-0958", "GB"), london);
+
+    assert_eq!(
+        find_country_by_code("AU").unwrap().international_prefix(),
+        "0011"
+    );
+    assert_eq!(
+        find_country_by_code("FR").unwrap().international_prefix(),
+        "00"
+    );
+}
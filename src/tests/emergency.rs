@@ -0,0 +1,54 @@
+use crate::{emergency_numbers_for_region, regions_by_emergency_number, DialPlan, DialTarget};
+
+#[test]
+fn test_emergency_numbers_for_region() {
+    assert_eq!(
+        emergency_numbers_for_region("JP"),
+        Some(&["110", "119"][..])
+    );
+    assert_eq!(
+        emergency_numbers_for_region("uk"),
+        Some(&["999", "112"][..])
+    );
+    // unknown regions and regions without data
+    assert_eq!(emergency_numbers_for_region("XX"), None);
+    assert_eq!(emergency_numbers_for_region("AD"), None);
+}
+
+#[test]
+fn test_regions_by_emergency_number() {
+    let codes = |number| {
+        regions_by_emergency_number(number)
+            .iter()
+            .map(|country| country.code)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(codes("119"), ["CN", "JP", "KR"]);
+    assert_eq!(codes("000"), ["AU"]);
+    assert_eq!(codes("10 111"), ["ZA"]);
+    assert!(codes("123456").is_empty());
+    assert!(codes("").is_empty());
+
+    for country in regions_by_emergency_number("911") {
+        assert!(emergency_numbers_for_region(country.code)
+            .unwrap()
+            .contains(&"911"));
+    }
+}
+
+#[test]
+fn test_dial_plan_emergency_numbers() {
+    let tokyo = DialPlan::new("JP", "0", 4).unwrap();
+    assert_eq!(tokyo.emergency_numbers, ["110", "119"]);
+    assert_eq!(
+        tokyo.from_internal("119"),
+        Some(DialTarget::Emergency("119".to_string()))
+    );
+
+    // 112 where the emergency numbers aren't known
+    assert_eq!(
+        DialPlan::new("AD", "0", 4).unwrap().emergency_numbers,
+        ["112"]
+    );
+}
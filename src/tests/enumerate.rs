@@ -0,0 +1,26 @@
+use crate::{enumerate_numbers, is_valid_phone_number, normalize_phone_number};
+
+fn enumerate(prefix: &str, count: usize) -> Vec<String> {
+    enumerate_numbers(prefix, count).map(String::from).collect()
+}
+
+#[test]
+fn test_enumerate_numbers() {
+    // shortest first, Lebanese numbers have 7 or 8 digits after the calling code
+    assert_eq!(
+        enumerate("+961 7912 312", 3),
+        ["+9617912312", "+96179123120", "+96179123121"]
+    );
+    // numbers fit the area codes of their country and don't start with 0 after the
+    // calling code
+    assert_eq!(enumerate("+1 787", 2), ["+17870000000", "+17870000001"]);
+    assert_eq!(enumerate("+33", 1), ["+33100000000"]);
+    assert!(enumerate("+330", 1).is_empty());
+    assert_eq!(enumerate("+96179123123", 5), ["+96179123123"]);
+    assert!(enumerate("+9617912312345", 1).is_empty());
+
+    for number in enumerate("+4420", 50) {
+        assert!(is_valid_phone_number(&number));
+        assert_eq!(normalize_phone_number(&number), Some(number));
+    }
+}
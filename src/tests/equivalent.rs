@@ -0,0 +1,41 @@
+use crate::equivalent_forms;
+
+#[test]
+fn test_equivalent_forms_trunk_prefix() {
+    assert_eq!(
+        equivalent_forms("+33 6 12 34 56 78"),
+        vec!["+33612345678", "0612345678", "612345678"]
+    );
+    assert_eq!(
+        equivalent_forms("+1 (202) 555-0173"),
+        vec!["+12025550173", "12025550173", "2025550173"]
+    );
+    // NANP members whose prefix includes the area code
+    assert_eq!(
+        equivalent_forms("+1 242 555 1234"),
+        vec!["+12425551234", "2425551234"]
+    );
+    assert_eq!(
+        equivalent_forms("+96179123123"),
+        vec!["+96179123123", "079123123", "79123123"]
+    );
+}
+
+#[test]
+fn test_equivalent_forms_mobile_digit() {
+    let forms = vec![
+        "+541112345678",
+        "+5491112345678",
+        "01112345678",
+        "1112345678",
+    ];
+    assert_eq!(equivalent_forms("+54 11 1234 5678"), forms);
+    assert_eq!(equivalent_forms("+54 9 11 1234 5678"), forms);
+
+    assert!(equivalent_forms("+52 1 55 1234 5678").contains(&"+525512345678".to_string()));
+}
+
+#[test]
+fn test_equivalent_forms_invalid() {
+    assert!(equivalent_forms("invalid_phone_number").is_empty());
+}
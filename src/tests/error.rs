@@ -0,0 +1,107 @@
+use crate::{
+    normalize_phone_number, try_extract_country, try_format_phone_number,
+    try_normalize_phone_number, PhoneError, PhoneFormat, PhoneNumber,
+};
+
+#[test]
+fn test_try_normalize_phone_number() {
+    assert_eq!(
+        try_normalize_phone_number(" +1 (202) 555-0173"),
+        Ok("+12025550173".to_string())
+    );
+    assert_eq!(
+        try_normalize_phone_number("+1 202 CALL 0173"),
+        Err(PhoneError::InvalidCharacter { position: 7 })
+    );
+    assert_eq!(
+        try_normalize_phone_number("+96179+123123"),
+        Err(PhoneError::InvalidCharacter { position: 6 })
+    );
+    assert_eq!(
+        try_normalize_phone_number("+1 23"),
+        Err(PhoneError::TooShort)
+    );
+    assert_eq!(try_normalize_phone_number(""), Err(PhoneError::TooShort));
+    assert_eq!(
+        try_normalize_phone_number(&"1".repeat(20)),
+        Err(PhoneError::TooLong)
+    );
+    assert_eq!(
+        try_normalize_phone_number("+999 123 456 789"),
+        Err(PhoneError::UnknownCountryCode)
+    );
+    assert_eq!(
+        try_normalize_phone_number("+961 79 123 1"),
+        Err(PhoneError::InvalidLengthForCountry { expected: &[7, 8] })
+    );
+}
+
+#[test]
+fn test_try_functions_with_zero_national_numbers() {
+    for phone_number in ["+33 000000000", "+44 0000000000", "+376 000000"] {
+        assert_eq!(
+            try_normalize_phone_number(phone_number),
+            Err(PhoneError::TooShort),
+            "{}",
+            phone_number
+        );
+        assert_eq!(
+            PhoneNumber::try_parse(phone_number),
+            Err(PhoneError::TooShort)
+        );
+        assert_eq!(normalize_phone_number(phone_number), None);
+    }
+    assert_eq!(
+        try_normalize_phone_number("+33 0 1234"),
+        Err(PhoneError::InvalidLengthForCountry { expected: &[9] })
+    );
+}
+
+#[test]
+fn test_phone_error_display() {
+    assert_eq!(
+        PhoneError::InvalidCharacter { position: 7 }.to_string(),
+        "the phone number has an invalid character at position 7"
+    );
+    assert_eq!(
+        PhoneError::InvalidLengthForCountry { expected: &[7, 8] }.to_string(),
+        "the phone number should have 7 or 8 digits after the country code"
+    );
+}
+
+#[test]
+fn test_try_functions_match_option_functions() {
+    for fixture in super::tests::phone_numbers() {
+        let phone_number = fixture.phone_number.as_str();
+        let normalized = try_normalize_phone_number(phone_number);
+
+        assert_eq!(
+            normalized.clone().ok(),
+            normalize_phone_number(phone_number)
+        );
+        assert_eq!(
+            try_extract_country(phone_number).ok(),
+            PhoneNumber::parse(phone_number).map(|number| number.country())
+        );
+        assert_eq!(
+            normalized.is_ok(),
+            PhoneNumber::try_parse(phone_number).is_ok()
+        );
+    }
+
+    assert_eq!(try_extract_country("+33612345678").unwrap().code, "FR");
+    assert_eq!(
+        try_format_phone_number("+1 202 555 0173 ext. 45", PhoneFormat::RFC3966),
+        Ok("tel:+1-202-555-0173;ext=45".to_string())
+    );
+    assert_eq!(
+        PhoneNumber::try_parse("+1 202 555 0173 x45")
+            .unwrap()
+            .extension(),
+        Some("45")
+    );
+    assert_eq!(
+        PhoneNumber::try_parse("+1 202 555 01"),
+        Err(PhoneError::InvalidLengthForCountry { expected: &[10] })
+    );
+}
@@ -0,0 +1,35 @@
+use crate::{detect_phone_number_type, example_number, placeholder_for_region, PhoneNumberType};
+
+#[test]
+fn test_placeholder_for_region() {
+    assert_eq!(
+        placeholder_for_region("GB", PhoneNumberType::Mobile).unwrap(),
+        "07700 900123"
+    );
+    assert_eq!(
+        placeholder_for_region("FR", PhoneNumberType::FixedLine).unwrap(),
+        "01 23 45 67 89"
+    );
+    assert_eq!(
+        placeholder_for_region("US", PhoneNumberType::Mobile).unwrap(),
+        "(201) 555-0123"
+    );
+    assert_eq!(placeholder_for_region("GB", PhoneNumberType::Pager), None);
+    assert_eq!(placeholder_for_region("XX", PhoneNumberType::Mobile), None);
+}
+
+#[test]
+fn test_example_numbers_have_their_type() {
+    for (region, number_type) in [
+        ("GB", PhoneNumberType::Mobile),
+        ("GB", PhoneNumberType::TollFree),
+        ("DE", PhoneNumberType::Mobile),
+        ("FR", PhoneNumberType::TollFree),
+        ("AU", PhoneNumberType::Mobile),
+        ("IN", PhoneNumberType::Mobile),
+        ("US", PhoneNumberType::TollFree),
+    ] {
+        let example = example_number(region, number_type).unwrap();
+        assert_eq!(detect_phone_number_type(example), Some(number_type));
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{country_by_id, explain, Parser, Verdict};
+
+#[test]
+fn test_explain_shared_calling_code() {
+    let explanation = explain("+7 701 234 5678", &Parser::new());
+    let verdicts: Vec<(&str, Verdict)> = explanation
+        .candidates
+        .iter()
+        .map(|candidate| (candidate.country.code, candidate.verdict))
+        .collect();
+    let kazakhstan = explanation.candidates[0].country;
+
+    assert_eq!(explanation.normalized, Some("+77012345678".to_string()));
+    assert_eq!(
+        verdicts,
+        vec![
+            ("KZ", Verdict::Chosen),
+            ("RU", Verdict::ShadowedBy(kazakhstan)),
+        ]
+    );
+}
+
+#[test]
+fn test_explain_area_codes() {
+    let verdicts = |phone_number| -> Vec<(&str, Verdict)> {
+        explain(phone_number, &Parser::new())
+            .candidates
+            .iter()
+            .map(|candidate| (candidate.country.code, candidate.verdict))
+            .collect()
+    };
+
+    assert_eq!(
+        verdicts("+7 912 345 6789"),
+        vec![("KZ", Verdict::WrongAreaCode), ("RU", Verdict::Chosen)]
+    );
+
+    let canada = crate::extract_country("+1 416 555 0123").unwrap();
+    assert_eq!(
+        verdicts("+1 416 555 0123"),
+        vec![
+            ("DO", Verdict::WrongAreaCode),
+            ("US", Verdict::ShadowedBy(canada)),
+            ("CA", Verdict::Chosen),
+            ("PR", Verdict::WrongAreaCode),
+        ]
+    );
+}
+
+#[test]
+fn test_explain_rendering() {
+    let parser = Parser::new().pre_hook(|phone_number| {
+        if let Some(external) = phone_number.strip_prefix("9-") {
+            *phone_number = external.to_string();
+        }
+    });
+
+    assert_eq!(
+        explain("9-00 961 (09) 123-123", &parser).to_string(),
+        [
+            r#"input: "9-00 961 (09) 123-123""#,
+            r#"1. pre-hook rewrote "9-00 961 (09) 123-123" to "00 961 (09) 123-123""#,
+            r#"2. removed non-digit characters "  () -""#,
+            r#"3. stripped international prefix "00""#,
+            "4. chose Lebanon (LB, +961)",
+            r#"5. removed trunk prefix "0""#,
+            "candidates:",
+            "  Lebanon (LB, +961): chosen",
+            "result: +9619123123",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn test_explain_rejected_number() {
+    let explanation = explain("+961 1234", &Parser::new());
+    let lebanon = country_by_id(127).unwrap();
+
+    assert_eq!(explanation.normalized, None);
+    assert_eq!(explanation.candidates.len(), 1);
+    assert_eq!(explanation.candidates[0].country, lebanon);
+    assert_eq!(
+        explanation.candidates[0].verdict,
+        Verdict::WrongLength {
+            national_number_length: 4
+        }
+    );
+    assert!(explanation.to_string().ends_with(
+        "  Lebanon (LB, +961): rejected, 4 digits after the calling code but expected [7, 8]\n\
+         result: invalid"
+    ));
+}
+
+#[test]
+fn test_explain_region_hint() {
+    let parser = Parser::new().region_hints(|| Some("FR".to_string()));
+    let explanation = explain("06 12 34 56 78", &parser);
+
+    assert_eq!(explanation.normalized, Some("+33612345678".to_string()));
+    assert_eq!(explanation.candidates.len(), 1);
+    assert_eq!(explanation.candidates[0].verdict, Verdict::Chosen);
+    assert!(explanation.to_string().contains(
+        "2. read as a national number of France (FR, +33)\n3. removed trunk prefix \"0\""
+    ));
+}
@@ -0,0 +1,134 @@
+use std::mem::{align_of, size_of};
+
+use crate::ffi::{analyze, Analysis, ErrorCode, NumberType};
+use crate::{PhoneError, PhoneNumber};
+
+#[test]
+fn test_analyze() {
+    let analysis = analyze("+33 6 12 34 56 78 ext. 12");
+    assert_eq!(analysis.error, ErrorCode::Ok);
+    assert_eq!(analysis.e164(), "+33612345678");
+    assert_eq!(&analysis.e164[..13], b"+33612345678\0");
+    assert_eq!(analysis.region(), "FR");
+    assert_eq!(analysis.region, *b"FR\0");
+    assert_eq!(analysis.calling_code, 33);
+    assert_eq!(
+        analysis.country_id,
+        PhoneNumber::parse("+33612345678").unwrap().country().id
+    );
+    assert_eq!(analysis.number_type, NumberType::Mobile);
+    assert_eq!(analysis.extension(), "12");
+
+    assert_eq!(analyze("+1 242 357 1234").calling_code, 1);
+    assert_eq!(
+        analyze(&format!("+12025550173 x{}", "9".repeat(20)))
+            .extension()
+            .len(),
+        16
+    );
+}
+
+#[test]
+fn test_analyze_errors() {
+    let analysis = analyze("+1 202 CALL 0173");
+    assert_eq!(analysis.error, ErrorCode::InvalidCharacter);
+    assert_eq!(analysis.error_position, 7);
+    assert_eq!(analysis.e164(), "");
+    assert_eq!(analysis.region(), "");
+
+    assert_eq!(analyze("+1 23").error, ErrorCode::TooShort);
+    assert_eq!(
+        analyze("+999 123 456 789").error,
+        ErrorCode::UnknownCountryCode
+    );
+    for phone_number in ["+33 000000000", "+44 0000000000", "+376 000000"] {
+        assert_eq!(analyze(phone_number).error, ErrorCode::TooShort);
+        let phone_number = std::ffi::CString::new(phone_number).unwrap();
+        let analysis = unsafe { crate::ffi::phonelib_analyze(phone_number.as_ptr()) };
+        assert_eq!(analysis.error, ErrorCode::TooShort);
+    }
+    assert_eq!(
+        Analysis::from(PhoneError::InvalidLengthForCountry { expected: &[9] }).error,
+        ErrorCode::InvalidLengthForCountry
+    );
+}
+
+#[test]
+fn test_analysis_layout() {
+    // the discriminants are part of the C ABI
+    assert_eq!(ErrorCode::InvalidLengthForCountry as u32, 5);
+    assert_eq!(NumberType::Unknown as u32, 11);
+    assert_eq!(align_of::<Analysis>(), 4);
+    assert_eq!(size_of::<Analysis>() % align_of::<Analysis>(), 0);
+}
+
+#[test]
+fn test_c_functions() {
+    use std::ffi::{CStr, CString};
+    use std::ptr::null;
+
+    use crate::ffi::{
+        phonelib_analyze, phonelib_extract_country, phonelib_format, phonelib_free_string,
+        phonelib_is_valid, phonelib_normalize, Format,
+    };
+
+    // takes ownership of a returned string
+    let owned = |string: *mut std::ffi::c_char| -> Option<String> {
+        if string.is_null() {
+            return None;
+        }
+        let owned = unsafe { CStr::from_ptr(string) }
+            .to_str()
+            .unwrap()
+            .to_string();
+        unsafe { phonelib_free_string(string) };
+        Some(owned)
+    };
+    let valid = CString::new("+33 6 12 34 56 78").unwrap();
+    let invalid = CString::new("invalid_phone_number").unwrap();
+    let not_utf8 = CString::new(vec![b'+', 0xff]).unwrap();
+
+    unsafe {
+        assert!(phonelib_is_valid(valid.as_ptr()));
+        assert!(!phonelib_is_valid(invalid.as_ptr()));
+        assert!(!phonelib_is_valid(not_utf8.as_ptr()));
+        assert!(!phonelib_is_valid(null()));
+
+        assert_eq!(
+            owned(phonelib_normalize(valid.as_ptr())).as_deref(),
+            Some("+33612345678")
+        );
+        assert_eq!(owned(phonelib_normalize(invalid.as_ptr())), None);
+        assert_eq!(owned(phonelib_normalize(null())), None);
+        assert_eq!(
+            owned(phonelib_format(valid.as_ptr(), Format::National)).as_deref(),
+            Some("06 12 34 56 78")
+        );
+        assert_eq!(
+            owned(phonelib_extract_country(valid.as_ptr())).as_deref(),
+            Some("FR")
+        );
+
+        assert_eq!(phonelib_analyze(valid.as_ptr()).region(), "FR");
+        assert_eq!(phonelib_analyze(null()).error, ErrorCode::InvalidCharacter);
+        phonelib_free_string(std::ptr::null_mut());
+    }
+}
+
+#[test]
+fn test_header_declares_the_c_functions() {
+    let header = include_str!("../../include/phonelib.h");
+    for function in [
+        "bool phonelib_is_valid(",
+        "char *phonelib_normalize(",
+        "char *phonelib_format(",
+        "char *phonelib_extract_country(",
+        "struct PhonelibAnalysis phonelib_analyze(",
+        "void phonelib_free_string(",
+    ] {
+        assert!(header.contains(function), "{} is missing", function);
+    }
+    assert!(header.contains("uint8_t e164[17];"));
+    // constants of the Rust API
+    assert!(!header.contains("#define Phonelib"));
+}
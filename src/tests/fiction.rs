@@ -0,0 +1,65 @@
+use crate::{
+    extract_country, generate_test_phone_number, is_reserved_test_number, is_valid_phone_number,
+    PhoneNumberGenerator,
+};
+
+#[test]
+fn test_reserved_test_numbers() {
+    for number in [
+        "+1 202 555 0100",
+        "+1 (416) 555-0199",
+        "+1 242 555 0123",
+        "+44 7700 900123",
+        "+44 20 7946 0000",
+        "+44 113 496 0999",
+        "+61 2 5550 1234",
+        "+61 3 7010 9876",
+        "+61 8 5550 0000",
+    ] {
+        assert!(is_reserved_test_number(number), "{}", number);
+    }
+}
+
+#[test]
+fn test_unreserved_numbers() {
+    for number in [
+        // 555 numbers outside 555-0100 to 555-0199
+        "+1 202 555 0200",
+        "+1 202 555 1234",
+        "+1 555 010 1234",
+        "+44 7700 901123",
+        "+44 20 7946 1000",
+        "+61 4 5550 1234",
+        "+61 2 5551 1234",
+        // no reserved ranges are known
+        "+33 6 12 34 56 78",
+        "not a number",
+        "",
+    ] {
+        assert!(!is_reserved_test_number(number), "{}", number);
+    }
+}
+
+#[test]
+fn test_generated_test_numbers() {
+    let mut generator = PhoneNumberGenerator::new(2788);
+    for region in ["US", "CA", "BS", "GB", "AU"] {
+        for _ in 0..20 {
+            let number = generator.generate_test_number(region).unwrap();
+            assert_eq!(number.country().code, region, "{}", number);
+            assert!(is_reserved_test_number(&number), "{}", number);
+            assert!(is_valid_phone_number(number), "{}", number);
+        }
+    }
+    assert!(generator.generate_test_number("FR").is_none());
+    assert!(generator.generate_test_number("XX").is_none());
+}
+
+#[test]
+fn test_generate_test_phone_number() {
+    let number = generate_test_phone_number("US").unwrap();
+    assert!(number.starts_with("+1"), "{}", number);
+    assert_eq!(&number[5..10], "55501");
+    assert_eq!(extract_country(&number).unwrap().code, "US");
+    assert_eq!(generate_test_phone_number("DE"), None);
+}
@@ -0,0 +1,227 @@
+use crate::constants::COUNTRIES;
+use crate::{
+    format_phone_number, format_phone_number_checked, input_mask_for_region,
+    input_mask_for_region_and_type, normalize_phone_number, PhoneFormat, PhoneNumberType, E164,
+};
+
+fn format(phone_number: &str, format: PhoneFormat) -> String {
+    format_phone_number(phone_number, format).unwrap()
+}
+
+#[test]
+fn test_format_phone_number_with_metadata() {
+    assert_eq!(
+        format("+12025550173", PhoneFormat::National),
+        "(202) 555-0173"
+    );
+    assert_eq!(
+        format("+12025550173", PhoneFormat::International),
+        "+1 202-555-0173"
+    );
+    assert_eq!(
+        format("+33612345678", PhoneFormat::National),
+        "06 12 34 56 78"
+    );
+    assert_eq!(
+        format("+33612345678", PhoneFormat::International),
+        "+33 6 12 34 56 78"
+    );
+    assert_eq!(
+        format("+442079460958", PhoneFormat::National),
+        "020 7946 0958"
+    );
+    assert_eq!(
+        format("+447911123456", PhoneFormat::National),
+        "07911 123456"
+    );
+    assert_eq!(
+        format("+4915112345678", PhoneFormat::National),
+        "0151 12345678"
+    );
+    assert_eq!(
+        format("+33612345678", PhoneFormat::RFC3966),
+        "tel:+33-6-12-34-56-78"
+    );
+    assert_eq!(
+        format("+1 (202) 555-0173", PhoneFormat::E164),
+        "+12025550173"
+    );
+}
+
+#[test]
+fn test_format_phone_number_per_country_patterns() {
+    for (phone_number, national, international) in [
+        ("+5511912345678", "(11) 91234-5678", "+55 11 91234-5678"),
+        ("+819012345678", "090-1234-5678", "+81 90-1234-5678"),
+        ("+919812345678", "098123 45678", "+91 98123 45678"),
+        ("+911800123456", "1800 123 456", "+91 1800 123 456"),
+        ("+74951234567", "8 (495) 123-45-67", "+7 495 123-45-67"),
+        ("+31612345678", "06 12345678", "+31 6 12345678"),
+        ("+34612345678", "612 34 56 78", "+34 612 34 56 78"),
+        ("+390612345678", "06 1234 5678", "+39 06 1234 5678"),
+        ("+390212345678", "02 1234 5678", "+39 02 1234 5678"),
+        ("+390111234567", "011 123 4567", "+39 011 123 4567"),
+        ("+390471123456", "0471 123456", "+39 0471 123456"),
+        ("+61412345678", "0412 345 678", "+61 412 345 678"),
+        ("+966501234567", "050 123 4567", "+966 50 123 4567"),
+    ] {
+        assert_eq!(format(phone_number, PhoneFormat::National), national);
+        assert_eq!(
+            format(phone_number, PhoneFormat::International),
+            international
+        );
+    }
+}
+
+#[test]
+fn test_format_phone_number_fallback() {
+    assert_eq!(format("+96179123123", PhoneFormat::National), "079 123 123");
+    assert_eq!(
+        format("+96179123123", PhoneFormat::International),
+        "+961 79 123 123"
+    );
+    assert_eq!(
+        format("+96179123123", PhoneFormat::RFC3966),
+        "tel:+961-79-123-123"
+    );
+    assert_eq!(format("+9617123123", PhoneFormat::National), "0712 3123");
+    assert_eq!(
+        format_phone_number("invalid_phone_number", PhoneFormat::National),
+        None
+    );
+}
+
+#[test]
+fn test_format_phone_number_with_extension() {
+    assert_eq!(
+        format("+1 202 555 0173 ext. 45", PhoneFormat::RFC3966),
+        "tel:+1-202-555-0173;ext=45"
+    );
+    assert_eq!(
+        format("tel:+33-6-12-34-56-78;ext=9", PhoneFormat::RFC3966),
+        "tel:+33-6-12-34-56-78;ext=9"
+    );
+    assert_eq!(
+        format("+96179123123 x12", PhoneFormat::National),
+        "079 123 123 ext. 12"
+    );
+    assert_eq!(
+        format("+1 202 555 0173 #45", PhoneFormat::International),
+        "+1 202-555-0173 ext. 45"
+    );
+    assert_eq!(
+        format("+1 202 555 0173 ext. 45", PhoneFormat::E164),
+        "+12025550173"
+    );
+}
+
+#[test]
+fn test_rfc3966_follows_national_grouping() {
+    // RFC3966 groups are the national groups, without the trunk prefix
+    let groups = |formatted: &str| -> Vec<usize> {
+        formatted
+            .split(|c: char| !c.is_ascii_digit())
+            .filter(|group| !group.is_empty())
+            .map(str::len)
+            .collect()
+    };
+
+    for phone_number in [
+        "+12025550173",
+        "+33612345678",
+        "+33801234567",
+        "+442079460958",
+        "+447911123456",
+        "+4915112345678",
+        "+96179123123",
+        "+9617123123",
+    ] {
+        let national = format(phone_number, PhoneFormat::National);
+        let rfc3966 = format(phone_number, PhoneFormat::RFC3966);
+        let national_number = national.trim_start_matches(|c: char| !c.is_ascii_digit());
+        let national_groups = groups(national_number.strip_prefix('0').unwrap_or(national_number));
+
+        assert_eq!(
+            groups(&rfc3966)[1..],
+            national_groups[..],
+            "{} {}",
+            national,
+            rfc3966
+        );
+    }
+}
+
+#[test]
+fn test_format_round_trip() {
+    let formats = [
+        PhoneFormat::E164,
+        PhoneFormat::International,
+        PhoneFormat::National,
+        PhoneFormat::RFC3966,
+    ];
+
+    for country in COUNTRIES.iter() {
+        for &length in country.phone_lengths {
+            for leading_digit in ["1", "2", "6", "7", "8", "9"] {
+                let national_number = format!("{}3456789012345", leading_digit);
+                let phone_number =
+                    format!("+{}{}", country.prefix, &national_number[..length as usize]);
+                let Some(number) = E164::parse(&phone_number) else {
+                    continue;
+                };
+
+                for format in formats {
+                    let formatted = format_phone_number_checked(number, format).unwrap();
+                    let reparsed = match format {
+                        PhoneFormat::National => E164::parse_national(&formatted, number.country()),
+                        _ => E164::parse(&formatted),
+                    };
+                    assert_eq!(reparsed, Some(number), "{:?} {}", format, formatted);
+                }
+                for format in [
+                    PhoneFormat::E164,
+                    PhoneFormat::International,
+                    PhoneFormat::RFC3966,
+                ] {
+                    let formatted = format_phone_number(number, format).unwrap();
+                    assert_eq!(normalize_phone_number(formatted).as_deref(), Some(&*number));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_format_phone_number_checked_falls_back_to_international() {
+    assert_eq!(
+        format_phone_number_checked("+12025550173", PhoneFormat::National),
+        Some("(202) 555-0173".to_string())
+    );
+    // the national form would be read with the "1" trunk prefix stripped
+    assert_eq!(
+        format_phone_number_checked("+11345678901 x2", PhoneFormat::National),
+        Some("+1 134-567-8901 ext. 2".to_string())
+    );
+}
+
+#[test]
+fn test_input_masks() {
+    assert_eq!(input_mask_for_region("FR").unwrap(), "## ## ## ## ##");
+    assert_eq!(input_mask_for_region("US").unwrap(), "(###) ###-####");
+    assert_eq!(input_mask_for_region("JM").unwrap(), "(###) ###-####");
+    assert_eq!(input_mask_for_region("LB").unwrap(), "### ### ###");
+    assert_eq!(input_mask_for_region("XX"), None);
+
+    assert_eq!(
+        input_mask_for_region_and_type("GB", PhoneNumberType::Mobile).unwrap(),
+        "##### ######"
+    );
+    assert_eq!(
+        input_mask_for_region_and_type("GB", PhoneNumberType::TollFree).unwrap(),
+        "#### ### ####"
+    );
+    assert_eq!(
+        input_mask_for_region_and_type("FR", PhoneNumberType::Pager),
+        None
+    );
+}
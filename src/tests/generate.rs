@@ -0,0 +1,128 @@
+use crate::{
+    all_countries, detect_phone_number_type, extract_country, generate_random_phone_number,
+    generate_random_phone_number_of_type, generate_random_phone_number_with_seed, validate,
+    PhoneNumberGenerator, PhoneNumberType, ValidationResult,
+};
+
+#[test]
+fn test_generated_numbers_are_valid() {
+    let mut generator = PhoneNumberGenerator::new(2786);
+    for country in all_countries() {
+        for _ in 0..20 {
+            let number = generator.generate(country.code).unwrap();
+            assert_eq!(number.country(), country, "{}", number);
+            assert_eq!(
+                validate(&number),
+                ValidationResult::ValidForCountry,
+                "{}",
+                number
+            );
+        }
+    }
+    assert_eq!(generator.generate("XX"), None);
+}
+
+#[test]
+fn test_generator_is_deterministic() {
+    let sequence = |seed| {
+        let mut generator = PhoneNumberGenerator::new(seed);
+        (0..10)
+            .map(|_| generator.generate("DE").unwrap())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(sequence(1), sequence(1));
+    assert_ne!(sequence(1), sequence(2));
+    // successive numbers of one generator don't repeat
+    let mut numbers = sequence(1);
+    numbers.sort();
+    numbers.dedup();
+    assert_eq!(numbers.len(), 10);
+
+    assert_eq!(
+        generate_random_phone_number_with_seed("LB", 5),
+        generate_random_phone_number_with_seed("LB", 5)
+    );
+    assert_eq!(generate_random_phone_number_with_seed("XX", 5), None);
+}
+
+#[test]
+fn test_generate_random_phone_number() {
+    let first = generate_random_phone_number("US").unwrap();
+    assert_eq!(extract_country(&first).unwrap().code, "US");
+    // unseeded calls in a row don't collide
+    assert!((0..5).any(|_| generate_random_phone_number("US").unwrap() != first));
+}
+
+#[test]
+fn test_generated_numbers_have_a_type() {
+    let mut generator = PhoneNumberGenerator::new(0);
+    for region in ["GB", "FR", "DE", "JP", "CN", "IT", "BR"] {
+        for _ in 0..20 {
+            let number = generator.generate(region).unwrap();
+            assert_ne!(
+                detect_phone_number_type(number),
+                Some(PhoneNumberType::Unknown),
+                "{}",
+                number
+            );
+        }
+    }
+}
+
+#[test]
+fn test_generate_of_type() {
+    let mut generator = PhoneNumberGenerator::new(2787);
+    let mut of_type = |region, number_type| {
+        let number = generator.generate_of_type(region, number_type)?;
+        assert_eq!(number.country().code, region);
+        Some(detect_phone_number_type(number).unwrap())
+    };
+
+    for _ in 0..20 {
+        assert_eq!(
+            of_type("GB", PhoneNumberType::Mobile),
+            Some(PhoneNumberType::Mobile)
+        );
+        assert_eq!(
+            of_type("FR", PhoneNumberType::FixedLine),
+            Some(PhoneNumberType::FixedLine)
+        );
+        assert_eq!(
+            of_type("DE", PhoneNumberType::TollFree),
+            Some(PhoneNumberType::TollFree)
+        );
+        // 10 digit mobiles but 9 digit landlines
+        assert_eq!(
+            of_type("JP", PhoneNumberType::Mobile),
+            Some(PhoneNumberType::Mobile)
+        );
+        // the NANP doesn't tell mobiles apart
+        assert_eq!(
+            of_type("US", PhoneNumberType::Mobile),
+            Some(PhoneNumberType::FixedLineOrMobile)
+        );
+        assert_eq!(
+            of_type("BS", PhoneNumberType::FixedLine),
+            Some(PhoneNumberType::FixedLineOrMobile)
+        );
+    }
+
+    // types the metadata doesn't know
+    assert_eq!(of_type("FR", PhoneNumberType::Pager), None);
+    assert_eq!(of_type("AD", PhoneNumberType::PremiumRate), None);
+    assert_eq!(of_type("XX", PhoneNumberType::Mobile), None);
+}
+
+#[test]
+fn test_generate_random_phone_number_of_type() {
+    let number = generate_random_phone_number_of_type("GB", PhoneNumberType::Mobile).unwrap();
+    assert_eq!(
+        detect_phone_number_type(&number),
+        Some(PhoneNumberType::Mobile)
+    );
+    assert_eq!(
+        generate_random_phone_number_of_type("GB", PhoneNumberType::Emergency),
+        None
+    );
+}
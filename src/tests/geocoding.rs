@@ -0,0 +1,38 @@
+use crate::describe_number;
+
+#[test]
+fn test_describe_number() {
+    assert_eq!(describe_number("+44 20 7946 0018", "en"), Some("London"));
+    assert_eq!(
+        describe_number("+44 161 496 0000", "en"),
+        Some("Manchester")
+    );
+    assert_eq!(describe_number("+1 (416) 555-0123", "en"), Some("Toronto"));
+    assert_eq!(
+        describe_number("+33 1 23 45 67 89", "en"),
+        Some("Île-de-France")
+    );
+    // the significant leading zero of Italian numbers
+    assert_eq!(describe_number("+39 06 1234 5678", "en"), Some("Rome"));
+    assert_eq!(describe_number("+39 011 123 4567", "en"), Some("Turin"));
+}
+
+#[test]
+fn test_describe_number_localized() {
+    assert_eq!(describe_number("+39 06 1234 5678", "it"), Some("Roma"));
+    assert_eq!(describe_number("+41 22 123 45 67", "fr-CH"), Some("Genève"));
+    assert_eq!(describe_number("+41 22 123 45 67", "DE_ch"), Some("Genf"));
+    // English name without translation
+    assert_eq!(describe_number("+49 30 12345678", "de"), Some("Berlin"));
+    assert_eq!(describe_number("+49 30 12345678", "ja"), Some("Berlin"));
+}
+
+#[test]
+fn test_describe_number_unknown() {
+    // mobile
+    assert_eq!(describe_number("+44 7911 123456", "en"), None);
+    // area code without data
+    assert_eq!(describe_number("+33 9 51 23 45 67", "en"), None);
+    assert_eq!(describe_number("+1 907 555 0123", "en"), None);
+    assert_eq!(describe_number("invalid_phone_number", "en"), None);
+}
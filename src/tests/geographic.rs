@@ -0,0 +1,56 @@
+use crate::same_geographic_area;
+
+#[test]
+fn test_same_geographic_area() {
+    // London
+    assert_eq!(
+        same_geographic_area("+44 20 7946 0958", "+44 (20) 7123-4567"),
+        Some(true)
+    );
+    assert_eq!(
+        same_geographic_area("+44 20 7946 0958", "+44 1632 960123"),
+        Some(false)
+    );
+    // Berlin, Washington
+    assert_eq!(
+        same_geographic_area("+49 30 12345678", "+49 30 87654321"),
+        Some(true)
+    );
+    assert_eq!(
+        same_geographic_area("+1 202 555 0173", "+1 202 555 0199"),
+        Some(true)
+    );
+    assert_eq!(
+        same_geographic_area("+1 202 555 0173", "+1 415 555 0173"),
+        Some(false)
+    );
+    // Paris and the north-west of France
+    assert_eq!(
+        same_geographic_area("+33 1 23 45 67 89", "+33 2 23 45 67 89"),
+        Some(false)
+    );
+    // other countries
+    assert_eq!(
+        same_geographic_area("+33 1 23 45 67 89", "+49 30 12345678"),
+        Some(false)
+    );
+}
+
+#[test]
+fn test_same_geographic_area_needs_fixed_lines() {
+    // mobile numbers have no area
+    assert_eq!(
+        same_geographic_area("+44 7700 900123", "+44 7700 900456"),
+        None
+    );
+    assert_eq!(
+        same_geographic_area("+33 6 12 34 56 78", "+33 1 23 45 67 89"),
+        None
+    );
+    // no area codes in the metadata
+    assert_eq!(same_geographic_area("+96111234567", "+96111234568"), None);
+    assert_eq!(
+        same_geographic_area("invalid_phone_number", "+44 20 7946 0958"),
+        None
+    );
+}
@@ -0,0 +1,46 @@
+use crate::{guess_countries, guess_countries_with_priors, CountryPriors};
+
+fn codes(phone_number: &str, priors: &CountryPriors) -> Vec<&'static str> {
+    guess_countries_with_priors(phone_number, priors)
+        .iter()
+        .map(|guess| guess.country.code)
+        .collect()
+}
+
+#[test]
+fn test_guess_countries_bundled_priors() {
+    let guesses = guess_countries("+500 51234");
+    assert_eq!(guesses.len(), 2);
+    assert_eq!(guesses[0].country.code, "FK");
+    let total: f64 = guesses.iter().map(|guess| guess.probability).sum();
+    assert!((total - 1.0).abs() < 1e-9);
+
+    // the structure decides first
+    assert_eq!(codes("+44 7911 123456", &CountryPriors::bundled()), ["GG"]);
+    assert_eq!(
+        codes("+590 590 27 12 34", &CountryPriors::bundled()),
+        ["BL"]
+    );
+    assert_eq!(codes("+1 242 357 1234", &CountryPriors::bundled()), ["BS"]);
+    assert_eq!(codes("+7 701 234 5678", &CountryPriors::bundled()), ["KZ"]);
+
+    assert!(guess_countries("invalid_phone_number").is_empty());
+}
+
+#[test]
+fn test_guess_countries_custom_priors() {
+    let uniform = guess_countries_with_priors("+500 51234", &CountryPriors::uniform());
+    assert_eq!(uniform.len(), 2);
+    assert_eq!(uniform[0].probability, 0.5);
+
+    // a traffic mix from South Georgia
+    let priors = CountryPriors::from_counts([("gs", 900), ("FK", 50), ("XX", 10), ("GS", 100)]);
+    assert_eq!(codes("+500 51234", &priors), ["GS", "FK"]);
+    assert_eq!(
+        guess_countries_with_priors("+500 51234", &priors)[0].probability,
+        1001.0 / 1052.0
+    );
+
+    let priors = CountryPriors::bundled().add("GS", 1_000_000);
+    assert_eq!(codes("+500 51234", &priors), ["GS", "FK"]);
+}
@@ -0,0 +1,22 @@
+use crate::numbering_hints_for_region;
+
+#[test]
+fn test_numbering_hints_for_region() {
+    let hints = numbering_hints_for_region("FR").unwrap();
+    assert_eq!(hints.region, "FR");
+    assert_eq!(hints.calling_code, 33);
+    assert_eq!(hints.national_number_lengths, &[9]);
+    assert_eq!(hints.trunk_prefix, Some("0"));
+    assert_eq!(hints.grouping, "## ## ## ## ##");
+    assert!(!hints.plus_customary);
+
+    let hints = numbering_hints_for_region("JM").unwrap();
+    assert_eq!(hints.calling_code, 1);
+    assert_eq!(hints.grouping, "(###) ###-####");
+
+    let hints = numbering_hints_for_region("AD").unwrap();
+    assert_eq!(hints.trunk_prefix, None);
+    assert!(hints.plus_customary);
+
+    assert_eq!(numbering_hints_for_region("XX"), None);
+}
@@ -0,0 +1,80 @@
+use std::time::{Duration, Instant};
+
+use crate::matcher::{Leniency, PhoneNumberMatcher};
+use crate::{
+    canonical_for_attestation, detect_phone_number_type, explain, extract_country,
+    format_phone_number, is_valid_phone_number, match_phone_numbers, newline_offsets,
+    normalize_phone_number, region_from_accept_language, sanitize_tel_href, validate_packed,
+    Parser, PhoneFormat, E164,
+};
+
+// inputs crafted to hit the worst case of each step: long runs of leading zeros, of digits,
+// of separators, unbalanced and nested parentheses and extension markers
+fn pathological_inputs(len: usize) -> Vec<String> {
+    vec![
+        "0".repeat(len),
+        format!("+{}", "0".repeat(len)),
+        format!("+961{}", "0".repeat(len)),
+        "1".repeat(len),
+        "1 -".repeat(len / 3),
+        "(".repeat(len),
+        format!("{}{}", "(".repeat(len / 2), ")".repeat(len / 2)),
+        "x".repeat(len),
+        format!("+1 202 555 0173{}", " ext.".repeat(len / 5)),
+        "tel:".repeat(len / 4),
+        "é".repeat(len / 2),
+    ]
+}
+
+fn run_all(input: &str) {
+    let parser = Parser::new().trace(true).max_input_length(usize::MAX);
+    normalize_phone_number(input);
+    is_valid_phone_number(input);
+    extract_country(input);
+    E164::parse(input);
+    format_phone_number(input, PhoneFormat::International);
+    detect_phone_number_type(input);
+    match_phone_numbers(input, input);
+    parser.normalize(input);
+    parser.normalize_with_trace(input);
+    explain(input, &parser);
+    sanitize_tel_href(input);
+    canonical_for_attestation(input);
+    #[cfg(feature = "unstable")]
+    crate::is_do_not_originate(input);
+    region_from_accept_language(input);
+    PhoneNumberMatcher::new(Leniency::StrictGrouping)
+        .default_region("US")
+        .find_iter(input)
+        .count();
+    validate_packed(input.as_bytes(), &newline_offsets(input.as_bytes()));
+}
+
+fn time(input: &str) -> Duration {
+    let start = Instant::now();
+    run_all(input);
+    start.elapsed()
+}
+
+#[test]
+fn test_pathological_inputs_take_linear_time() {
+    // warm up the lazy tables so they are not measured
+    pathological_inputs(1 << 10)
+        .iter()
+        .for_each(|input| run_all(input));
+
+    let small = pathological_inputs(1 << 14);
+    let large = pathological_inputs(1 << 18);
+    for (small, large) in small.iter().zip(&large) {
+        let (small_time, large_time) = (time(small), time(large));
+        // 16 times more input may not take more than 16 times longer, with slack for
+        // noise; a quadratic step takes 256 times longer
+        assert!(
+            large_time < small_time * 16 * 4 + Duration::from_millis(50),
+            "{:?} for {:?}... of 256 KiB vs {:?} for 16 KiB",
+            large_time,
+            large.chars().take(16).collect::<String>(),
+            small_time
+        );
+    }
+}
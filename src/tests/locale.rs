@@ -0,0 +1,32 @@
+use crate::region_from_accept_language;
+
+#[test]
+fn test_region_from_accept_language() {
+    assert_eq!(
+        region_from_accept_language("fr-BE,fr;q=0.9"),
+        vec!["BE", "FR"]
+    );
+    assert_eq!(
+        region_from_accept_language("en;q=0.5, de-AT;q=0.8, *;q=0.1"),
+        vec!["AT", "US"]
+    );
+    assert_eq!(
+        region_from_accept_language("en-GB,en;q=0.9,en-US;q=0.8"),
+        vec!["GB", "US"]
+    );
+    assert_eq!(region_from_accept_language("zh-Hant-TW"), vec!["TW"]);
+    assert_eq!(region_from_accept_language("pt_BR"), vec!["BR"]);
+    assert_eq!(region_from_accept_language("en-UK"), vec!["GB"]);
+    assert_eq!(region_from_accept_language("es-419,es;q=0.9"), vec!["ES"]);
+}
+
+#[test]
+fn test_region_from_accept_language_skips_unusable_ranges() {
+    assert_eq!(region_from_accept_language("fr;q=0,de"), vec!["DE"]);
+    assert_eq!(
+        region_from_accept_language("xx, de;q=abc"),
+        Vec::<&str>::new()
+    );
+    assert_eq!(region_from_accept_language(""), Vec::<&str>::new());
+    assert_eq!(region_from_accept_language("*"), Vec::<&str>::new());
+}
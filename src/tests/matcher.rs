@@ -0,0 +1,77 @@
+use crate::matcher::{Leniency, PhoneNumberMatcher};
+
+fn found(matcher: &PhoneNumberMatcher, text: &str) -> Vec<String> {
+    matcher
+        .find_iter(text)
+        .map(|found| found.raw.to_string())
+        .collect()
+}
+
+#[test]
+fn test_matcher_offsets() {
+    let text = "Tel: +961 79 123 123. Fax: (+1) 202-555-0173";
+    let matcher = PhoneNumberMatcher::new(Leniency::Valid);
+    let matches: Vec<_> = matcher.find_iter(text).collect();
+
+    assert_eq!(matches.len(), 2);
+    assert_eq!(matches[0].raw, "+961 79 123 123");
+    assert_eq!(&text[matches[0].start..matches[0].end()], matches[0].raw);
+    assert_eq!(matches[0].number.as_ref().unwrap().e164(), "+96179123123");
+    assert_eq!(matches[1].raw, "(+1) 202-555-0173");
+    assert_eq!(matches[1].number.as_ref().unwrap().country().code, "US");
+}
+
+#[test]
+fn test_matcher_false_positives() {
+    let matcher = PhoneNumberMatcher::new(Leniency::Possible).default_region("US");
+
+    // dates
+    assert!(found(&matcher, "on 2026-10-15 and 10/15/2026 or 15.10.26").is_empty());
+    // prices and amounts
+    assert!(found(&matcher, "total $2025550173 or 2025550173€ or 2025550173%").is_empty());
+    // IDs and references
+    assert!(found(&matcher, "ID-2025550173, order #2025550173, SKU2025550173").is_empty());
+    assert!(found(&matcher, "mail 2025550173@example.com").is_empty());
+
+    assert_eq!(found(&matcher, "call 202-555-0173 today"), ["202-555-0173"]);
+}
+
+#[test]
+fn test_matcher_leniency() {
+    // no French range starts with 85
+    let text = "+33 8 51 23 45 67, +33 61 234 5678, +33 6 12 34 56 78";
+    let lenient = |leniency| found(&PhoneNumberMatcher::new(leniency), text);
+
+    assert_eq!(
+        lenient(Leniency::Possible),
+        ["+33 8 51 23 45 67", "+33 61 234 5678", "+33 6 12 34 56 78"]
+    );
+    assert_eq!(
+        lenient(Leniency::Valid),
+        ["+33 61 234 5678", "+33 6 12 34 56 78"]
+    );
+    assert_eq!(lenient(Leniency::StrictGrouping), ["+33 6 12 34 56 78"]);
+
+    let strict = PhoneNumberMatcher::new(Leniency::StrictGrouping).default_region("FR");
+    assert_eq!(
+        found(&strict, "+33612345678 or +33 612345678 or 06 12 34 56 78"),
+        ["+33612345678", "+33 612345678", "06 12 34 56 78"]
+    );
+}
+
+#[test]
+fn test_matcher_national_numbers() {
+    let text = "Appelez le 06 12 34 56 78";
+
+    // national numbers need a region
+    assert!(found(&PhoneNumberMatcher::new(Leniency::Valid), text).is_empty());
+    assert_eq!(
+        found(
+            &PhoneNumberMatcher::new(Leniency::Valid).default_region("FR"),
+            text
+        ),
+        ["06 12 34 56 78"]
+    );
+    assert!(found(&PhoneNumberMatcher::new(Leniency::Valid), "").is_empty());
+    assert!(found(&PhoneNumberMatcher::new(Leniency::Valid), "(+ - )").is_empty());
+}
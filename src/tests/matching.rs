@@ -0,0 +1,116 @@
+use crate::{
+    are_phone_numbers_equal, match_by_suffix, match_phone_numbers, match_phone_numbers_with,
+    merge_confidence, ExtensionPolicy, MatchType,
+};
+
+#[test]
+fn test_match_phone_numbers() {
+    assert_eq!(
+        match_phone_numbers("+1 (202) 555-0173", "+12025550173"),
+        MatchType::ExactMatch
+    );
+    assert_eq!(
+        match_phone_numbers("TEL:+33612345678", "+33 6 12 34 56 78"),
+        MatchType::ExactMatch
+    );
+    assert_eq!(
+        match_phone_numbers("+33612345678", "06 12 34 56 78"),
+        MatchType::NsnMatch
+    );
+    assert_eq!(
+        match_phone_numbers("+33612345678", "+32612345678"),
+        MatchType::NsnMatch
+    );
+    assert_eq!(
+        match_phone_numbers("+12025550173", "5550173"),
+        MatchType::ShortNsnMatch
+    );
+    assert_eq!(
+        match_phone_numbers("+12025550173", "0173"),
+        MatchType::NoMatch
+    );
+    assert_eq!(
+        match_phone_numbers("+12025550173", "+96179123123"),
+        MatchType::NoMatch
+    );
+    assert_eq!(match_phone_numbers("", ""), MatchType::NoMatch);
+    // the area code of the NANP members is part of their national number
+    assert_eq!(
+        match_phone_numbers("+1 242 555 1234", "242 555 1234"),
+        MatchType::NsnMatch
+    );
+    assert_eq!(
+        match_phone_numbers("+1 242 555 1234", "+1 441 555 1234"),
+        MatchType::NoMatch
+    );
+}
+
+#[test]
+fn test_merge_confidence() {
+    let contact = ["+12025550173", "+33612345678"];
+
+    assert_eq!(merge_confidence(&contact, &[]), 0.0);
+    assert_eq!(merge_confidence(&contact, &["+96179123123"]), 0.0);
+
+    let exact = merge_confidence(&contact, &["+1 202 555 0173"]);
+    let nsn = merge_confidence(&contact, &["0612345678"]);
+    let suffix = merge_confidence(&contact, &["555-0173"]);
+    let both = merge_confidence(&contact, &["+1 202 555 0173", "0612345678"]);
+
+    assert!(exact > nsn && nsn > suffix && suffix > 0.0);
+    assert!(both > exact && both <= 1.0);
+}
+
+#[test]
+fn test_extension_policies() {
+    let desk = "+1 202 555 0173 ext. 45";
+    let other_desk = "+1 (202) 555-0173 x46";
+    let switchboard = "+12025550173";
+
+    assert_eq!(match_phone_numbers(desk, other_desk), MatchType::NoMatch);
+    assert_eq!(
+        match_phone_numbers(desk, switchboard),
+        MatchType::ExactMatch
+    );
+    assert_eq!(
+        match_phone_numbers_with(desk, other_desk, ExtensionPolicy::Ignore),
+        MatchType::ExactMatch
+    );
+
+    assert!(are_phone_numbers_equal(
+        desk,
+        "tel:+1-202-555-0173;ext=45",
+        ExtensionPolicy::Require
+    ));
+    assert!(!are_phone_numbers_equal(
+        desk,
+        switchboard,
+        ExtensionPolicy::Require
+    ));
+    assert!(are_phone_numbers_equal(
+        desk,
+        switchboard,
+        ExtensionPolicy::MatchIfPresent
+    ));
+    assert!(are_phone_numbers_equal(
+        desk,
+        other_desk,
+        ExtensionPolicy::Ignore
+    ));
+    assert!(!are_phone_numbers_equal(
+        desk,
+        "+96179123123",
+        ExtensionPolicy::Ignore
+    ));
+}
+
+#[test]
+fn test_match_by_suffix() {
+    assert!(match_by_suffix("+1 (202) 555-0173", "2025550173", 10));
+    assert!(match_by_suffix("+1 (202) 555-0173", "555-0173", 7));
+    assert!(match_by_suffix("+12025550173 ext. 12", "5550173", 0));
+    assert!(!match_by_suffix("+12025550173", "+12025550174", 7));
+    // suffixes shorter than 7 digits are never trusted
+    assert!(!match_by_suffix("+12025550173", "50173", 5));
+    assert!(!match_by_suffix("+12025550173", "2125550173", 10));
+}
@@ -0,0 +1,62 @@
+use crate::revalidation::previous_metadata;
+use crate::{changelog, changes_since, diff_metadata, Country, Metadata, MetadataChange};
+
+#[test]
+fn test_diff_metadata_matches_changelog() {
+    let bundled = Metadata::bundled();
+    assert!(diff_metadata(&bundled, &bundled).is_empty());
+
+    // every tracked snapshot, rebuilt from the changelog, differs from the previous one by
+    // the changes of its entry
+    for entry in changelog().iter().skip(1) {
+        let version = entry.metadata_version;
+        let before = previous_metadata(version - 1, changes_since(version - 1));
+        let after = previous_metadata(version, changes_since(version));
+
+        let diff = diff_metadata(&before, &after);
+        let mut changes = entry.changes.to_vec();
+        changes.sort_by_key(MetadataChange::country_id);
+        assert_eq!((diff.from_version, diff.to_version), (version - 1, version));
+        assert_eq!(diff.changes, changes, "metadata version {}", version);
+    }
+}
+
+#[test]
+fn test_diff_metadata() {
+    let bundled = Metadata::bundled();
+    let mut countries: Vec<Country> = bundled.countries().to_vec();
+    let lebanon = countries
+        .iter_mut()
+        .find(|country| country.code == "LB")
+        .unwrap();
+    lebanon.prefix = 962;
+    lebanon.phone_lengths = &[8];
+    let removed = countries.remove(0);
+    let update = Metadata::new(bundled.version() + 1, countries);
+
+    assert_eq!(
+        diff_metadata(&bundled, &update).changes,
+        [
+            MetadataChange::CountryRemoved {
+                id: removed.id,
+                code: removed.code,
+                prefix: removed.prefix,
+                phone_lengths: removed.phone_lengths,
+            },
+            MetadataChange::PrefixChanged {
+                id: 127,
+                before: 961,
+                after: 962,
+            },
+            MetadataChange::LengthsChanged {
+                id: 127,
+                before: &[7, 8],
+                after: &[8],
+            },
+        ]
+    );
+    assert_eq!(
+        diff_metadata(&update, &bundled).changes[0],
+        MetadataChange::CountryAdded { id: removed.id }
+    );
+}
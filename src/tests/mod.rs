@@ -1,870 +1,467 @@
+#[cfg(all(test, feature = "arrow"))]
+mod arrow;
 #[cfg(test)]
+mod as_you_type;
+#[cfg(test)]
+mod attestation;
+#[cfg(test)]
+mod bulk;
+#[cfg(all(test, feature = "carrier"))]
+mod carrier;
+#[cfg(test)]
+mod changelog;
+#[cfg(all(test, feature = "compact-metadata"))]
+mod compact;
+#[cfg(test)]
+mod compat;
+#[cfg(test)]
+mod corpus;
+#[cfg(test)]
+mod countries;
+#[cfg(test)]
+mod determinism;
+#[cfg(test)]
+mod dial_plan;
+#[cfg(test)]
+mod display;
+#[cfg(test)]
+mod e164;
+#[cfg(test)]
+mod emergency;
+#[cfg(test)]
+mod enumerate;
+#[cfg(test)]
+mod equivalent;
+#[cfg(test)]
+mod error;
+#[cfg(test)]
+mod examples;
+#[cfg(test)]
+mod explain;
+#[cfg(test)]
+mod ffi;
+#[cfg(test)]
+mod fiction;
+#[cfg(test)]
+mod formatting;
+#[cfg(test)]
+mod generate;
+#[cfg(all(test, feature = "geocoding"))]
+mod geocoding;
+#[cfg(test)]
+mod geographic;
+#[cfg(test)]
+mod guess;
+#[cfg(test)]
+mod hints;
+#[cfg(test)]
+mod linear_time;
+#[cfg(test)]
+mod locale;
+#[cfg(test)]
+mod matcher;
+#[cfg(test)]
+mod matching;
+#[cfg(test)]
+mod metadata;
+#[cfg(test)]
+mod msisdn;
+#[cfg(test)]
+mod neighbors;
+#[cfg(test)]
+mod number_type;
+#[cfg(test)]
+mod parser;
+#[cfg(test)]
+mod phone_number;
+#[cfg(all(test, feature = "polars"))]
+mod polars_plugin;
+#[cfg(test)]
+mod private_plans;
+#[cfg(test)]
+mod properties;
+#[cfg(test)]
+mod quiet_hours;
+#[cfg(test)]
+mod quirks;
+#[cfg(test)]
+mod redaction;
+#[cfg(test)]
+mod revalidation;
+#[cfg(test)]
+mod rfc3966;
+#[cfg(test)]
+mod risk;
+#[cfg(all(test, feature = "unstable"))]
+mod risk_score;
+#[cfg(test)]
+mod round_trip;
+#[cfg(test)]
+mod sampler;
+#[cfg(test)]
+mod scrub;
+#[cfg(test)]
+mod sender_id;
+#[cfg(all(test, feature = "serde"))]
+mod serialization;
+#[cfg(test)]
+mod short_codes;
+#[cfg(test)]
+mod smpp;
+#[cfg(all(test, feature = "stats"))]
+mod stats;
+#[cfg(test)]
+mod sync;
+#[cfg(test)]
+mod test_numbers;
+#[cfg(test)]
+mod toll_free;
+#[cfg(test)]
+mod unicode;
+#[cfg(test)]
+mod validation;
+#[cfg(test)]
+mod vanity;
+#[cfg(all(test, feature = "wasm"))]
+mod wasm;
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
 mod tests {
+    use crate::constants::{AREA_CODES, COUNTRIES};
+    use std::ops::RangeInclusive;
+
+    use crate::number_type::type_prefixes;
     use crate::{
-        extract_country, is_valid_phone_number, normalize_phone_number,
-        normalize_phone_number_in_place,
+        calling_code, extract_country, extract_country_unchecked_digits, format_phone_number,
+        is_valid_phone_number, normalize_phone_number, normalize_phone_number_in_place,
+        normalize_phone_number_in_region, normalize_unchecked_digits, strip_country_code, Country,
+        PhoneFormat, PhoneNumberType,
     };
 
-    struct PhoneNumber {
-        country_code: &'static str,
-        phone_number: &'static str,
+    pub(super) struct PhoneNumber {
+        pub(super) country: &'static Country,
+        pub(super) phone_number: String,
     }
 
-    const PHONE_NUMBERS: [PhoneNumber; 211] = [
-        PhoneNumber {
-            country_code: "US",
-            phone_number: "+12025550173",
-        },
-        PhoneNumber {
-            country_code: "GB",
-            phone_number: "+442079460958",
-        },
-        PhoneNumber {
-            country_code: "IN",
-            phone_number: "+919876543210",
-        },
-        PhoneNumber {
-            country_code: "DE",
-            phone_number: "+493012345678",
-        },
-        PhoneNumber {
-            country_code: "AU",
-            phone_number: "+61412345678",
-        },
-        PhoneNumber {
-            country_code: "CN",
-            phone_number: "+8613800138000",
-        },
-        PhoneNumber {
-            country_code: "FR",
-            phone_number: "+33123456789",
-        },
-        PhoneNumber {
-            country_code: "BR",
-            phone_number: "+5511912345678",
-        },
-        PhoneNumber {
-            country_code: "JP",
-            phone_number: "+819012345678",
-        },
-        PhoneNumber {
-            country_code: "ZA",
-            phone_number: "+27821234567",
-        },
-        PhoneNumber {
-            country_code: "LB",
-            phone_number: "+96179123123",
-        },
-        PhoneNumber {
-            country_code: "SY",
-            phone_number: "+963944567890",
-        },
-        PhoneNumber {
-            country_code: "IQ",
-            phone_number: "+9647901234567",
-        },
-        PhoneNumber {
-            country_code: "KW",
-            phone_number: "+96550012345",
-        },
-        PhoneNumber {
-            country_code: "SA",
-            phone_number: "+966512345678",
-        },
-        PhoneNumber {
-            country_code: "YE",
-            phone_number: "+967711234567",
-        },
-        PhoneNumber {
-            country_code: "OM",
-            phone_number: "+96892123456",
-        },
-        PhoneNumber {
-            country_code: "PS",
-            phone_number: "+970599123456",
-        },
-        PhoneNumber {
-            country_code: "AE",
-            phone_number: "+971501234567",
-        },
-        PhoneNumber {
-            country_code: "IL",
-            phone_number: "+972501234567",
-        },
-        PhoneNumber {
-            country_code: "BH",
-            phone_number: "+97336012345",
-        },
-        PhoneNumber {
-            country_code: "QA",
-            phone_number: "+97433123456",
-        },
-        PhoneNumber {
-            country_code: "BT",
-            phone_number: "+97517123456",
-        },
-        PhoneNumber {
-            country_code: "MN",
-            phone_number: "+97699123456",
-        },
-        PhoneNumber {
-            country_code: "NP",
-            phone_number: "+9779841234567",
-        },
-        PhoneNumber {
-            country_code: "IR",
-            phone_number: "+989123456789",
-        },
-        PhoneNumber {
-            country_code: "TJ",
-            phone_number: "+992931234567",
-        },
-        PhoneNumber {
-            country_code: "TM",
-            phone_number: "+99365123456",
-        },
-        PhoneNumber {
-            country_code: "AZ",
-            phone_number: "+994401234567",
-        },
-        PhoneNumber {
-            country_code: "GE",
-            phone_number: "+995591234567",
-        },
-        PhoneNumber {
-            country_code: "KG",
-            phone_number: "+996551234567",
-        },
-        PhoneNumber {
-            country_code: "UZ",
-            phone_number: "+998971234567",
-        },
-        PhoneNumber {
-            country_code: "BS",
-            phone_number: "+12425571234",
-        },
-        PhoneNumber {
-            country_code: "BB",
-            phone_number: "+12462311234",
-        },
-        PhoneNumber {
-            country_code: "AI",
-            phone_number: "+12642351234",
-        },
-        PhoneNumber {
-            country_code: "AG",
-            phone_number: "+12684641234",
-        },
-        PhoneNumber {
-            country_code: "VG",
-            phone_number: "+12844681234",
-        },
-        PhoneNumber {
-            country_code: "VI",
-            phone_number: "+13406901234",
-        },
-        PhoneNumber {
-            country_code: "KY",
-            phone_number: "+13453211234",
-        },
-        PhoneNumber {
-            country_code: "BM",
-            phone_number: "+14412341234",
-        },
-        PhoneNumber {
-            country_code: "GD",
-            phone_number: "+14732341234",
-        },
-        PhoneNumber {
-            country_code: "TC",
-            phone_number: "+16492311234",
-        },
-        PhoneNumber {
-            country_code: "MS",
-            phone_number: "+16642351234",
-        },
-        PhoneNumber {
-            country_code: "MP",
-            phone_number: "+16702351234",
-        },
-        PhoneNumber {
-            country_code: "GU",
-            phone_number: "+16712351234",
-        },
-        PhoneNumber {
-            country_code: "AS",
-            phone_number: "+16842351234",
-        },
-        PhoneNumber {
-            country_code: "SX",
-            phone_number: "+17215431234",
-        },
-        PhoneNumber {
-            country_code: "LC",
-            phone_number: "+17582841234",
-        },
-        PhoneNumber {
-            country_code: "DM",
-            phone_number: "+17672351234",
-        },
-        PhoneNumber {
-            country_code: "VC",
-            phone_number: "+17842351234",
-        },
-        PhoneNumber {
-            country_code: "PR",
-            phone_number: "+17872351234",
-        },
-        PhoneNumber {
-            country_code: "DO",
-            phone_number: "+18092351234",
-        },
-        PhoneNumber {
-            country_code: "DO",
-            phone_number: "+18292351234",
-        },
-        PhoneNumber {
-            country_code: "DO",
-            phone_number: "+18492351234",
-        },
-        PhoneNumber {
-            country_code: "TT",
-            phone_number: "+18682351234",
-        },
-        PhoneNumber {
-            country_code: "KN",
-            phone_number: "+18692351234",
-        },
-        PhoneNumber {
-            country_code: "JM",
-            phone_number: "+18762351234",
-        },
-        PhoneNumber {
-            country_code: "RE",
-            phone_number: "+262692691234",
-        },
-        PhoneNumber {
-            country_code: "ZW",
-            phone_number: "+263772112345",
-        },
-        PhoneNumber {
-            country_code: "NA",
-            phone_number: "+264601234567",
-        },
-        PhoneNumber {
-            country_code: "MW",
-            phone_number: "+265991234567",
-        },
-        PhoneNumber {
-            country_code: "LS",
-            phone_number: "+26662012345",
-        },
-        PhoneNumber {
-            country_code: "BW",
-            phone_number: "+26771123456",
-        },
-        PhoneNumber {
-            country_code: "SZ",
-            phone_number: "+26876123456",
-        },
-        PhoneNumber {
-            country_code: "KM",
-            phone_number: "+2693112345",
-        },
-        PhoneNumber {
-            country_code: "SH",
-            phone_number: "+29022123",
-        },
-        PhoneNumber {
-            country_code: "ER",
-            phone_number: "+2917111234",
-        },
-        PhoneNumber {
-            country_code: "AW",
-            phone_number: "+2975601234",
-        },
-        PhoneNumber {
-            country_code: "FO",
-            phone_number: "+298201234",
-        },
-        PhoneNumber {
-            country_code: "GL",
-            phone_number: "+299201234",
-        },
-        PhoneNumber {
-            country_code: "GI",
-            phone_number: "+35056012345",
-        },
-        PhoneNumber {
-            country_code: "PT",
-            phone_number: "+351201234567",
-        },
-        PhoneNumber {
-            country_code: "LU",
-            phone_number: "+35220123456",
-        },
-        PhoneNumber {
-            country_code: "IE",
-            phone_number: "+353201234567",
-        },
-        PhoneNumber {
-            country_code: "IS",
-            phone_number: "+3544101234",
-        },
-        PhoneNumber {
-            country_code: "AL",
-            phone_number: "+355691234567",
-        },
-        PhoneNumber {
-            country_code: "MT",
-            phone_number: "+35679012345",
-        },
-        PhoneNumber {
-            country_code: "CY",
-            phone_number: "+35796123456",
-        },
-        PhoneNumber {
-            country_code: "FI",
-            phone_number: "+358201234567",
-        },
-        PhoneNumber {
-            country_code: "BG",
-            phone_number: "+35920123456",
-        },
-        PhoneNumber {
-            country_code: "LT",
-            phone_number: "+37061234567",
-        },
-        PhoneNumber {
-            country_code: "LV",
-            phone_number: "+37120123456",
-        },
-        PhoneNumber {
-            country_code: "EE",
-            phone_number: "+37251234567",
-        },
-        PhoneNumber {
-            country_code: "MD",
-            phone_number: "+37368123456",
-        },
-        PhoneNumber {
-            country_code: "AM",
-            phone_number: "+37491234567",
-        },
-        PhoneNumber {
-            country_code: "BY",
-            phone_number: "+375291234567",
-        },
-        PhoneNumber {
-            country_code: "AD",
-            phone_number: "+376312345",
-        },
-        PhoneNumber {
-            country_code: "MC",
-            phone_number: "+37761234567",
-        },
-        PhoneNumber {
-            country_code: "SM",
-            phone_number: "+378661234567",
-        },
-        PhoneNumber {
-            country_code: "VA",
-            phone_number: "+379612345678",
-        },
-        PhoneNumber {
-            country_code: "UA",
-            phone_number: "+380501234567",
-        },
-        PhoneNumber {
-            country_code: "RS",
-            phone_number: "+381601234567",
-        },
-        PhoneNumber {
-            country_code: "ME",
-            phone_number: "+38267123456",
-        },
-        PhoneNumber {
-            country_code: "HR",
-            phone_number: "+385911234567",
-        },
-        PhoneNumber {
-            country_code: "SI",
-            phone_number: "+38631234567",
-        },
-        PhoneNumber {
-            country_code: "BA",
-            phone_number: "+38761123456",
-        },
-        PhoneNumber {
-            country_code: "MK",
-            phone_number: "+38970123456",
-        },
-        PhoneNumber {
-            country_code: "CZ",
-            phone_number: "+420601123456",
-        },
-        PhoneNumber {
-            country_code: "SK",
-            phone_number: "+421912345678",
-        },
-        PhoneNumber {
-            country_code: "LI",
-            phone_number: "+4236608811",
-        },
-        PhoneNumber {
-            country_code: "FK",
-            phone_number: "+50051234",
-        },
-        PhoneNumber {
-            country_code: "BZ",
-            phone_number: "+5018221234",
-        },
-        PhoneNumber {
-            country_code: "GT",
-            phone_number: "+50251234567",
-        },
-        PhoneNumber {
-            country_code: "SV",
-            phone_number: "+50370123456",
-        },
-        PhoneNumber {
-            country_code: "HN",
-            phone_number: "+50491234567",
-        },
-        PhoneNumber {
-            country_code: "NI",
-            phone_number: "+50581234567",
-        },
-        PhoneNumber {
-            country_code: "CR",
-            phone_number: "+50670123456",
-        },
-        PhoneNumber {
-            country_code: "PA",
-            phone_number: "+50761234567",
-        },
-        PhoneNumber {
-            country_code: "PM",
-            phone_number: "+50850123456",
-        },
-        PhoneNumber {
-            country_code: "HT",
-            phone_number: "+50928123456",
-        },
-        PhoneNumber {
-            country_code: "GP",
-            phone_number: "+590590123456",
-        },
-        PhoneNumber {
-            country_code: "BO",
-            phone_number: "+59171234567",
-        },
-        PhoneNumber {
-            country_code: "GY",
-            phone_number: "+5926091234",
-        },
-        PhoneNumber {
-            country_code: "EC",
-            phone_number: "+593991234567",
-        },
-        PhoneNumber {
-            country_code: "GF",
-            phone_number: "+594694201234",
-        },
-        PhoneNumber {
-            country_code: "PY",
-            phone_number: "+595961456789",
-        },
-        PhoneNumber {
-            country_code: "MQ",
-            phone_number: "+596696201234",
-        },
-        PhoneNumber {
-            country_code: "SR",
-            phone_number: "+5977412345",
-        },
-        PhoneNumber {
-            country_code: "UY",
-            phone_number: "+59894231234",
-        },
-        PhoneNumber {
-            country_code: "CW",
-            phone_number: "+59995181234",
-        },
-        PhoneNumber {
-            country_code: "TL",
-            phone_number: "+67077231234",
-        },
-        PhoneNumber {
-            country_code: "NF",
-            phone_number: "+672312345",
-        },
-        PhoneNumber {
-            country_code: "BN",
-            phone_number: "+6737123456",
-        },
-        PhoneNumber {
-            country_code: "NR",
-            phone_number: "+6745571234",
-        },
-        PhoneNumber {
-            country_code: "PG",
-            phone_number: "+67570123456",
-        },
-        PhoneNumber {
-            country_code: "TO",
-            phone_number: "+67677151234",
-        },
-        PhoneNumber {
-            country_code: "SB",
-            phone_number: "+67762123",
-        },
-        PhoneNumber {
-            country_code: "VU",
-            phone_number: "+67824612",
-        },
-        PhoneNumber {
-            country_code: "FJ",
-            phone_number: "+6797012345",
-        },
-        PhoneNumber {
-            country_code: "PW",
-            phone_number: "+6806201234",
-        },
-        PhoneNumber {
-            country_code: "WF",
-            phone_number: "+681501234",
-        },
-        PhoneNumber {
-            country_code: "CK",
-            phone_number: "+68222123",
-        },
-        PhoneNumber {
-            country_code: "KI",
-            phone_number: "+68660123",
-        },
-        PhoneNumber {
-            country_code: "NC",
-            phone_number: "+687501234",
-        },
-        PhoneNumber {
-            country_code: "TV",
-            phone_number: "+688901234",
-        },
-        PhoneNumber {
-            country_code: "PF",
-            phone_number: "+68987123456",
-        },
-        PhoneNumber {
-            country_code: "TK",
-            phone_number: "+6903012",
-        },
-        PhoneNumber {
-            country_code: "FM",
-            phone_number: "+6913501234",
-        },
-        PhoneNumber {
-            country_code: "MH",
-            phone_number: "+6922471234",
-        },
-        PhoneNumber {
-            country_code: "KP",
-            phone_number: "+8501912345678",
-        },
-        PhoneNumber {
-            country_code: "HK",
-            phone_number: "+85251234567",
-        },
-        PhoneNumber {
-            country_code: "MO",
-            phone_number: "+85366123456",
-        },
-        PhoneNumber {
-            country_code: "KH",
-            phone_number: "+85512345678",
-        },
-        PhoneNumber {
-            country_code: "LA",
-            phone_number: "+85620911234",
-        },
-        PhoneNumber {
-            country_code: "BD",
-            phone_number: "+8801812345678",
-        },
-        PhoneNumber {
-            country_code: "TW",
-            phone_number: "+886912345678",
-        },
-        PhoneNumber {
-            country_code: "MV",
-            phone_number: "+9607712345",
-        },
-        PhoneNumber {
-            country_code: "JO",
-            phone_number: "+962791234567",
-        },
-        PhoneNumber {
-            country_code: "SG",
-            phone_number: "+6581234567",
-        }, // Singapore
-        PhoneNumber {
-            country_code: "MY",
-            phone_number: "+60121234567",
-        }, // Malaysia
-        PhoneNumber {
-            country_code: "PH",
-            phone_number: "+639171234567",
-        }, // Philippines
-        PhoneNumber {
-            country_code: "TH",
-            phone_number: "+66812345678",
-        }, // Thailand
-        PhoneNumber {
-            country_code: "VN",
-            phone_number: "+84912345678",
-        }, // Vietnam
-        PhoneNumber {
-            country_code: "PK",
-            phone_number: "+923001234567",
-        }, // Pakistan
-        PhoneNumber {
-            country_code: "LK",
-            phone_number: "+94771234567",
-        }, // Sri Lanka
-        PhoneNumber {
-            country_code: "AF",
-            phone_number: "+93700123456",
-        }, // Afghanistan
-        PhoneNumber {
-            country_code: "MM",
-            phone_number: "+959123456789",
-        }, // Myanmar
-        PhoneNumber {
-            country_code: "KZ",
-            phone_number: "+77012345678",
-        }, // Kazakhstan
-        PhoneNumber {
-            country_code: "UZ",
-            phone_number: "+998901234567",
-        }, // Uzbekistan
-        PhoneNumber {
-            country_code: "TJ",
-            phone_number: "+992551234567",
-        }, // Tajikistan
-        PhoneNumber {
-            country_code: "KG",
-            phone_number: "+996701234567",
-        }, // Kyrgyzstan
-        PhoneNumber {
-            country_code: "TM",
-            phone_number: "+993651234567",
-        }, // Turkmenistan
-        PhoneNumber {
-            country_code: "MN",
-            phone_number: "+97688123456",
-        }, // Mongolia
-        PhoneNumber {
-            country_code: "NP",
-            phone_number: "+9779812345678",
-        }, // Nepal
-        PhoneNumber {
-            country_code: "BT",
-            phone_number: "+97517123456",
-        }, // Bhutan
-        PhoneNumber {
-            country_code: "AM",
-            phone_number: "+37494123456",
-        }, // Armenia
-        PhoneNumber {
-            country_code: "AZ",
-            phone_number: "+994501234567",
-        }, // Azerbaijan
-        PhoneNumber {
-            country_code: "GE",
-            phone_number: "+995555123456",
-        }, // Georgia
-        PhoneNumber {
-            country_code: "TM",
-            phone_number: "+99312123456",
-        }, // Turkmenistan
-        PhoneNumber {
-            country_code: "AE",
-            phone_number: "+971501234567",
-        }, // UAE
-        PhoneNumber {
-            country_code: "BH",
-            phone_number: "+97333123456",
-        }, // Bahrain
-        PhoneNumber {
-            country_code: "QA",
-            phone_number: "+97450123456",
-        }, // Qatar
-        PhoneNumber {
-            country_code: "KW",
-            phone_number: "+96550123456",
-        }, // Kuwait
-        PhoneNumber {
-            country_code: "OM",
-            phone_number: "+96892123456",
-        }, // Oman
-        PhoneNumber {
-            country_code: "YE",
-            phone_number: "+967711234567",
-        }, // Yemen
-        PhoneNumber {
-            country_code: "SO",
-            phone_number: "+252615123456",
-        }, // Somalia
-        PhoneNumber {
-            country_code: "KE",
-            phone_number: "+254701234567",
-        }, // Kenya
-        PhoneNumber {
-            country_code: "TZ",
-            phone_number: "+255621234567",
-        }, // Tanzania
-        PhoneNumber {
-            country_code: "UG",
-            phone_number: "+256701234567",
-        }, // Uganda
-        PhoneNumber {
-            country_code: "ET",
-            phone_number: "+251911234567",
-        }, // Ethiopia
-        PhoneNumber {
-            country_code: "NG",
-            phone_number: "+234701234567",
-        }, // Nigeria
-        PhoneNumber {
-            country_code: "GH",
-            phone_number: "+233501234567",
-        }, // Ghana
-        PhoneNumber {
-            country_code: "SN",
-            phone_number: "+221771234567",
-        }, // Senegal
-        PhoneNumber {
-            country_code: "CI",
-            phone_number: "+22551234567",
-        }, // Ivory Coast
-        PhoneNumber {
-            country_code: "ML",
-            phone_number: "+22365123456",
-        }, // Mali
-        PhoneNumber {
-            country_code: "ZM",
-            phone_number: "+260961234567",
-        }, // Zambia
-        PhoneNumber {
-            country_code: "ZW",
-            phone_number: "+263771234567",
-        }, // Zimbabwe
-        PhoneNumber {
-            country_code: "BW",
-            phone_number: "+26772123456",
-        }, // Botswana
-        PhoneNumber {
-            country_code: "NA",
-            phone_number: "+264811234567",
-        }, // Namibia
-        PhoneNumber {
-            country_code: "MG",
-            phone_number: "+261341234567",
-        }, // Madagascar
-        PhoneNumber {
-            country_code: "RE",
-            phone_number: "+262692123456",
-        }, // Reunion
-        PhoneNumber {
-            country_code: "MU",
-            phone_number: "+23057123456",
-        }, // Mauritius
-        PhoneNumber {
-            country_code: "SC",
-            phone_number: "+2482512345",
-        }, // Seychelles
-        PhoneNumber {
-            country_code: "MW",
-            phone_number: "+265991234567",
-        }, // Malawi
-        PhoneNumber {
-            country_code: "LS",
-            phone_number: "+26650123456",
-        }, // Lesotho
-        PhoneNumber {
-            country_code: "SZ",
-            phone_number: "+26876123456",
-        }, // Eswatini
-        PhoneNumber {
-            country_code: "CV",
-            phone_number: "+2389912345",
-        }, // Cape Verde
-        PhoneNumber {
-            country_code: "ST",
-            phone_number: "+2399912345",
-        }, // Sao Tome and Principe
-        PhoneNumber {
-            country_code: "GQ",
-            phone_number: "+240222123456",
-        }, // Equatorial Guinea
-        PhoneNumber {
-            country_code: "CD",
-            phone_number: "+243991234567",
-        }, // Democratic Republic of the Congo
-        PhoneNumber {
-            country_code: "AO",
-            phone_number: "+244921234567",
-        }, // Angola
-        PhoneNumber {
-            country_code: "GW",
-            phone_number: "+245501234511",
-        }, // Guinea-Bissau
-        PhoneNumber {
-            country_code: "IO",
-            phone_number: "+2463801234",
-        }, // British Indian Ocean Territory
-        PhoneNumber {
-            country_code: "AC",
-            phone_number: "+2473612",
-        }, // Ascension Island
-        PhoneNumber {
-            country_code: "SC",
-            phone_number: "+2482512345",
-        }, // Seychelles
-        PhoneNumber {
-            country_code: "SD",
-            phone_number: "+249911231234",
-        }, // Sudan
-        PhoneNumber {
-            country_code: "RW",
-            phone_number: "+250720123456",
-        }, // Rwanda
-        PhoneNumber {
-            country_code: "SO",
-            phone_number: "+252615123456",
-        }, // Somalia
-        PhoneNumber {
-            country_code: "DJ",
-            phone_number: "+25377123123",
-        }, // Djibouti
-        PhoneNumber {
-            country_code: "BI",
-            phone_number: "+25779123456",
-        }, // Burundi
-        PhoneNumber {
-            country_code: "MZ",
-            phone_number: "+258821234567",
-        }, // Mozambique
+    // real numbers, from the examples of the numbering plans, next to the generated ones:
+    // they cover the number ranges the generated fixtures don't reach, e.g. the three area
+    // codes of the Dominican Republic or the regions sharing a calling code
+    pub(super) const REAL_NUMBERS: &[(&str, &str)] = &[
+        ("US", "+12025550173"),
+        ("GB", "+442079460958"),
+        ("IN", "+919876543210"),
+        ("DE", "+493012345678"),
+        ("AU", "+61412345678"),
+        ("CN", "+8613800138000"),
+        ("FR", "+33123456789"),
+        ("BR", "+5511912345678"),
+        ("JP", "+819012345678"),
+        ("ZA", "+27821234567"),
+        ("LB", "+96179123123"),
+        ("SY", "+963944567890"),
+        ("IQ", "+9647901234567"),
+        ("KW", "+96550012345"),
+        ("SA", "+966512345678"),
+        ("YE", "+967711234567"),
+        ("OM", "+96892123456"),
+        ("PS", "+970599123456"),
+        ("AE", "+971501234567"),
+        ("IL", "+972501234567"),
+        ("BH", "+97336012345"),
+        ("QA", "+97433123456"),
+        ("BT", "+97517123456"),
+        ("MN", "+97699123456"),
+        ("NP", "+9779841234567"),
+        ("IR", "+989123456789"),
+        ("TJ", "+992931234567"),
+        ("TM", "+99365123456"),
+        ("AZ", "+994401234567"),
+        ("GE", "+995591234567"),
+        ("KG", "+996551234567"),
+        ("UZ", "+998971234567"),
+        ("BS", "+12425571234"),
+        ("BB", "+12462311234"),
+        ("AI", "+12642351234"),
+        ("AG", "+12684641234"),
+        ("VG", "+12844681234"),
+        ("VI", "+13406901234"),
+        ("KY", "+13453211234"),
+        ("BM", "+14412341234"),
+        ("GD", "+14732341234"),
+        ("TC", "+16492311234"),
+        ("MS", "+16642351234"),
+        ("MP", "+16702351234"),
+        ("GU", "+16712351234"),
+        ("AS", "+16842351234"),
+        ("SX", "+17215431234"),
+        ("LC", "+17582841234"),
+        ("DM", "+17672351234"),
+        ("VC", "+17842351234"),
+        ("PR", "+17872351234"),
+        ("DO", "+18092351234"),
+        ("DO", "+18292351234"),
+        ("DO", "+18492351234"),
+        ("TT", "+18682351234"),
+        ("KN", "+18692351234"),
+        ("JM", "+18762351234"),
+        ("RE", "+262692691234"),
+        ("ZW", "+263772112345"),
+        ("NA", "+264601234567"),
+        ("MW", "+265991234567"),
+        ("LS", "+26662012345"),
+        ("BW", "+26771123456"),
+        ("SZ", "+26876123456"),
+        ("KM", "+2693112345"),
+        ("SH", "+29022123"),
+        ("ER", "+2917111234"),
+        ("AW", "+2975601234"),
+        ("FO", "+298201234"),
+        ("GL", "+299201234"),
+        ("GI", "+35056012345"),
+        ("PT", "+351201234567"),
+        ("LU", "+35220123456"),
+        ("IE", "+353201234567"),
+        ("IS", "+3544101234"),
+        ("AL", "+355691234567"),
+        ("MT", "+35679012345"),
+        ("CY", "+35796123456"),
+        ("FI", "+358201234567"),
+        ("BG", "+35920123456"),
+        ("LT", "+37061234567"),
+        ("LV", "+37120123456"),
+        ("EE", "+37251234567"),
+        ("MD", "+37368123456"),
+        ("AM", "+37491234567"),
+        ("BY", "+375291234567"),
+        ("AD", "+376312345"),
+        ("MC", "+37761234567"),
+        ("SM", "+378661234567"),
+        ("VA", "+379612345678"),
+        ("UA", "+380501234567"),
+        ("RS", "+381601234567"),
+        ("ME", "+38267123456"),
+        ("HR", "+385911234567"),
+        ("SI", "+38631234567"),
+        ("BA", "+38761123456"),
+        ("MK", "+38970123456"),
+        ("CZ", "+420601123456"),
+        ("SK", "+421912345678"),
+        ("LI", "+4236608811"),
+        ("FK", "+50051234"),
+        ("BZ", "+5018221234"),
+        ("GT", "+50251234567"),
+        ("SV", "+50370123456"),
+        ("HN", "+50491234567"),
+        ("NI", "+50581234567"),
+        ("CR", "+50670123456"),
+        ("PA", "+50761234567"),
+        ("PM", "+50850123456"),
+        ("HT", "+50928123456"),
+        ("GP", "+590590123456"),
+        ("BO", "+59171234567"),
+        ("GY", "+5926091234"),
+        ("EC", "+593991234567"),
+        ("GF", "+594694201234"),
+        ("PY", "+595961456789"),
+        ("MQ", "+596696201234"),
+        ("SR", "+5977412345"),
+        ("UY", "+59894231234"),
+        ("CW", "+59995181234"),
+        ("TL", "+67077231234"),
+        ("NF", "+672312345"),
+        ("BN", "+6737123456"),
+        ("NR", "+6745571234"),
+        ("PG", "+67570123456"),
+        ("TO", "+67677151234"),
+        ("SB", "+67762123"),
+        ("VU", "+67824612"),
+        ("FJ", "+6797012345"),
+        ("PW", "+6806201234"),
+        ("WF", "+681501234"),
+        ("CK", "+68222123"),
+        ("KI", "+68660123"),
+        ("NC", "+687501234"),
+        ("TV", "+688901234"),
+        ("PF", "+68987123456"),
+        ("TK", "+6903012"),
+        ("FM", "+6913501234"),
+        ("MH", "+6922471234"),
+        ("KP", "+8501912345678"),
+        ("HK", "+85251234567"),
+        ("MO", "+85366123456"),
+        ("KH", "+85512345678"),
+        ("LA", "+85620911234"),
+        ("BD", "+8801812345678"),
+        ("TW", "+886912345678"),
+        ("MV", "+9607712345"),
+        ("JO", "+962791234567"),
+        ("SG", "+6581234567"),
+        ("MY", "+60121234567"),
+        ("PH", "+639171234567"),
+        ("TH", "+66812345678"),
+        ("VN", "+84912345678"),
+        ("PK", "+923001234567"),
+        ("LK", "+94771234567"),
+        ("AF", "+93700123456"),
+        ("MM", "+959123456789"),
+        ("KZ", "+77012345678"),
+        ("UZ", "+998901234567"),
+        ("TJ", "+992551234567"),
+        ("KG", "+996701234567"),
+        ("TM", "+993651234567"),
+        ("MN", "+97688123456"),
+        ("NP", "+9779812345678"),
+        ("BT", "+97517123456"),
+        ("AM", "+37494123456"),
+        ("AZ", "+994501234567"),
+        ("GE", "+995555123456"),
+        ("TM", "+99312123456"),
+        ("AE", "+971501234567"),
+        ("BH", "+97333123456"),
+        ("QA", "+97450123456"),
+        ("KW", "+96550123456"),
+        ("OM", "+96892123456"),
+        ("YE", "+967711234567"),
+        ("SO", "+252615123456"),
+        ("KE", "+254701234567"),
+        ("TZ", "+255621234567"),
+        ("UG", "+256701234567"),
+        ("ET", "+251911234567"),
+        ("NG", "+234701234567"),
+        ("GH", "+233501234567"),
+        ("SN", "+221771234567"),
+        ("CI", "+22551234567"),
+        ("ML", "+22365123456"),
+        ("ZM", "+260961234567"),
+        ("ZW", "+263771234567"),
+        ("BW", "+26772123456"),
+        ("NA", "+264811234567"),
+        ("MG", "+261341234567"),
+        ("RE", "+262692123456"),
+        ("MU", "+23057123456"),
+        ("SC", "+2482512345"),
+        ("MW", "+265991234567"),
+        ("LS", "+26650123456"),
+        ("SZ", "+26876123456"),
+        ("CV", "+2389912345"),
+        ("ST", "+2399912345"),
+        ("GQ", "+240222123456"),
+        ("CD", "+243991234567"),
+        ("AO", "+244921234567"),
+        ("GW", "+245501234511"),
+        ("IO", "+2463801234"),
+        ("AC", "+2473612"),
+        ("SC", "+2482512345"),
+        ("SD", "+249911231234"),
+        ("RW", "+250720123456"),
+        ("SO", "+252615123456"),
+        ("DJ", "+25377123123"),
+        ("BI", "+25779123456"),
+        ("MZ", "+258821234567"),
+        ("AX", "+35818123456"),
+        ("BL", "+590590271234"),
+        ("MF", "+590590871234"),
+        ("AQ", "+672101234"),
+        ("GG", "+441481256789"),
+        ("JE", "+441534456789"),
+        ("IM", "+441624456789"),
+        ("SJ", "+4779021234"),
+        ("CC", "+61891621234"),
+        ("CX", "+61891641234"),
+        ("MA", "+212522123456"),
+        ("EH", "+212528812345"),
+        ("YT", "+262269612345"),
+        ("RU", "+79123456789"),
+        ("CA", "+14165550123"),
+        ("NO", "+4722123456"),
     ];
 
+    // one number per country of the metadata and length, starting with the first area code
+    // of the country if it has some, so a new country is covered without adding fixtures.
+    // The digits start with 2, national numbers starting with a trunk prefix are ambiguous.
+    // Plans with lengths per type get one number per type and length instead, starting with
+    // the first number range of the type, e.g. the 10 digits of Japanese mobiles.
+    pub(super) fn phone_numbers() -> impl Iterator<Item = PhoneNumber> {
+        COUNTRIES.iter().flat_map(|country| {
+            let area_code = AREA_CODES
+                .iter()
+                .find(|(code, _)| *code == country.code)
+                .map_or("", |(_, area_codes)| area_codes[0]);
+            let typed: Vec<(&str, RangeInclusive<u8>)> =
+                [PhoneNumberType::Mobile, PhoneNumberType::FixedLine]
+                    .into_iter()
+                    .filter_map(|number_type| {
+                        let lengths = country.length_range_for_type(number_type)?;
+                        let (lead, _) = type_prefixes(calling_code(country))
+                            .find(|(_, rule_type)| *rule_type == number_type)?;
+                        Some((lead, lengths))
+                    })
+                    .collect();
+            let leads = match typed.is_empty() {
+                true => vec![(area_code, 0..=u8::MAX)],
+                false => typed,
+            };
+
+            leads.into_iter().flat_map(move |(lead, lengths)| {
+                country
+                    .phone_lengths
+                    .iter()
+                    .filter(move |length| lengths.contains(length))
+                    .map(move |&length| {
+                        let national_number: String = lead
+                            .chars()
+                            .chain("234567891".chars().cycle())
+                            .take(length.into())
+                            .collect();
+                        PhoneNumber {
+                            country,
+                            phone_number: format!("+{}{}", country.prefix, national_number),
+                        }
+                    })
+            })
+        })
+    }
+
+    #[test]
+    fn test_phone_numbers_are_valid() {
+        for phone_number in phone_numbers() {
+            assert!(
+                is_valid_phone_number(&phone_number.phone_number),
+                "{}",
+                phone_number.phone_number
+            );
+            // numbers without an area code go to the main country of a shared calling code
+            assert_eq!(
+                extract_country(&phone_number.phone_number).map(|country| country.prefix),
+                Some(phone_number.country.prefix),
+                "{}",
+                phone_number.phone_number
+            );
+        }
+    }
+
+    #[test]
+    fn test_real_numbers_are_valid() {
+        for (code, phone_number) in REAL_NUMBERS {
+            assert!(is_valid_phone_number(phone_number), "{}", phone_number);
+            assert_eq!(
+                extract_country(phone_number).map(|country| country.code),
+                Some(*code),
+                "{}",
+                phone_number
+            );
+        }
+    }
+
     #[test]
     fn test_is_valid_phone_number() {
         // Valid phone number
-        assert!(is_valid_phone_number("+96179123123".to_string()));
-        assert!(!is_valid_phone_number("invalid_phone_number".to_string()));
+        assert!(is_valid_phone_number("+96179123123"));
+        assert!(!is_valid_phone_number("invalid_phone_number"));
         // Valid phone number with parentheses
-        assert!(is_valid_phone_number("+1 (234) 567-8990".to_string()));
-        assert!(!is_valid_phone_number("+1 (234) 567-890".to_string()));
+        assert!(is_valid_phone_number("+1 (234) 567-8990"));
+        assert!(!is_valid_phone_number("+1 (234) 567-890"));
     }
 
     #[test]
@@ -900,34 +497,167 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_accepts_str_and_string() {
+        let phone_number = "+96179123123".to_string();
+
+        assert!(is_valid_phone_number(&phone_number));
+        assert!(is_valid_phone_number(phone_number.as_str()));
+        assert_eq!(
+            normalize_phone_number(&phone_number),
+            normalize_phone_number(phone_number.clone())
+        );
+        assert_eq!(
+            extract_country(&phone_number),
+            extract_country(phone_number)
+        );
+    }
+
     #[test]
     fn test_extract_country() {
         // Valid country code
         assert_eq!(
-            extract_country("+11231231232".to_string())
-                .unwrap()
-                .code
-                .to_string(),
+            extract_country("+11231231232").unwrap().code.to_string(),
             "US".to_string()
         );
         // Invalid country code
-        assert_eq!(extract_country("+987654321".to_string()), None);
+        assert_eq!(extract_country("+987654321"), None);
     }
 
     #[test]
     fn test_normalize_phone_number() {
-        for phone_number in PHONE_NUMBERS.iter() {
-            let normalized_phone_number =
-                normalize_phone_number(phone_number.phone_number.to_string());
+        for phone_number in phone_numbers() {
+            let normalized_phone_number = normalize_phone_number(&phone_number.phone_number);
+            assert_eq!(normalized_phone_number, Some(phone_number.phone_number));
+        }
+
+        assert_eq!(normalize_phone_number("invalid_phone_number"), None);
+    }
+
+    #[test]
+    fn test_normalize_unchecked_digits() {
+        for phone_number in phone_numbers() {
+            let digits = phone_number.phone_number.trim_start_matches('+');
             assert_eq!(
-                normalized_phone_number,
-                Some(phone_number.phone_number.to_string())
+                normalize_unchecked_digits(digits),
+                normalize_phone_number(&phone_number.phone_number)
             );
         }
 
         assert_eq!(
-            normalize_phone_number("invalid_phone_number".to_string()),
-            None
+            normalize_unchecked_digits("0096109123123"),
+            Some("+9619123123".to_string())
+        );
+        assert_eq!(normalize_unchecked_digits("987654321"), None);
+        assert_eq!(
+            extract_country_unchecked_digits("0012025550173")
+                .unwrap()
+                .code,
+            "US"
+        );
+    }
+
+    #[test]
+    fn test_strip_country_code() {
+        for phone_number in phone_numbers() {
+            let (country, national_number) =
+                strip_country_code(&phone_number.phone_number).unwrap();
+            assert_eq!(
+                format!("+{}{}", country.prefix, national_number),
+                phone_number.phone_number
+            );
+        }
+
+        let (country, national_number) = strip_country_code("+96179123123").unwrap();
+        assert_eq!((country.code, national_number), ("LB", "79123123"));
+        assert_eq!(strip_country_code("96179123123"), None);
+        assert_eq!(strip_country_code("+961 79 123 123"), None);
+        assert_eq!(strip_country_code("+987654321"), None);
+    }
+
+    #[test]
+    fn test_extensions_are_left_out() {
+        for phone_number in [
+            "+1 202 555 0173 ext. 45",
+            "+1 202 555 0173 x45",
+            "+1 202 555 0173 #45",
+            "+1 202 555 0173;ext=45",
+        ] {
+            assert!(is_valid_phone_number(phone_number), "{}", phone_number);
+            assert_eq!(
+                normalize_phone_number(phone_number),
+                Some("+12025550173".to_string())
+            );
+            assert_eq!(extract_country(phone_number).unwrap().code, "US");
+            assert_eq!(
+                format_phone_number(phone_number, PhoneFormat::RFC3966),
+                Some("tel:+1-202-555-0173;ext=45".to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn test_labels_are_not_extensions() {
+        for (phone_number, expected, code) in [
+            ("Phone: +1 202 555 0173", "+12025550173", "US"),
+            ("Tel +44 20 7946 0958", "+442079460958", "GB"),
+            ("mobile 0096179123123", "+96179123123", "LB"),
+            ("Ext office: +1 202 555 0173 x45", "+12025550173", "US"),
+        ] {
+            assert!(is_valid_phone_number(phone_number), "{}", phone_number);
+            assert_eq!(
+                normalize_phone_number(phone_number),
+                Some(expected.to_string())
+            );
+            assert_eq!(extract_country(phone_number).unwrap().code, code);
+        }
+
+        // trailing letters are neither an extension nor a label
+        assert!(!is_valid_phone_number("+12025550173xyz"));
+        assert!(!is_valid_phone_number("+1 202 555 0173 ext."));
+        assert!(!is_valid_phone_number("+1 202 555 0173 MAX 45"));
+    }
+
+    #[test]
+    fn test_normalize_phone_number_in_region() {
+        let normalize = normalize_phone_number_in_region;
+
+        // read as a +61 number without the region
+        assert_ne!(
+            normalize_phone_number("0612345678"),
+            Some("+33612345678".to_string())
+        );
+        assert_eq!(
+            normalize("06 12 34 56 78", "FR"),
+            Some("+33612345678".to_string())
+        );
+        assert_eq!(
+            normalize("612345678", "fr"),
+            Some("+33612345678".to_string())
+        );
+        assert_eq!(
+            normalize("8 912 345 67 89", "RU"),
+            Some("+79123456789".to_string())
+        );
+        assert_eq!(
+            normalize("0049 30 123456", "FR"),
+            Some("+4930123456".to_string())
+        );
+        assert_eq!(
+            normalize("+49 30 123456", "FR"),
+            Some("+4930123456".to_string())
+        );
+        assert_eq!(
+            normalize("0612345678", "ZZ"),
+            normalize_phone_number("0612345678")
         );
+        assert_eq!(normalize("invalid_phone_number", "FR"), None);
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn test_normalize_unchecked_digits_rejects_non_digits_in_debug() {
+        normalize_unchecked_digits("+96179123123");
     }
 }
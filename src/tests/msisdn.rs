@@ -0,0 +1,45 @@
+use crate::{
+    e164_to_msisdn, iccid_matches_msisdn, is_plausible_msisdn, is_valid_iccid, msisdn_to_e164,
+};
+
+#[test]
+fn test_is_plausible_msisdn() {
+    assert!(is_plausible_msisdn("96179123123"));
+    assert!(is_plausible_msisdn("447911123456"));
+    assert!(!is_plausible_msisdn("+96179123123"));
+    assert!(!is_plausible_msisdn("0096179123123"));
+    assert!(!is_plausible_msisdn("9617912312312345"));
+    assert!(!is_plausible_msisdn("987654321"));
+    assert!(!is_plausible_msisdn(""));
+}
+
+#[test]
+fn test_msisdn_conversions() {
+    assert_eq!(
+        msisdn_to_e164("96179123123"),
+        Some("+96179123123".to_string())
+    );
+    assert_eq!(msisdn_to_e164("+96179123123"), None);
+    assert_eq!(
+        e164_to_msisdn("+961 79 123 123"),
+        Some("96179123123".to_string())
+    );
+    assert_eq!(e164_to_msisdn("invalid_phone_number"), None);
+}
+
+#[test]
+fn test_iccid_validation() {
+    assert!(is_valid_iccid("8944110068256270054"));
+    assert!(!is_valid_iccid("8944110068256270055"));
+    assert!(!is_valid_iccid("1244110068256270054"));
+
+    assert_eq!(
+        iccid_matches_msisdn("8944110068256270054", "447911123456"),
+        Some(true)
+    );
+    assert_eq!(
+        iccid_matches_msisdn("8944110068256270054", "96179123123"),
+        Some(false)
+    );
+    assert_eq!(iccid_matches_msisdn("89441", "447911123456"), None);
+}
@@ -0,0 +1,63 @@
+use crate::constants::NEIGHBORS;
+use crate::{extract_country, find_country_by_code, likely_country, neighbors_of, PhoneNumber};
+
+#[test]
+fn test_neighbors_metadata() {
+    for (a, b) in NEIGHBORS {
+        assert!(find_country_by_code(a).is_some(), "{}", a);
+        assert!(find_country_by_code(b).is_some(), "{}", b);
+        assert_ne!(a, b);
+    }
+
+    let neighbors = neighbors_of("fr");
+    for code in ["BE", "CH", "ES", "DE"] {
+        assert!(neighbors.contains(&code), "{}", code);
+    }
+    assert!(!neighbors.contains(&"AU"));
+    assert!(neighbors_of("BE").contains(&"FR"));
+    assert!(neighbors_of("ZZ").is_empty());
+}
+
+#[test]
+fn test_likely_country() {
+    let code = |phone_number, hint| likely_country(phone_number, hint).map(|country| country.code);
+
+    // +7 is shared by Russia and Kazakhstan, told apart by area code whatever the hint
+    assert_eq!(extract_country("+7 701 234 5678").unwrap().code, "KZ");
+    assert_eq!(code("+7 701 234 5678", "RU"), Some("KZ"));
+    assert_eq!(code("+7 912 345 6789", "KZ"), Some("RU"));
+    // +500 is shared by the Falkland Islands and South Georgia
+    assert_eq!(code("+500 51234", "GS"), Some("GS"));
+    assert_eq!(code("+500 51234", "AR"), Some("FK"));
+    assert_eq!(code("+44 7624 123456", "IE"), Some("IM"));
+    // numbers of a single country don't depend on the hint
+    assert_eq!(code("+33612345678", "AU"), Some("FR"));
+    assert_eq!(code("+33612345678", "ZZ"), Some("FR"));
+    assert_eq!(code("invalid_phone_number", "FR"), None);
+}
+
+#[test]
+fn test_parse_with_country_prefers_neighbors() {
+    let country = |phone_number, region| {
+        PhoneNumber::parse_with_country(phone_number, region)
+            .unwrap()
+            .country()
+            .code
+    };
+
+    assert_eq!(country("+7 701 234 5678", "BY"), "KZ");
+    assert_eq!(country("07624 123456", "GB"), "IM");
+    assert_eq!(country("+500 51234", "GS"), "GS");
+    assert_eq!(country("+500 51234", "UY"), "FK");
+    // +358 is shared by Finland and the Åland Islands, told apart by area code
+    assert_eq!(country("+358 18 1234567", "EE"), "AX");
+    assert_eq!(country("+358 9 12345678", "AX"), "FI");
+}
+
+#[test]
+fn test_likely_country_of_national_numbers() {
+    let code = |phone_number, hint| likely_country(phone_number, hint).map(|country| country.code);
+
+    assert_eq!(code("06 12 34 56 78", "FR"), Some("FR"));
+    assert_eq!(code("0612345678", "ZZ"), code("+612345678", "ZZ"));
+}
@@ -0,0 +1,151 @@
+use crate::number_type::has_type_metadata;
+use crate::{
+    all_countries, country_by_iso_code, detect_phone_number_type, is_valid_phone_number,
+    PhoneNumberType,
+};
+
+#[test]
+fn test_detect_phone_number_type() {
+    let number_type = |phone_number: &str| detect_phone_number_type(phone_number);
+
+    assert_eq!(
+        number_type("+12025550173"),
+        Some(PhoneNumberType::FixedLineOrMobile)
+    );
+    assert_eq!(number_type("+18005550173"), Some(PhoneNumberType::TollFree));
+    assert_eq!(number_type("+447911123456"), Some(PhoneNumberType::Mobile));
+    assert_eq!(
+        number_type("+447012345678"),
+        Some(PhoneNumberType::PersonalNumber)
+    );
+    assert_eq!(
+        number_type("+442079460958"),
+        Some(PhoneNumberType::FixedLine)
+    );
+    assert_eq!(number_type("+4915112345678"), Some(PhoneNumberType::Mobile));
+    assert_eq!(
+        number_type("+493012345678"),
+        Some(PhoneNumberType::FixedLine)
+    );
+    assert_eq!(number_type("+33612345678"), Some(PhoneNumberType::Mobile));
+    assert_eq!(number_type("+61412345678"), Some(PhoneNumberType::Mobile));
+    assert_eq!(number_type("+919876543210"), Some(PhoneNumberType::Mobile));
+    assert_eq!(number_type("+80012345678"), Some(PhoneNumberType::TollFree));
+    assert_eq!(number_type("+96179123123"), Some(PhoneNumberType::Mobile));
+    assert_eq!(
+        number_type("+96111234567"),
+        Some(PhoneNumberType::FixedLine)
+    );
+    assert_eq!(number_type("+8613800138000"), Some(PhoneNumberType::Mobile));
+    // Rome and Milan, Italian landlines keep the leading zero of the area code
+    assert_eq!(
+        number_type("+39 06 1234 5678"),
+        Some(PhoneNumberType::FixedLine)
+    );
+    assert_eq!(
+        number_type("+39 02 1234 5678"),
+        Some(PhoneNumberType::FixedLine)
+    );
+    assert_eq!(number_type("+376312345"), Some(PhoneNumberType::Mobile));
+    // no French range starts with 85
+    assert_eq!(number_type("+33851234567"), Some(PhoneNumberType::Unknown));
+    assert_eq!(number_type("invalid_phone_number"), None);
+}
+
+#[test]
+fn test_every_calling_code_has_type_metadata() {
+    for country in all_countries() {
+        assert!(
+            has_type_metadata(country.calling_code()),
+            "{} +{}",
+            country.code,
+            country.calling_code()
+        );
+    }
+}
+
+#[test]
+fn test_number_type_names() {
+    assert_eq!(PhoneNumberType::Mobile.as_str(), "mobile");
+    assert_eq!(
+        PhoneNumberType::FixedLineOrMobile.as_str(),
+        "fixed_line_or_mobile"
+    );
+    assert_eq!(PhoneNumberType::Unknown.as_str(), "unknown");
+}
+
+#[test]
+fn test_lengths_per_type() {
+    // German landlines may have 6 digits, mobiles 10 or 11
+    assert!(is_valid_phone_number("+49 30 1234"));
+    assert!(!is_valid_phone_number("+49 151 234"));
+    assert!(is_valid_phone_number("+49 151 23456789"));
+    assert!(is_valid_phone_number("+49 170 1234567"));
+    // Japanese mobiles have 10 digits, landlines 9
+    assert_eq!(
+        detect_phone_number_type("+81 90 1234 5678"),
+        Some(PhoneNumberType::Mobile)
+    );
+    assert_eq!(
+        detect_phone_number_type("+81 3 1234 5678"),
+        Some(PhoneNumberType::FixedLine)
+    );
+    assert!(!is_valid_phone_number("+81 90 1234 567"));
+    assert!(!is_valid_phone_number("+81 3 1234 56789"));
+    // Chinese mobiles have 11 digits
+    assert!(is_valid_phone_number("+86 138 0013 8000"));
+    assert!(!is_valid_phone_number("+86 138 0013 800"));
+    assert!(is_valid_phone_number("+86 10 1234 5678"));
+
+    let japan = country_by_iso_code("JP").unwrap();
+    assert_eq!(
+        japan.length_range_for_type(PhoneNumberType::Mobile),
+        Some(10..=10)
+    );
+    assert_eq!(japan.length_range_for_type(PhoneNumberType::TollFree), None);
+}
+
+#[test]
+fn test_length_ranges() {
+    // German landlines have any length from 6 to 11 digits
+    for number in [
+        "+49 30 1234",
+        "+49 30 12345",
+        "+49 30 123456",
+        "+49 30 1234567",
+        "+49 30 12345678",
+        "+49 33 123456789",
+    ] {
+        assert_eq!(
+            detect_phone_number_type(number),
+            Some(PhoneNumberType::FixedLine),
+            "{number}"
+        );
+    }
+    assert!(!is_valid_phone_number("+49 33 1234567890"));
+
+    let germany = country_by_iso_code("DE").unwrap();
+    assert_eq!(
+        germany.length_range_for_type(PhoneNumberType::FixedLine),
+        Some(6..=11)
+    );
+    assert_eq!(
+        germany.length_range_for_type(PhoneNumberType::Mobile),
+        Some(10..=11)
+    );
+}
+
+#[test]
+fn test_type_lengths_are_country_lengths() {
+    for country in all_countries() {
+        for number_type in [PhoneNumberType::Mobile, PhoneNumberType::FixedLine] {
+            for length in country
+                .length_range_for_type(number_type)
+                .into_iter()
+                .flatten()
+            {
+                assert!(country.phone_lengths.contains(&length), "{}", country.code);
+            }
+        }
+    }
+}
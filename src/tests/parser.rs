@@ -0,0 +1,341 @@
+use crate::{
+    country_by_id, explain, normalize_phone_number, ParseError, Parser, RegionHintProvider,
+    TraceStep, E164,
+};
+
+#[test]
+fn test_parser_without_hooks() {
+    let parser = Parser::new();
+
+    assert_eq!(
+        parser.normalize("+0096109123123"),
+        Some("+9619123123".to_string())
+    );
+    assert!(parser.is_valid("+96179123123"));
+    assert_eq!(parser.extract_country("+12025550173").unwrap().code, "US");
+}
+
+#[test]
+fn test_parser_hooks_run_in_order() {
+    let parser = Parser::new()
+        // strip the outside line prefix of the office PBX
+        .pre_hook(|phone_number| {
+            if phone_number.starts_with('9') {
+                phone_number.remove(0);
+            }
+        })
+        .pre_hook(|phone_number| phone_number.insert(0, '+'))
+        .post_hook(|phone_number| phone_number.replace_range(0..1, "00"));
+
+    assert_eq!(
+        parser.normalize("996179123123"),
+        Some("0096179123123".to_string())
+    );
+    assert!(parser.is_valid("996179123123"));
+    assert_eq!(parser.extract_country("996179123123").unwrap().code, "LB");
+    assert_eq!(parser.normalize("invalid_phone_number"), None);
+    assert_eq!(
+        format!("{:?}", parser),
+        "Parser { pre_hooks: 2, post_hooks: 1, trace: false, international_prefix: None, region_hints: false, rejection_sampler: None, max_input_length: 256, deprioritize_irsf_prone: false, test_numbers: None, private_plans: None, vanity_numbers: false }"
+    );
+}
+
+#[test]
+fn test_parser_trace() {
+    let parser = Parser::new()
+        .pre_hook(|phone_number| {
+            if let Some(external) = phone_number.strip_prefix("9-") {
+                *phone_number = external.to_string();
+            }
+        })
+        .post_hook(|_| {})
+        .trace(true);
+
+    let (normalized, trace) = parser.normalize_with_trace("9-00 961 (09) 123-123");
+    let trace = trace.unwrap();
+    let lebanon = country_by_id(127).unwrap();
+
+    assert_eq!(normalized, Some("+9619123123".to_string()));
+    assert_eq!(trace.input, "9-00 961 (09) 123-123");
+    assert_eq!(
+        trace.steps,
+        vec![
+            TraceStep::PreHook {
+                before: "9-00 961 (09) 123-123".to_string(),
+                after: "00 961 (09) 123-123".to_string(),
+            },
+            TraceStep::CharactersRemoved("  () -".to_string()),
+            TraceStep::IddStripped("00".to_string()),
+            TraceStep::CountryChosen {
+                country: lebanon,
+                candidates: vec![lebanon],
+            },
+            TraceStep::TrunkPrefixRemoved("0".to_string()),
+        ]
+    );
+
+    let (normalized, trace) = parser.normalize_with_trace("+999");
+    assert_eq!(normalized, None);
+    assert_eq!(
+        trace.unwrap().steps.last(),
+        Some(&TraceStep::NoCountryFound)
+    );
+
+    assert_eq!(Parser::new().normalize_with_trace("+96179123123").1, None);
+}
+
+#[test]
+fn test_parser_trace_matches_normalize() {
+    let parser = Parser::new().trace(true);
+    for phone_number in ["+12025550173", "+7 701 234 5678", "0044 7012 345678", "abc"] {
+        assert_eq!(
+            parser.normalize_with_trace(phone_number).0,
+            normalize_phone_number(phone_number)
+        );
+    }
+
+    // +500 is shared by the Falkland Islands and South Georgia, the first one in the table
+    // is chosen
+    let (_, trace) = parser.normalize_with_trace("+500 51234");
+    let chosen = trace
+        .unwrap()
+        .steps
+        .into_iter()
+        .find_map(|step| match step {
+            TraceStep::CountryChosen {
+                country,
+                candidates,
+            } => Some((country, candidates)),
+            _ => None,
+        });
+    let (country, candidates) = chosen.unwrap();
+    assert_eq!(country.code, "FK");
+    assert!(candidates.iter().any(|candidate| candidate.code == "GS"));
+}
+
+#[test]
+fn test_parser_international_prefix() {
+    let from_france = Parser::new().international_prefix_of("FR");
+    let from_us = Parser::new()
+        .post_hook(|phone_number| assert!(phone_number.starts_with('+')))
+        .international_prefix_of("US")
+        .trace(true);
+
+    assert_eq!(
+        from_france.normalize("+49 30 12345678"),
+        Some("00493012345678".to_string())
+    );
+    assert_eq!(
+        Parser::new()
+            .international_prefix_of("AU")
+            .normalize("+49 30 12345678"),
+        Some("0011493012345678".to_string())
+    );
+    assert_eq!(
+        Parser::new()
+            .international_prefix_of("ZZ")
+            .normalize("+49 30 12345678"),
+        Some("00493012345678".to_string())
+    );
+
+    let (normalized, trace) = from_us.normalize_with_trace("+49 30 12345678");
+    assert_eq!(normalized, from_us.normalize("+49 30 12345678"));
+    assert_eq!(normalized, Some("011493012345678".to_string()));
+    assert_eq!(
+        trace.unwrap().steps.last(),
+        Some(&TraceStep::InternationalPrefixApplied("011".to_string()))
+    );
+}
+
+#[test]
+fn test_parser_region_hints() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    struct GeoIp {
+        lookups: Arc<AtomicUsize>,
+    }
+
+    impl RegionHintProvider for GeoIp {
+        fn region_hint(&self) -> Option<String> {
+            self.lookups.fetch_add(1, Ordering::Relaxed);
+            Some("fr".to_string())
+        }
+    }
+
+    let lookups = Arc::new(AtomicUsize::new(0));
+    let parser = Parser::new()
+        .region_hints(GeoIp {
+            lookups: lookups.clone(),
+        })
+        .trace(true);
+
+    assert_eq!(
+        parser.normalize("06 12 34 56 78"),
+        Some("+33612345678".to_string())
+    );
+    assert_eq!(parser.extract_country("06 12 34 56 78").unwrap().code, "FR");
+    assert_eq!(lookups.load(Ordering::Relaxed), 2);
+
+    // numbers starting with + don't need the region, the international prefix depends on it
+    assert_eq!(
+        parser.normalize("+44 7012 345678"),
+        Some("+447012345678".to_string())
+    );
+    assert_eq!(lookups.load(Ordering::Relaxed), 2);
+    assert_eq!(
+        parser.normalize("0044 7012 345678"),
+        Some("+447012345678".to_string())
+    );
+    assert_eq!(lookups.load(Ordering::Relaxed), 3);
+
+    let (normalized, trace) = parser.normalize_with_trace("06 12 34 56 78");
+    let trace = trace.unwrap();
+    assert_eq!(normalized, Some("+33612345678".to_string()));
+    assert_eq!(trace.matched_digits, "33612345678");
+    assert_eq!(
+        trace.steps[1..3],
+        [
+            TraceStep::RegionHintUsed(parser.extract_country("+33612345678").unwrap()),
+            TraceStep::TrunkPrefixRemoved("0".to_string()),
+        ]
+    );
+
+    let without_hint = Parser::new().region_hints(|| None);
+    assert_eq!(
+        without_hint.normalize("96179123123"),
+        Some("+96179123123".to_string())
+    );
+}
+
+#[test]
+fn test_parser_region_hints_agree_with_default_region() {
+    for (region, phone_number, e164, country) in [
+        ("US", "011 44 20 7946 0958", "+442079460958", "GB"),
+        ("FR", "96179123123", "+96179123123", "LB"),
+        ("FR", "06 12 34 56 78", "+33612345678", "FR"),
+    ] {
+        let parser = Parser::new()
+            .region_hints(move || Some(region.to_string()))
+            .trace(true);
+
+        assert_eq!(
+            E164::parse_with_default_region(phone_number, region).unwrap(),
+            e164
+        );
+        assert_eq!(parser.normalize(phone_number).as_deref(), Some(e164));
+        assert!(parser.is_valid(phone_number), "{phone_number}");
+        assert_eq!(parser.parse(phone_number).unwrap().e164(), e164);
+        assert_eq!(parser.extract_country(phone_number).unwrap().code, country);
+        assert_eq!(
+            parser.normalize_with_trace(phone_number).0.as_deref(),
+            Some(e164)
+        );
+    }
+
+    // the international prefix of the hinted region isn't read as a national number
+    let from_us = Parser::new()
+        .region_hints(|| Some("US".to_string()))
+        .trace(true);
+    let (_, trace) = from_us.normalize_with_trace("011 44 20 7946 0958");
+    let trace = trace.unwrap();
+    assert!(trace
+        .steps
+        .contains(&TraceStep::IddStripped("011".to_string())));
+    assert_eq!(trace.matched_digits, "442079460958");
+}
+
+#[test]
+fn test_parser_extensions() {
+    let parser = Parser::new()
+        .pre_hook(|phone_number| *phone_number = phone_number.replace("poste", "ext"))
+        .region_hints(|| Some("FR".to_string()))
+        .trace(true);
+
+    let number = parser.parse("01 23 45 67 89 poste 12").unwrap();
+    assert_eq!(number.e164(), "+33123456789");
+    assert_eq!(number.extension(), Some("12"));
+    assert_eq!(number.raw(), "01 23 45 67 89 poste 12");
+    assert_eq!(parser.parse("+1 202 555 0173").unwrap().extension(), None);
+    assert_eq!(parser.parse("invalid_phone_number"), None);
+
+    // the extension is left out of the E.164 output
+    assert_eq!(
+        parser.normalize("+1 202 555 0173 x45"),
+        Some("+12025550173".to_string())
+    );
+    assert!(parser.is_valid("+1 202 555 0173 ext. 45"));
+    let (_, trace) = parser.normalize_with_trace("+1 202 555 0173;ext=45");
+    assert_eq!(
+        trace.unwrap().steps[0],
+        TraceStep::ExtensionRemoved("45".to_string())
+    );
+}
+
+#[test]
+fn test_parser_max_input_length() {
+    let parser = Parser::new();
+    let padded = format!("+961 79 123 123{}", " ".repeat(300));
+
+    assert_eq!(
+        parser.try_normalize(&padded),
+        Err(ParseError::InputTooLong {
+            length: 315,
+            max_length: Parser::DEFAULT_MAX_INPUT_LENGTH
+        })
+    );
+    assert_eq!(parser.normalize(&padded), None);
+    assert!(!parser.is_valid(&padded));
+    assert_eq!(parser.extract_country(&padded), None);
+    assert_eq!(
+        parser.try_normalize("invalid_phone_number"),
+        Err(ParseError::InvalidNumber)
+    );
+
+    // the limit applies to the raw input, before the pre-hooks
+    let parser = Parser::new()
+        .max_input_length(12)
+        .pre_hook(|phone_number| phone_number.push_str("          "));
+    assert_eq!(
+        parser.try_normalize("+96179123123"),
+        Ok("+96179123123".to_string())
+    );
+    assert_eq!(
+        parser
+            .try_normalize("+961 79123123")
+            .unwrap_err()
+            .to_string(),
+        "input of 13 bytes is longer than the limit of 12 bytes"
+    );
+
+    let parser = Parser::new().trace(true).max_input_length(12);
+    let (normalized, trace) = parser.normalize_with_trace("+961 79123123");
+    assert_eq!(normalized, None);
+    assert_eq!(
+        trace.unwrap().steps,
+        [TraceStep::InputTooLong {
+            length: 13,
+            max_length: 12
+        }]
+    );
+    assert!(explain("+961 79123123", &parser)
+        .to_string()
+        .contains("1. input of 13 bytes is longer than the limit of 12 bytes"));
+}
+
+#[test]
+fn test_parser_deprioritize_irsf_prone() {
+    // +672 is shared by Antarctica and Norfolk Island, told apart by area code
+    for parser in [Parser::new(), Parser::new().deprioritize_irsf_prone(true)] {
+        assert_eq!(parser.extract_country("+672 312345").unwrap().code, "NF");
+        // numbers only Antarctica fits are still Antarctica
+        assert_eq!(parser.extract_country("+672 12345").unwrap().code, "AQ");
+        assert_eq!(parser.extract_country("+33612345678").unwrap().code, "FR");
+    }
+
+    // the destination can be explicitly hinted
+    let parser = Parser::new()
+        .deprioritize_irsf_prone(true)
+        .region_hints(|| Some("AQ".to_string()));
+    assert_eq!(parser.extract_country("12345").unwrap().code, "AQ");
+}
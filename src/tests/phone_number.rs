@@ -0,0 +1,172 @@
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::{
+    detect_phone_number_type, format_phone_number, is_valid_phone_number, normalize_phone_number,
+    ExtensionPolicy, PhoneFormat, PhoneNumber, PhoneNumberType,
+};
+
+#[test]
+fn test_phone_number_parse() {
+    let number = PhoneNumber::parse("+44 7400 123456").unwrap();
+
+    assert_eq!(number.raw(), "+44 7400 123456");
+    assert_eq!(number.e164(), "+447400123456");
+    assert_eq!(number.country().code, "GB");
+    assert_eq!(number.national_number(), "7400123456");
+    assert_eq!(number.extension(), None);
+    assert_eq!(number.number_type(), PhoneNumberType::Mobile);
+    assert!(number.is_valid());
+    assert_eq!(
+        number.format(PhoneFormat::National),
+        "07400 123456".to_string()
+    );
+
+    let desk = PhoneNumber::parse("tel:+1-202-555-0173;ext=45").unwrap();
+    assert_eq!(desk.e164(), "+12025550173");
+    assert_eq!(desk.national_number(), "2025550173");
+    assert_eq!(desk.extension(), Some("45"));
+    assert_eq!(
+        desk.format(PhoneFormat::International),
+        "+1 202-555-0173 ext. 45"
+    );
+
+    assert_eq!(PhoneNumber::parse("invalid_phone_number"), None);
+    assert_eq!(PhoneNumber::parse(""), None);
+}
+
+#[test]
+fn test_phone_number_matches_free_functions() {
+    for fixture in super::tests::phone_numbers() {
+        let phone_number = fixture.phone_number;
+        let number = PhoneNumber::parse(&phone_number);
+
+        assert_eq!(
+            number.as_ref().map(|number| number.e164().to_string()),
+            normalize_phone_number(phone_number.clone())
+        );
+        assert_eq!(
+            number.as_ref().is_some_and(PhoneNumber::is_valid),
+            is_valid_phone_number(phone_number.clone())
+        );
+        assert_eq!(
+            number.as_ref().map(PhoneNumber::number_type),
+            detect_phone_number_type(phone_number.clone())
+        );
+        assert_eq!(
+            number.map(|number| number.format(PhoneFormat::RFC3966)),
+            format_phone_number(phone_number, PhoneFormat::RFC3966)
+        );
+    }
+}
+
+#[test]
+fn test_phone_number_as_map_key() {
+    let parse = |phone_number| PhoneNumber::parse(phone_number).unwrap();
+    let desk = parse("+1 (202) 555-0173 ext. 45");
+
+    assert_eq!(desk, parse("tel:+1-202-555-0173;ext=45"));
+    assert_ne!(desk, parse("+12025550173"));
+    assert_ne!(desk, parse("+1 202 555 0173 x46"));
+
+    let mut calls = HashMap::new();
+    for phone_number in [
+        "+1 (202) 555-0173",
+        "+12025550173",
+        "001 202 555 0173",
+        "+96179123123",
+    ] {
+        *calls.entry(parse(phone_number)).or_insert(0) += 1;
+    }
+    assert_eq!(calls[&parse("+1 202-555-0173")], 3);
+    assert_eq!(calls.len(), 2);
+
+    // +500 is shared by FK and GS: the hint changes the country, not the number
+    let hinted = PhoneNumber::parse_with_country("51234 ext. 7", "GS").unwrap();
+    let unhinted = parse("+500 51234 ext. 7");
+    assert_ne!(hinted.country(), unhinted.country());
+    assert_eq!(hinted, unhinted);
+    assert_eq!(HashSet::from([hinted, unhinted]).len(), 1);
+
+    let numbers = [
+        parse("+12025550173"),
+        parse("+12025550173 ext. 45"),
+        parse("+12025550173 ext. 46"),
+    ];
+    let keys = |policy| {
+        numbers
+            .iter()
+            .map(|number| number.key(policy))
+            .collect::<HashSet<_>>()
+            .len()
+    };
+    assert_eq!(keys(ExtensionPolicy::Ignore), 1);
+    assert_eq!(keys(ExtensionPolicy::Require), 3);
+    assert_eq!(keys(ExtensionPolicy::MatchIfPresent), 3);
+}
+
+#[test]
+fn test_phone_number_ordering_agrees_with_equality() {
+    let numbers = [
+        PhoneNumber::parse("+500 51234").unwrap(),
+        PhoneNumber::parse_with_country("51234", "GS").unwrap(),
+        PhoneNumber::parse_with_country("51234 ext. 7", "GS").unwrap(),
+        PhoneNumber::parse("+500 51234 ext. 7").unwrap(),
+        PhoneNumber::parse("+1 202 555 0173").unwrap(),
+    ];
+    for a in &numbers {
+        for b in &numbers {
+            assert_eq!(a.cmp(b) == Ordering::Equal, a == b, "{a:?} {b:?}");
+        }
+    }
+    assert_eq!(numbers.iter().collect::<BTreeSet<_>>().len(), 3);
+}
+
+#[test]
+fn test_phone_number_parse_with_country() {
+    let e164 = |phone_number, region| {
+        PhoneNumber::parse_with_country(phone_number, region)
+            .map(|number| number.e164().to_string())
+    };
+
+    // the trunk prefix is read as such in the hinted country
+    assert_eq!(
+        e164("06 12 34 56 78", "FR").as_deref(),
+        Some("+33612345678")
+    );
+    assert_eq!(e164("6 12 34 56 78", "fr").as_deref(), Some("+33612345678"));
+    assert_eq!(
+        e164("020 7946 0958", "GB").as_deref(),
+        Some("+442079460958")
+    );
+    assert_eq!(
+        e164("(202) 555-0173", "US").as_deref(),
+        Some("+12025550173")
+    );
+    // the hint wins over the countries the digits could be read in
+    assert_eq!(e164("0612345678", "FR").as_deref(), Some("+33612345678"));
+    assert_ne!(
+        PhoneNumber::parse("0612345678").map(|number| number.e164().to_string()),
+        Some("+33612345678".to_string())
+    );
+
+    // international numbers
+    assert_eq!(e164("+96179123123", "FR").as_deref(), Some("+96179123123"));
+    assert_eq!(
+        e164("00 961 79 123 123", "FR").as_deref(),
+        Some("+96179123123")
+    );
+    assert_eq!(
+        e164("011 33 6 12 34 56 78", "US").as_deref(),
+        Some("+33612345678")
+    );
+    assert_eq!(e164("0096179123123", "ZZ").as_deref(), Some("+96179123123"));
+    assert_eq!(e164("tel:+49-123-456", "DE").as_deref(), Some("+49123456"));
+    // numbers that don't fit the hinted country
+    assert_eq!(e164("96179123123", "FR").as_deref(), Some("+96179123123"));
+
+    let desk = PhoneNumber::parse_with_country("020 7946 0958 ext. 12", "GB").unwrap();
+    assert_eq!(desk.extension(), Some("12"));
+    assert_eq!(desk.raw(), "020 7946 0958 ext. 12");
+    assert_eq!(PhoneNumber::parse_with_country("123", "FR"), None);
+}
@@ -0,0 +1,47 @@
+use polars::prelude::*;
+
+use crate::polars_plugin::{country_series, is_valid_series, normalize_series};
+
+fn phone_numbers() -> Series {
+    Series::new(
+        "phone".into(),
+        &[
+            Some("+96179123123"),
+            None,
+            Some("+1 (234) 567-8990"),
+            Some("invalid_phone_number"),
+        ],
+    )
+}
+
+#[test]
+fn test_normalize_expression() {
+    let normalized = normalize_series(&phone_numbers()).unwrap();
+
+    assert_eq!(normalized.name().as_str(), "phone");
+    assert_eq!(
+        normalized.str().unwrap().into_iter().collect::<Vec<_>>(),
+        [Some("+96179123123"), None, Some("+12345678990"), None]
+    );
+}
+
+#[test]
+fn test_is_valid_expression() {
+    let validity = is_valid_series(&phone_numbers()).unwrap();
+
+    assert_eq!(
+        validity.bool().unwrap().into_iter().collect::<Vec<_>>(),
+        [Some(true), None, Some(true), Some(false)]
+    );
+}
+
+#[test]
+fn test_country_expression() {
+    let countries = country_series(&phone_numbers()).unwrap();
+
+    assert_eq!(
+        countries.str().unwrap().into_iter().collect::<Vec<_>>(),
+        [Some("LB"), None, Some("US"), None]
+    );
+    assert!(country_series(&Series::new("phone".into(), &[1, 2])).is_err());
+}
@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use crate::{
+    normalize_phone_number, Parser, PhoneFormat, PrivateNumber, PrivateNumberingPlan,
+    PrivatePlanRegistry,
+};
+
+#[test]
+fn test_private_plan_registry() {
+    let registry = PrivatePlanRegistry::new();
+    assert!(registry.register(PrivateNumberingPlan::new("acme", "999", 4..=6)));
+    // used by public numbers, or a prefix of them
+    assert!(!registry.register(PrivateNumberingPlan::new("france", "33", 9..=9)));
+    assert!(!registry.register(PrivateNumberingPlan::new("nines", "9", 4..=6)));
+    assert!(!registry.register(PrivateNumberingPlan::new("long", "9999", 4..=6)));
+
+    assert_eq!(
+        registry.get("+999 1234 ext. 5"),
+        Some(PrivateNumber {
+            plan: "acme".to_string(),
+            calling_code: "999".to_string(),
+            national_number: "1234".to_string(),
+        })
+    );
+    assert!(registry.get("00999 123456").is_some());
+    // too long, not international, or with letters
+    assert_eq!(registry.get("+999 1234567"), None);
+    assert_eq!(registry.get("999 1234"), None);
+    assert_eq!(registry.get("+999 12a34"), None);
+
+    assert_eq!(registry.unregister("acme").unwrap().calling_code(), "999");
+    assert_eq!(registry.get("+9991234"), None);
+    assert_eq!(registry.unregister("acme"), None);
+}
+
+#[test]
+fn test_parser_with_private_plans() {
+    let registry = Arc::new(PrivatePlanRegistry::new());
+    registry.register(PrivateNumberingPlan::new("acme", "999", 4..=6).format("##-##"));
+    let parser = Parser::new()
+        // internal extensions are dialed with a leading '*'
+        .pre_hook(|phone_number| {
+            if let Some(internal) = phone_number.strip_prefix('*') {
+                *phone_number = format!("+999{}", internal);
+            }
+        })
+        .private_plans(registry.clone());
+
+    assert!(parser.is_valid("*1234"));
+    assert_eq!(parser.normalize("*1234"), Some("+9991234".to_string()));
+    assert_eq!(
+        parser.private_number("*1234").unwrap().national_number,
+        "1234"
+    );
+    // the bundled metadata doesn't know the plan
+    assert_eq!(normalize_phone_number("+9991234"), None);
+    assert_eq!(parser.extract_country("+9991234"), None);
+
+    assert_eq!(
+        parser.format("+9991234", PhoneFormat::International),
+        Some("+999 12-34".to_string())
+    );
+    assert_eq!(
+        parser.format("+9991234", PhoneFormat::National),
+        Some("12-34".to_string())
+    );
+    assert_eq!(
+        parser.format("+999123456", PhoneFormat::RFC3966),
+        Some("tel:+999-123-456".to_string())
+    );
+    assert_eq!(
+        parser.format("+33612345678", PhoneFormat::International),
+        Some("+33 6 12 34 56 78".to_string())
+    );
+
+    registry.unregister("acme");
+    assert!(!parser.is_valid("+9991234"));
+    assert_eq!(parser.format("+9991234", PhoneFormat::E164), None);
+}
@@ -0,0 +1,88 @@
+// Invariants checked on generated inputs: any string for the functions that must accept
+// anything, and numbers built from the metadata (a country, one of its lengths, random
+// digits) for the ones about valid numbers.
+
+use proptest::prelude::*;
+
+use crate::{
+    all_countries, enumerate_numbers, extract_country, is_valid_phone_number,
+    normalize_phone_number, Country, PhoneFormat, PhoneNumber,
+};
+
+// digits, separators, a few letters and the characters of prefixes and extensions
+const PHONE_LIKE: &str = "[0-9 +()\\-.xext;=a]{0,24}";
+
+// "+", the calling code and national digits of one of the lengths of a random country
+fn international_number() -> impl Strategy<Value = (&'static Country, String)> {
+    let countries: Vec<&'static Country> = all_countries().iter().collect();
+    proptest::sample::select(countries)
+        .prop_flat_map(|country| {
+            let lengths = country.phone_lengths.to_vec();
+            (Just(country), proptest::sample::select(lengths))
+        })
+        .prop_flat_map(|(country, length)| {
+            let digits = proptest::string::string_regex(&format!("[0-9]{{{}}}", length));
+            (Just(country), digits.unwrap())
+        })
+        .prop_map(|(country, digits)| (country, format!("+{}{}", country.prefix, digits)))
+}
+
+// a number of `international_number` as people write it: with "00" or "+", trunk zeros
+// after the calling code and separators
+fn written_number() -> impl Strategy<Value = String> {
+    (international_number(), "\\+|00|\\+00", "0{0,2}", "[ .\\-]?").prop_map(
+        |((country, number), idd, zeros, separator)| {
+            let national_number = &number[1 + country.prefix_len()..];
+            format!(
+                "{}{}{}{}{}",
+                idd, country.prefix, separator, zeros, national_number
+            )
+        },
+    )
+}
+
+// any phone-like string or a written number
+fn any_input() -> impl Strategy<Value = String> {
+    prop_oneof![PHONE_LIKE, written_number()]
+}
+
+proptest! {
+    #[test]
+    fn test_normalization_is_idempotent(input in any_input()) {
+        if let Some(normalized) = normalize_phone_number(&input) {
+            prop_assert_eq!(normalize_phone_number(&normalized), Some(normalized.clone()));
+            prop_assert!(is_valid_phone_number(&normalized));
+        }
+    }
+
+    #[test]
+    fn test_normalization_keeps_the_country(input in any_input()) {
+        if let Some(normalized) = normalize_phone_number(&input) {
+            prop_assert_eq!(extract_country(&normalized), extract_country(&input));
+        }
+    }
+
+    #[test]
+    fn test_formats_parse_back((_, input) in international_number()) {
+        if let Some(number) = PhoneNumber::parse(&input) {
+            for format in [PhoneFormat::E164, PhoneFormat::International, PhoneFormat::RFC3966] {
+                let formatted = number.format(format);
+                prop_assert_eq!(
+                    PhoneNumber::parse(&formatted).map(|reparsed| reparsed.e164()),
+                    Some(number.e164()),
+                    "{} formatted as {}", input, formatted
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_enumerated_numbers_are_valid((_, input) in international_number()) {
+        // the first digits of a number fitting the metadata
+        let prefix = &input[..input.len() - 2];
+        for number in enumerate_numbers(prefix, 3) {
+            prop_assert!(is_valid_phone_number(number), "{} from {}", number, prefix);
+            prop_assert!(number.starts_with(prefix));
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{is_within_local_hours, Country, LocalDateTime, QuietHoursProvider};
+
+// 2026-12-25 at 12:00 UTC
+const CHRISTMAS_NOON: i64 = 1_798_200_000;
+
+struct Calendar;
+
+impl QuietHoursProvider for Calendar {
+    fn local_time(&self, country: &'static Country, unix_seconds: i64) -> Option<LocalDateTime> {
+        match country.code {
+            "FR" => Some(LocalDateTime::from_unix_seconds(unix_seconds, 60)),
+            "LB" => Some(LocalDateTime::from_unix_seconds(unix_seconds, 120)),
+            _ => None,
+        }
+    }
+
+    fn is_quiet(&self, country: &'static Country, local: &LocalDateTime) -> bool {
+        let christmas = country.code == "FR" && (local.month, local.day) == (12, 25);
+        christmas || local.hour < 9 || local.hour >= 20
+    }
+}
+
+#[test]
+fn test_local_date_time_from_unix_seconds() {
+    let date_time = |year, month, day, hour, minute| LocalDateTime {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+    };
+
+    assert_eq!(
+        LocalDateTime::from_unix_seconds(0, 0),
+        date_time(1970, 1, 1, 0, 0)
+    );
+    assert_eq!(
+        LocalDateTime::from_unix_seconds(CHRISTMAS_NOON, 0),
+        date_time(2026, 12, 25, 12, 0)
+    );
+    // offsets move across days, months and years
+    assert_eq!(
+        LocalDateTime::from_unix_seconds(0, -90),
+        date_time(1969, 12, 31, 22, 30)
+    );
+    assert_eq!(
+        LocalDateTime::from_unix_seconds(951_825_600, 0),
+        date_time(2000, 2, 29, 12, 0)
+    );
+    assert_eq!(
+        LocalDateTime::from_unix_seconds(951_825_600, 720),
+        date_time(2000, 3, 1, 0, 0)
+    );
+}
+
+#[test]
+fn test_is_within_local_hours() {
+    // holidays and quiet hours of the provider apply
+    assert_eq!(
+        is_within_local_hours("+33612345678", CHRISTMAS_NOON, &Calendar),
+        Some(false)
+    );
+    assert_eq!(
+        is_within_local_hours("+96179123123", CHRISTMAS_NOON, &Calendar),
+        Some(true)
+    );
+    // 19:00 UTC is 21:00 in Beirut
+    assert_eq!(
+        is_within_local_hours("+96179123123", CHRISTMAS_NOON + 7 * 3600, &Calendar),
+        Some(false)
+    );
+    assert_eq!(
+        is_within_local_hours("+4930123456", CHRISTMAS_NOON, &Calendar),
+        None
+    );
+    assert_eq!(
+        is_within_local_hours("invalid_phone_number", CHRISTMAS_NOON, &Calendar),
+        None
+    );
+}
+
+#[test]
+fn test_default_quiet_hours() {
+    struct Utc;
+
+    impl QuietHoursProvider for Utc {
+        fn local_time(&self, _: &'static Country, unix_seconds: i64) -> Option<LocalDateTime> {
+            Some(LocalDateTime::from_unix_seconds(unix_seconds, 0))
+        }
+    }
+
+    let at = |hour: i64| {
+        is_within_local_hours("+33612345678", CHRISTMAS_NOON + (hour - 12) * 3600, &Utc)
+    };
+    assert_eq!(at(7), Some(false));
+    assert_eq!(at(8), Some(true));
+    assert_eq!(at(20), Some(true));
+    assert_eq!(at(21), Some(false));
+}
@@ -0,0 +1,14 @@
+use crate::{equivalent_forms, find_country_by_code, quirks};
+
+#[test]
+fn test_argentinian_mobile_digit() {
+    let argentina = find_country_by_code("AR").unwrap();
+    assert_eq!(quirks::mobile_digit(argentina), Some("9"));
+    assert_eq!(argentina.trunk_prefix(), Some("0"));
+
+    assert_eq!(
+        equivalent_forms("+54 9 11 1234 5678")[0],
+        equivalent_forms("+54 11 1234 5678")[0]
+    );
+    assert!(equivalent_forms("+54 11 1234 5678").contains(&"+5491112345678".to_string()));
+}
@@ -0,0 +1,17 @@
+use crate::{find_country_by_code, normalize_phone_number_in_region};
+
+#[test]
+fn test_hungarian_trunk_prefix() {
+    assert_eq!(
+        find_country_by_code("HU").unwrap().trunk_prefix(),
+        Some("06")
+    );
+    assert_eq!(
+        normalize_phone_number_in_region("06 30 123 4567", "HU"),
+        Some("+36301234567".to_string())
+    );
+    assert_eq!(
+        normalize_phone_number_in_region("30 123 4567", "HU"),
+        Some("+36301234567".to_string())
+    );
+}
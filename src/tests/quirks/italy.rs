@@ -0,0 +1,60 @@
+use crate::{
+    find_country_by_code, format_phone_number, normalize_phone_number,
+    normalize_phone_number_in_place, normalize_phone_number_in_region, Parser, PhoneFormat, E164,
+};
+
+#[test]
+fn test_italian_leading_zero_is_kept() {
+    assert_eq!(E164::parse("+39 06 1234 5678").unwrap(), "+390612345678");
+    assert_eq!(E164::parse("0039 06 1234 5678").unwrap(), "+390612345678");
+    assert_eq!(
+        normalize_phone_number_in_region("06 1234 5678", "IT"),
+        Some("+390612345678".to_string())
+    );
+    assert_eq!(
+        format_phone_number("+390612345678", PhoneFormat::International),
+        Some("+39 06 1234 5678".to_string())
+    );
+}
+
+#[test]
+fn test_italian_mobiles_have_no_leading_zero() {
+    assert_eq!(E164::parse("+39 312 345 6789").unwrap(), "+393123456789");
+    assert_eq!(
+        normalize_phone_number_in_region("312 345 6789", "IT"),
+        Some("+393123456789".to_string())
+    );
+}
+
+#[test]
+fn test_zero_significant_plans() {
+    for region in ["IT", "SM"] {
+        assert!(find_country_by_code(region).unwrap().zero_significant());
+    }
+    assert!(!find_country_by_code("FR").unwrap().zero_significant());
+
+    assert_eq!(
+        normalize_phone_number("+39 06 1234 5678"),
+        Some("+390612345678".to_string())
+    );
+    assert_eq!(
+        normalize_phone_number_in_place(&mut "0039 06 1234 5678".to_string()),
+        Some("+390612345678".to_string())
+    );
+    assert_eq!(
+        normalize_phone_number("+378 0549 123456"),
+        Some("+3780549123456".to_string())
+    );
+    assert_eq!(
+        Parser::new()
+            .trace(true)
+            .normalize_with_trace("+39 06 1234 5678")
+            .0,
+        Some("+390612345678".to_string())
+    );
+    // the trunk prefix of other plans is still dropped
+    assert_eq!(
+        normalize_phone_number("+961 07 123 123"),
+        Some("+9617123123".to_string())
+    );
+}
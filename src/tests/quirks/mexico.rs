@@ -0,0 +1,12 @@
+use crate::{equivalent_forms, find_country_by_code, quirks};
+
+#[test]
+fn test_mexican_legacy_mobile_digit() {
+    let mexico = find_country_by_code("MX").unwrap();
+    assert_eq!(quirks::mobile_digit(mexico), Some("1"));
+    assert_eq!(mexico.trunk_prefix(), None);
+
+    let forms = equivalent_forms("+52 1 55 1234 5678");
+    assert_eq!(forms[0], "+525512345678");
+    assert!(forms.contains(&"+5215512345678".to_string()));
+}
@@ -0,0 +1,5 @@
+mod argentina;
+mod hungary;
+mod italy;
+mod mexico;
+mod russia;
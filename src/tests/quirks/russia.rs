@@ -0,0 +1,23 @@
+use crate::{
+    find_country_by_code, format_phone_number, normalize_phone_number_in_region, PhoneFormat,
+};
+
+#[test]
+fn test_soviet_plan_trunk_prefix() {
+    for region in ["RU", "BY", "KZ", "TJ", "TM", "UZ"] {
+        assert_eq!(
+            find_country_by_code(region).unwrap().trunk_prefix(),
+            Some("8"),
+            "{}",
+            region
+        );
+    }
+    assert_eq!(
+        normalize_phone_number_in_region("8 (912) 345-67-89", "RU"),
+        Some("+79123456789".to_string())
+    );
+    assert_eq!(
+        format_phone_number("+79123456789", PhoneFormat::National),
+        Some("8 (912) 345-67-89".to_string())
+    );
+}
@@ -0,0 +1,97 @@
+use crate::matcher::{Leniency, PhoneNumberMatcher};
+use crate::{
+    mask_phone_number, redact_phone_numbers_in_text, redact_phone_numbers_in_text_with_region,
+    MaskStyle, Redaction,
+};
+
+#[test]
+fn test_mask_phone_number() {
+    let masked = |style| mask_phone_number("+1 (202) 555-0173", style);
+
+    assert_eq!(masked(MaskStyle::Compact), Some("+1••••••73".to_string()));
+    assert_eq!(masked(MaskStyle::E164), Some("+1••••••••73".to_string()));
+    assert_eq!(
+        masked(MaskStyle::International),
+        Some("+1 •••-•••-••73".to_string())
+    );
+    assert_eq!(masked(MaskStyle::Placeholder), Some("[PHONE]".to_string()));
+
+    // the compact mask hides the length of the number
+    assert_eq!(
+        mask_phone_number("+961 79 123 123", MaskStyle::Compact),
+        Some("+961••••••23".to_string())
+    );
+    assert_eq!(mask_phone_number("+1 202", MaskStyle::Compact), None);
+}
+
+#[test]
+fn test_redact_phone_numbers_in_text() {
+    let text = "user +961 79 123 123 paid $2025550173 on 2026-10-15, retry +1 202-555-0173";
+    let (redacted, redactions) = redact_phone_numbers_in_text(text);
+
+    assert_eq!(
+        redacted,
+        "user +961••••••23 paid $2025550173 on 2026-10-15, retry +1••••••73"
+    );
+    assert_eq!(
+        redactions,
+        [
+            Redaction {
+                start: 5,
+                end: 20,
+                replacement: "+961••••••23".to_string(),
+            },
+            Redaction {
+                start: 59,
+                end: 74,
+                replacement: "+1••••••73".to_string(),
+            },
+        ]
+    );
+    for redaction in &redactions {
+        assert!(text[redaction.start..redaction.end].starts_with('+'));
+    }
+
+    let (unchanged, redactions) = redact_phone_numbers_in_text("nothing to see");
+    assert_eq!(unchanged, "nothing to see");
+    assert!(redactions.is_empty());
+}
+
+#[test]
+fn test_redact_phone_numbers_dialed_or_written_nationally() {
+    // "00" reads as an international prefix without a region
+    let (redacted, _) = redact_phone_numbers_in_text("call 0033 6 12 34 56 78");
+    assert_eq!(redacted, "call +33••••••78");
+
+    // national numbers need their region
+    let (unchanged, _) = redact_phone_numbers_in_text("call (202) 555-0173");
+    assert_eq!(unchanged, "call (202) 555-0173");
+    let (redacted, redactions) =
+        redact_phone_numbers_in_text_with_region("call (202) 555-0173", "US");
+    assert_eq!(redacted, "call +1••••••73");
+    assert_eq!((redactions[0].start, redactions[0].end), (5, 19));
+
+    // and so does the international prefix of the region
+    let (redacted, _) = redact_phone_numbers_in_text_with_region(
+        "call 011 33 6 12 34 56 78 or 0033 6 12 34 56 78",
+        "US",
+    );
+    assert_eq!(redacted, "call +33••••••78 or +33••••••78");
+    let (redacted, _) = redact_phone_numbers_in_text_with_region("call 06 12 34 56 78", "FR");
+    assert_eq!(redacted, "call +33••••••78");
+}
+
+#[test]
+fn test_matcher_redact() {
+    let matcher = PhoneNumberMatcher::new(Leniency::Valid).default_region("FR");
+    let (redacted, _) = matcher.redact(
+        "Appelez le 06 12 34 56 78 ou le +33 1 23 45 67 89",
+        MaskStyle::Placeholder,
+    );
+    assert_eq!(redacted, "Appelez le [PHONE] ou le [PHONE]");
+
+    // no French range starts with 85, the number is still masked
+    let (redacted, _) =
+        PhoneNumberMatcher::new(Leniency::Possible).redact("+33 8 51 23 45 67", MaskStyle::E164);
+    assert_eq!(redacted, "+33•••••••67");
+}
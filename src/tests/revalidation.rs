@@ -0,0 +1,95 @@
+use crate::revalidation::{previous_metadata, revalidate_against};
+use crate::{revalidate_stored, MetadataChange, RevalidationStatus, METADATA_VERSION};
+
+fn numbers() -> Vec<String> {
+    vec![
+        "+96179123123".to_string(),
+        "+9617912312".to_string(),
+        "+12685551234".to_string(),
+        "invalid_phone_number".to_string(),
+        "+9991234567".to_string(),
+    ]
+}
+
+#[test]
+fn test_revalidate_stored_without_changes() {
+    let outcomes = revalidate_stored(&numbers(), METADATA_VERSION);
+
+    assert_eq!(outcomes.len(), 5);
+    assert!(outcomes.iter().all(|outcome| !outcome.is_changed()));
+    assert_eq!(outcomes[0].country.unwrap().code, "LB");
+    assert_eq!(outcomes[0].previous_country_id, Some(127));
+    assert_eq!(outcomes[3].country, None);
+}
+
+#[test]
+fn test_revalidate_stored_flags_changes() {
+    let changes = [
+        // Lebanon used to accept 7 digit national numbers only
+        MetadataChange::LengthsChanged {
+            id: 127,
+            before: &[7],
+            after: &[7, 8],
+        },
+        // Antigua and Barbuda used to take 10 digits after +1268, leaving its numbers to
+        // the United States
+        MetadataChange::LengthsChanged {
+            id: 5,
+            before: &[10],
+            after: &[7],
+        },
+        // and a long gone country used to own +999
+        MetadataChange::CountryRemoved {
+            id: 9999,
+            code: "XX",
+            prefix: 999,
+            phone_lengths: &[7],
+        },
+    ];
+    let outcomes = revalidate_against(&numbers(), &previous_metadata(0, changes.iter()));
+
+    assert_eq!(outcomes[0].status, RevalidationStatus::BecameValid);
+    assert_eq!(outcomes[1].status, RevalidationStatus::Unchanged);
+    assert_eq!(outcomes[2].status, RevalidationStatus::CountryChanged);
+    assert_eq!(outcomes[2].previous_country_id, Some(230));
+    assert_eq!(outcomes[2].country.unwrap().code, "AG");
+    assert_eq!(outcomes[3].status, RevalidationStatus::Unchanged);
+    assert_eq!(outcomes[4].status, RevalidationStatus::BecameInvalid);
+    assert_eq!(outcomes[4].previous_country_id, Some(9999));
+
+    let added = [MetadataChange::CountryAdded { id: 127 }];
+    let outcomes = revalidate_against(&numbers(), &previous_metadata(0, added.iter()));
+    assert_eq!(outcomes[0].status, RevalidationStatus::BecameValid);
+}
+
+#[test]
+fn test_revalidate_stored_replays_area_codes() {
+    // Western Sahara took every +212 number before it had area codes of its own
+    let outcomes = revalidate_stored(
+        &["+212 522 123456".to_string(), "+212 5288 12345".to_string()],
+        4,
+    );
+
+    assert_eq!(outcomes[0].status, RevalidationStatus::CountryChanged);
+    assert_eq!(outcomes[0].previous_country_id, Some(67));
+    assert_eq!(outcomes[0].country.unwrap().code, "MA");
+    assert_eq!(outcomes[1].status, RevalidationStatus::Unchanged);
+    assert_eq!(outcomes[1].country.unwrap().code, "EH");
+}
+
+#[test]
+fn test_revalidate_stored_replays_type_lengths() {
+    // 0.1.5 accepted German mobiles of 9 digits, a valid length for landlines
+    let outcomes = revalidate_stored(
+        &["+49 151 234567".to_string(), "+49 151 23456789".to_string()],
+        1,
+    );
+
+    assert_eq!(outcomes[0].status, RevalidationStatus::BecameInvalid);
+    assert_eq!(outcomes[0].previous_country_id, Some(58));
+    assert_eq!(outcomes[1].status, RevalidationStatus::Unchanged);
+    assert_eq!(
+        revalidate_stored(&[outcomes[0].number.clone()], 4)[0].status,
+        RevalidationStatus::Unchanged
+    );
+}
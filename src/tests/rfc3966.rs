@@ -0,0 +1,74 @@
+use crate::{parse_rfc3966, sanitize_tel_href};
+
+#[test]
+fn test_sanitize_tel_href() {
+    let href = Some("tel:+12025550173".to_string());
+
+    assert_eq!(sanitize_tel_href("+1 (202) 555-0173"), href);
+    assert_eq!(sanitize_tel_href("  tel:+1-202-555-0173 "), href);
+    assert_eq!(sanitize_tel_href("TEL:+1.202.555.0173"), href);
+    assert_eq!(
+        sanitize_tel_href("+1 202 555 0173 ext. 12"),
+        Some("tel:+12025550173;ext=12".to_string())
+    );
+    assert_eq!(
+        sanitize_tel_href("tel:+1-202-555-0173;ext=12"),
+        Some("tel:+12025550173;ext=12".to_string())
+    );
+}
+
+#[test]
+fn test_sanitize_tel_href_rejects_unsafe_input() {
+    assert_eq!(sanitize_tel_href("javascript:alert(1)"), None);
+    assert_eq!(sanitize_tel_href("+12025550173\" onclick=\"alert(1)"), None);
+    assert_eq!(sanitize_tel_href("+1202555<script>0173"), None);
+    assert_eq!(sanitize_tel_href("tel:%2B12025550173"), None);
+    assert_eq!(sanitize_tel_href("+1202555\n0173"), None);
+    assert_eq!(sanitize_tel_href("+12025550173;phone-context=evil"), None);
+    assert_eq!(sanitize_tel_href("+12025550173 ext"), None);
+    assert_eq!(sanitize_tel_href("call me maybe"), None);
+    assert_eq!(sanitize_tel_href(""), None);
+}
+
+#[test]
+fn test_parse_rfc3966() {
+    let number = parse_rfc3966("tel:+1-202-555-0173;ext=12").unwrap();
+    assert_eq!(number.e164(), "+12025550173");
+    assert_eq!(number.extension(), Some("12"));
+    assert_eq!(number.phone_context(), None);
+    assert_eq!(number.raw(), "tel:+1-202-555-0173;ext=12");
+
+    let number = parse_rfc3966("TEL:+33.6.12.34.56.78").unwrap();
+    assert_eq!(number.e164(), "+33612345678");
+    assert_eq!(number.extension(), None);
+
+    let number = parse_rfc3966("tel:+1(202)555-0173;isub=1411;ext=9").unwrap();
+    assert_eq!(number.e164(), "+12025550173");
+    assert_eq!(number.extension(), Some("9"));
+}
+
+#[test]
+fn test_parse_rfc3966_phone_context() {
+    let number = parse_rfc3966("tel:555-0173;phone-context=+1-202").unwrap();
+    assert_eq!(number.e164(), "+12025550173");
+    assert_eq!(number.phone_context(), Some("+1-202"));
+
+    let number = parse_rfc3966("tel:+12025550173;phone-context=example.com").unwrap();
+    assert_eq!(number.phone_context(), Some("example.com"));
+
+    // the calling code of a domain context is unknown
+    assert_eq!(
+        parse_rfc3966("tel:555-0173;phone-context=example.com"),
+        None
+    );
+    assert_eq!(parse_rfc3966("tel:555-0173"), None);
+}
+
+#[test]
+fn test_parse_rfc3966_rejects_invalid_uris() {
+    assert_eq!(parse_rfc3966("+12025550173"), None);
+    assert_eq!(parse_rfc3966("sip:+12025550173@example.com"), None);
+    assert_eq!(parse_rfc3966("tel:+1 202 555 0173"), None);
+    assert_eq!(parse_rfc3966("tel:+12025550173;ext="), None);
+    assert_eq!(parse_rfc3966("tel:+"), None);
+}
@@ -0,0 +1,8 @@
+use crate::{find_country_by_code, IRSF_PRONE_REGIONS};
+
+#[test]
+fn test_irsf_prone_regions_exist() {
+    for region in IRSF_PRONE_REGIONS {
+        assert!(find_country_by_code(region).is_some(), "{}", region);
+    }
+}
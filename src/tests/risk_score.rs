@@ -0,0 +1,38 @@
+use crate::{is_do_not_originate, risk_profile, RangeTag};
+
+#[test]
+fn test_risk_profile_tags() {
+    let profile = risk_profile("+1 (900) 234-0173").unwrap();
+    assert_eq!(profile.country.code, "US");
+    assert_eq!(profile.tags, vec![RangeTag::Premium]);
+
+    let profile = risk_profile("+44 7012 345678").unwrap();
+    assert!(profile.has_tag(RangeTag::Temporary));
+    assert!(!profile.has_tag(RangeTag::Premium));
+
+    assert!(risk_profile("+4990012345678")
+        .unwrap()
+        .has_tag(RangeTag::Premium));
+}
+
+#[test]
+fn test_risk_profile_untagged_and_invalid() {
+    assert!(risk_profile("+96179123123").unwrap().tags.is_empty());
+    assert_eq!(risk_profile("invalid_phone_number"), None);
+}
+
+#[test]
+fn test_is_do_not_originate() {
+    assert!(is_do_not_originate("+1 202 555 0173"));
+    assert!(is_do_not_originate("+1 (202) 958-1234"));
+    assert!(is_do_not_originate("+1 911 234 5678"));
+    assert!(is_do_not_originate("+1 375 234 5678"));
+    assert!(is_do_not_originate("+1 202 011 5678"));
+    assert!(!is_do_not_originate("+1 202 456 1111"));
+    assert!(!is_do_not_originate("+96179123123"));
+    assert!(!is_do_not_originate("invalid_phone_number"));
+
+    assert!(risk_profile("+12025550173")
+        .unwrap()
+        .has_tag(RangeTag::DoNotOriginate));
+}
@@ -0,0 +1,56 @@
+use super::tests::{phone_numbers, REAL_NUMBERS};
+use crate::{best_fitting_countries, PhoneFormat, PhoneNumber};
+
+// Generates a test per format, checking that every generated fixture is parsed and
+// normalized unchanged, then parsed back to the same number from that format (national
+// formats in the region of the number), and the same for the real numbers.
+macro_rules! round_trip_tests {
+    ($($name:ident: $format:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                for fixture in phone_numbers() {
+                    let number = PhoneNumber::parse(&fixture.phone_number).unwrap();
+                    assert_eq!(number.e164(), fixture.phone_number.as_str());
+                    // the first country of the table takes the ranges shared with others
+                    let fitting = best_fitting_countries(&fixture.phone_number[1..]);
+                    assert!(fitting.contains(&fixture.country));
+                    assert_eq!(number.country(), fitting[0]);
+
+                    let formatted = number.format($format);
+                    let reparsed =
+                        PhoneNumber::parse_with_country(&formatted, number.country().code);
+                    assert_eq!(
+                        reparsed.map(|number| number.e164()),
+                        Some(number.e164()),
+                        "{} formatted as {}",
+                        fixture.phone_number,
+                        formatted
+                    );
+                }
+
+                for (code, phone_number) in REAL_NUMBERS {
+                    let number = PhoneNumber::parse(phone_number).unwrap();
+                    assert_eq!(number.e164(), *phone_number);
+
+                    let formatted = number.format($format);
+                    let reparsed = PhoneNumber::parse_with_country(&formatted, code);
+                    assert_eq!(
+                        reparsed.map(|number| number.e164()),
+                        Some(number.e164()),
+                        "{} formatted as {}",
+                        phone_number,
+                        formatted
+                    );
+                }
+            }
+        )*
+    };
+}
+
+round_trip_tests! {
+    test_round_trip_e164: PhoneFormat::E164,
+    test_round_trip_international: PhoneFormat::International,
+    test_round_trip_national: PhoneFormat::National,
+    test_round_trip_rfc3966: PhoneFormat::RFC3966,
+}
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use crate::{CharacterClass, Parser, RejectionSampler};
+
+#[test]
+fn test_rejection_sampler() {
+    let sampler = Arc::new(RejectionSampler::new(1));
+    let parser = Parser::new().rejection_sampler(sampler.clone());
+    let traced = Parser::new().rejection_sampler(sampler.clone()).trace(true);
+
+    assert!(parser.normalize("+96179123123").is_some());
+    assert_eq!(parser.normalize("+961 1234"), None);
+    assert_eq!(parser.normalize("call me"), None);
+    assert_eq!(traced.normalize_with_trace("(961) 1234").0, None);
+
+    let stats = sampler.stats();
+    assert_eq!((stats.rejected, stats.sampled), (3, 3));
+    assert_eq!(
+        stats.digit_counts.into_iter().collect::<Vec<_>>(),
+        [(0, 1), (7, 2)]
+    );
+    assert_eq!(
+        stats.character_classes.into_iter().collect::<Vec<_>>(),
+        [
+            (CharacterClass::Digit, 2),
+            (CharacterClass::Plus, 1),
+            (CharacterClass::Separator, 3),
+            (CharacterClass::Letter, 1),
+        ]
+    );
+    assert_eq!(
+        stats.candidate_countries.into_iter().collect::<Vec<_>>(),
+        [(None, 1), (Some("LB"), 2)]
+    );
+}
+
+#[test]
+fn test_rejection_sampler_sampling() {
+    let sampler = Arc::new(RejectionSampler::new(3));
+    let parser = Parser::new()
+        .region_hints(|| Some("FR".to_string()))
+        .rejection_sampler(sampler.clone());
+
+    for _ in 0..7 {
+        assert_eq!(parser.normalize("06 12"), None);
+    }
+
+    let stats = sampler.stats();
+    assert_eq!((stats.rejected, stats.sampled), (7, 3));
+    assert_eq!(
+        stats.candidate_countries.into_iter().collect::<Vec<_>>(),
+        [(Some("FR"), 3)]
+    );
+    assert_eq!(RejectionSampler::new(0).stats(), Default::default());
+}
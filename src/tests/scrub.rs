@@ -0,0 +1,116 @@
+use crate::{
+    scrub_campaign_list, Country, LocalDateTime, PhoneNumberType, QuietHoursProvider, ScrubOptions,
+    ScrubRejection,
+};
+
+struct ParisEvening;
+
+impl QuietHoursProvider for ParisEvening {
+    fn local_time(&self, country: &'static Country, _unix_seconds: i64) -> Option<LocalDateTime> {
+        (country.code == "FR").then_some(LocalDateTime {
+            year: 2026,
+            month: 1,
+            day: 15,
+            hour: 22,
+            minute: 0,
+        })
+    }
+}
+
+#[test]
+fn test_scrub_normalizes_and_deduplicates() {
+    let report = scrub_campaign_list(
+        [
+            "+33 6 12 34 56 78",
+            "+33612345678",
+            "invalid",
+            "+1 202 555 0173",
+        ],
+        &ScrubOptions::new(),
+    );
+
+    let kept: Vec<_> = report
+        .kept
+        .iter()
+        .map(|kept| (kept.index, kept.number))
+        .collect();
+    assert_eq!(kept.len(), 2);
+    assert_eq!((kept[0].0, kept[0].1.as_str()), (0, "+33612345678"));
+    assert_eq!((kept[1].0, kept[1].1.as_str()), (3, "+12025550173"));
+    assert_eq!(
+        report.rejected,
+        [(1, ScrubRejection::Duplicate), (2, ScrubRejection::Invalid)]
+    );
+    assert_eq!(report.kept[0].within_local_hours, None);
+}
+
+#[test]
+fn test_scrub_matches_numbers_whatever_the_region_hint() {
+    // +500 is shared by FK and GS: the hint changes the country, not the number
+    let options = ScrubOptions::new()
+        .default_region("GS")
+        .blocklist(["+500 51234"]);
+    let report = scrub_campaign_list(["+500 51234"], &options);
+    assert!(report.kept.is_empty());
+    assert_eq!(report.rejected, [(0, ScrubRejection::Blocklisted)]);
+
+    let report = scrub_campaign_list(
+        ["+500 51234", "51234"],
+        &ScrubOptions::new().default_region("GS"),
+    );
+    assert_eq!(report.kept.len(), 1);
+    assert_eq!(report.rejected, [(1, ScrubRejection::Duplicate)]);
+}
+
+#[test]
+fn test_scrub_filters() {
+    let options = ScrubOptions::new()
+        .default_region("FR")
+        .allowed_regions(&["fr", "XX"])
+        .number_types(&[PhoneNumberType::Mobile])
+        .blocklist(vec![
+            "06 99 99 99 99".to_string(),
+            "+33 6 99 99 99 99".to_string(),
+        ]);
+    let report = scrub_campaign_list(
+        vec![
+            "06 12 34 56 78",
+            "01 23 45 67 89",
+            "+4915112345678",
+            "+33699999999",
+        ],
+        &options,
+    );
+
+    assert_eq!(report.kept.len(), 1);
+    assert_eq!(report.kept[0].country.code, "FR");
+    assert_eq!(report.kept[0].number_type, PhoneNumberType::Mobile);
+    assert_eq!(
+        report.rejected,
+        [
+            (1, ScrubRejection::TypeNotAllowed),
+            (2, ScrubRejection::RegionNotAllowed),
+            (3, ScrubRejection::Blocklisted),
+        ]
+    );
+}
+
+#[test]
+fn test_scrub_tags_quiet_hours() {
+    let options = ScrubOptions::new().quiet_hours(ParisEvening, 0);
+    let report = scrub_campaign_list(["+33612345678", "+4915112345678"], &options);
+
+    assert_eq!(report.kept[0].within_local_hours, Some(false));
+    assert_eq!(report.kept[1].within_local_hours, None);
+    assert!(report.rejected.is_empty());
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn test_scrub_do_not_originate() {
+    let options = ScrubOptions::new().exclude_do_not_originate(true);
+    let report = scrub_campaign_list(["+1 202 555 0173", "+1 202 736 0173"], &options);
+
+    assert_eq!(report.kept.len(), 1);
+    assert_eq!(report.rejected, [(0, ScrubRejection::DoNotOriginate)]);
+}
@@ -0,0 +1,45 @@
+use crate::{is_valid_alpha_sender_id, is_valid_alpha_sender_id_in_region};
+
+#[test]
+fn test_is_valid_alpha_sender_id() {
+    assert!(is_valid_alpha_sender_id("ACME"));
+    assert!(is_valid_alpha_sender_id("Shop-24 &Co"));
+    assert!(is_valid_alpha_sender_id("a"));
+    assert!(is_valid_alpha_sender_id("INFO.SMS_1+"));
+
+    assert!(!is_valid_alpha_sender_id(""));
+    assert!(!is_valid_alpha_sender_id("123456"));
+    assert!(!is_valid_alpha_sender_id("InfoServices"));
+    assert!(!is_valid_alpha_sender_id("Café"));
+    assert!(!is_valid_alpha_sender_id("ACME!"));
+}
+
+#[test]
+fn test_is_valid_alpha_sender_id_in_region() {
+    assert_eq!(is_valid_alpha_sender_id_in_region("ACME", "FR"), Some(true));
+    assert_eq!(is_valid_alpha_sender_id_in_region("ACME", "lb"), Some(true));
+    assert_eq!(
+        is_valid_alpha_sender_id_in_region("InfoServices", "FR"),
+        Some(false)
+    );
+    // prohibited regions
+    assert_eq!(
+        is_valid_alpha_sender_id_in_region("ACME", "US"),
+        Some(false)
+    );
+    assert_eq!(
+        is_valid_alpha_sender_id_in_region("ACME", "CA"),
+        Some(false)
+    );
+    // regions with their own lengths
+    assert_eq!(
+        is_valid_alpha_sender_id_in_region("ACME", "IN"),
+        Some(false)
+    );
+    assert_eq!(
+        is_valid_alpha_sender_id_in_region("ACMEBK", "IN"),
+        Some(true)
+    );
+
+    assert_eq!(is_valid_alpha_sender_id_in_region("ACME", "XX"), None);
+}
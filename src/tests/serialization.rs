@@ -0,0 +1,75 @@
+use serde_json::json;
+
+use crate::{find_country_by_code, Country, PhoneFormat, PhoneNumber, PhoneNumberType, E164};
+
+#[test]
+fn test_country_round_trip() {
+    let lebanon = find_country_by_code("LB").unwrap();
+    let value = serde_json::to_value(lebanon).unwrap();
+
+    assert_eq!(
+        value,
+        json!({
+            "id": lebanon.id,
+            "name": lebanon.name,
+            "code": "LB",
+            "phone_lengths": [7, 8],
+            "prefix": 961,
+        })
+    );
+    assert_eq!(serde_json::from_value::<Country>(value).unwrap(), *lebanon);
+    // the id wins over the other fields, the code is enough without it
+    assert_eq!(
+        serde_json::from_value::<Country>(json!({"id": lebanon.id, "code": "FR"})).unwrap(),
+        *lebanon
+    );
+    assert_eq!(
+        serde_json::from_value::<Country>(json!({"code": "LB"})).unwrap(),
+        *lebanon
+    );
+    assert!(serde_json::from_value::<Country>(json!({"id": u16::MAX})).is_err());
+    assert!(serde_json::from_value::<Country>(json!({"name": "Lebanon"})).is_err());
+}
+
+#[test]
+fn test_phone_number_round_trip() {
+    let number = PhoneNumber::parse("+1 (202) 555-0173 ext. 45").unwrap();
+    let value = serde_json::to_value(&number).unwrap();
+
+    assert_eq!(
+        value,
+        json!({"e164": "+12025550173", "country": "US", "extension": "45"})
+    );
+    let read: PhoneNumber = serde_json::from_value(value).unwrap();
+    assert_eq!(read, number);
+    assert_eq!(read.raw(), "+12025550173");
+
+    // the country tells apart the countries sharing the calling code
+    let isle_of_man = PhoneNumber::parse_with_country("+44 7624 123456", "IM").unwrap();
+    let read: PhoneNumber =
+        serde_json::from_str(&serde_json::to_string(&isle_of_man).unwrap()).unwrap();
+    assert_eq!(read.country().code, "IM");
+    assert_eq!(read.extension(), None);
+
+    assert!(serde_json::from_value::<PhoneNumber>(json!({"e164": "+1", "country": "US"})).is_err());
+}
+
+#[test]
+fn test_e164_and_enums() {
+    let number = E164::parse("+96179123123").unwrap();
+    assert_eq!(serde_json::to_string(&number).unwrap(), "\"+96179123123\"");
+    assert_eq!(
+        serde_json::from_str::<E164>("\"+961 79 123 123\"").unwrap(),
+        number
+    );
+    assert!(serde_json::from_str::<E164>("\"invalid_phone_number\"").is_err());
+
+    assert_eq!(
+        serde_json::to_string(&PhoneNumberType::TollFree).unwrap(),
+        "\"TollFree\""
+    );
+    assert_eq!(
+        serde_json::from_str::<PhoneFormat>("\"RFC3966\"").unwrap(),
+        PhoneFormat::RFC3966
+    );
+}
@@ -0,0 +1,63 @@
+use crate::{
+    emergency_numbers_for_region, is_emergency_number, is_short_code, is_valid_phone_number,
+    short_code_type, Parser, PhoneNumberType,
+};
+
+#[test]
+fn test_is_emergency_number() {
+    assert!(is_emergency_number("112", "DE"));
+    assert!(is_emergency_number("911", "us"));
+    assert!(is_emergency_number("999", "UK"));
+    assert!(is_emergency_number("100", "IN"));
+    assert!(is_emergency_number("10 111", "ZA"));
+    assert!(is_emergency_number("000", "AU"));
+    // emergency numbers are not valid phone numbers
+    assert!(!is_valid_phone_number("112"));
+
+    assert!(!is_emergency_number("911", "DE"));
+    assert!(!is_emergency_number("+49 112", "DE"));
+    assert!(!is_emergency_number("112", "XX"));
+    assert!(!is_emergency_number("", "DE"));
+    // 112 is routed where the emergency numbers aren't known
+    assert_eq!(emergency_numbers_for_region("AD"), None);
+    assert!(is_emergency_number("112", "AD"));
+    assert!(!is_emergency_number("911", "AD"));
+}
+
+#[test]
+fn test_short_code_type() {
+    assert_eq!(
+        short_code_type("112", "FR"),
+        Some(PhoneNumberType::Emergency)
+    );
+    assert_eq!(
+        short_code_type("611", "US"),
+        Some(PhoneNumberType::ShortCode)
+    );
+    // 101 is the police non-emergency number in the UK
+    assert_eq!(
+        short_code_type("101", "GB"),
+        Some(PhoneNumberType::ShortCode)
+    );
+    assert_eq!(short_code_type("611", "FR"), None);
+    assert_eq!(short_code_type("61a", "US"), None);
+
+    assert!(is_short_code("3179", "FR"));
+    assert!(is_short_code("112", "FR"));
+    assert!(!is_short_code("0612345678", "FR"));
+}
+
+#[test]
+fn test_parser_short_codes() {
+    let parser = Parser::new().region_hints(|| Some("GB".to_string()));
+    assert_eq!(parser.number_type("999"), Some(PhoneNumberType::Emergency));
+    assert_eq!(parser.number_type("111"), Some(PhoneNumberType::ShortCode));
+    assert_eq!(
+        parser.number_type("07400 123456"),
+        Some(PhoneNumberType::Mobile)
+    );
+    assert_eq!(parser.number_type("12"), None);
+
+    // short codes are only recognized within a region
+    assert_eq!(Parser::new().number_type("999"), None);
+}
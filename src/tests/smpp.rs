@@ -0,0 +1,41 @@
+use crate::{from_smpp_address, to_smpp_address, Npi, SmppAddress, Ton};
+
+#[test]
+fn test_smpp_address_round_trip() {
+    let address = to_smpp_address("+961 79 123 123").unwrap();
+
+    assert_eq!(address.ton, Ton::International);
+    assert_eq!(address.npi, Npi::Isdn);
+    assert_eq!(address.address, "96179123123");
+    assert_eq!(address.type_of_address(), 0x91);
+    assert_eq!(
+        from_smpp_address(&address),
+        Some("+96179123123".to_string())
+    );
+
+    assert_eq!(to_smpp_address("invalid_phone_number"), None);
+}
+
+#[test]
+fn test_from_smpp_address_needs_international_number() {
+    let address = |ton, npi, address: &str| SmppAddress {
+        ton,
+        npi,
+        address: address.to_string(),
+    };
+
+    assert_eq!(
+        from_smpp_address(&address(Ton::Unknown, Npi::Unknown, "+12025550173")),
+        Some("+12025550173".to_string())
+    );
+    assert_eq!(
+        from_smpp_address(&address(Ton::National, Npi::Isdn, "2025550173")),
+        None
+    );
+    assert_eq!(
+        from_smpp_address(&address(Ton::Alphanumeric, Npi::Unknown, "PHONELIB")),
+        None
+    );
+    assert_eq!(Ton::try_from(5), Ok(Ton::Alphanumeric));
+    assert_eq!(Npi::try_from(2), Err(2));
+}
@@ -0,0 +1,36 @@
+use crate::{extract_country, normalize_phone_number, ParseStats};
+
+#[test]
+fn test_parse_stats() {
+    let (normalized, stats) = ParseStats::collect(|| normalize_phone_number("+961 79 123 123"));
+    assert_eq!(normalized, Some("+96179123123".to_string()));
+    assert_eq!(stats.bytes_scanned, "+961 79 123 123".len());
+    assert!(stats.candidates_considered > 0);
+    assert!(stats.table_lookups > 0);
+
+    // nothing is recorded outside of `collect`, and calls don't leak into the next one
+    normalize_phone_number("+961 79 123 123");
+    let (_, again) = ParseStats::collect(|| normalize_phone_number("+961 79 123 123"));
+    assert_eq!(again, stats);
+
+    // only the countries of the calling codes starting the number are tried
+    assert_eq!(stats.candidates_considered, 1);
+    let (country, invalid) = ParseStats::collect(|| extract_country("+999 1"));
+    assert_eq!(country, None);
+    assert_eq!(invalid.candidates_considered, 0);
+    let (country, shared) = ParseStats::collect(|| extract_country("+7 912 345 6789"));
+    assert_eq!(country.unwrap().code, "RU");
+    assert_eq!(shared.candidates_considered, 2);
+}
+
+#[test]
+fn test_parse_stats_nested() {
+    let ((inner, inner_stats), outer_stats) = ParseStats::collect(|| {
+        normalize_phone_number("+33612345678");
+        ParseStats::collect(|| normalize_phone_number("+96179123123"))
+    });
+
+    assert_eq!(inner, Some("+96179123123".to_string()));
+    assert!(outer_stats.bytes_scanned > inner_stats.bytes_scanned);
+    assert!(outer_stats.table_lookups > inner_stats.table_lookups);
+}
@@ -0,0 +1,50 @@
+use crate::{sync_delta, PhoneNumberSet};
+
+#[test]
+fn test_phone_number_set() {
+    let mut numbers = PhoneNumberSet::new();
+    assert!(numbers.is_empty());
+    assert!(numbers.insert("0033612345678"));
+    assert!(!numbers.insert("+33 6 12 34 56 78"));
+    assert!(!numbers.insert("invalid_phone_number"));
+
+    assert_eq!(numbers.len(), 1);
+    assert!(numbers.contains("+33612345678"));
+    assert!(!numbers.contains("+12025550173"));
+    assert_eq!(numbers.raw("+33 6 12 34 56 78"), Some("0033612345678"));
+    let listed: Vec<(String, &str)> = numbers
+        .iter()
+        .map(|(number, raw)| (number.to_string(), raw))
+        .collect();
+    assert_eq!(listed, [("+33612345678".to_string(), "0033612345678")]);
+}
+
+#[test]
+fn test_sync_delta() {
+    let previous: PhoneNumberSet = ["+33612345678", "+4915112345678", "+12025550173"]
+        .into_iter()
+        .collect();
+    let current = [
+        "+1 202 555 0173",
+        "+33612345678",
+        "+447911123456",
+        "invalid_phone_number",
+        "+44 7911 123456",
+    ]
+    .map(String::from);
+    let delta = sync_delta(&previous, &current);
+
+    assert_eq!(delta.added, ["+447911123456"]);
+    assert_eq!(delta.removed, ["+4915112345678"]);
+    assert_eq!(delta.changed_format, ["+12025550173"]);
+    assert!(!delta.is_empty());
+}
+
+#[test]
+fn test_sync_delta_without_changes() {
+    let current = ["+33612345678".to_string(), "+12025550173".to_string()];
+    let previous: PhoneNumberSet = current.iter().collect();
+
+    assert!(sync_delta(&previous, &current).is_empty());
+    assert_eq!(sync_delta(&PhoneNumberSet::new(), &current).added.len(), 2);
+}
@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use crate::{detect_phone_number_type, Parser, PhoneNumberType, TestNumber, TestNumberRegistry};
+
+#[test]
+fn test_test_number_registry() {
+    let registry = TestNumberRegistry::new();
+    assert!(registry.register("+33 6 12 34 56 78", TestNumber::new("reviewer")));
+    assert!(!registry.register("invalid_phone_number", TestNumber::new("broken")));
+
+    // matched in E.164 form, without extension
+    assert!(registry.is_test_number("+33612345678"));
+    assert!(registry.is_test_number("+33 (6) 12-34-56-78 ext. 9"));
+    assert!(!registry.is_test_number("+33612345679"));
+    assert_eq!(
+        registry.get("+33612345678"),
+        Some(TestNumber {
+            label: "reviewer".to_string(),
+            verification_code: None,
+        })
+    );
+
+    // registering again replaces the registration
+    registry.register(
+        "+33612345678",
+        TestNumber::new("reviewer").verification_code("123456"),
+    );
+    assert_eq!(
+        registry.get("+33612345678").unwrap().verification_code,
+        Some("123456".to_string())
+    );
+    assert_eq!(
+        registry.unregister("+33612345678").unwrap().label,
+        "reviewer"
+    );
+    assert!(!registry.is_test_number("+33612345678"));
+    assert_eq!(registry.unregister("+33612345678"), None);
+}
+
+#[test]
+fn test_parser_flags_test_numbers() {
+    let registry = Arc::new(TestNumberRegistry::new());
+    let parser = Parser::new()
+        .pre_hook(|phone_number| {
+            if let Some(external) = phone_number.strip_prefix("9-") {
+                *phone_number = external.to_string();
+            }
+        })
+        .test_numbers(registry.clone());
+    let plain = Parser::new();
+
+    assert_eq!(
+        parser.number_type("9-+33612345678"),
+        Some(PhoneNumberType::Mobile)
+    );
+    // registered at runtime, after the parser was built
+    registry.register("+33612345678", TestNumber::new("load test"));
+    assert_eq!(
+        parser.number_type("9-+33612345678"),
+        Some(PhoneNumberType::TestNumber)
+    );
+    assert_eq!(
+        parser.test_number("+33612345678").unwrap().label,
+        "load test"
+    );
+
+    // only parsers using the registry flag the number
+    assert_eq!(
+        plain.number_type("+33612345678"),
+        Some(PhoneNumberType::Mobile)
+    );
+    assert_eq!(plain.test_number("+33612345678"), None);
+    assert_eq!(
+        detect_phone_number_type("+33612345678"),
+        Some(PhoneNumberType::Mobile)
+    );
+    assert_eq!(parser.number_type("invalid_phone_number"), None);
+}
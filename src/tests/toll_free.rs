@@ -0,0 +1,23 @@
+use crate::itfs_reachable_from;
+
+#[test]
+fn test_national_toll_free_reachability() {
+    assert_eq!(itfs_reachable_from("+18005550173", "US"), Some(true));
+    assert_eq!(itfs_reachable_from("+18005550173", "JM"), Some(true));
+    assert_eq!(itfs_reachable_from("+18005550173", "GB"), Some(false));
+    assert_eq!(itfs_reachable_from("+448001234567", "GB"), Some(true));
+    assert_eq!(itfs_reachable_from("+448001234567", "FR"), Some(false));
+}
+
+#[test]
+fn test_uifn_reachability() {
+    assert_eq!(itfs_reachable_from("+800 1234 5678", "DE"), Some(true));
+    assert_eq!(itfs_reachable_from("+800 1234 5678", "LB"), None);
+}
+
+#[test]
+fn test_itfs_reachable_from_non_toll_free() {
+    assert_eq!(itfs_reachable_from("+12025550173", "US"), None);
+    assert_eq!(itfs_reachable_from("+18005550173", "XX"), None);
+    assert_eq!(itfs_reachable_from("invalid_phone_number", "US"), None);
+}
@@ -0,0 +1,77 @@
+use crate::{
+    extract_country, is_valid_phone_number, normalize_phone_number,
+    normalize_phone_number_in_region, Parser, PhoneError, PhoneNumber, E164,
+};
+
+#[test]
+fn test_unicode_digits() {
+    // Arabic-Indic, Persian, Devanagari and fullwidth digits
+    assert_eq!(
+        normalize_phone_number("+٩٦١ ٧٩ ١٢٣ ١٢٣"),
+        Some("+96179123123".to_string())
+    );
+    assert_eq!(
+        normalize_phone_number("+۹۸ ۹۱۲ ۳۴۵ ۶۷۸۹"),
+        Some("+989123456789".to_string())
+    );
+    assert_eq!(
+        normalize_phone_number("+९१ ९८७६५ ४३२१०"),
+        Some("+919876543210".to_string())
+    );
+    assert_eq!(
+        E164::parse("+３３ ６ １２ ３４ ５６ ７８").unwrap(),
+        "+33612345678"
+    );
+    assert!(is_valid_phone_number("+٩٦١ ٧٩ ١٢٣ ١٢٣"));
+    assert_eq!(extract_country("+٩٦١٧٩١٢٣١٢٣").unwrap().code, "LB");
+    assert_eq!(
+        normalize_phone_number_in_region("٠٦ ١٢ ٣٤ ٥٦ ٧٨", "FR"),
+        Some("+33612345678".to_string())
+    );
+    // digits mixed from several scripts are still digits
+    assert_eq!(
+        normalize_phone_number("+961 ٧٩ 123 ١٢٣"),
+        Some("+96179123123".to_string())
+    );
+
+    // numeric characters that are not decimal digits are not read as digits
+    assert_eq!(
+        PhoneNumber::try_parse("+961 79 123 12½").unwrap_err(),
+        PhoneError::InvalidCharacter { position: 14 }
+    );
+    assert_eq!(
+        normalize_phone_number("+961 79 123 123²"),
+        Some("+96179123123".to_string())
+    );
+}
+
+#[test]
+fn test_bidi_controls() {
+    // numbers in right-to-left text are wrapped in marks and isolates
+    let marked = "\u{200F}+961 79 123 123\u{200E}";
+    assert!(is_valid_phone_number(marked));
+    assert_eq!(
+        normalize_phone_number(marked),
+        Some("+96179123123".to_string())
+    );
+    assert_eq!(
+        PhoneNumber::try_parse("\u{2067}+٩٦١ ٧٩ ١٢٣ ١٢٣\u{2069}")
+            .unwrap()
+            .e164(),
+        "+96179123123"
+    );
+    assert_eq!(
+        normalize_phone_number_in_region("\u{200F}+33 6 12 34 56 78", "LB"),
+        Some("+33612345678".to_string())
+    );
+
+    let parser = Parser::new().region_hints(|| Some("LB".to_string()));
+    assert_eq!(
+        parser.normalize("\u{200F}+33 6 12 34 56 78"),
+        Some("+33612345678".to_string())
+    );
+    assert_eq!(
+        parser.normalize("٧٩ ١٢٣ ١٢٣"),
+        Some("+96179123123".to_string())
+    );
+}
@@ -0,0 +1,47 @@
+use crate::{validate, ValidationResult};
+
+#[test]
+fn test_validate() {
+    assert_eq!(validate("+33612345678"), ValidationResult::ValidForCountry);
+    assert_eq!(
+        validate("+1 (202) 555-0173"),
+        ValidationResult::ValidForCountry
+    );
+    // no type metadata, the length decides
+    assert_eq!(validate("+96179123123"), ValidationResult::ValidForCountry);
+    assert_eq!(
+        validate("+39 06 1234 5678"),
+        ValidationResult::ValidForCountry
+    );
+    assert_eq!(
+        validate("+39 02 1234 5678"),
+        ValidationResult::ValidForCountry
+    );
+
+    // outside of the number ranges of the country
+    assert_eq!(validate("+33851234567"), ValidationResult::IsPossible);
+    // outside of the area codes of the countries of +262
+    assert_eq!(validate("+262 123 456 789"), ValidationResult::IsPossible);
+
+    assert_eq!(validate("+3361234567"), ValidationResult::InvalidLength);
+    assert_eq!(validate("+336123456789"), ValidationResult::InvalidLength);
+    assert_eq!(
+        validate("+999123456789"),
+        ValidationResult::InvalidCountryCode
+    );
+    assert_eq!(validate(""), ValidationResult::InvalidCountryCode);
+}
+
+#[test]
+fn test_validation_strictness() {
+    for (phone_number, strict, lenient) in [
+        ("+33612345678", true, true),
+        ("+33851234567", false, true),
+        ("+3361234567", false, false),
+        ("+999123456789", false, false),
+    ] {
+        let result = validate(phone_number);
+        assert_eq!(result.is_valid(), strict, "{}", phone_number);
+        assert_eq!(result.is_possible(), lenient, "{}", phone_number);
+    }
+}
@@ -0,0 +1,64 @@
+use crate::{convert_alpha_characters, is_valid_phone_number, Parser, TraceStep};
+
+#[test]
+fn test_convert_alpha_characters() {
+    assert_eq!(convert_alpha_characters("1-800-FLOWERS"), "18003569377");
+    assert_eq!(convert_alpha_characters("1-800-flowers"), "18003569377");
+    assert_eq!(
+        convert_alpha_characters(" +1 (800) GOT-JUNK"),
+        "+18004685865"
+    );
+    assert_eq!(
+        convert_alpha_characters("ABC DEF GHI JKL MNO PQRS TUV WXYZ"),
+        "22233344455566677778889999"
+    );
+    assert_eq!(convert_alpha_characters("0800 123"), "0800123");
+    assert_eq!(convert_alpha_characters("Ω"), "");
+}
+
+#[test]
+fn test_parser_vanity_numbers() {
+    let parser = Parser::new().vanity_numbers(true);
+
+    assert!(!is_valid_phone_number("+1-800-FLOWERS"));
+    assert!(parser.is_valid("+1-800-FLOWERS"));
+    assert_eq!(
+        parser.normalize("+1 800 GOT JUNK"),
+        Some("+18004685865".to_string())
+    );
+    // the extension markers are not letters of the number
+    let number = parser.parse("+1-800-FLOWERS ext. 12").unwrap();
+    assert_eq!(number.e164(), "+18003569377");
+    assert_eq!(number.extension(), Some("12"));
+    assert_eq!(
+        parser.parse("+1-800-FLOWERS x 7").unwrap().extension(),
+        Some("7")
+    );
+    // a word ending with x is not an extension marker
+    assert_eq!(
+        parser.normalize("+1 800 TAX 1234"),
+        Some("+18008291234".to_string())
+    );
+    assert!(parser.is_valid("+1 800 55 FEDEX"));
+
+    // numbers without letters are unaffected
+    assert_eq!(
+        parser.normalize("+33 6 12 34 56 78"),
+        Some("+33612345678".to_string())
+    );
+}
+
+#[test]
+fn test_vanity_numbers_trace() {
+    let parser = Parser::new().vanity_numbers(true).trace(true);
+    let (normalized, trace) = parser.normalize_with_trace("+1-800-FLOWERS");
+
+    assert_eq!(normalized, Some("+18003569377".to_string()));
+    assert_eq!(
+        trace.unwrap().steps[0],
+        TraceStep::LettersConverted {
+            before: "+1-800-FLOWERS".to_string(),
+            after: "+1-800-3569377".to_string(),
+        }
+    );
+}
@@ -0,0 +1,22 @@
+use crate::wasm::{extract, format, is_valid, normalize};
+
+#[test]
+fn test_wasm_bindings() {
+    assert!(is_valid("+96179123123"));
+    assert!(!is_valid("invalid_phone_number"));
+    assert_eq!(
+        normalize("+33 6 12 34 56 78"),
+        Some("+33612345678".to_string())
+    );
+    assert_eq!(
+        format("+33612345678", "International"),
+        Some("+33 6 12 34 56 78".to_string())
+    );
+    assert_eq!(
+        format("+33612345678", "rfc3966"),
+        Some("tel:+33-6-12-34-56-78".to_string())
+    );
+    assert_eq!(format("+33612345678", "pretty"), None);
+    assert_eq!(extract("+12025550173"), Some("US".to_string()));
+    assert_eq!(extract("invalid_phone_number"), None);
+}
@@ -0,0 +1,32 @@
+use crate::number_type::number_type_of;
+use crate::{find_country_by_code, PhoneNumberType, E164};
+
+// regions known to give access to +800 universal international freephone numbers
+const UIFN_REGIONS: &[&str] = &[
+    "AR", "AT", "AU", "BE", "BR", "CA", "CH", "CN", "CO", "DE", "DK", "ES", "FI", "FR", "GB", "HK",
+    "HU", "IE", "IL", "IT", "JP", "KR", "LU", "MY", "NL", "NO", "NZ", "PH", "PL", "PT", "RU", "SE",
+    "SG", "TH", "TW", "US", "ZA",
+];
+
+/// Tells whether a toll-free number can be dialed from `region` (an ISO 3166 code), so a
+/// support page can show the right number to each visitor.
+///
+/// National toll-free numbers are only reachable from their own country (or from any NANP
+/// member for NANP numbers). +800 numbers are reachable from the regions known to take
+/// part in the universal international freephone service, other regions give `None`.
+/// Returns `None` as well for numbers that are not toll-free and unknown regions.
+pub fn itfs_reachable_from(phone_number: &str, region: &str) -> Option<bool> {
+    let number = E164::parse(phone_number)?;
+    let region = find_country_by_code(region)?;
+    if number_type_of(&number) != PhoneNumberType::TollFree {
+        return None;
+    }
+
+    let country = number.country();
+    if country.prefix == 800 {
+        return UIFN_REGIONS.contains(&region.code).then_some(true);
+    }
+
+    let is_nanp = |prefix: u32| prefix.to_string().starts_with('1');
+    Some(country.code == region.code || (is_nanp(country.prefix) && is_nanp(region.prefix)))
+}
@@ -0,0 +1,177 @@
+use crate::definitions::Country;
+use crate::unicode::ascii_digit;
+use crate::{best_fitting_countries, calling_code, extract_country_data, split_extension, E164};
+
+/// Record of the transformations applied while normalizing one input, see
+/// [`crate::Parser::trace`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessingTrace {
+    /// The input as given to the parser, before any hook ran.
+    pub input: String,
+    /// Steps in the order they were applied.
+    pub steps: Vec<TraceStep>,
+    /// The digits matched against the calling codes of the countries: the input digits
+    /// without international prefix, or the calling code of the hinted region followed by
+    /// the national number.
+    pub matched_digits: String,
+}
+
+/// One transformation of a [`ProcessingTrace`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceStep {
+    /// The input is longer than [`crate::Parser::max_input_length`] and was not processed.
+    InputTooLong { length: usize, max_length: usize },
+    /// A pre-hook rewrote the input.
+    PreHook { before: String, after: String },
+    /// The letters of a vanity number were converted to digits, see
+    /// [`crate::Parser::vanity_numbers`].
+    LettersConverted { before: String, after: String },
+    /// The extension was removed, the digits are kept by [`crate::PhoneNumber::extension`].
+    ExtensionRemoved(String),
+    /// Characters other than digits were removed, in input order.
+    CharactersRemoved(String),
+    /// The international dialing prefix was stripped: leading zeros, e.g. `"00"`, or the
+    /// prefix of the hinted region, e.g. `"011"` in the United States.
+    IddStripped(String),
+    /// The number was read as a national number of the region given by the
+    /// [`crate::RegionHintProvider`] of the parser.
+    RegionHintUsed(&'static Country),
+    /// The country was chosen among every country whose calling code and area code start
+    /// the number and whose lengths fit it, matching the most digits. Countries come in
+    /// table order and the first one wins, so `candidates[0]` is `country`.
+    CountryChosen {
+        country: &'static Country,
+        candidates: Vec<&'static Country>,
+    },
+    /// No country has a calling code starting the number with a fitting length.
+    NoCountryFound,
+    /// Zeros after the calling code were removed as a national trunk prefix.
+    TrunkPrefixRemoved(String),
+    /// The national number left without the trunk prefix has no valid length for the
+    /// country, e.g. `"+376 000000"`.
+    InvalidNationalLength,
+    /// A post-hook rewrote the normalized number.
+    PostHook { before: String, after: String },
+    /// The leading `+` was replaced with this international dialing prefix, see
+    /// [`crate::Parser::international_prefix_of`].
+    InternationalPrefixApplied(String),
+}
+
+impl ProcessingTrace {
+    pub(crate) fn new(input: &str) -> ProcessingTrace {
+        ProcessingTrace {
+            input: input.to_string(),
+            steps: Vec::new(),
+            matched_digits: String::new(),
+        }
+    }
+
+    // the input as rewritten by the pre-hooks
+    pub(crate) fn pre_hooks_output(&self) -> &str {
+        self.steps
+            .iter()
+            .rev()
+            .find_map(|step| match step {
+                TraceStep::PreHook { after, .. } => Some(after.as_str()),
+                _ => None,
+            })
+            .unwrap_or(&self.input)
+    }
+}
+
+// same as `normalize_phone_number`, or `E164::parse_dialed_from` when a region is given,
+// recording each step
+pub(crate) fn normalize_traced(
+    phone_number: &str,
+    region: Option<&'static Country>,
+    trace: &mut ProcessingTrace,
+) -> Option<String> {
+    let (phone_number, extension) = split_extension(phone_number);
+    if let Some(extension) = extension {
+        trace.steps.push(TraceStep::ExtensionRemoved(extension));
+    }
+    let mut digits: String = phone_number.chars().filter_map(ascii_digit).collect();
+    let removed: String = phone_number
+        .chars()
+        .filter(|c| ascii_digit(*c).is_none())
+        .collect();
+    if !removed.is_empty() {
+        trace.steps.push(TraceStep::CharactersRemoved(removed));
+    }
+
+    // dialed from the region, the number is international when it starts with the
+    // international prefix of the region or isn't a national number there
+    let idd = region
+        .map(Country::international_prefix)
+        .filter(|idd| digits.starts_with(idd));
+    let national_region = region
+        .filter(|region| idd.is_none() && E164::parse_national(phone_number, region).is_some());
+    match national_region {
+        Some(region) => {
+            trace.steps.push(TraceStep::RegionHintUsed(region));
+            if let Some(prefix) = region
+                .trunk_prefix()
+                .filter(|prefix| digits.starts_with(prefix))
+            {
+                digits.drain(..prefix.len());
+                trace
+                    .steps
+                    .push(TraceStep::TrunkPrefixRemoved(prefix.to_string()));
+            }
+            digits.insert_str(0, &calling_code(region).to_string());
+        }
+        None => {
+            let mut idd: String =
+                idd.map_or(String::new(), |idd| digits.drain(..idd.len()).collect());
+            idd.push_str(&strip_leading_zeros(&mut digits));
+            if !idd.is_empty() {
+                trace.steps.push(TraceStep::IddStripped(idd));
+            }
+        }
+    }
+    trace.matched_digits = digits.clone();
+
+    let Some(country) = extract_country_data(&digits) else {
+        trace.steps.push(TraceStep::NoCountryFound);
+        return None;
+    };
+    trace.steps.push(TraceStep::CountryChosen {
+        country,
+        candidates: best_fitting_countries(&digits),
+    });
+
+    let mut national_number = digits.split_off(country.prefix_len());
+    let trunk_prefix = match country.zero_significant() {
+        true => String::new(),
+        false => strip_leading_zeros(&mut national_number),
+    };
+    if trunk_prefix.is_empty() {
+        return Some(format!("+{}{}", country.prefix, national_number));
+    }
+    trace
+        .steps
+        .push(TraceStep::TrunkPrefixRemoved(trunk_prefix));
+
+    // the country is matched again on the digits left, see `split_national_number`
+    digits.push_str(&national_number);
+    match extract_country_data(&digits) {
+        Some(matched) => {
+            if matched.id != country.id {
+                trace.steps.push(TraceStep::CountryChosen {
+                    country: matched,
+                    candidates: best_fitting_countries(&digits),
+                });
+            }
+            Some(format!("+{}", digits))
+        }
+        None => {
+            trace.steps.push(TraceStep::InvalidNationalLength);
+            None
+        }
+    }
+}
+
+fn strip_leading_zeros(digits: &mut String) -> String {
+    let zeros = digits.len() - digits.trim_start_matches('0').len();
+    digits.drain(..zeros).collect()
+}
@@ -0,0 +1,52 @@
+// Numbers pasted from Arabic, Persian or Hindi text are written with the digits of their
+// script and wrapped in bidi control characters. The cleaners read these digits as ASCII
+// digits and ignore the controls, so such numbers parse like ASCII ones.
+
+// the zero of each block of ten decimal digits (Unicode category Nd) likely in numbers
+#[rustfmt::skip]
+const DIGIT_ZEROS: &[u32] = &[
+    0x0660, // Arabic-Indic
+    0x06F0, // Extended Arabic-Indic, used in Persian and Urdu
+    0x07C0, // NKo
+    0x0966, // Devanagari
+    0x09E6, // Bengali
+    0x0A66, // Gurmukhi
+    0x0AE6, // Gujarati
+    0x0B66, // Oriya
+    0x0BE6, // Tamil
+    0x0C66, // Telugu
+    0x0CE6, // Kannada
+    0x0D66, // Malayalam
+    0x0E50, // Thai
+    0x0ED0, // Lao
+    0x0F20, // Tibetan
+    0x1040, // Myanmar
+    0x17E0, // Khmer
+    0x1810, // Mongolian
+    0xFF10, // fullwidth, from CJK input methods
+];
+
+// the ASCII digit of a decimal digit of any script in `DIGIT_ZEROS`
+pub(crate) fn ascii_digit(c: char) -> Option<char> {
+    if c.is_ascii_digit() {
+        return Some(c);
+    }
+    let code = c as u32;
+    DIGIT_ZEROS
+        .iter()
+        .find(|zero| (**zero..**zero + 10).contains(&code))
+        .map(|zero| (b'0' + (code - zero) as u8) as char)
+}
+
+// the invisible marks setting the direction of text, e.g. the right-to-left mark
+pub(crate) fn is_bidi_control(c: char) -> bool {
+    matches!(
+        c,
+        '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+// the start of a number, after the spaces and bidi controls
+pub(crate) fn trim_start(phone_number: &str) -> &str {
+    phone_number.trim_start_matches(|c: char| c.is_whitespace() || is_bidi_control(c))
+}
@@ -0,0 +1,79 @@
+use crate::constants::COUNTRIES;
+use crate::metadata::BUNDLED;
+use crate::number_type::{has_type_metadata, number_type_of};
+use crate::{calling_code, country_fits_length, remove_unwanted_character, PhoneNumberType, E164};
+
+/// Outcome of [`validate`], from the strictest to the most lenient acceptance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValidationResult {
+    /// The number fits the calling code, area codes and number ranges of its country.
+    ValidForCountry,
+    /// The number has a valid length for a country of its calling code, but isn't in any
+    /// of the known area codes or number ranges of the country.
+    IsPossible,
+    /// The calling code is known, but no country of it has numbers of this length.
+    InvalidLength,
+    /// No country has a calling code starting the number.
+    InvalidCountryCode,
+}
+
+impl ValidationResult {
+    /// True for [`ValidationResult::ValidForCountry`], to reject borderline numbers.
+    pub fn is_valid(self) -> bool {
+        self == ValidationResult::ValidForCountry
+    }
+
+    /// True for [`ValidationResult::ValidForCountry`] and [`ValidationResult::IsPossible`],
+    /// to accept borderline numbers.
+    pub fn is_possible(self) -> bool {
+        matches!(
+            self,
+            ValidationResult::ValidForCountry | ValidationResult::IsPossible
+        )
+    }
+}
+
+/// Validates a phone number in international form, telling plausible numbers apart from
+/// the ones matching the metadata of their country, like the `is_possible_number` and
+/// `is_valid_number` pair of libphonenumber.
+///
+/// Numbers outside the known ranges of their country, whose type is
+/// [`crate::PhoneNumberType::Unknown`], are only possible, see
+/// [`crate::detect_phone_number_type`].
+///
+/// ```
+/// use phonelib::{validate, ValidationResult};
+///
+/// assert_eq!(validate("+33 6 12 34 56 78"), ValidationResult::ValidForCountry);
+/// // no French range starts with 85
+/// assert_eq!(validate("+33 8 51 23 45 67"), ValidationResult::IsPossible);
+/// assert_eq!(validate("+33 6 12 34 56"), ValidationResult::InvalidLength);
+/// assert_eq!(validate("+999 123 456 789"), ValidationResult::InvalidCountryCode);
+/// ```
+pub fn validate(phone_number: &str) -> ValidationResult {
+    if let Some(number) = E164::parse(phone_number) {
+        let in_known_range = !has_type_metadata(calling_code(number.country()))
+            || number_type_of(&number) != PhoneNumberType::Unknown;
+        return match in_known_range {
+            true => ValidationResult::ValidForCountry,
+            false => ValidationResult::IsPossible,
+        };
+    }
+
+    let mut digits = phone_number.to_string();
+    remove_unwanted_character(&mut digits);
+    if COUNTRIES
+        .iter()
+        .any(|country| country_fits_length(&BUNDLED, country, &digits))
+    {
+        // the length fits, the area code doesn't
+        ValidationResult::IsPossible
+    } else if COUNTRIES
+        .iter()
+        .any(|country| digits.starts_with(&country.prefix_str()))
+    {
+        ValidationResult::InvalidLength
+    } else {
+        ValidationResult::InvalidCountryCode
+    }
+}
@@ -0,0 +1,53 @@
+use crate::strip_extension;
+
+// the digit of the phone keypad key of a letter, per ITU-T E.161
+fn keypad_digit(c: char) -> Option<char> {
+    let digit = match c.to_ascii_uppercase() {
+        'A'..='C' => '2',
+        'D'..='F' => '3',
+        'G'..='I' => '4',
+        'J'..='L' => '5',
+        'M'..='O' => '6',
+        'P'..='S' => '7',
+        'T'..='V' => '8',
+        'W'..='Z' => '9',
+        _ => return None,
+    };
+    Some(digit)
+}
+
+/// Converts a vanity number to digits, replacing each letter with the digit of its phone
+/// keypad key and removing the other characters, except a leading `+`.
+///
+/// ```
+/// assert_eq!(phonelib::convert_alpha_characters("1-800-FLOWERS"), "18003569377");
+/// assert_eq!(phonelib::convert_alpha_characters("+44 800 CALL ME"), "+44800225563");
+/// ```
+pub fn convert_alpha_characters(phone_number: &str) -> String {
+    let phone_number = phone_number.trim_start();
+    let plus = phone_number.starts_with('+').then_some('+');
+    plus.into_iter()
+        .chain(
+            phone_number
+                .chars()
+                .filter_map(|c| match c.is_ascii_digit() {
+                    true => Some(c),
+                    false => keypad_digit(c),
+                }),
+        )
+        .collect()
+}
+
+// converts the letters of a vanity number in place, keeping the separators and the
+// extension, e.g. "1-800-FLOWERS ext. 12" to "1-800-3569377 ext. 12"
+pub(crate) fn convert_vanity_letters(phone_number: &mut String) {
+    if !phone_number.bytes().any(|b| b.is_ascii_alphabetic()) {
+        return;
+    }
+    let end = strip_extension(phone_number).len();
+    let converted: String = phone_number[..end]
+        .chars()
+        .map(|c| keypad_digit(c).unwrap_or(c))
+        .collect();
+    phone_number.replace_range(..end, &converted);
+}
@@ -0,0 +1,36 @@
+// JavaScript bindings. The functions are exported by any cdylib built for wasm32 linking
+// the crate with the `wasm` feature, the README shows how to call them from a browser.
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::{extract_country, format_phone_number, normalize_phone_number, PhoneFormat};
+
+#[wasm_bindgen(js_name = isValidPhoneNumber)]
+pub fn is_valid(phone_number: &str) -> bool {
+    crate::is_valid_phone_number(phone_number)
+}
+
+#[wasm_bindgen(js_name = normalizePhoneNumber)]
+pub fn normalize(phone_number: &str) -> Option<String> {
+    normalize_phone_number(phone_number)
+}
+
+/// `format` is one of `"e164"`, `"international"`, `"national"` and `"rfc3966"`, in any
+/// case. Returns `undefined` for invalid numbers and unknown formats.
+#[wasm_bindgen(js_name = formatPhoneNumber)]
+pub fn format(phone_number: &str, format: &str) -> Option<String> {
+    let format = match format.to_ascii_lowercase().as_str() {
+        "e164" => PhoneFormat::E164,
+        "international" => PhoneFormat::International,
+        "national" => PhoneFormat::National,
+        "rfc3966" => PhoneFormat::RFC3966,
+        _ => return None,
+    };
+    format_phone_number(phone_number, format)
+}
+
+/// Returns the ISO 3166 code of the country of the number.
+#[wasm_bindgen(js_name = extractCountry)]
+pub fn extract(phone_number: &str) -> Option<String> {
+    extract_country(phone_number).map(|country| country.code.to_string())
+}